@@ -1,10 +1,8 @@
 use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
-use std::thread::sleep;
-use std::time::Duration;
 
 use korangar_debug::logging::Colorize;
-use korangar_networking::{DisconnectReason, NetworkEvent, NetworkingSystem, SupportedPacketVersion};
+use korangar_networking::{HeadlessClient, NetworkEvent, PasswordEncryption, SupportedPacketVersion};
 use reqwest::StatusCode;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -70,136 +68,76 @@ async fn main() {
     const PASSWORD: &str = "password";
     const CHARACTER_NAME: &str = "character name";
 
-    // Create the networking system and HTTP client.
-    let (mut networking_system, mut network_event_buffer) = NetworkingSystem::spawn();
+    // Create the headless client and HTTP client.
+    let mut bot = HeadlessClient::new(PACKET_VERSION, SOCKET_ADDR);
     let client = reqwest::Client::new();
 
     // Persistent data.
-    let mut saved_login_data = None;
     let mut message_history = MessageHistory { hash_map: HashMap::new() };
 
-    // Kick of the bot by connecting to the login server.
-    networking_system.connect_to_login_server(PACKET_VERSION, SOCKET_ADDR, USERNAME.to_owned(), PASSWORD.to_owned());
+    // Log in and select the character.
+    bot.login(USERNAME, PASSWORD, PasswordEncryption::None)
+        .await
+        .expect("Failed to log in");
 
-    loop {
-        networking_system.get_events(&mut network_event_buffer);
-
-        for event in network_event_buffer.drain() {
-            match event {
-                NetworkEvent::LoginServerConnected {
-                    character_servers,
-                    login_data,
-                } => {
-                    println!("[{}] Successfully connected to login server", "Setup".green());
+    println!("[{}] Successfully connected to character server", "Setup".green());
 
-                    networking_system.disconnect_from_login_server();
-                    networking_system.connect_to_character_server(PACKET_VERSION, &login_data, character_servers[0].clone());
+    bot.select_character(CHARACTER_NAME).await.expect("Failed to select character");
 
-                    saved_login_data = Some(login_data);
-                }
-                NetworkEvent::LoginServerConnectionFailed { message, .. } => {
-                    panic!("Failed to connect to login server: {}", message);
-                }
-                NetworkEvent::LoginServerDisconnected {
-                    reason: DisconnectReason::ConnectionError,
-                } => {
-                    panic!("Login server connection error");
-                }
-                NetworkEvent::CharacterServerConnected { .. } => {
-                    println!("[{}] Successfully connected to character server", "Setup".green());
+    println!("[{}] Using character: {}", "Setup".green(), CHARACTER_NAME.green());
 
-                    networking_system.request_character_list().expect("Character server disconnected");
-                }
-                NetworkEvent::CharacterServerConnectionFailed { message, .. } => {
-                    panic!("Failed to connect to character server: {}", message);
-                }
-                NetworkEvent::CharacterServerDisconnected {
-                    reason: DisconnectReason::ConnectionError,
-                } => {
-                    panic!("Character server connection error");
-                }
-                NetworkEvent::CharacterSelectionFailed { message, .. } => {
-                    panic!("Failed to select character: {}", message);
-                }
-                NetworkEvent::MapServerDisconnected {
-                    reason: DisconnectReason::ConnectionError,
-                } => {
-                    panic!("Map server connection error");
-                }
-                NetworkEvent::CharacterList { characters } => {
-                    let character_slot = characters
-                        .iter()
-                        .find(|character| character.name == CHARACTER_NAME)
-                        .unwrap_or_else(|| panic!("Character with name \"{}\" not found for this user", CHARACTER_NAME))
-                        .character_number as usize;
-
-                    println!("[{}] Using character in slot: {}", "Setup".green(), character_slot.green());
-
-                    networking_system
-                        .select_character(character_slot)
-                        .expect("Character server disconnected");
+    loop {
+        match bot.next_event().await {
+            NetworkEvent::ChatMessage { text, .. } => {
+                if text.starts_with(CHARACTER_NAME) {
+                    continue;
                 }
-                NetworkEvent::CharacterSelected { login_data, .. } => {
-                    let login_login_data = saved_login_data.as_ref().unwrap();
-
-                    networking_system.disconnect_from_character_server();
-                    networking_system.connect_to_map_server(PACKET_VERSION, login_login_data, login_data);
 
-                    networking_system.map_loaded().expect("Map server disconnected");
-                }
-                NetworkEvent::ChatMessage { text, .. } => {
-                    if text.starts_with(CHARACTER_NAME) {
-                        continue;
-                    }
+                let Some((user, message)) = text.split_once(" : ") else {
+                    continue;
+                };
 
-                    let Some((user, message)) = text.split_once(" : ") else {
-                        continue;
-                    };
+                println!("[{}] Received Message by user: {}", "Chatbot".cyan(), user.yellow());
+                println!("[{}] Message content: {}", "Chatbot".cyan(), message.yellow());
+                println!("[{}] Generating response..", "LLaMA".magenta());
 
-                    println!("[{}] Received Message by user: {}", "Chatbot".cyan(), user.yellow());
-                    println!("[{}] Message content: {}", "Chatbot".cyan(), message.yellow());
-                    println!("[{}] Generating response..", "LLaMA".magenta());
+                let previous_messages = message_history.get_message_history_with(user.to_owned());
 
-                    let previous_messages = message_history.get_message_history_with(user.to_owned());
+                previous_messages.push(Message {
+                    role: "user".to_owned(),
+                    content: message.to_owned(),
+                });
+
+                let result = client
+                    .post(OLLAMA_ENDPOINT)
+                    .json(&Request {
+                        model: OLLAMA_MODEL.to_owned(),
+                        messages: previous_messages.clone(),
+                        stream: false,
+                    })
+                    .send()
+                    .await
+                    .expect("failed to send request to ollama");
+
+                if result.status() == StatusCode::OK {
+                    let response: Response = result.json().await.unwrap();
+                    let response = &response.message;
+
+                    println!("[{}] Generated response: {}", "LLaMA".magenta(), response.content.yellow());
+                    println!("[{}] Sending response..", "Chatbot".cyan());
 
                     previous_messages.push(Message {
-                        role: "user".to_owned(),
-                        content: message.to_owned(),
+                        role: response.role.to_owned(),
+                        content: response.content.to_owned(),
                     });
 
-                    let result = client
-                        .post(OLLAMA_ENDPOINT)
-                        .json(&Request {
-                            model: OLLAMA_MODEL.to_owned(),
-                            messages: previous_messages.clone(),
-                            stream: false,
-                        })
-                        .send()
-                        .await
-                        .expect("failed to send request to ollama");
-
-                    if result.status() == StatusCode::OK {
-                        let response: Response = result.json().await.unwrap();
-                        let response = &response.message;
-
-                        println!("[{}] Generated response: {}", "LLaMA".magenta(), response.content.yellow());
-                        println!("[{}] Sending response..", "Chatbot".cyan());
-
-                        previous_messages.push(Message {
-                            role: response.role.to_owned(),
-                            content: response.content.to_owned(),
-                        });
-
-                        networking_system
-                            .send_chat_message(CHARACTER_NAME, &response.content)
-                            .expect("Map server disconnected");
-                    }
+                    bot.chat(&response.content).expect("Map server disconnected");
                 }
-                _ => {}
             }
+            NetworkEvent::MapServerDisconnected { reason } => {
+                panic!("Map server disconnected: {:?}", reason);
+            }
+            _ => {}
         }
-
-        // After processing events, sleep for a bit.
-        sleep(Duration::from_millis(200));
     }
 }
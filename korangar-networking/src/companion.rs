@@ -0,0 +1,159 @@
+use ragnarok_packets::{HomunculusInformationPacket, MercenaryInformationPacket, PetInformationPacket};
+
+/// The kind of companion an entity is. Companions are sent as regular entities
+/// and only differ in their object type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompanionType {
+    Pet,
+    Homunculus,
+    Mercenary,
+}
+
+impl CompanionType {
+    pub fn from_object_type(object_type: u8) -> Option<Self> {
+        match object_type {
+            7 => Some(Self::Pet),
+            8 => Some(Self::Homunculus),
+            9 => Some(Self::Mercenary),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct PetInformation {
+    pub name: String,
+    /// A pet can only be renamed once.
+    pub renamed: bool,
+    pub level: u16,
+    /// Ranges from 0 to 100.
+    pub hunger: u16,
+    /// Ranges from 0 to 1000.
+    pub intimacy: u16,
+    /// Item id of the equipped accessory, `0` if there is none.
+    pub accessory: u16,
+    pub job: u16,
+}
+
+impl From<PetInformationPacket> for PetInformation {
+    fn from(packet: PetInformationPacket) -> Self {
+        Self {
+            name: packet.name,
+            renamed: packet.renamed != 0,
+            level: packet.level,
+            hunger: packet.hunger,
+            intimacy: packet.intimacy,
+            accessory: packet.accessory,
+            job: packet.job,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct HomunculusInformation {
+    pub name: String,
+    /// A homunculus can only be renamed once.
+    pub renamed: bool,
+    pub vaporized: bool,
+    pub dead: bool,
+    pub level: u16,
+    /// Ranges from 0 to 100.
+    pub hunger: u16,
+    /// Ranges from 0 to 1000.
+    pub intimacy: u16,
+    pub attack: u16,
+    pub magic_attack: u16,
+    pub hit: u16,
+    pub critical: u16,
+    pub defense: u16,
+    pub magic_defense: u16,
+    pub flee: u16,
+    pub attack_speed: u16,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+    pub spell_points: u32,
+    pub maximum_spell_points: u32,
+    pub experience: u64,
+    pub next_level_experience: u64,
+    pub skill_points: u16,
+}
+
+impl From<HomunculusInformationPacket> for HomunculusInformation {
+    fn from(packet: HomunculusInformationPacket) -> Self {
+        Self {
+            name: packet.name,
+            renamed: packet.flags & 0b001 != 0,
+            vaporized: packet.flags & 0b010 != 0,
+            dead: packet.flags & 0b100 != 0,
+            level: packet.level,
+            hunger: packet.hunger,
+            intimacy: packet.intimacy,
+            attack: packet.attack,
+            magic_attack: packet.magic_attack,
+            hit: packet.hit,
+            critical: packet.critical,
+            defense: packet.defense,
+            magic_defense: packet.magic_defense,
+            flee: packet.flee,
+            attack_speed: packet.attack_speed,
+            health_points: packet.health_points,
+            maximum_health_points: packet.maximum_health_points,
+            spell_points: packet.spell_points as u32,
+            maximum_spell_points: packet.maximum_spell_points as u32,
+            experience: packet.experience,
+            next_level_experience: packet.next_level_experience,
+            skill_points: packet.skill_points,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MercenaryInformation {
+    pub name: String,
+    pub level: u16,
+    pub attack: u16,
+    pub magic_attack: u16,
+    pub hit: u16,
+    pub critical: u16,
+    pub defense: u16,
+    pub magic_defense: u16,
+    pub flee: u16,
+    pub attack_speed: u16,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+    pub spell_points: u32,
+    pub maximum_spell_points: u32,
+    /// Unix timestamp of when the contract of the mercenary ends.
+    pub expiration_time: u32,
+    pub faith: u16,
+    pub call_count: u32,
+    pub kill_count: u32,
+}
+
+impl From<MercenaryInformationPacket> for MercenaryInformation {
+    fn from(packet: MercenaryInformationPacket) -> Self {
+        Self {
+            name: packet.name,
+            level: packet.level,
+            attack: packet.attack,
+            magic_attack: packet.magic_attack,
+            hit: packet.hit,
+            critical: packet.critical,
+            defense: packet.defense,
+            magic_defense: packet.magic_defense,
+            flee: packet.flee,
+            attack_speed: packet.attack_speed,
+            health_points: packet.health_points,
+            maximum_health_points: packet.maximum_health_points,
+            spell_points: packet.spell_points,
+            maximum_spell_points: packet.maximum_spell_points,
+            expiration_time: packet.expiration_time,
+            faith: packet.faith,
+            call_count: packet.call_count,
+            kill_count: packet.kill_count,
+        }
+    }
+}
@@ -1,6 +1,96 @@
 use ragnarok_packets::*;
 
-#[derive(Debug)]
+use crate::CompanionType;
+
+/// View ids of the equipment that is visible on a character.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EquipmentLook {
+    pub weapon: u32,
+    pub shield: u32,
+    pub head_top: u16,
+    pub head_middle: u16,
+    pub head_bottom: u16,
+    pub robe: u16,
+}
+
+/// A change to a single part of an [`EquipmentLook`].
+#[derive(Debug, Clone, Copy)]
+pub enum EquipmentLookChange {
+    /// The weapon and shield are always sent together.
+    Weapon {
+        weapon: u32,
+        shield: u32,
+    },
+    HeadTop(u16),
+    HeadMiddle(u16),
+    HeadBottom(u16),
+    Robe(u16),
+}
+
+impl EquipmentLook {
+    pub fn apply(&mut self, change: EquipmentLookChange) {
+        match change {
+            EquipmentLookChange::Weapon { weapon, shield } => {
+                self.weapon = weapon;
+                self.shield = shield;
+            }
+            EquipmentLookChange::HeadTop(head_top) => self.head_top = head_top,
+            EquipmentLookChange::HeadMiddle(head_middle) => self.head_middle = head_middle,
+            EquipmentLookChange::HeadBottom(head_bottom) => self.head_bottom = head_bottom,
+            EquipmentLookChange::Robe(robe) => self.robe = robe,
+        }
+    }
+}
+
+/// Condition of the body of an entity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BodyState {
+    #[default]
+    Normal,
+    /// Turned to stone by Stone Curse.
+    Stone,
+    Frozen,
+    Stunned,
+    Sleeping,
+    /// Slowly turning to stone. The entity can still move and act.
+    Petrifying,
+}
+
+impl From<u16> for BodyState {
+    fn from(value: u16) -> Self {
+        match value {
+            1 => BodyState::Stone,
+            2 => BodyState::Frozen,
+            3 => BodyState::Stunned,
+            4 => BodyState::Sleeping,
+            6 => BodyState::Petrifying,
+            _ => BodyState::Normal,
+        }
+    }
+}
+
+/// Options of an entity that make it invisible to others: hiding, cloaking,
+/// invisibility and chase walk.
+const HIDDEN_OPTIONS: u32 = 0x2 | 0x4 | 0x40 | 0x4000;
+
+/// State of an entity that changes how it is displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EntityState {
+    pub body_state: BodyState,
+    /// The entity is hiding or cloaking.
+    pub hidden: bool,
+}
+
+impl EntityState {
+    pub fn new(body_state: u16, effect_state: u32) -> Self {
+        Self {
+            body_state: body_state.into(),
+            hidden: effect_state & HIDDEN_OPTIONS != 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct EntityData {
     pub entity_id: EntityId,
     pub movement_speed: u16,
@@ -12,6 +102,16 @@ pub struct EntityData {
     pub maximum_health_points: i32,
     pub head_direction: usize,
     pub sex: Sex,
+    pub guild_id: Option<GuildId>,
+    pub emblem_id: u32,
+    pub equipment_look: EquipmentLook,
+    /// Palette index of the hair. `0` is the default palette of the sprite.
+    pub hair_color: u16,
+    /// Palette index of the body. `0` is the default palette of the sprite.
+    pub clothes_color: u16,
+    /// Set if the entity is a pet, homunculus or mercenary.
+    pub companion_type: Option<CompanionType>,
+    pub state: EntityState,
 }
 
 impl EntityData {
@@ -27,6 +127,20 @@ impl EntityData {
             maximum_health_points: character_information.maximum_health_points as i32,
             head_direction: 0, // TODO: get correct rotation
             sex: character_information.sex,
+            guild_id: None,
+            emblem_id: 0,
+            equipment_look: EquipmentLook {
+                weapon: character_information.weapon as u32,
+                shield: character_information.shield as u32,
+                head_top: character_information.head_top as u16,
+                head_middle: character_information.head_middle as u16,
+                head_bottom: character_information.head_bottom as u16,
+                robe: character_information.robe as u16,
+            },
+            hair_color: character_information.head_palette as u16,
+            clothes_color: character_information.body_palette as u16,
+            companion_type: None,
+            state: EntityState::default(),
         }
     }
 }
@@ -44,6 +158,20 @@ impl From<EntityAppearedPacket> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            guild_id: (packet.guild_id != 0).then_some(GuildId(packet.guild_id)),
+            emblem_id: packet.emblem_version as u32,
+            equipment_look: EquipmentLook {
+                weapon: packet.weapon,
+                shield: packet.shield,
+                head_top: packet.head_top,
+                head_middle: packet.head_middle,
+                head_bottom: packet.head_bottom,
+                robe: packet.robe,
+            },
+            hair_color: packet.head_palette,
+            clothes_color: packet.body_palette,
+            companion_type: CompanionType::from_object_type(packet.object_type),
+            state: EntityState::new(packet.body_state, packet.effect_state),
         }
     }
 }
@@ -61,6 +189,20 @@ impl From<EntityAppeared2Packet> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            guild_id: (packet.guild_id != 0).then_some(GuildId(packet.guild_id)),
+            emblem_id: packet.emblem_version as u32,
+            equipment_look: EquipmentLook {
+                weapon: packet.weapon,
+                shield: packet.shield,
+                head_top: packet.head_top,
+                head_middle: packet.head_middle,
+                head_bottom: packet.head_bottom,
+                robe: packet.robe,
+            },
+            hair_color: packet.head_palette,
+            clothes_color: packet.body_palette,
+            companion_type: CompanionType::from_object_type(packet.object_type),
+            state: EntityState::new(packet.body_state, packet.effect_state),
         }
     }
 }
@@ -80,6 +222,20 @@ impl From<MovingEntityAppearedPacket> for EntityData {
             maximum_health_points: packet.maximum_health_points,
             head_direction: packet.head_direction as usize,
             sex: packet.sex,
+            guild_id: (packet.guild_id != 0).then_some(GuildId(packet.guild_id)),
+            emblem_id: packet.emblem_version as u32,
+            equipment_look: EquipmentLook {
+                weapon: packet.weapon,
+                shield: packet.shield,
+                head_top: packet.head_top,
+                head_middle: packet.head_middle,
+                head_bottom: packet.head_bottom,
+                robe: packet.robe,
+            },
+            hair_color: packet.head_palette,
+            clothes_color: packet.body_palette,
+            companion_type: CompanionType::from_object_type(packet.object_type),
+            state: EntityState::new(packet.body_state, packet.effect_state),
         }
     }
 }
@@ -3,9 +3,10 @@ use std::time::Instant;
 use ragnarok_packets::*;
 
 use crate::hotkey::HotkeyState;
-use crate::items::ShopItem;
+use crate::items::{ShopItem, VendingItem};
 use crate::{
-    CharacterServerLoginData, EntityData, InventoryItem, LoginServerLoginData, MessageColor, NoMetadata,
+    CharacterServerLoginData, EntityData, EntityState, EquipmentLookChange, HomunculusInformation, InventoryItem, LoginServerLoginData,
+    MailSummary, MercenaryInformation, MessageColor, NoMetadata, PartyMember, PetInformation, StatusEffect,
     UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
 
@@ -23,6 +24,12 @@ pub enum NetworkEvent {
     LoginServerDisconnected {
         reason: DisconnectReason,
     },
+    /// The login server sent the salt for logging in with a hashed password.
+    /// Use [`NetworkingSystem::send_hashed_login`](crate::NetworkingSystem::send_hashed_login)
+    /// to continue the login.
+    LoginServerPasswordSalt {
+        salt: Vec<u8>,
+    },
     CharacterServerConnected {
         normal_slot_count: usize,
     },
@@ -61,6 +68,14 @@ pub enum NetworkEvent {
     MapServerDisconnected {
         reason: DisconnectReason,
     },
+    /// The connection to the map server was lost and the client is trying to
+    /// log back in. If all attempts fail,
+    /// [`NetworkEvent::MapServerDisconnected`] is sent.
+    MapServerReconnecting {
+        attempt: usize,
+    },
+    /// Logged back in to the map server after the connection was lost.
+    MapServerReconnected,
     /// Initial player status.
     InitialStats {
         strength_stat_points_cost: u8,
@@ -185,13 +200,69 @@ pub enum NetworkEvent {
         account_id: AccountId,
         hair_id: u32,
     },
+    ChangeHairColor {
+        account_id: AccountId,
+        hair_color: u16,
+    },
+    ChangeClothesColor {
+        account_id: AccountId,
+        clothes_color: u16,
+    },
+    ChangeEquipmentLook {
+        account_id: AccountId,
+        change: EquipmentLookChange,
+    },
     LoggedOut,
     FriendRequest {
         requestee: Friend,
     },
     VisualEffect {
-        effect_path: &'static str,
+        effect: VisualEffect,
+        entity_id: EntityId,
+    },
+    SpecialEffect {
+        effect_id: EffectId,
+        entity_id: EntityId,
+    },
+    /// A status effect was added to an entity or its duration was refreshed.
+    AddStatusEffect {
+        entity_id: EntityId,
+        status_effect: StatusEffect,
+    },
+    RemoveStatusEffect {
         entity_id: EntityId,
+        status_effect_id: StatusEffectId,
+    },
+    /// The body state or the options of an entity changed.
+    ChangeEntityState {
+        entity_id: EntityId,
+        state: EntityState,
+    },
+    /// An entity started casting a skill. The position is only set if the
+    /// skill targets the ground.
+    SkillCast {
+        source_entity_id: EntityId,
+        destination_entity_id: EntityId,
+        position: Option<TilePosition>,
+        skill_id: SkillId,
+        element: u32,
+        cast_time: u32,
+    },
+    /// An entity stopped casting before the cast time was over.
+    CastCancel {
+        entity_id: EntityId,
+    },
+    /// A skill was used on an entity.
+    SkillEffect {
+        skill_id: SkillId,
+        source_entity_id: EntityId,
+        destination_entity_id: EntityId,
+    },
+    /// A skill was used on the ground.
+    GroundSkillEffect {
+        skill_id: SkillId,
+        entity_id: EntityId,
+        position: TilePosition,
     },
     AddSkillUnit {
         entity_id: EntityId,
@@ -211,6 +282,141 @@ pub enum NetworkEvent {
         account_id: AccountId,
         character_id: CharacterId,
     },
+    PartyInvitation {
+        party_id: PartyId,
+        party_name: String,
+    },
+    SetParty {
+        party_name: String,
+        members: Vec<PartyMember>,
+    },
+    UpdatePartyMember {
+        party_name: String,
+        member: PartyMember,
+        item_pickup_rule: PartyItemShare,
+        item_distribution_rule: PartyItemShare,
+    },
+    /// A character left the party or was expelled from it. This may also be
+    /// the player themselves.
+    PartyMemberLeft {
+        account_id: AccountId,
+        name: String,
+    },
+    UpdatePartyMemberHealth {
+        account_id: AccountId,
+        health_points: u32,
+        maximum_health_points: u32,
+    },
+    UpdatePartyMemberPosition {
+        account_id: AccountId,
+        position: TilePosition,
+    },
+    UpdatePartyOptions {
+        experience_share: PartyExperienceShare,
+        item_pickup_rule: PartyItemShare,
+        item_distribution_rule: PartyItemShare,
+    },
+    PartyLeaderChanged {
+        old_leader_account_id: AccountId,
+        new_leader_account_id: AccountId,
+    },
+    /// Provides the guild of the player when entering the map server.
+    SetPlayerGuild {
+        guild_id: GuildId,
+        guild_name: String,
+        emblem_id: u32,
+        is_master: bool,
+    },
+    SetGuildInformation {
+        information: GuildInformation,
+    },
+    SetGuildRelations {
+        relations: Vec<GuildRelation>,
+    },
+    SetGuildMembers {
+        members: Vec<GuildMemberInformation>,
+    },
+    SetGuildPositions {
+        positions: Vec<GuildPositionInformation>,
+    },
+    SetGuildPositionNames {
+        names: Vec<GuildPositionName>,
+    },
+    SetGuildNotice {
+        subject: String,
+        notice: String,
+    },
+    /// The zlib compressed bitmap of a guild emblem.
+    GuildEmblem {
+        guild_id: GuildId,
+        emblem_id: u32,
+        emblem_data: Vec<u8>,
+    },
+    EntityGuildChanged {
+        entity_id: EntityId,
+        guild_id: Option<GuildId>,
+        emblem_id: u32,
+    },
+    GuildMemberStatus {
+        account_id: AccountId,
+        character_id: CharacterId,
+        online: bool,
+    },
+    CharacterName {
+        character_id: CharacterId,
+        name: String,
+    },
+    TradeRequest {
+        character_name: String,
+        character_id: CharacterId,
+        base_level: u16,
+    },
+    TradeStarted {
+        character_id: CharacterId,
+        base_level: u16,
+    },
+    /// An item or zeny offered by the player was added to the trade. The index
+    /// is `None` for zeny.
+    OwnTradeItemAdded {
+        index: Option<InventoryIndex>,
+    },
+    /// An item or zeny offered by the player could not be added to the trade.
+    /// The index is `None` for zeny.
+    OwnTradeItemRejected {
+        index: Option<InventoryIndex>,
+    },
+    /// The trade partner offered an item. Since the server does not send an
+    /// inventory index for these items, the index is always 0.
+    PartnerTradeItemAdded {
+        item: InventoryItem<NoMetadata>,
+    },
+    PartnerTradeZenyAdded {
+        amount: u32,
+    },
+    TradeLocked {
+        participant: TradeParticipant,
+    },
+    TradeCancelled,
+    TradeCompleted,
+    /// The storage was opened. The index of every item is the storage index
+    /// rather than an inventory index.
+    SetStorage {
+        items: Vec<InventoryItem<NoMetadata>>,
+    },
+    StorageItemCount {
+        item_count: u16,
+        maximum_item_count: u16,
+    },
+    /// An item was added to the storage. The index of the item is the storage
+    /// index rather than an inventory index.
+    StorageItemAdded {
+        item: InventoryItem<NoMetadata>,
+    },
+    StorageItemRemoved {
+        index: InventoryIndex,
+        amount: u32,
+    },
+    StorageClosed,
     SetHotkeyData {
         tab: HotbarTab,
         hotkeys: Vec<HotkeyState>,
@@ -241,6 +447,163 @@ pub enum NetworkEvent {
         player_position: TilePosition,
         attack_range: AttackRange,
     },
+    /// The player used a pet incubator and has to pick the egg to hatch.
+    PetEggList {
+        eggs: Vec<InventoryIndex>,
+    },
+    /// The pet of the player was spawned.
+    SetPet {
+        entity_id: EntityId,
+    },
+    UpdatePetInformation {
+        information: PetInformation,
+    },
+    UpdatePetHunger {
+        hunger: u16,
+    },
+    UpdatePetIntimacy {
+        intimacy: u16,
+    },
+    PetFed {
+        success: bool,
+        item_id: ItemId,
+    },
+    /// The homunculus of the player was spawned.
+    SetHomunculus {
+        entity_id: EntityId,
+    },
+    UpdateHomunculusInformation {
+        information: HomunculusInformation,
+    },
+    UpdateHomunculusHunger {
+        hunger: u16,
+    },
+    UpdateHomunculusIntimacy {
+        intimacy: u16,
+    },
+    HomunculusSkillTree {
+        skill_information: Vec<SkillInformation>,
+    },
+    HomunculusFed {
+        success: bool,
+        item_id: ItemId,
+    },
+    /// The mercenary of the player was summoned or its information was
+    /// requested.
+    UpdateMercenaryInformation {
+        entity_id: EntityId,
+        information: MercenaryInformation,
+    },
+    /// A single stat of the mercenary changed. Only health and spell points
+    /// are forwarded.
+    UpdateMercenaryStat {
+        stat_type: StatType,
+    },
+    MercenarySkillTree {
+        skill_information: Vec<SkillInformation>,
+    },
+    /// The items in the cart of the player. Sent when loading onto a map or
+    /// when the player gets a cart.
+    SetCart {
+        items: Vec<InventoryItem<NoMetadata>>,
+    },
+    CartItemCount {
+        item_count: u16,
+        maximum_item_count: u16,
+        weight: u32,
+        maximum_weight: u32,
+    },
+    CartItemAdded {
+        item: InventoryItem<NoMetadata>,
+    },
+    CartItemRemoved {
+        index: InventoryIndex,
+        amount: u32,
+    },
+    /// The player no longer has a cart.
+    CartRemoved,
+    /// The cart of an entity changed. A cart type of `0` means that the entity
+    /// has no cart.
+    ChangeCart {
+        entity_id: EntityId,
+        cart_type: u32,
+    },
+    /// The player used the vending skill and can now pick the items to sell.
+    OpenVendingSetup {
+        maximum_item_count: u16,
+    },
+    /// The vending shop of the player was opened.
+    VendingOpened {
+        items: Vec<VendingItem<NoMetadata>>,
+    },
+    /// Another player bought an item from the vending shop of the player.
+    VendingItemSold {
+        index: InventoryIndex,
+        amount: u16,
+        zeny: u32,
+    },
+    /// A player nearby opened a vending shop or came into view with an open
+    /// shop.
+    VendingShopOpened {
+        entity_id: EntityId,
+        shop_title: String,
+    },
+    VendingShopClosed {
+        entity_id: EntityId,
+    },
+    /// The items of the vending shop the player clicked on.
+    VendingItemList {
+        entity_id: EntityId,
+        vending_id: u32,
+        items: Vec<VendingItem<NoMetadata>>,
+    },
+    /// Sent when logging in and when the player receives a new mail.
+    MailNotification {
+        has_new_mail: bool,
+    },
+    /// The newest mails in the mailbox of the player.
+    MailList {
+        mails: Vec<MailSummary>,
+    },
+    /// The contents of a mail the player opened. The index of every item is
+    /// its position in the mail.
+    MailRead {
+        open_type: MailOpenType,
+        mail_id: MailId,
+        text: String,
+        zeny: u64,
+        items: Vec<InventoryItem<NoMetadata>>,
+    },
+    MailZenyRetrieved {
+        mail_id: MailId,
+    },
+    MailItemsRetrieved {
+        mail_id: MailId,
+    },
+    MailDeleted {
+        mail_id: MailId,
+    },
+    /// An item was attached to the mail that is being written. The amount of
+    /// the item is the total amount that is attached and the weight is the
+    /// weight of all attached items.
+    MailItemAttached {
+        item: InventoryItem<NoMetadata>,
+        weight: u16,
+    },
+    /// An item was detached from the mail that is being written. The amount
+    /// is the amount that is still attached.
+    MailItemDetached {
+        index: InventoryIndex,
+        amount: u16,
+        weight: u16,
+    },
+    /// The character a mail should be sent to. There is no character id if no
+    /// character with that name exists.
+    MailReceiver {
+        character_id: Option<CharacterId>,
+        name: String,
+    },
+    MailSent,
 }
 
 /// New-type so we can implement some `From` traits. This will help when
@@ -281,30 +644,6 @@ impl From<NoNetworkEvents> for NetworkEventList {
 pub enum DisconnectReason {
     ClosedByClient,
     ConnectionError,
-}
-
-pub(crate) trait DisconnectedEvent {
-    fn create_event(reason: DisconnectReason) -> NetworkEvent;
-}
-
-pub(crate) struct LoginServerDisconnectedEvent;
-pub(crate) struct CharacterServerDisconnectedEvent;
-pub(crate) struct MapServerDisconnectedEvent;
-
-impl DisconnectedEvent for LoginServerDisconnectedEvent {
-    fn create_event(reason: DisconnectReason) -> NetworkEvent {
-        NetworkEvent::LoginServerDisconnected { reason }
-    }
-}
-
-impl DisconnectedEvent for CharacterServerDisconnectedEvent {
-    fn create_event(reason: DisconnectReason) -> NetworkEvent {
-        NetworkEvent::CharacterServerDisconnected { reason }
-    }
-}
-
-impl DisconnectedEvent for MapServerDisconnectedEvent {
-    fn create_event(reason: DisconnectReason) -> NetworkEvent {
-        NetworkEvent::MapServerDisconnected { reason }
-    }
+    /// The server stopped replying to keep-alive packets.
+    TimedOut,
 }
@@ -0,0 +1,390 @@
+mod state;
+
+use std::collections::VecDeque;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::handler::{NoPacketCallback, PacketCallback};
+use ragnarok_packets::{Direction, TilePosition, WorldPosition};
+
+pub use self::state::GameState;
+use crate::{
+    DisconnectReason, NetworkEvent, NetworkEventBuffer, NetworkingSystem, NotConnectedError, PasswordEncryption, SupportedPacketVersion,
+};
+
+/// How often the client checks for new events while waiting.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+/// Maximum number of events kept for [`HeadlessClient::next_event`]. If the
+/// events are never read, the oldest ones are dropped.
+const MAXIMUM_QUEUED_EVENTS: usize = 1024;
+/// Movement speed of a character if the server didn't tell us otherwise.
+const DEFAULT_MOVEMENT_SPEED: u64 = 150;
+
+#[derive(Debug)]
+pub enum HeadlessError {
+    /// The server didn't answer within the timeout.
+    Timeout,
+    LoginFailed {
+        message: &'static str,
+    },
+    /// The login server didn't send any character servers.
+    NoCharacterServer,
+    CharacterServerConnectionFailed {
+        message: &'static str,
+    },
+    CharacterNotFound {
+        name: String,
+    },
+    CharacterSelectionFailed {
+        message: &'static str,
+    },
+    Disconnected(DisconnectReason),
+    NotConnected,
+}
+
+impl From<NotConnectedError> for HeadlessError {
+    fn from(_: NotConnectedError) -> Self {
+        Self::NotConnected
+    }
+}
+
+/// A client without any graphics, meant for bots and tools. It drives the
+/// [`NetworkingSystem`] through the login flow and keeps track of the
+/// [`GameState`].
+///
+/// All events are also queued, so they can be handled with
+/// [`HeadlessClient::next_event`]. Salts for hashed logins and map changes
+/// are handled automatically.
+pub struct HeadlessClient<Callback = NoPacketCallback> {
+    networking_system: NetworkingSystem<Callback>,
+    event_buffer: NetworkEventBuffer,
+    packet_version: SupportedPacketVersion,
+    login_server_address: SocketAddr,
+    state: GameState,
+    events: VecDeque<NetworkEvent>,
+    timeout: Duration,
+}
+
+impl HeadlessClient<NoPacketCallback> {
+    pub fn new(packet_version: SupportedPacketVersion, login_server_address: SocketAddr) -> Self {
+        Self::from_spawned(NetworkingSystem::spawn(), packet_version, login_server_address)
+    }
+}
+
+impl<Callback> HeadlessClient<Callback>
+where
+    Callback: PacketCallback + Send,
+{
+    /// Use an already spawned networking system, e.g. one with a packet
+    /// callback or one that replays a session.
+    pub fn from_spawned(
+        (networking_system, event_buffer): (NetworkingSystem<Callback>, NetworkEventBuffer),
+        packet_version: SupportedPacketVersion,
+        login_server_address: SocketAddr,
+    ) -> Self {
+        Self {
+            networking_system,
+            event_buffer,
+            packet_version,
+            login_server_address,
+            state: GameState::default(),
+            events: VecDeque::new(),
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Access to the underlying networking system, to send anything that the
+    /// headless client doesn't wrap.
+    pub fn networking_system(&mut self) -> &mut NetworkingSystem<Callback> {
+        &mut self.networking_system
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    /// How long to wait for the server to answer a request.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Process all events that arrived since the last call, without waiting.
+    pub fn update(&mut self) {
+        self.networking_system.get_events(&mut self.event_buffer);
+
+        let events: Vec<NetworkEvent> = self.event_buffer.drain().collect();
+        events.into_iter().for_each(|event| self.process_event(event));
+    }
+
+    /// Take the oldest event that wasn't handled yet, without waiting.
+    pub fn try_next_event(&mut self) -> Option<NetworkEvent> {
+        if self.events.is_empty() {
+            self.update();
+        }
+
+        self.events.pop_front()
+    }
+
+    /// Wait for the next event.
+    pub async fn next_event(&mut self) -> NetworkEvent {
+        loop {
+            if let Some(event) = self.try_next_event() {
+                return event;
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Wait for some time while keeping the state up to date.
+    pub async fn wait(&mut self, duration: Duration) {
+        let deadline = Instant::now() + duration;
+
+        loop {
+            self.update();
+
+            let now = Instant::now();
+            if now >= deadline {
+                return;
+            }
+
+            tokio::time::sleep(POLL_INTERVAL.min(deadline - now)).await;
+        }
+    }
+
+    /// Log in to the login server and connect to the first character server.
+    /// Afterwards the characters of the account are available in
+    /// [`GameState::characters`].
+    pub async fn login(
+        &mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+        password_encryption: PasswordEncryption,
+    ) -> Result<(), HeadlessError> {
+        self.networking_system.connect_to_login_server(
+            self.packet_version,
+            self.login_server_address,
+            username,
+            password,
+            password_encryption,
+        );
+
+        let (login_data, character_servers) = self
+            .wait_for(|event| match event {
+                NetworkEvent::LoginServerConnected {
+                    character_servers,
+                    login_data,
+                } => Some(Ok((*login_data, character_servers.clone()))),
+                NetworkEvent::LoginServerConnectionFailed { message, .. } => Some(Err(HeadlessError::LoginFailed { message })),
+                NetworkEvent::LoginServerDisconnected { reason } => Some(Err(HeadlessError::Disconnected(*reason))),
+                _ => None,
+            })
+            .await?;
+
+        self.networking_system.disconnect_from_login_server();
+
+        let character_server = character_servers.into_iter().next().ok_or(HeadlessError::NoCharacterServer)?;
+        self.networking_system
+            .connect_to_character_server(self.packet_version, &login_data, character_server);
+
+        self.wait_for(|event| match event {
+            NetworkEvent::CharacterServerConnected { .. } => Some(Ok(())),
+            NetworkEvent::CharacterServerConnectionFailed { message, .. } => {
+                Some(Err(HeadlessError::CharacterServerConnectionFailed { message }))
+            }
+            NetworkEvent::CharacterServerDisconnected { reason } => Some(Err(HeadlessError::Disconnected(*reason))),
+            _ => None,
+        })
+        .await?;
+
+        self.networking_system.request_character_list()?;
+
+        self.wait_for(|event| match event {
+            NetworkEvent::CharacterList { .. } => Some(Ok(())),
+            NetworkEvent::CharacterServerDisconnected { reason } => Some(Err(HeadlessError::Disconnected(*reason))),
+            _ => None,
+        })
+        .await
+    }
+
+    /// Select one of the characters of the account by name and log in to the
+    /// map server with it.
+    pub async fn select_character(&mut self, name: &str) -> Result<(), HeadlessError> {
+        let character = self
+            .state
+            .characters
+            .iter()
+            .find(|character| character.name == name)
+            .cloned()
+            .ok_or_else(|| HeadlessError::CharacterNotFound { name: name.to_owned() })?;
+        let login_data = self.state.login_data.ok_or(HeadlessError::NotConnected)?;
+
+        self.networking_system.select_character(character.character_number as usize)?;
+
+        let character_server_login_data = self
+            .wait_for(|event| match event {
+                NetworkEvent::CharacterSelected { login_data } => Some(Ok(*login_data)),
+                NetworkEvent::CharacterSelectionFailed { message, .. } => Some(Err(HeadlessError::CharacterSelectionFailed { message })),
+                NetworkEvent::CharacterServerDisconnected { reason } => Some(Err(HeadlessError::Disconnected(*reason))),
+                _ => None,
+            })
+            .await?;
+
+        self.networking_system.disconnect_from_character_server();
+        self.networking_system
+            .connect_to_map_server(self.packet_version, &login_data, character_server_login_data);
+        let _ = self.networking_system.request_client_tick();
+
+        self.state.map_name = Some(character.map_name.replace(".gat", ""));
+        self.state.character = Some(character);
+
+        // The map server confirms the login with the current client tick.
+        self.wait_for(|event| match event {
+            NetworkEvent::UpdateClientTick { .. } => Some(Ok(())),
+            NetworkEvent::MapServerDisconnected { reason } => Some(Err(HeadlessError::Disconnected(*reason))),
+            _ => None,
+        })
+        .await?;
+
+        // We don't need to load anything, so the map is ready right away.
+        self.networking_system.map_loaded()?;
+
+        Ok(())
+    }
+
+    /// Walk to a position on the current map and wait until the player
+    /// arrives. Returns the destination that the server chose, which might
+    /// differ from the requested position.
+    ///
+    /// If the server can't find a path to the position it doesn't answer at
+    /// all, so this fails with [`HeadlessError::Timeout`].
+    pub async fn walk_to(&mut self, position: TilePosition) -> Result<TilePosition, HeadlessError> {
+        self.networking_system
+            .player_move(WorldPosition::new(position.x, position.y, Direction::North))?;
+
+        let (origin, destination) = self
+            .wait_for(|event| match event {
+                NetworkEvent::PlayerMove { origin, destination, .. } => Some(Ok((origin.tile_position(), destination.tile_position()))),
+                NetworkEvent::MapServerDisconnected { reason } => Some(Err(HeadlessError::Disconnected(*reason))),
+                _ => None,
+            })
+            .await?;
+
+        let movement_speed = self
+            .state
+            .character
+            .as_ref()
+            .map(|character| character.movement_speed as u64)
+            .unwrap_or(DEFAULT_MOVEMENT_SPEED);
+
+        self.wait(walk_duration(origin, destination, movement_speed)).await;
+
+        Ok(destination)
+    }
+
+    /// Warp to a map with the `@warp` command. The account needs the
+    /// permission to use it.
+    pub async fn warp_to_map(&mut self, map_name: impl Into<String>, position: TilePosition) -> Result<(), HeadlessError> {
+        self.networking_system.warp_to_map(map_name.into(), position)?;
+
+        self.wait_for(|event| match event {
+            NetworkEvent::ChangeMap { .. } => Some(Ok(())),
+            NetworkEvent::MapServerDisconnected { reason } => Some(Err(HeadlessError::Disconnected(*reason))),
+            _ => None,
+        })
+        .await
+    }
+
+    /// Send a message to the public chat as the current character.
+    pub fn chat(&mut self, text: &str) -> Result<(), HeadlessError> {
+        let character = self.state.character.as_ref().ok_or(HeadlessError::NotConnected)?;
+        self.networking_system.send_chat_message(&character.name, text)?;
+
+        Ok(())
+    }
+
+    fn process_event(&mut self, event: NetworkEvent) {
+        self.state.update(&event);
+
+        match &event {
+            NetworkEvent::LoginServerPasswordSalt { salt } => {
+                let _ = self.networking_system.send_hashed_login(salt);
+            }
+            NetworkEvent::ChangeMap { .. } => {
+                let _ = self.networking_system.map_loaded();
+            }
+            _ => {}
+        }
+
+        if self.events.len() == MAXIMUM_QUEUED_EVENTS {
+            self.events.pop_front();
+        }
+
+        self.events.push_back(event);
+    }
+
+    /// Process events until the matcher returns a result or the timeout
+    /// passes. The matching event is still queued for
+    /// [`HeadlessClient::next_event`].
+    async fn wait_for<T>(
+        &mut self,
+        mut matcher: impl FnMut(&NetworkEvent) -> Option<Result<T, HeadlessError>>,
+    ) -> Result<T, HeadlessError> {
+        let deadline = Instant::now() + self.timeout;
+
+        loop {
+            self.networking_system.get_events(&mut self.event_buffer);
+
+            let events: Vec<NetworkEvent> = self.event_buffer.drain().collect();
+            let mut result = None;
+
+            for event in events {
+                if result.is_none() {
+                    result = matcher(&event);
+                }
+
+                self.process_event(event);
+            }
+
+            if let Some(result) = result {
+                return result;
+            }
+
+            if Instant::now() >= deadline {
+                return Err(HeadlessError::Timeout);
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+}
+
+/// Estimate how long it takes to walk from one tile to another. Like on the
+/// server, diagonal steps take 1.4 times as long as straight ones.
+fn walk_duration(origin: TilePosition, destination: TilePosition, movement_speed: u64) -> Duration {
+    let distance_x = origin.x.abs_diff(destination.x) as u64;
+    let distance_y = origin.y.abs_diff(destination.y) as u64;
+
+    let diagonal_steps = distance_x.min(distance_y);
+    let straight_steps = distance_x.max(distance_y) - diagonal_steps;
+
+    Duration::from_millis(straight_steps * movement_speed + diagonal_steps * movement_speed * 14 / 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ragnarok_packets::TilePosition;
+
+    use super::walk_duration;
+
+    #[test]
+    fn walk_duration_diagonal() {
+        let origin = TilePosition { x: 10, y: 10 };
+        let destination = TilePosition { x: 14, y: 12 };
+
+        // 2 diagonal and 2 straight steps.
+        assert_eq!(walk_duration(origin, destination, 150), Duration::from_millis(720));
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use ragnarok_packets::{CharacterInformation, CharacterServerInformation, EntityId, StatType, TilePosition};
+
+use crate::{EntityData, InventoryItem, InventoryItemDetails, LoginServerLoginData, NetworkEvent, NoMetadata};
+
+/// Everything the [`HeadlessClient`](super::HeadlessClient) knows about the
+/// session, built from the [`NetworkEvent`]s it received.
+#[derive(Debug, Default)]
+pub struct GameState {
+    pub login_data: Option<LoginServerLoginData>,
+    pub character_servers: Vec<CharacterServerInformation>,
+    /// Characters of the account, as sent by the character server.
+    pub characters: Vec<CharacterInformation>,
+    /// The character that is currently playing.
+    pub character: Option<CharacterInformation>,
+    pub map_name: Option<String>,
+    /// Position of the player. This is the destination of the last movement,
+    /// so the player might not have arrived yet.
+    pub position: Option<TilePosition>,
+    /// Entities near the player, except for the player itself.
+    pub entities: HashMap<EntityId, EntityData>,
+    pub inventory: Vec<InventoryItem<NoMetadata>>,
+}
+
+impl GameState {
+    /// Apply a single event to the state. Events that don't affect the state
+    /// are ignored.
+    pub fn update(&mut self, event: &NetworkEvent) {
+        match event {
+            NetworkEvent::LoginServerConnected {
+                character_servers,
+                login_data,
+            } => {
+                self.login_data = Some(*login_data);
+                self.character_servers = character_servers.clone();
+            }
+            NetworkEvent::CharacterList { characters } => self.characters = characters.clone(),
+            NetworkEvent::CharacterCreated { character_information } => self.characters.push(character_information.clone()),
+            NetworkEvent::ChangeMap { map_name, position } => {
+                self.map_name = Some(map_name.clone());
+                self.position = Some(*position);
+                self.entities.clear();
+            }
+            NetworkEvent::PlayerMove { destination, .. } => self.position = Some(destination.tile_position()),
+            NetworkEvent::AddEntity { entity_data } => {
+                self.entities.insert(entity_data.entity_id, entity_data.clone());
+            }
+            NetworkEvent::RemoveEntity { entity_id, .. } => {
+                self.entities.remove(entity_id);
+            }
+            NetworkEvent::EntityMove {
+                entity_id,
+                origin,
+                destination,
+                ..
+            } => {
+                if let Some(entity) = self.entities.get_mut(entity_id) {
+                    entity.position = *origin;
+                    entity.destination = Some(*destination);
+                }
+            }
+            NetworkEvent::UpdateEntityHealth {
+                entity_id,
+                health_points,
+                maximum_health_points,
+            } => {
+                if let Some(entity) = self.entities.get_mut(entity_id) {
+                    entity.health_points = *health_points as i32;
+                    entity.maximum_health_points = *maximum_health_points as i32;
+                }
+            }
+            NetworkEvent::UpdateStat {
+                stat_type: StatType::MovementSpeed(movement_speed),
+            } => {
+                if let Some(character) = &mut self.character {
+                    character.movement_speed = *movement_speed as i16;
+                }
+            }
+            NetworkEvent::SetInventory { items } => self.inventory = items.clone(),
+            NetworkEvent::IventoryItemAdded { item } => {
+                let existing = self.inventory.iter_mut().find(|existing| existing.index == item.index);
+
+                match (existing, &item.details) {
+                    (
+                        Some(InventoryItem {
+                            details: InventoryItemDetails::Regular { amount, .. },
+                            ..
+                        }),
+                        InventoryItemDetails::Regular { amount: added, .. },
+                    ) => *amount += added,
+                    (Some(existing), _) => *existing = item.clone(),
+                    (None, _) => self.inventory.push(item.clone()),
+                }
+            }
+            NetworkEvent::InventoryItemRemoved { index, amount, .. } => {
+                let Some(position) = self.inventory.iter().position(|item| item.index == *index) else {
+                    return;
+                };
+
+                match &mut self.inventory[position].details {
+                    InventoryItemDetails::Regular { amount: remaining, .. } if *remaining > *amount => *remaining -= amount,
+                    _ => drop(self.inventory.remove(position)),
+                }
+            }
+            NetworkEvent::UpdateEquippedPosition { index, equipped_position } => {
+                if let Some(InventoryItemDetails::Equippable {
+                    equipped_position: current,
+                    ..
+                }) = self
+                    .inventory
+                    .iter_mut()
+                    .find(|item| item.index == *index)
+                    .map(|item| &mut item.details)
+                {
+                    *current = *equipped_position;
+                }
+            }
+            NetworkEvent::MapServerDisconnected { .. } | NetworkEvent::LoggedOut => {
+                self.character = None;
+                self.map_name = None;
+                self.position = None;
+                self.entities.clear();
+                self.inventory.clear();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ragnarok_packets::{EquipPosition, InventoryIndex, ItemId, RegularItemFlags, RemoveItemReason};
+
+    use super::GameState;
+    use crate::{InventoryItem, InventoryItemDetails, NetworkEvent, NoMetadata};
+
+    fn potion(amount: u16) -> InventoryItem<NoMetadata> {
+        InventoryItem {
+            metadata: NoMetadata,
+            index: InventoryIndex(2),
+            item_id: ItemId(501),
+            item_type: 0,
+            slot: [0; 4],
+            hire_expiration_date: 0,
+            details: InventoryItemDetails::Regular {
+                amount,
+                equipped_position: EquipPosition::NONE,
+                flags: RegularItemFlags::IDENTIFIED,
+            },
+        }
+    }
+
+    #[test]
+    fn inventory() {
+        let mut state = GameState::default();
+
+        state.update(&NetworkEvent::SetInventory { items: vec![potion(5)] });
+        state.update(&NetworkEvent::IventoryItemAdded { item: potion(3) });
+        assert_eq!(state.inventory.len(), 1);
+        assert_eq!(state.inventory[0].amount(), 8);
+
+        state.update(&NetworkEvent::InventoryItemRemoved {
+            reason: RemoveItemReason::Normal,
+            index: InventoryIndex(2),
+            amount: 2,
+        });
+        assert_eq!(state.inventory[0].amount(), 6);
+
+        state.update(&NetworkEvent::InventoryItemRemoved {
+            reason: RemoveItemReason::Normal,
+            index: InventoryIndex(2),
+            amount: 6,
+        });
+        assert!(state.inventory.is_empty());
+    }
+}
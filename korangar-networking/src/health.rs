@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+/// Weight of a new sample in the moving average of the round trip time.
+const AVERAGE_WEIGHT: f64 = 0.125;
+
+/// Round trip time statistics of a server connection, measured by timing
+/// the replies to the keep-alive packets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyStatistics {
+    /// Round trip time of the most recent keep-alive packet.
+    pub latest: Duration,
+    /// Exponential moving average of the round trip time, so that single
+    /// spikes don't affect it too much.
+    pub average: Duration,
+    pub minimum: Duration,
+    pub maximum: Duration,
+    /// Number of round trips that were measured.
+    pub samples: usize,
+}
+
+impl LatencyStatistics {
+    fn new(round_trip_time: Duration) -> Self {
+        Self {
+            latest: round_trip_time,
+            average: round_trip_time,
+            minimum: round_trip_time,
+            maximum: round_trip_time,
+            samples: 1,
+        }
+    }
+
+    fn add_sample(&mut self, round_trip_time: Duration) {
+        let average = self.average.as_secs_f64() * (1.0 - AVERAGE_WEIGHT) + round_trip_time.as_secs_f64() * AVERAGE_WEIGHT;
+
+        self.latest = round_trip_time;
+        self.average = Duration::from_secs_f64(average);
+        self.minimum = self.minimum.min(round_trip_time);
+        self.maximum = self.maximum.max(round_trip_time);
+        self.samples += 1;
+    }
+}
+
+/// Health of a single server connection. It is updated by the networking
+/// thread and read by the main thread.
+#[derive(Debug, Default)]
+pub(crate) struct ConnectionHealth {
+    pub latency: Option<LatencyStatistics>,
+    pub missed_keepalives: usize,
+    /// Set if the connection was closed because the server stopped replying.
+    pub timed_out: bool,
+}
+
+impl ConnectionHealth {
+    pub fn record_round_trip(&mut self, round_trip_time: Duration) {
+        match &mut self.latency {
+            Some(latency) => latency.add_sample(round_trip_time),
+            None => self.latency = Some(LatencyStatistics::new(round_trip_time)),
+        }
+
+        self.missed_keepalives = 0;
+    }
+
+    /// Returns `true` if the connection should be considered stalled.
+    pub fn record_missed_keepalive(&mut self, max_missed_keepalives: usize) -> bool {
+        self.missed_keepalives += 1;
+        self.timed_out = self.missed_keepalives >= max_missed_keepalives;
+        self.timed_out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::ConnectionHealth;
+
+    #[test]
+    fn latency_statistics() {
+        let mut health = ConnectionHealth::default();
+
+        health.record_round_trip(Duration::from_millis(80));
+        health.record_round_trip(Duration::from_millis(160));
+        health.record_round_trip(Duration::from_millis(40));
+
+        let latency = health.latency.unwrap();
+        assert_eq!(latency.latest, Duration::from_millis(40));
+        assert_eq!(latency.minimum, Duration::from_millis(40));
+        assert_eq!(latency.maximum, Duration::from_millis(160));
+        assert_eq!(latency.samples, 3);
+        // 80 -> 90 -> 83.75
+        assert!((latency.average.as_secs_f64() - 0.08375).abs() < 1e-6);
+    }
+
+    #[test]
+    fn missed_keepalives() {
+        let mut health = ConnectionHealth::default();
+
+        assert!(!health.record_missed_keepalive(3));
+        assert!(!health.record_missed_keepalive(3));
+
+        // A reply resets the counter.
+        health.record_round_trip(Duration::from_millis(50));
+        assert!(!health.record_missed_keepalive(3));
+        assert!(!health.record_missed_keepalive(3));
+        assert!(health.record_missed_keepalive(3));
+        assert!(health.timed_out);
+    }
+}
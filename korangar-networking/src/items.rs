@@ -1,4 +1,7 @@
-use ragnarok_packets::{EquipPosition, EquippableItemFlags, InventoryIndex, ItemId, ItemOptions, Price, RegularItemFlags};
+use ragnarok_packets::{
+    EquipPosition, EquippableItemFlags, InventoryIndex, ItemId, ItemOptions, OwnVendingItemInformation, Price, RegularItemFlags,
+    VendingItemInformation,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NoMetadata;
@@ -41,6 +44,14 @@ impl<Meta> InventoryItem<Meta> {
             InventoryItemDetails::Equippable { flags, .. } => flags.contains(EquippableItemFlags::IDENTIFIED),
         }
     }
+
+    /// The number of items in this stack. Equippable items never stack.
+    pub fn amount(&self) -> u16 {
+        match &self.details {
+            InventoryItemDetails::Regular { amount, .. } => *amount,
+            InventoryItemDetails::Equippable { .. } => 1,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -76,3 +87,50 @@ pub struct SellItem<Meta> {
     pub price: Price,
     pub overcharge_price: Price,
 }
+
+/// An item offered in a vending shop. The index is the cart index of the
+/// vendor.
+#[derive(Debug, Clone)]
+pub struct VendingItem<Meta> {
+    pub metadata: Meta,
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub price: Price,
+    pub amount: u16,
+    pub is_identified: bool,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+}
+
+impl From<VendingItemInformation> for VendingItem<NoMetadata> {
+    fn from(item: VendingItemInformation) -> Self {
+        Self {
+            metadata: NoMetadata,
+            index: item.index,
+            item_id: item.item_id,
+            item_type: item.item_type,
+            price: item.price,
+            amount: item.amount,
+            is_identified: item.is_identified != 0,
+            refinement_level: item.refinement_level,
+            cards: item.cards,
+        }
+    }
+}
+
+impl From<OwnVendingItemInformation> for VendingItem<NoMetadata> {
+    fn from(item: OwnVendingItemInformation) -> Self {
+        Self {
+            metadata: NoMetadata,
+            index: item.index,
+            item_id: item.item_id,
+            item_type: item.item_type,
+            price: item.price,
+            amount: item.amount,
+            is_identified: item.is_identified != 0,
+            refinement_level: item.refinement_level,
+            cards: item.cards,
+        }
+    }
+}
@@ -1,42 +1,69 @@
 #![cfg_attr(feature = "interface", feature(impl_trait_in_assoc_type))]
 #![cfg_attr(feature = "interface", feature(negative_impls))]
 
+mod companion;
 mod entity;
 mod event;
+mod headless;
+mod health;
 mod hotkey;
 mod items;
+mod mail;
+mod md5;
 mod message;
+mod obfuscation;
 mod packet_versions;
+mod party;
 mod server;
+mod session;
+mod status;
 
 use std::net::{IpAddr, SocketAddr};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
-use event::{
-    CharacterServerDisconnectedEvent, DisconnectedEvent, LoginServerDisconnectedEvent, MapServerDisconnectedEvent, NetworkEventList,
-};
+use event::NetworkEventList;
+use health::ConnectionHealth;
 use ragnarok_bytes::encoding::UTF_8;
 use ragnarok_bytes::{ByteReader, ByteWriter, FromBytes};
-use ragnarok_packets::handler::{DuplicateHandlerError, HandlerResult, NoPacketCallback, PacketCallback, PacketHandler};
+use ragnarok_packets::handler::{
+    DuplicateHandlerError, HandlerResult, NoPacketCallback, PacketCallback, PacketDirection, PacketHandler, ServerKind,
+};
 use ragnarok_packets::*;
-use server::{ServerConnectCommand, ServerConnection};
+use server::{MapServerReconnect, MapServerSession, PendingHashedLogin, ReconnectStage, ServerConnectCommand, ServerConnection};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::task::JoinHandle;
 
-pub use self::entity::EntityData;
+pub use self::companion::{CompanionType, HomunculusInformation, MercenaryInformation, PetInformation};
+#[cfg(feature = "interface")]
+pub use self::companion::{HomunculusInformationPathExt, MercenaryInformationPathExt, PetInformationPathExt};
+pub use self::entity::{BodyState, EntityData, EntityState, EquipmentLook, EquipmentLookChange};
 pub use self::event::{DisconnectReason, NetworkEvent};
+pub use self::headless::{GameState, HeadlessClient, HeadlessError};
+pub use self::health::LatencyStatistics;
 pub use self::hotkey::HotkeyState;
-pub use self::items::{InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem};
+pub use self::items::{InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem, VendingItem};
+pub use self::mail::MailSummary;
+#[cfg(feature = "interface")]
+pub use self::mail::MailSummaryPathExt;
 pub use self::message::MessageColor;
+pub use self::obfuscation::PacketKeys;
 pub use self::packet_versions::SupportedPacketVersion;
+pub use self::party::PartyMember;
+#[cfg(feature = "interface")]
+pub use self::party::PartyMemberPathExt;
 pub use self::server::{
-    CharacterServerLoginData, LoginServerLoginData, NotConnectedError, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
+    CharacterServerLoginData, ConnectionSettings, LoginServerLoginData, NotConnectedError, PasswordEncryption,
+    UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
+pub use self::session::{Session, SessionRecord, SessionRecordKind, SessionRecorder};
+pub use self::status::StatusEffect;
+use crate::obfuscation::PacketObfuscation;
 use crate::server::NetworkTaskError;
+use crate::session::SessionReplay;
 
 /// Buffer for networking events. This struct exists to reduce heap allocations
 /// and is purely an optimization.
@@ -68,8 +95,8 @@ impl TimeSynchronization {
     /// Returns the client tick that must be used when sending the time
     /// synchronization request immediately after calling this function.
     fn request_client_tick(&mut self) -> u32 {
-        let request_send = Instant::now();
-        let elapsed = request_send.duration_since(self.request_received).as_secs_f64();
+        self.request_send = Instant::now();
+        let elapsed = self.request_send.duration_since(self.request_received).as_secs_f64();
         (self.client_tick + (elapsed * 1000.0)) as u32
     }
 
@@ -89,14 +116,26 @@ pub struct NetworkingSystem<Callback> {
     login_server_connection: ServerConnection,
     character_server_connection: ServerConnection,
     map_server_connection: ServerConnection,
+    pending_hashed_login: Option<PendingHashedLogin>,
+    connection_settings: ConnectionSettings,
+    /// The character server we last connected to. Needed to select the
+    /// character again when reconnecting to the map server.
+    last_character_server: Option<CharacterServerInformation>,
+    map_server_session: Option<MapServerSession>,
+    map_server_reconnect: Option<MapServerReconnect>,
     packet_callback: Callback,
 }
 
 impl NetworkingSystem<NoPacketCallback> {
     pub fn spawn() -> (Self, NetworkEventBuffer) {
-        let (command_sender, time_synchronization) = Self::spawn_networking_thread(NoPacketCallback);
+        let (command_sender, time_synchronization) = Self::spawn_networking_thread(NoPacketCallback, None);
         Self::inner_new(command_sender, time_synchronization, NoPacketCallback)
     }
+
+    /// Replay a recorded [`Session`] instead of connecting to the servers.
+    pub fn spawn_replay(session: Session) -> (Self, NetworkEventBuffer) {
+        Self::spawn_replay_with_callback(session, NoPacketCallback)
+    }
 }
 
 impl<Callback> NetworkingSystem<Callback>
@@ -114,6 +153,11 @@ where
             login_server_connection: ServerConnection::Disconnected,
             character_server_connection: ServerConnection::Disconnected,
             map_server_connection: ServerConnection::Disconnected,
+            pending_hashed_login: None,
+            connection_settings: ConnectionSettings::default(),
+            last_character_server: None,
+            map_server_session: None,
+            map_server_reconnect: None,
             packet_callback,
         };
         let event_buffer = NetworkEventBuffer(Vec::new());
@@ -122,11 +166,26 @@ where
     }
 
     pub fn spawn_with_callback(packet_callback: Callback) -> (Self, NetworkEventBuffer) {
-        let (command_sender, time_synchronization) = Self::spawn_networking_thread(packet_callback.clone());
+        let (command_sender, time_synchronization) = Self::spawn_networking_thread(packet_callback.clone(), None);
         Self::inner_new(command_sender, time_synchronization, packet_callback)
     }
 
-    fn spawn_networking_thread(packet_callback: Callback) -> (UnboundedSender<ServerConnectCommand>, Arc<Mutex<TimeSynchronization>>) {
+    /// Replay a recorded [`Session`] instead of connecting to the servers.
+    ///
+    /// Every connection to a server replays the next recorded connection to
+    /// the same kind of server. Incoming packets are delayed like in the
+    /// recording, but packets that the server sent in response to the client
+    /// are only replayed once the client sends the same packet again.
+    pub fn spawn_replay_with_callback(session: Session, packet_callback: Callback) -> (Self, NetworkEventBuffer) {
+        let replay = SessionReplay::new(session);
+        let (command_sender, time_synchronization) = Self::spawn_networking_thread(packet_callback.clone(), Some(replay));
+        Self::inner_new(command_sender, time_synchronization, packet_callback)
+    }
+
+    fn spawn_networking_thread(
+        packet_callback: Callback,
+        mut replay: Option<SessionReplay>,
+    ) -> (UnboundedSender<ServerConnectCommand>, Arc<Mutex<TimeSynchronization>>) {
         let (command_sender, mut command_receiver) = tokio::sync::mpsc::unbounded_channel::<ServerConnectCommand>();
         let time_synchronization = Arc::new(Mutex::new(TimeSynchronization::new()));
         let thread_time_synchronization = Arc::clone(&time_synchronization);
@@ -149,23 +208,38 @@ where
                             action_receiver,
                             event_sender,
                             packet_version,
+                            settings,
+                            health,
                         } => {
                             if let Some(handle) = login_server_task_handle.take() {
-                                // TODO: Maybe add a timeout here? Maybe handle Result?
-                                let _ = handle.await.unwrap();
+                                Self::finish_task(handle).await;
                             }
 
                             let packet_handler = Self::create_login_server_packet_handler(packet_callback.clone(), packet_version).unwrap();
-                            let handle = local_set.spawn_local(Self::handle_server_connection(
-                                address,
-                                action_receiver,
-                                event_sender,
-                                packet_handler,
-                                |_| LoginServerKeepalivePacket::new(),
-                                Duration::from_secs(58),
-                                false,
-                                thread_time_synchronization.clone(),
-                            ));
+                            let handle = match replay.as_mut() {
+                                Some(replay) => local_set.spawn_local(Self::handle_replay_connection(
+                                    replay.next_connection(ServerKind::Login),
+                                    action_receiver,
+                                    event_sender,
+                                    packet_handler,
+                                    false,
+                                    thread_time_synchronization.clone(),
+                                )),
+                                None => local_set.spawn_local(Self::handle_server_connection(
+                                    ServerKind::Login,
+                                    address,
+                                    action_receiver,
+                                    event_sender,
+                                    packet_handler,
+                                    |_| LoginServerKeepalivePacket::new(),
+                                    Duration::from_secs(58),
+                                    false,
+                                    None,
+                                    settings,
+                                    health,
+                                    thread_time_synchronization.clone(),
+                                )),
+                            };
 
                             login_server_task_handle = Some(handle);
                         }
@@ -174,24 +248,39 @@ where
                             action_receiver,
                             event_sender,
                             packet_version,
+                            settings,
+                            health,
                         } => {
                             if let Some(handle) = character_server_task_handle.take() {
-                                // TODO: Maybe add a timeout here? Maybe handle Result?
-                                let _ = handle.await.unwrap();
+                                Self::finish_task(handle).await;
                             }
 
                             let packet_handler =
                                 Self::create_character_server_packet_handler(packet_callback.clone(), packet_version).unwrap();
-                            let handle = local_set.spawn_local(Self::handle_server_connection(
-                                address,
-                                action_receiver,
-                                event_sender,
-                                packet_handler,
-                                |_| CharacterServerKeepalivePacket::new(),
-                                Duration::from_secs(10),
-                                true,
-                                thread_time_synchronization.clone(),
-                            ));
+                            let handle = match replay.as_mut() {
+                                Some(replay) => local_set.spawn_local(Self::handle_replay_connection(
+                                    replay.next_connection(ServerKind::Character),
+                                    action_receiver,
+                                    event_sender,
+                                    packet_handler,
+                                    true,
+                                    thread_time_synchronization.clone(),
+                                )),
+                                None => local_set.spawn_local(Self::handle_server_connection(
+                                    ServerKind::Character,
+                                    address,
+                                    action_receiver,
+                                    event_sender,
+                                    packet_handler,
+                                    |_| CharacterServerKeepalivePacket::new(),
+                                    Duration::from_secs(10),
+                                    true,
+                                    None,
+                                    settings,
+                                    health,
+                                    thread_time_synchronization.clone(),
+                                )),
+                            };
 
                             character_server_task_handle = Some(handle);
                         }
@@ -200,29 +289,44 @@ where
                             action_receiver,
                             event_sender,
                             packet_version,
+                            settings,
+                            health,
                         } => {
                             if let Some(handle) = map_server_task_handle.take() {
-                                // TODO: Maybe add a timeout here? Maybe handle Result?
-                                let _ = handle.await.unwrap();
+                                Self::finish_task(handle).await;
                             }
 
                             let packet_handler = Self::create_map_server_packet_handler(packet_callback.clone(), packet_version).unwrap();
-                            let handle = local_set.spawn_local(Self::handle_server_connection(
-                                address,
-                                action_receiver,
-                                event_sender,
-                                packet_handler,
-                                |time_synchronization| match time_synchronization.lock() {
-                                    Ok(mut time_synchronization) => {
-                                        let client_tick = time_synchronization.request_client_tick();
-                                        RequestServerTickPacket::new(ClientTick(client_tick))
-                                    }
-                                    Err(_) => RequestServerTickPacket::new(ClientTick(100)),
-                                },
-                                Duration::from_secs(10),
-                                false,
-                                thread_time_synchronization.clone(),
-                            ));
+                            let handle = match replay.as_mut() {
+                                Some(replay) => local_set.spawn_local(Self::handle_replay_connection(
+                                    replay.next_connection(ServerKind::Map),
+                                    action_receiver,
+                                    event_sender,
+                                    packet_handler,
+                                    false,
+                                    thread_time_synchronization.clone(),
+                                )),
+                                None => local_set.spawn_local(Self::handle_server_connection(
+                                    ServerKind::Map,
+                                    address,
+                                    action_receiver,
+                                    event_sender,
+                                    packet_handler,
+                                    |time_synchronization| match time_synchronization.lock() {
+                                        Ok(mut time_synchronization) => {
+                                            let client_tick = time_synchronization.request_client_tick();
+                                            RequestServerTickPacket::new(ClientTick(client_tick))
+                                        }
+                                        Err(_) => RequestServerTickPacket::new(ClientTick(100)),
+                                    },
+                                    Duration::from_secs(10),
+                                    false,
+                                    Some(PacketObfuscation::new(packet_version.packet_keys())),
+                                    settings,
+                                    health,
+                                    thread_time_synchronization.clone(),
+                                )),
+                            };
 
                             map_server_task_handle = Some(handle);
                         }
@@ -234,51 +338,231 @@ where
         (command_sender, time_synchronization)
     }
 
-    fn handle_connection<Event>(connection: &mut ServerConnection, event_buffer: &mut NetworkEventBuffer)
-    where
-        Event: DisconnectedEvent,
-    {
+    /// Wait for the task of the previous connection to a server to finish.
+    /// The task stops once the action sender is dropped, but it might be stuck
+    /// writing to a stalled connection, so it is aborted after a short time.
+    async fn finish_task(handle: JoinHandle<Result<(), NetworkTaskError>>) {
+        let abort_handle = handle.abort_handle();
+
+        // Errors are already reported to the main thread by closing the event
+        // channel, so there is nothing left to do with the result.
+        if tokio::time::timeout(Duration::from_secs(1), handle).await.is_err() {
+            abort_handle.abort();
+        }
+    }
+
+    /// Move all events received on a connection into the buffer. Returns the
+    /// reason if the connection was closed.
+    fn handle_connection(connection: &mut ServerConnection, events: &mut Vec<NetworkEvent>) -> Option<DisconnectReason> {
         match connection.take() {
             ServerConnection::Connected {
                 action_sender,
                 mut event_receiver,
                 packet_version,
+                health,
             } => loop {
                 match event_receiver.try_recv() {
-                    Ok(login_event) => {
-                        event_buffer.0.push(login_event);
+                    Ok(event) => {
+                        events.push(event);
                     }
                     Err(TryRecvError::Empty) => {
                         *connection = ServerConnection::Connected {
                             action_sender,
                             event_receiver,
                             packet_version,
+                            health,
                         };
-                        break;
+                        break None;
                     }
                     Err(..) => {
-                        event_buffer.0.push(Event::create_event(DisconnectReason::ConnectionError));
-                        *connection = ServerConnection::Disconnected;
-                        break;
+                        let timed_out = health.lock().is_ok_and(|health| health.timed_out);
+
+                        break match timed_out {
+                            true => Some(DisconnectReason::TimedOut),
+                            false => Some(DisconnectReason::ConnectionError),
+                        };
                     }
                 }
             },
-            ServerConnection::ClosingManually => {
-                event_buffer.0.push(Event::create_event(DisconnectReason::ClosedByClient));
-                *connection = ServerConnection::Disconnected;
+            ServerConnection::ClosingManually => Some(DisconnectReason::ClosedByClient),
+            ServerConnection::Disconnected => None,
+        }
+    }
+
+    pub fn get_events(&mut self, events: &mut NetworkEventBuffer) {
+        let events = &mut events.0;
+
+        if let Some(reason) = Self::handle_connection(&mut self.login_server_connection, events) {
+            events.push(NetworkEvent::LoginServerDisconnected { reason });
+        }
+
+        match self.map_server_reconnect.is_some() {
+            // While reconnecting, the character server is only used to select the
+            // character again, so none of its events are forwarded.
+            true => {
+                let mut character_server_events = Vec::new();
+                let reason = Self::handle_connection(&mut self.character_server_connection, &mut character_server_events);
+
+                for event in character_server_events {
+                    self.handle_reconnect_event(event, events);
+                }
+
+                if reason.is_some_and(|reason| reason != DisconnectReason::ClosedByClient) {
+                    self.retry_map_server_reconnect(events);
+                }
+            }
+            false => {
+                if let Some(reason) = Self::handle_connection(&mut self.character_server_connection, events) {
+                    events.push(NetworkEvent::CharacterServerDisconnected { reason });
+                }
+            }
+        }
+
+        let map_server_events_start = events.len();
+        let reason = Self::handle_connection(&mut self.map_server_connection, events);
+        let map_server_events = &events[map_server_events_start..];
+
+        // The map server sends the client tick once the login succeeded.
+        if map_server_events
+            .iter()
+            .any(|event| matches!(event, NetworkEvent::UpdateClientTick { .. }))
+            && self
+                .map_server_reconnect
+                .take_if(|reconnect| matches!(reconnect.stage, ReconnectStage::LoggingIn))
+                .is_some()
+        {
+            events.insert(map_server_events_start, NetworkEvent::MapServerReconnected);
+        }
+
+        if let Some(reason) = reason {
+            // The server closes the connection after logging out, which is expected.
+            let logged_out = events[map_server_events_start..]
+                .iter()
+                .any(|event| matches!(event, NetworkEvent::LoggedOut));
+
+            self.map_server_disconnected(reason, logged_out, events);
+        }
+
+        if let Some(reconnect) = &mut self.map_server_reconnect
+            && let ReconnectStage::Waiting { until } = reconnect.stage
+            && Instant::now() >= until
+        {
+            reconnect.stage = ReconnectStage::SelectingCharacter;
+
+            let MapServerSession {
+                packet_version,
+                login_data,
+                character_server,
+                ..
+            } = reconnect.session.clone();
+
+            self.connect_to_character_server(packet_version, &login_data, character_server);
+        }
+    }
+
+    fn map_server_disconnected(&mut self, reason: DisconnectReason, logged_out: bool, events: &mut Vec<NetworkEvent>) {
+        // Logging in to the map server failed while reconnecting.
+        if self.map_server_reconnect.is_some() {
+            self.retry_map_server_reconnect(events);
+            return;
+        }
+
+        let should_reconnect =
+            self.connection_settings.reconnect_to_map_server && reason != DisconnectReason::ClosedByClient && !logged_out;
+
+        match self.map_server_session.clone() {
+            Some(session) if should_reconnect => {
+                self.map_server_reconnect = Some(MapServerReconnect {
+                    session,
+                    reason,
+                    attempt: 0,
+                    stage: ReconnectStage::Waiting { until: Instant::now() },
+                });
+                self.retry_map_server_reconnect(events);
             }
-            _ => (),
+            _ => events.push(NetworkEvent::MapServerDisconnected { reason }),
+        }
+    }
+
+    /// Schedule the next attempt to reconnect to the map server, or give up if
+    /// there are no attempts left.
+    fn retry_map_server_reconnect(&mut self, events: &mut Vec<NetworkEvent>) {
+        let Some(reconnect) = &mut self.map_server_reconnect else {
+            return;
+        };
+
+        // Drop the connection of the failed attempt without sending any events.
+        self.character_server_connection = ServerConnection::Disconnected;
+
+        if reconnect.attempt >= self.connection_settings.reconnect_attempts {
+            self.abort_map_server_reconnect(events);
+            return;
+        }
+
+        let delay = self
+            .connection_settings
+            .reconnect_delay
+            .saturating_mul(1 << reconnect.attempt.min(8));
+
+        reconnect.attempt += 1;
+        reconnect.stage = ReconnectStage::Waiting {
+            until: Instant::now() + delay,
         };
+
+        events.push(NetworkEvent::MapServerReconnecting {
+            attempt: reconnect.attempt,
+        });
     }
 
-    pub fn get_events(&mut self, events: &mut NetworkEventBuffer) {
-        Self::handle_connection::<LoginServerDisconnectedEvent>(&mut self.login_server_connection, events);
-        Self::handle_connection::<CharacterServerDisconnectedEvent>(&mut self.character_server_connection, events);
-        Self::handle_connection::<MapServerDisconnectedEvent>(&mut self.map_server_connection, events);
+    fn abort_map_server_reconnect(&mut self, events: &mut Vec<NetworkEvent>) {
+        if let Some(reconnect) = self.map_server_reconnect.take() {
+            self.character_server_connection = ServerConnection::Disconnected;
+            events.push(NetworkEvent::MapServerDisconnected { reason: reconnect.reason });
+        }
+    }
+
+    /// Handle an event of the character server while reconnecting to the map
+    /// server.
+    fn handle_reconnect_event(&mut self, event: NetworkEvent, events: &mut Vec<NetworkEvent>) {
+        let Some(reconnect) = &mut self.map_server_reconnect else {
+            return;
+        };
+
+        match event {
+            NetworkEvent::CharacterServerConnected { .. } => {
+                let _ = self.request_character_list();
+            }
+            NetworkEvent::CharacterList { characters } => {
+                let character_id = reconnect.session.character_id;
+
+                match characters.iter().find(|character| character.character_id == character_id) {
+                    Some(character) => {
+                        let _ = self.select_character(character.character_number as usize);
+                    }
+                    // Retrying won't bring the character back.
+                    None => self.abort_map_server_reconnect(events),
+                }
+            }
+            NetworkEvent::CharacterSelected { login_data } => {
+                reconnect.stage = ReconnectStage::LoggingIn;
+
+                let packet_version = reconnect.session.packet_version;
+                let login_server_login_data = reconnect.session.login_data;
+
+                self.disconnect_from_character_server();
+                self.connect_to_map_server(packet_version, &login_server_login_data, login_data);
+                let _ = self.request_client_tick();
+            }
+            NetworkEvent::CharacterServerConnectionFailed { .. } | NetworkEvent::CharacterSelectionFailed { .. } => {
+                self.retry_map_server_reconnect(events);
+            }
+            _ => {}
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
     async fn handle_server_connection<PingPacket>(
+        server: ServerKind,
         address: SocketAddr,
         mut action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
@@ -289,13 +573,28 @@ where
         // Since our packet handler has no way of working with this, we need to add some special
         // logic.
         mut read_account_id: bool,
+        // Packet headers have to be obfuscated in the order the packets are written to the
+        // stream, so we do it here instead of when serializing them.
+        mut packet_obfuscation: Option<PacketObfuscation>,
+        settings: ConnectionSettings,
+        health: Arc<Mutex<ConnectionHealth>>,
         time_synchronization: Arc<Mutex<TimeSynchronization>>,
     ) -> Result<(), NetworkTaskError>
     where
         PingPacket: Packet + ClientPacket,
         Callback: PacketCallback,
     {
-        let mut stream = TcpStream::connect(address).await.map_err(|_| NetworkTaskError::FailedToConnect)?;
+        let mut stream = tokio::time::timeout(settings.connect_timeout, TcpStream::connect(address))
+            .await
+            .map_err(|_| NetworkTaskError::FailedToConnect)?
+            .map_err(|_| NetworkTaskError::FailedToConnect)?;
+        packet_handler.packet_callback().server_connected(server);
+
+        // Only the map server replies to keep-alive packets, so it's the only
+        // connection that can be monitored.
+        let monitor_keepalives = server == ServerKind::Map;
+        let mut pending_keepalive: Option<Instant> = None;
+
         let mut interval = tokio::time::interval(ping_frequency);
         let mut buffer = [0u8; 8192];
         let mut cut_off_buffer_base = 0;
@@ -303,14 +602,27 @@ where
         let mut byte_writer = ByteWriter::with_encoding(UTF_8);
 
         loop {
+            let keepalive_deadline = pending_keepalive.map(|sent_at| tokio::time::Instant::from_std(sent_at + settings.read_timeout));
+
             tokio::select! {
                 // Send a packet to the server.
                 action = action_receiver.recv() => {
-                    let Some(action) = action else {
+                    let Some(mut action) = action else {
                         // Channel was closed by the main thread.
                         break Ok(());
                     };
 
+                    packet_handler.packet_callback().raw_packet(server, PacketDirection::Outgoing, &action);
+
+                    // The client may also request the server tick manually.
+                    if monitor_keepalives && pending_keepalive.is_none() && action.get(..2) == Some(&PingPacket::HEADER.0.to_le_bytes()) {
+                        pending_keepalive = Some(Instant::now());
+                    }
+
+                    if let Some(packet_obfuscation) = &mut packet_obfuscation {
+                        packet_obfuscation.encode_header(&mut action);
+                    }
+
                     stream.write_all(&action).await.map_err(|_| NetworkTaskError::ConnectionClosed)?;
                 }
                 // Receive some packets from the server.
@@ -332,15 +644,21 @@ where
 
                     if read_account_id {
                         let account_id = AccountId::from_bytes(&mut byte_reader).unwrap();
+                        packet_handler.packet_callback().raw_packet(server, PacketDirection::Incoming, &data[..byte_reader.get_offset()]);
                         events.push(NetworkEvent::AccountId { account_id });
                         read_account_id = false;
                     }
 
                     while !byte_reader.is_empty() {
+                        let packet_start = byte_reader.get_offset();
+
                         match packet_handler.process_one(&mut byte_reader) {
-                            HandlerResult::Ok(packet_events) => events.extend(packet_events.0.into_iter()),
+                            HandlerResult::Ok(packet_events) => {
+                                let packet_end = byte_reader.get_offset();
+                                packet_handler.packet_callback().raw_packet(server, PacketDirection::Incoming, &data[packet_start..packet_end]);
+                                events.extend(packet_events.0.into_iter());
+                            },
                             HandlerResult::PacketCutOff => {
-                                let packet_start = byte_reader.get_offset();
                                 let packet_end = cut_off_buffer_base + received_bytes;
 
                                 if packet_start == 0 {
@@ -358,32 +676,150 @@ where
                             },
                             // The packet callback can take care of handling these properly.
                             HandlerResult::UnhandledPacket => {
+                                packet_handler.packet_callback().raw_packet(server, PacketDirection::Incoming, &data[packet_start..]);
                                 cut_off_buffer_base = 0;
                                 break
                             },
                             HandlerResult::InternalError(..) => {
+                                packet_handler.packet_callback().raw_packet(server, PacketDirection::Incoming, &data[packet_start..]);
                                 cut_off_buffer_base = 0;
                                 break
                             },
                         }
                     }
 
-                    for event in events.drain(..) {
-                        if let NetworkEvent::UpdateClientTick {client_tick,received_at} = &event && let Ok(mut time_synchronization) = time_synchronization.lock() {
-                            time_synchronization.estimated_client_tick(client_tick.0, *received_at);
+                    if let Some(sent_at) = pending_keepalive
+                        && let Some(received_at) = events.iter().find_map(|event| match event {
+                            NetworkEvent::UpdateClientTick { received_at, .. } => Some(*received_at),
+                            _ => None,
+                        })
+                    {
+                        if let Ok(mut health) = health.lock() {
+                            health.record_round_trip(received_at.saturating_duration_since(sent_at));
                         }
 
-                        event_sender.send(event).map_err(|_| NetworkTaskError::ConnectionClosed)?;
+                        pending_keepalive = None;
                     }
+
+                    Self::forward_events(&mut events, &event_sender, &time_synchronization)?;
                 }
                 // Send a keep-alive packet to the server.
                 _ = interval.tick() => {
                     ping_factory(&time_synchronization).packet_to_bytes(&mut byte_writer).unwrap();
+
+                    if let Some(packet_obfuscation) = &mut packet_obfuscation {
+                        packet_obfuscation.encode_header(byte_writer.as_mut_slice());
+                    }
+
                     stream.write_all(byte_writer.as_slice()).await.map_err(|_| NetworkTaskError::ConnectionClosed)?;
                     byte_writer.clear();
+
+                    if monitor_keepalives && pending_keepalive.is_none() {
+                        pending_keepalive = Some(Instant::now());
+                    }
+                }
+                // The server didn't reply to the keep-alive packet in time.
+                _ = tokio::time::sleep_until(keepalive_deadline.unwrap_or_else(tokio::time::Instant::now)), if keepalive_deadline.is_some() => {
+                    pending_keepalive = None;
+
+                    let stalled = health
+                        .lock()
+                        .is_ok_and(|mut health| health.record_missed_keepalive(settings.max_missed_keepalives));
+
+                    if stalled {
+                        break Err(NetworkTaskError::TimedOut);
+                    }
+                }
+            }
+        }
+    }
+
+    fn forward_events(
+        events: &mut Vec<NetworkEvent>,
+        event_sender: &UnboundedSender<NetworkEvent>,
+        time_synchronization: &Mutex<TimeSynchronization>,
+    ) -> Result<(), NetworkTaskError> {
+        for event in events.drain(..) {
+            if let NetworkEvent::UpdateClientTick { client_tick, received_at } = &event
+                && let Ok(mut time_synchronization) = time_synchronization.lock()
+            {
+                time_synchronization.estimated_client_tick(client_tick.0, *received_at);
+            }
+
+            event_sender.send(event).map_err(|_| NetworkTaskError::ConnectionClosed)?;
+        }
+
+        Ok(())
+    }
+
+    async fn handle_replay_connection(
+        records: Option<Vec<SessionRecord>>,
+        mut action_receiver: UnboundedReceiver<Vec<u8>>,
+        event_sender: UnboundedSender<NetworkEvent>,
+        mut packet_handler: PacketHandler<NetworkEventList, (), Callback>,
+        mut read_account_id: bool,
+        time_synchronization: Arc<Mutex<TimeSynchronization>>,
+    ) -> Result<(), NetworkTaskError>
+    where
+        Callback: PacketCallback,
+    {
+        // There is no recorded connection left, so we act as if the server is
+        // unreachable.
+        let records = records.ok_or(NetworkTaskError::FailedToConnect)?;
+        let mut events = Vec::new();
+        let mut previous_timestamp = Duration::ZERO;
+
+        for record in records {
+            match record.kind {
+                SessionRecordKind::Connected => {}
+                SessionRecordKind::Incoming(bytes) => {
+                    tokio::time::sleep(record.timestamp.saturating_sub(previous_timestamp)).await;
+
+                    let mut byte_reader = ByteReader::without_metadata(&bytes);
+                    byte_reader.set_encoding(UTF_8);
+
+                    if read_account_id {
+                        // A truncated or edited recording ends the replay, just like a server that
+                        // closes the connection.
+                        let account_id = AccountId::from_bytes(&mut byte_reader).map_err(|_| NetworkTaskError::ConnectionClosed)?;
+                        events.push(NetworkEvent::AccountId { account_id });
+                        read_account_id = false;
+                    }
+
+                    // Every record holds exactly one packet, so there is nothing to do if the
+                    // packet can't be processed.
+                    while !byte_reader.is_empty() {
+                        match packet_handler.process_one(&mut byte_reader) {
+                            HandlerResult::Ok(packet_events) => events.extend(packet_events.0.into_iter()),
+                            _ => break,
+                        }
+                    }
+
+                    Self::forward_events(&mut events, &event_sender, &time_synchronization)?;
+                }
+                SessionRecordKind::Outgoing(bytes) => {
+                    // Wait for the client to send the same packet as in the recording. Packets
+                    // that were not sent in the recorded session are dropped.
+                    loop {
+                        let Some(action) = action_receiver.recv().await else {
+                            // Channel was closed by the main thread.
+                            return Ok(());
+                        };
+
+                        if action.get(..2) == bytes.get(..2) {
+                            break;
+                        }
+                    }
                 }
             }
+
+            previous_timestamp = record.timestamp;
         }
+
+        // Keep the connection open until the client closes it.
+        while action_receiver.recv().await.is_some() {}
+
+        Ok(())
     }
 
     pub fn connect_to_login_server(
@@ -392,6 +828,7 @@ where
         address: SocketAddr,
         username: impl Into<String>,
         password: impl Into<String>,
+        password_encryption: PasswordEncryption,
     ) {
         if !matches!(self.login_server_connection, ServerConnection::Disconnected) {
             return;
@@ -399,6 +836,7 @@ where
 
         let (action_sender, action_receiver) = tokio::sync::mpsc::unbounded_channel();
         let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let health = Arc::new(Mutex::new(ConnectionHealth::default()));
 
         self.command_sender
             .send(ServerConnectCommand::Login {
@@ -406,24 +844,57 @@ where
                 action_receiver,
                 event_sender,
                 packet_version,
+                settings: self.connection_settings,
+                health: Arc::clone(&health),
             })
             .expect("network thread dropped");
 
-        let login_packet = LoginServerLoginPacket::new(username.into(), password.into());
-
-        self.packet_callback.outgoing_packet(&login_packet);
-
-        let mut byte_writer = ByteWriter::with_encoding(UTF_8);
-        login_packet.packet_to_bytes(&mut byte_writer).unwrap();
-        action_sender
-            .send(byte_writer.into_inner())
-            .expect("action receiver instantly dropped");
-
         self.login_server_connection = ServerConnection::Connected {
             action_sender,
             event_receiver,
             packet_version,
+            health,
+        };
+
+        let result = match password_encryption {
+            PasswordEncryption::None => {
+                self.pending_hashed_login = None;
+                self.send_login_server_packet(LoginServerLoginPacket::new(username.into(), password.into()))
+            }
+            PasswordEncryption::Method1 | PasswordEncryption::Method2 => {
+                // We can only hash the password once the login server sent us the salt.
+                self.pending_hashed_login = Some(PendingHashedLogin {
+                    username: username.into(),
+                    password: password.into(),
+                    password_encryption,
+                });
+                self.send_login_server_packet(RequestPasswordSaltPacket::default())
+            }
+        };
+
+        result.expect("action receiver instantly dropped");
+    }
+
+    /// Finish a login that was started with a hashed [`PasswordEncryption`],
+    /// after the login server sent the salt.
+    pub fn send_hashed_login(&mut self, salt: &[u8]) -> Result<(), NotConnectedError> {
+        let PendingHashedLogin {
+            username,
+            password,
+            password_encryption,
+        } = self.pending_hashed_login.take().ok_or(NotConnectedError)?;
+
+        let salted_password = match password_encryption {
+            PasswordEncryption::Method2 => [password.as_bytes(), salt].concat(),
+            _ => [salt, password.as_bytes()].concat(),
         };
+        let password_hash = md5::md5(&salted_password);
+
+        match self.login_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_login_server_packet(LoginServerHashedLoginPacket::new(username, password_hash))
+            }
+        }
     }
 
     pub fn connect_to_character_server(
@@ -438,8 +909,10 @@ where
 
         let (action_sender, action_receiver) = tokio::sync::mpsc::unbounded_channel();
         let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let health = Arc::new(Mutex::new(ConnectionHealth::default()));
 
         let address = SocketAddr::new(IpAddr::V4(server.server_ip.into()), server.server_port);
+        self.last_character_server = Some(server);
 
         self.command_sender
             .send(ServerConnectCommand::Character {
@@ -447,6 +920,8 @@ where
                 action_receiver,
                 event_sender,
                 packet_version,
+                settings: self.connection_settings,
+                health: Arc::clone(&health),
             })
             .expect("network thread dropped");
 
@@ -469,6 +944,7 @@ where
             action_sender,
             event_receiver,
             packet_version,
+            health,
         };
     }
 
@@ -484,15 +960,25 @@ where
 
         let (action_sender, action_receiver) = tokio::sync::mpsc::unbounded_channel();
         let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let health = Arc::new(Mutex::new(ConnectionHealth::default()));
 
         let address = SocketAddr::new(character_server_login_data.server_ip, character_server_login_data.server_port);
 
+        self.map_server_session = self.last_character_server.clone().map(|character_server| MapServerSession {
+            packet_version,
+            login_data: *login_server_login_data,
+            character_server,
+            character_id: character_server_login_data.character_id,
+        });
+
         self.command_sender
             .send(ServerConnectCommand::Map {
                 address,
                 action_receiver,
                 event_sender,
                 packet_version,
+                settings: self.connection_settings,
+                health: Arc::clone(&health),
             })
             .expect("network thread dropped");
 
@@ -518,6 +1004,7 @@ where
             action_sender,
             event_receiver,
             packet_version,
+            health,
         };
     }
 
@@ -530,6 +1017,10 @@ where
     }
 
     pub fn disconnect_from_map_server(&mut self) {
+        if self.map_server_reconnect.take().is_some() {
+            self.character_server_connection = ServerConnection::Disconnected;
+        }
+
         self.map_server_connection = ServerConnection::ClosingManually;
     }
 
@@ -545,6 +1036,37 @@ where
         matches!(self.map_server_connection, ServerConnection::Connected { .. })
     }
 
+    pub fn is_reconnecting_to_map_server(&self) -> bool {
+        self.map_server_reconnect.is_some()
+    }
+
+    /// The timeouts only apply to connections that are established after
+    /// calling this, the reconnect settings apply right away.
+    pub fn set_connection_settings(&mut self, connection_settings: ConnectionSettings) {
+        self.connection_settings = connection_settings;
+    }
+
+    pub fn connection_settings(&self) -> ConnectionSettings {
+        self.connection_settings
+    }
+
+    /// Round trip time statistics of the current connection to the map
+    /// server. Returns `None` until the first reply to a keep-alive packet
+    /// was received.
+    pub fn map_server_latency(&self) -> Option<LatencyStatistics> {
+        match &self.map_server_connection {
+            ServerConnection::Connected { health, .. } => health.lock().ok().and_then(|health| health.latency),
+            _ => None,
+        }
+    }
+
+    fn login_server_packet_version(&self) -> Result<SupportedPacketVersion, NotConnectedError> {
+        match &self.login_server_connection {
+            ServerConnection::Connected { packet_version, .. } => Ok(*packet_version),
+            _ => Err(NotConnectedError),
+        }
+    }
+
     fn character_server_packet_version(&self) -> Result<SupportedPacketVersion, NotConnectedError> {
         match &self.character_server_connection {
             ServerConnection::Connected { packet_version, .. } => Ok(*packet_version),
@@ -559,6 +1081,20 @@ where
         }
     }
 
+    fn send_login_server_packet(&mut self, packet: impl LoginServerPacket) -> Result<(), NotConnectedError> {
+        match &mut self.login_server_connection {
+            ServerConnection::Connected { action_sender, .. } => {
+                self.packet_callback.outgoing_packet(&packet);
+
+                // FIX: Don't unwrap.
+                let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+                packet.packet_to_bytes(&mut byte_writer).unwrap();
+                action_sender.send(byte_writer.into_inner()).map_err(|_| NotConnectedError)
+            }
+            _ => Err(NotConnectedError),
+        }
+    }
+
     fn send_character_server_packet(&mut self, packet: impl CharacterServerPacket) -> Result<(), NotConnectedError> {
         match &mut self.character_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
@@ -594,6 +1130,7 @@ where
         let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
 
         match packet_version {
+            SupportedPacketVersion::_20200401 => packet_versions::version_20200401::register_login_server_packets(&mut packet_handler)?,
             SupportedPacketVersion::_20220406 => packet_versions::version_20220406::register_login_server_packets(&mut packet_handler)?,
         }
 
@@ -607,6 +1144,7 @@ where
         let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
 
         match packet_version {
+            SupportedPacketVersion::_20200401 => packet_versions::version_20200401::register_character_server_packets(&mut packet_handler)?,
             SupportedPacketVersion::_20220406 => packet_versions::version_20220406::register_character_server_packets(&mut packet_handler)?,
         }
 
@@ -620,6 +1158,7 @@ where
         let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
 
         match packet_version {
+            SupportedPacketVersion::_20200401 => packet_versions::version_20200401::register_map_server_packets(&mut packet_handler)?,
             SupportedPacketVersion::_20220406 => packet_versions::version_20220406::register_map_server_packets(&mut packet_handler)?,
         }
 
@@ -628,13 +1167,17 @@ where
 
     pub fn request_character_list(&mut self) -> Result<(), NotConnectedError> {
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_character_server_packet(RequestCharacterListPacket::default()),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_character_server_packet(RequestCharacterListPacket::default())
+            }
         }
     }
 
     pub fn select_character(&mut self, character_slot: usize) -> Result<(), NotConnectedError> {
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_character_server_packet(SelectCharacterPacket::new(character_slot as u8)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_character_server_packet(SelectCharacterPacket::new(character_slot as u8))
+            }
         }
     }
 
@@ -645,9 +1188,9 @@ where
         let sex = Sex::Male;
 
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_character_server_packet(CreateCharacterPacket::new(
-                name, slot as u8, hair_color, hair_style, start_job, sex,
-            )),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_character_server_packet(
+                CreateCharacterPacket::new(name, slot as u8, hair_color, hair_style, start_job, sex),
+            ),
         }
     }
 
@@ -655,13 +1198,15 @@ where
         let email = "a@a.com".to_string();
 
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_character_server_packet(DeleteCharacterPacket::new(character_id, email)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_character_server_packet(DeleteCharacterPacket::new(character_id, email))
+            }
         }
     }
 
     pub fn switch_character_slot(&mut self, origin_slot: usize, destination_slot: usize) -> Result<(), NotConnectedError> {
         match self.character_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
                 self.send_character_server_packet(SwitchCharacterSlotPacket::new(origin_slot as u16, destination_slot as u16))
             }
         }
@@ -669,7 +1214,9 @@ where
 
     pub fn map_loaded(&mut self) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(MapLoadedPacket::default()),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(MapLoadedPacket::default())
+            }
         }
     }
 
@@ -681,43 +1228,57 @@ where
             .unwrap_or(100);
 
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestServerTickPacket::new(ClientTick(client_tick))),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestServerTickPacket::new(ClientTick(client_tick)))
+            }
         }
     }
 
     pub fn respawn(&mut self) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RestartPacket::new(RestartType::Respawn)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RestartPacket::new(RestartType::Respawn))
+            }
         }
     }
 
     pub fn log_out(&mut self) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RestartPacket::new(RestartType::Disconnect)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RestartPacket::new(RestartType::Disconnect))
+            }
         }
     }
 
     pub fn player_move(&mut self, position: WorldPosition) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestPlayerMovePacket::new(position)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestPlayerMovePacket::new(position))
+            }
         }
     }
 
     pub fn warp_to_map(&mut self, map_name: String, position: TilePosition) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestWarpToMapPacket::new(map_name, position)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestWarpToMapPacket::new(map_name, position))
+            }
         }
     }
 
     pub fn entity_details(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestDetailsPacket::new(entity_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestDetailsPacket::new(entity_id))
+            }
         }
     }
 
     pub fn player_attack(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestActionPacket::new(entity_id, Action::Attack)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestActionPacket::new(entity_id, Action::Attack))
+            }
         }
     }
 
@@ -725,49 +1286,65 @@ where
         let message = format!("{} : {}", player_name, text);
 
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(GlobalMessagePacket::new(message)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(GlobalMessagePacket::new(message))
+            }
         }
     }
 
     pub fn start_dialog(&mut self, npc_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(StartDialogPacket::new(npc_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(StartDialogPacket::new(npc_id))
+            }
         }
     }
 
     pub fn next_dialog(&mut self, npc_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(NextDialogPacket::new(npc_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(NextDialogPacket::new(npc_id))
+            }
         }
     }
 
     pub fn close_dialog(&mut self, npc_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(CloseDialogPacket::new(npc_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CloseDialogPacket::new(npc_id))
+            }
         }
     }
 
     pub fn choose_dialog_option(&mut self, npc_id: EntityId, option: i8) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(ChooseDialogOptionPacket::new(npc_id, option)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(ChooseDialogOptionPacket::new(npc_id, option))
+            }
         }
     }
 
     pub fn request_item_equip(&mut self, item_index: InventoryIndex, equip_position: EquipPosition) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestEquipItemPacket::new(item_index, equip_position)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestEquipItemPacket::new(item_index, equip_position))
+            }
         }
     }
 
     pub fn request_item_unequip(&mut self, item_index: InventoryIndex) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestUnequipItemPacket::new(item_index)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestUnequipItemPacket::new(item_index))
+            }
         }
     }
 
     pub fn cast_skill(&mut self, skill_id: SkillId, skill_level: SkillLevel, entity_id: EntityId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(UseSkillAtIdPacket::new(skill_level, skill_id, entity_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(UseSkillAtIdPacket::new(skill_level, skill_id, entity_id))
+            }
         }
     }
 
@@ -778,7 +1355,7 @@ where
         target_position: TilePosition,
     ) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
                 self.send_map_server_packet(UseSkillOnGroundPacket::new(skill_level, skill_id, target_position))
             }
         }
@@ -791,57 +1368,281 @@ where
         entity_id: EntityId,
     ) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(StartUseSkillPacket::new(skill_id, skill_level, entity_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(StartUseSkillPacket::new(skill_id, skill_level, entity_id))
+            }
         }
     }
 
     pub fn stop_channeling_skill(&mut self, skill_id: SkillId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(EndUseSkillPacket::new(skill_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(EndUseSkillPacket::new(skill_id))
+            }
         }
     }
 
     pub fn add_friend(&mut self, name: String) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(AddFriendPacket::new(name)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddFriendPacket::new(name))
+            }
         }
     }
 
     pub fn remove_friend(&mut self, account_id: AccountId, character_id: CharacterId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RemoveFriendPacket::new(account_id, character_id)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RemoveFriendPacket::new(account_id, character_id))
+            }
         }
     }
 
     pub fn reject_friend_request(&mut self, account_id: AccountId, character_id: CharacterId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(FriendRequestResponsePacket::new(
-                account_id,
-                character_id,
-                FriendRequestResponse::Reject,
-            )),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(
+                FriendRequestResponsePacket::new(account_id, character_id, FriendRequestResponse::Reject),
+            ),
         }
     }
 
     pub fn accept_friend_request(&mut self, account_id: AccountId, character_id: CharacterId) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(FriendRequestResponsePacket::new(
-                account_id,
-                character_id,
-                FriendRequestResponse::Accept,
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(
+                FriendRequestResponsePacket::new(account_id, character_id, FriendRequestResponse::Accept),
+            ),
+        }
+    }
+
+    pub fn create_party(&mut self, party_name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(CreatePartyPacket::new(
+                party_name,
+                PartyItemShare::EachTake,
+                PartyItemShare::EachTake,
             )),
         }
     }
 
+    pub fn invite_to_party(&mut self, name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(InvitePartyMemberPacket::new(name))
+            }
+        }
+    }
+
+    pub fn reject_party_invite(&mut self, party_id: PartyId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(PartyInviteResponsePacket::new(party_id, PartyInviteResponse::Reject))
+            }
+        }
+    }
+
+    pub fn accept_party_invite(&mut self, party_id: PartyId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(PartyInviteResponsePacket::new(party_id, PartyInviteResponse::Accept))
+            }
+        }
+    }
+
+    pub fn leave_party(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(LeavePartyPacket::default())
+            }
+        }
+    }
+
+    pub fn expel_party_member(&mut self, account_id: AccountId, name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(ExpelPartyMemberPacket::new(account_id, name))
+            }
+        }
+    }
+
+    pub fn change_party_options(
+        &mut self,
+        experience_share: PartyExperienceShare,
+        item_pickup_rule: PartyItemShare,
+        item_distribution_rule: PartyItemShare,
+    ) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(
+                ChangePartyOptionsPacket::new(experience_share, item_pickup_rule, item_distribution_rule),
+            ),
+        }
+    }
+
+    pub fn change_party_leader(&mut self, account_id: AccountId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(ChangePartyLeaderPacket::new(account_id))
+            }
+        }
+    }
+
+    pub fn send_party_message(&mut self, player_name: &str, text: &str) -> Result<(), NotConnectedError> {
+        let message = format!("{} : {}", player_name, text);
+
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(SendPartyMessagePacket::new(message))
+            }
+        }
+    }
+
+    pub fn request_guild_information(&mut self, information_type: GuildInformationType) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestGuildInformationPacket::new(information_type))
+            }
+        }
+    }
+
+    pub fn request_guild_emblem(&mut self, guild_id: GuildId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestGuildEmblemPacket::new(guild_id))
+            }
+        }
+    }
+
+    pub fn request_character_name(&mut self, character_id: CharacterId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestCharacterNamePacket::new(character_id))
+            }
+        }
+    }
+
+    pub fn send_guild_message(&mut self, player_name: &str, text: &str) -> Result<(), NotConnectedError> {
+        let message = format!("{} : {}", player_name, text);
+
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(SendGuildMessagePacket::new(message))
+            }
+        }
+    }
+
+    pub fn request_trade(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestTradePacket::new(entity_id))
+            }
+        }
+    }
+
+    pub fn reject_trade_request(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(TradeRequestResponsePacket::new(TradeRequestResponse::Reject))
+            }
+        }
+    }
+
+    pub fn accept_trade_request(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(TradeRequestResponsePacket::new(TradeRequestResponse::Accept))
+            }
+        }
+    }
+
+    pub fn add_trade_item(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        // The trade packet uses the raw item index, which is the inventory index + 2.
+        let raw_index = index.0 + 2;
+
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddTradeItemPacket::new(raw_index, amount))
+            }
+        }
+    }
+
+    pub fn add_trade_zeny(&mut self, amount: u32) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddTradeItemPacket::new(0, amount))
+            }
+        }
+    }
+
+    pub fn lock_trade(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(LockTradePacket::default())
+            }
+        }
+    }
+
+    pub fn cancel_trade(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CancelTradePacket::default())
+            }
+        }
+    }
+
+    pub fn commit_trade(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CommitTradePacket::default())
+            }
+        }
+    }
+
+    pub fn add_storage_item(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddStorageItemPacket::new(index, amount))
+            }
+        }
+    }
+
+    pub fn add_storage_item_from_cart(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddStorageItemFromCartPacket::new(index, amount))
+            }
+        }
+    }
+
+    /// Move an item from the storage to the inventory. The index is the storage
+    /// index of the item.
+    pub fn remove_storage_item(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RemoveStorageItemPacket::new(StorageIndex(index.0), amount))
+            }
+        }
+    }
+
+    pub fn close_storage(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CloseStoragePacket::default())
+            }
+        }
+    }
+
     pub fn set_hotkey_data(&mut self, tab: HotbarTab, index: HotbarSlot, hotkey_data: HotkeyData) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(SetHotkeyData2Packet::new(tab, index, hotkey_data)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(SetHotkeyData2Packet::new(tab, index, hotkey_data))
+            }
         }
     }
 
     pub fn select_buy_or_sell(&mut self, shop_id: ShopId, buy_or_sell: BuyOrSellOption) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(SelectBuyOrSellPacket::new(shop_id, buy_or_sell)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(SelectBuyOrSellPacket::new(shop_id, buy_or_sell))
+            }
         }
     }
 
@@ -855,25 +1656,311 @@ where
             .collect();
 
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(BuyShopItemsPacket::new(item_information)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(BuyShopItemsPacket::new(item_information))
+            }
         }
     }
 
     pub fn close_shop(&mut self) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(CloseShopPacket::new()),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(CloseShopPacket::new()),
         }
     }
 
     pub fn sell_items(&mut self, items: Vec<SoldItemInformation>) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(SellItemsPacket { items }),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(SellItemsPacket { items }),
         }
     }
 
     pub fn request_stat_up(&mut self, stat_type: StatUpType) -> Result<(), NotConnectedError> {
         match self.map_server_packet_version()? {
-            SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestStatUpPacket::new(stat_type)),
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestStatUpPacket::new(stat_type))
+            }
+        }
+    }
+
+    pub fn select_pet_egg(&mut self, index: InventoryIndex) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(SelectPetEggPacket { index })
+            }
+        }
+    }
+
+    pub fn pet_command(&mut self, command: PetCommand) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(PetCommandPacket { command })
+            }
+        }
+    }
+
+    pub fn rename_pet(&mut self, name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(RenamePetPacket { name }),
+        }
+    }
+
+    pub fn homunculus_command(&mut self, command: HomunculusCommand) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(HomunculusCommandPacket {
+                companion_type: 0,
+                command,
+            }),
+        }
+    }
+
+    pub fn rename_homunculus(&mut self, name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RenameHomunculusPacket { name })
+            }
+        }
+    }
+
+    pub fn mercenary_command(&mut self, command: MercenaryCommand) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(MercenaryCommandPacket { command })
+            }
+        }
+    }
+
+    /// Move the homunculus or mercenary of the player.
+    pub fn move_companion(&mut self, entity_id: EntityId, position: WorldPosition) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(MoveCompanionPacket { entity_id, position })
+            }
+        }
+    }
+
+    /// Make the homunculus or mercenary of the player return to the player.
+    pub fn move_companion_to_owner(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(MoveCompanionToOwnerPacket { entity_id })
+            }
+        }
+    }
+
+    /// Make the homunculus or mercenary of the player attack an entity. If
+    /// `continuous` is set, the companion keeps attacking the target.
+    pub fn companion_attack(&mut self, entity_id: EntityId, target_entity_id: EntityId, continuous: bool) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(CompanionAttackPacket {
+                entity_id,
+                target_entity_id,
+                action: continuous as u8,
+            }),
+        }
+    }
+
+    /// Move an item from the inventory into the cart.
+    pub fn add_cart_item(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddCartItemPacket { index, amount })
+            }
+        }
+    }
+
+    /// Move an item from the cart into the inventory. The index is the cart
+    /// index of the item.
+    pub fn remove_cart_item(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RemoveCartItemPacket { index, amount })
+            }
+        }
+    }
+
+    /// Open a vending shop with items from the cart. Only valid after the
+    /// server asked the player to set up the shop.
+    pub fn open_vending(&mut self, shop_title: String, items: Vec<VendingItemSetup>) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(SetupVendingPacket {
+                shop_title,
+                open: 1,
+                items,
+            }),
+        }
+    }
+
+    pub fn cancel_vending_setup(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(SetupVendingPacket {
+                shop_title: String::new(),
+                open: 0,
+                items: Vec::new(),
+            }),
+        }
+    }
+
+    pub fn close_vending(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CloseVendingPacket::default())
+            }
+        }
+    }
+
+    /// Request the items of the vending shop of another player.
+    pub fn request_vending_items(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestVendingItemsPacket { entity_id })
+            }
+        }
+    }
+
+    pub fn buy_vending_items(
+        &mut self,
+        entity_id: EntityId,
+        vending_id: u32,
+        items: Vec<VendingPurchase>,
+    ) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(BuyVendingItemsPacket {
+                entity_id,
+                vending_id,
+                items,
+            }),
+        }
+    }
+
+    /// Open the mailbox. The server answers with the newest mails.
+    pub fn open_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(OpenMailboxPacket {
+                mail_id: MailId(0),
+                unknown: [0; 16],
+            }),
+        }
+    }
+
+    pub fn refresh_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(RefreshMailboxPacket {
+                mail_id: MailId(0),
+                unknown: [0; 16],
+            }),
+        }
+    }
+
+    pub fn close_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CloseMailboxPacket::default())
+            }
+        }
+    }
+
+    pub fn read_mail(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestReadMailPacket { open_type, mail_id })
+            }
+        }
+    }
+
+    /// Take the zeny attached to a mail.
+    pub fn request_mail_zeny(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestMailZenyPacket { mail_id, open_type })
+            }
+        }
+    }
+
+    /// Take the items attached to a mail.
+    pub fn request_mail_items(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RequestMailItemsPacket { mail_id, open_type })
+            }
+        }
+    }
+
+    pub fn delete_mail(&mut self, open_type: MailOpenType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(DeleteMailPacket { open_type, mail_id })
+            }
+        }
+    }
+
+    /// Start writing a mail. Items can only be attached after this.
+    pub fn start_writing_mail(&mut self, receiver_name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(OpenWriteMailPacket { receiver_name })
+            }
+        }
+    }
+
+    /// Stop writing a mail. The attached items are given back to the player.
+    pub fn cancel_writing_mail(&mut self) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CancelWriteMailPacket::default())
+            }
+        }
+    }
+
+    pub fn add_mail_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(AddMailItemPacket { index, amount })
+            }
+        }
+    }
+
+    pub fn remove_mail_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(RemoveMailItemPacket { index, amount })
+            }
+        }
+    }
+
+    /// Look up the character a mail should be sent to. The mail can only be
+    /// sent once the server answered with the id of the character.
+    pub fn check_mail_receiver(&mut self, name: String) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => {
+                self.send_map_server_packet(CheckMailReceiverPacket { name })
+            }
+        }
+    }
+
+    pub fn send_mail(
+        &mut self,
+        player_name: &str,
+        receiver_name: String,
+        receiver_character_id: CharacterId,
+        title: String,
+        text: String,
+        zeny: u64,
+    ) -> Result<(), NotConnectedError> {
+        // The lengths include the terminator.
+        let title_length = title.len() as u16 + 1;
+        let text_length = text.len() as u16 + 1;
+
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20200401 | SupportedPacketVersion::_20220406 => self.send_map_server_packet(SendMailPacket {
+                receiver_name,
+                sender_name: player_name.to_owned(),
+                zeny,
+                title_length,
+                text_length,
+                receiver_character_id,
+                title,
+                text,
+            }),
         }
     }
 }
@@ -885,19 +1972,37 @@ mod packet_handlers {
     use crate::{NetworkingSystem, SupportedPacketVersion};
 
     #[test]
-    fn login_server() {
+    fn login_server_20200401() {
+        let result = NetworkingSystem::create_login_server_packet_handler(NoPacketCallback, SupportedPacketVersion::_20200401);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn login_server_20220406() {
         let result = NetworkingSystem::create_login_server_packet_handler(NoPacketCallback, SupportedPacketVersion::_20220406);
         assert!(result.is_ok());
     }
 
     #[test]
-    fn character_server() {
+    fn character_server_20200401() {
+        let result = NetworkingSystem::create_character_server_packet_handler(NoPacketCallback, SupportedPacketVersion::_20200401);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn character_server_20220406() {
         let result = NetworkingSystem::create_character_server_packet_handler(NoPacketCallback, SupportedPacketVersion::_20220406);
         assert!(result.is_ok());
     }
 
     #[test]
-    fn map_server() {
+    fn map_server_20200401() {
+        let result = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback, SupportedPacketVersion::_20200401);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn map_server_20220406() {
         let result = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback, SupportedPacketVersion::_20220406);
         assert!(result.is_ok());
     }
@@ -0,0 +1,38 @@
+use ragnarok_packets::{MailId, MailListEntry, MailOpenType};
+
+const MAIL_TYPE_ZENY: u8 = 0x2;
+const MAIL_TYPE_ITEM: u8 = 0x4;
+const MAIL_TYPE_NPC: u8 = 0x8;
+
+/// A mail in the mailbox of the player, without its contents.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MailSummary {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub sender_name: String,
+    pub title: String,
+    pub is_read: bool,
+    pub has_zeny: bool,
+    pub has_items: bool,
+    /// The mail was sent by an NPC rather than another player.
+    pub from_npc: bool,
+    /// Seconds until the mail expires.
+    pub expiration_time: u32,
+}
+
+impl From<MailListEntry> for MailSummary {
+    fn from(entry: MailListEntry) -> Self {
+        Self {
+            mail_id: entry.mail_id,
+            open_type: entry.open_type,
+            sender_name: entry.sender_name,
+            title: entry.title,
+            is_read: entry.is_read != 0,
+            has_zeny: entry.mail_type & MAIL_TYPE_ZENY != 0,
+            has_items: entry.mail_type & MAIL_TYPE_ITEM != 0,
+            from_npc: entry.mail_type & MAIL_TYPE_NPC != 0,
+            expiration_time: entry.expiration_time,
+        }
+    }
+}
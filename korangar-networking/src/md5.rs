@@ -0,0 +1,108 @@
+//! Minimal MD5 implementation (RFC 1321) used for hashed passwords when
+//! logging in to the login server.
+
+const SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4,
+    11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+const INITIAL_STATE: [u32; 4] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476];
+
+/// `floor(abs(sin(index + 1)) * 2^32)` for every round.
+const CONSTANTS: [u32; 64] = [
+    0xD76AA478, 0xE8C7B756, 0x242070DB, 0xC1BDCEEE, 0xF57C0FAF, 0x4787C62A, 0xA8304613, 0xFD469501, 0x698098D8, 0x8B44F7AF, 0xFFFF5BB1,
+    0x895CD7BE, 0x6B901122, 0xFD987193, 0xA679438E, 0x49B40821, 0xF61E2562, 0xC040B340, 0x265E5A51, 0xE9B6C7AA, 0xD62F105D, 0x02441453,
+    0xD8A1E681, 0xE7D3FBC8, 0x21E1CDE6, 0xC33707D6, 0xF4D50D87, 0x455A14ED, 0xA9E3E905, 0xFCEFA3F8, 0x676F02D9, 0x8D2A4C8A, 0xFFFA3942,
+    0x8771F681, 0x6D9D6122, 0xFDE5380C, 0xA4BEEA44, 0x4BDECFA9, 0xF6BB4B60, 0xBEBFBC70, 0x289B7EC6, 0xEAA127FA, 0xD4EF3085, 0x04881D05,
+    0xD9D4D039, 0xE6DB99E5, 0x1FA27CF8, 0xC4AC5665, 0xF4292244, 0x432AFF97, 0xAB9423A7, 0xFC93A039, 0x655B59C3, 0x8F0CCC92, 0xFFEFF47D,
+    0x85845DD1, 0x6FA87E4F, 0xFE2CE6E0, 0xA3014314, 0x4E0811A1, 0xF7537E82, 0xBD3AF235, 0x2AD7D2BB, 0xEB86D391,
+];
+
+fn process_block(state: &mut [u32; 4], block: &[u8]) {
+    let words: [u32; 16] = std::array::from_fn(|index| u32::from_le_bytes(block[index * 4..index * 4 + 4].try_into().unwrap()));
+    let [mut a, mut b, mut c, mut d] = *state;
+
+    for round in 0..64 {
+        let (mixed, word_index) = match round / 16 {
+            0 => ((b & c) | (!b & d), round),
+            1 => ((d & b) | (!d & c), (5 * round + 1) % 16),
+            2 => (b ^ c ^ d, (3 * round + 5) % 16),
+            _ => (c ^ (b | !d), (7 * round) % 16),
+        };
+
+        let rotated = a
+            .wrapping_add(mixed)
+            .wrapping_add(CONSTANTS[round])
+            .wrapping_add(words[word_index])
+            .rotate_left(SHIFTS[round]);
+
+        a = d;
+        d = c;
+        c = b;
+        b = b.wrapping_add(rotated);
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+}
+
+pub(crate) fn md5(data: &[u8]) -> [u8; 16] {
+    let mut state = INITIAL_STATE;
+
+    // Pad the message with a single set bit, followed by zeros and the length of
+    // the message in bits, so that the total length is a multiple of 64 bytes.
+    let mut message = data.to_vec();
+    message.push(0x80);
+    message.resize(message.len().next_multiple_of(64), 0);
+
+    if 64 - (data.len() % 64) <= 8 {
+        message.extend_from_slice(&[0; 64]);
+    }
+
+    let length_offset = message.len() - 8;
+    message[length_offset..].copy_from_slice(&(data.len() as u64).wrapping_mul(8).to_le_bytes());
+
+    message.chunks_exact(64).for_each(|block| process_block(&mut state, block));
+
+    let mut digest = [0; 16];
+    digest
+        .chunks_exact_mut(4)
+        .zip(state)
+        .for_each(|(bytes, word)| bytes.copy_from_slice(&word.to_le_bytes()));
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::md5;
+
+    fn hex(digest: [u8; 16]) -> String {
+        digest.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_vectors() {
+        // Test suite from RFC 1321.
+        let cases = [
+            ("", "d41d8cd98f00b204e9800998ecf8427e"),
+            ("a", "0cc175b9c0f1b6a831c399e269772661"),
+            ("abc", "900150983cd24fb0d6963f7d28e17f72"),
+            ("message digest", "f96b697d7cb7938d525a2f31aaf161d0"),
+            ("abcdefghijklmnopqrstuvwxyz", "c3fcd3d76192e4007dfb496cca67e13b"),
+            (
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+                "d174ab98d277d9f5a5611c2c9f419d9f",
+            ),
+            (
+                "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                "57edf4a22be3c955ac49da2e2107b67a",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(hex(md5(input.as_bytes())), expected, "input: {input:?}");
+        }
+    }
+}
@@ -6,4 +6,6 @@ pub enum MessageColor {
     Server,
     Error,
     Information,
+    Party,
+    Guild,
 }
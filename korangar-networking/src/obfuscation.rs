@@ -0,0 +1,107 @@
+/// The three keys that a client uses to obfuscate the headers of the packets
+/// it sends to the map server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PacketKeys(pub u32, pub u32, pub u32);
+
+/// Obfuscation state of a map server connection.
+///
+/// Each packet header is XORed with a value derived from the current key. The
+/// first key is `keys.0 * keys.1 + keys.2`. The server decodes the first
+/// packet with it and keeps the same key for the second packet, so the key is
+/// only advanced after every packet starting with the second one. Packets
+/// therefore have to be encoded in the exact order they are sent.
+pub(crate) struct PacketObfuscation {
+    keys: PacketKeys,
+    current_key: u32,
+    is_first_packet: bool,
+}
+
+impl PacketObfuscation {
+    pub fn new(keys: PacketKeys) -> Self {
+        let PacketKeys(initial, multiplier, increment) = keys;
+
+        Self {
+            keys,
+            current_key: initial.wrapping_mul(multiplier).wrapping_add(increment),
+            is_first_packet: true,
+        }
+    }
+
+    /// Encode the header of a single serialized packet.
+    pub fn encode_header(&mut self, packet: &mut [u8]) {
+        let PacketKeys(_, multiplier, increment) = self.keys;
+
+        let mask = ((self.current_key >> 16) & 0x7FFF) as u16;
+        let header = u16::from_le_bytes([packet[0], packet[1]]) ^ mask;
+
+        packet[..2].copy_from_slice(&header.to_le_bytes());
+
+        match self.is_first_packet {
+            true => self.is_first_packet = false,
+            false => self.current_key = self.current_key.wrapping_mul(multiplier).wrapping_add(increment),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PacketKeys, PacketObfuscation};
+
+    fn encode_headers(keys: PacketKeys, headers: &[u16]) -> Vec<u16> {
+        let mut packet_obfuscation = PacketObfuscation::new(keys);
+
+        headers
+            .iter()
+            .map(|header| {
+                let mut packet = header.to_le_bytes();
+                packet_obfuscation.encode_header(&mut packet);
+                u16::from_le_bytes(packet)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encode_headers_like_rathena() {
+        // Expected headers as decoded by rAthena's `clif_parse_cmd`: The first packet
+        // and the second packet use `k0 * k1 + k2`, every following packet uses
+        // `previous * k1 + k2`.
+        let keys = PacketKeys(0x7E241DE0, 0x5E805580, 0x3D807D80);
+        let headers = [0x0436, 0x0360, 0x007D, 0x0360, 0x0089];
+
+        assert_eq!(encode_headers(keys, &headers), [0x794C, 0x7E1A, 0x015F, 0x14AA, 0x3343]);
+    }
+
+    #[test]
+    fn second_packet_uses_first_key() {
+        let keys = PacketKeys(0x00010000, 0x00000002, 0x00030000);
+
+        // The first key is 0x00010000 * 2 + 0x00030000 = 0x00050000, the third
+        // packet uses 0x00050000 * 2 + 0x00030000 = 0x000D0000.
+        assert_eq!(encode_headers(keys, &[0x0436, 0x0436, 0x0436]), [0x0433, 0x0433, 0x043B]);
+    }
+
+    #[test]
+    fn body_is_unchanged() {
+        let mut packet_obfuscation = PacketObfuscation::new(PacketKeys(0x00010000, 0x00000002, 0x00030000));
+
+        let mut packet = [0x36, 0x04, 0xAA];
+        packet_obfuscation.encode_header(&mut packet);
+        assert_eq!(packet, [0x33, 0x04, 0xAA]);
+    }
+
+    #[test]
+    fn zero_keys_leave_headers_unchanged() {
+        let headers = [0x0436, 0x0360, 0x007D];
+
+        assert_eq!(encode_headers(PacketKeys(0, 0, 0), &headers), headers);
+    }
+
+    #[test]
+    fn mask_is_15_bits() {
+        let mut packet_obfuscation = PacketObfuscation::new(PacketKeys(0xFFFF0000, 1, 0));
+
+        let mut packet = [0x00, 0x00];
+        packet_obfuscation.encode_header(&mut packet);
+        assert_eq!(u16::from_le_bytes(packet), 0x7FFF);
+    }
+}
@@ -1,7 +1,23 @@
+pub mod version_20200401;
 pub mod version_20220406;
 
+use crate::PacketKeys;
+
 /// All supported packet versions.
 #[derive(Debug, Clone, Copy)]
 pub enum SupportedPacketVersion {
+    _20200401,
     _20220406,
 }
+
+impl SupportedPacketVersion {
+    /// Keys used to obfuscate the headers of packets sent to the map server.
+    /// Official clients of both versions ship with all keys set to zero, which
+    /// leaves the headers unchanged.
+    pub fn packet_keys(self) -> PacketKeys {
+        match self {
+            SupportedPacketVersion::_20200401 => version_20200401::PACKET_KEYS,
+            SupportedPacketVersion::_20220406 => version_20220406::PACKET_KEYS,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Most packets of the 2020-04-01 client are identical to the ones of the
+//! 2022-04-06 client, so we register the handlers of [`version_20220406`] and
+//! convert the packets that changed in between.
+
+use ragnarok_packets::handler::{DuplicateHandlerError, PacketCallback, PacketHandler};
+use ragnarok_packets::packet_versions::version_20200401;
+use ragnarok_packets::*;
+
+use super::version_20220406;
+use crate::PacketKeys;
+use crate::event::NetworkEventList;
+
+/// Packet keys of the 2020-04-01 client.
+pub const PACKET_KEYS: PacketKeys = PacketKeys(0x00000000, 0x00000000, 0x00000000);
+
+pub fn register_login_server_packets<Callback>(
+    packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
+) -> Result<(), DuplicateHandlerError>
+where
+    Callback: PacketCallback,
+{
+    version_20220406::register_login_server_packets(packet_handler)
+}
+
+pub fn register_character_server_packets<Callback>(
+    packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
+) -> Result<(), DuplicateHandlerError>
+where
+    Callback: PacketCallback,
+{
+    version_20220406::register_character_server_packets(packet_handler)?;
+
+    packet_handler.register_converted::<version_20200401::CharacterListPacket, CharacterListPacket>()?;
+    packet_handler.register_converted::<version_20200401::RequestCharacterListSuccessPacket, RequestCharacterListSuccessPacket>()?;
+    packet_handler.register_converted::<version_20200401::CreateCharacterSuccessPacket, CreateCharacterSuccessPacket>()?;
+
+    Ok(())
+}
+
+pub fn register_map_server_packets<Callback>(
+    packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
+) -> Result<(), DuplicateHandlerError>
+where
+    Callback: PacketCallback,
+{
+    version_20220406::register_map_server_packets(packet_handler)?;
+
+    packet_handler.register_converted::<version_20200401::EquippableItemListPacket, EquippableItemListPacket>()?;
+    packet_handler.register_converted::<version_20200401::ItemPickupPacket, ItemPickupPacket>()?;
+    packet_handler.register_converted::<version_20200401::TradeItemAddedPacket, TradeItemAddedPacket>()?;
+    packet_handler.register_converted::<version_20200401::StorageItemAddedPacket, StorageItemAddedPacket>()?;
+    packet_handler.register_converted::<version_20200401::HomunculusInformationPacket, HomunculusInformationPacket>()?;
+    packet_handler.register_converted::<version_20200401::CartItemAddedPacket, CartItemAddedPacket>()?;
+    packet_handler.register_converted::<version_20200401::OwnVendingItemListPacket, OwnVendingItemListPacket>()?;
+    packet_handler.register_converted::<version_20200401::VendingItemListPacket, VendingItemListPacket>()?;
+    packet_handler.register_converted::<version_20200401::ReadMailPacket, ReadMailPacket>()?;
+    packet_handler.register_converted::<version_20200401::MailItemAddedPacket, MailItemAddedPacket>()?;
+
+    Ok(())
+}
@@ -9,10 +9,18 @@ use ragnarok_packets::*;
 use crate::event::{NetworkEventList, NoNetworkEvents};
 use crate::items::ItemQuantity;
 use crate::{
-    CharacterServerLoginData, HotkeyState, InventoryItem, InventoryItemDetails, LoginServerLoginData, MessageColor, NetworkEvent,
-    NoMetadata, ShopItem, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
+    CharacterServerLoginData, EntityState, EquipmentLookChange, HomunculusInformation, HotkeyState, InventoryItem, InventoryItemDetails,
+    LoginServerLoginData, MailSummary, MercenaryInformation, MessageColor, NetworkEvent, NoMetadata, PacketKeys, PartyMember,
+    PetInformation, ShopItem, StatusEffect, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason, VendingItem,
 };
 
+/// Packet keys of the 2022-04-06 client.
+pub const PACKET_KEYS: PacketKeys = PacketKeys(0x00000000, 0x00000000, 0x00000000);
+
+/// Status effect that is active while an entity is pushing a cart. The first
+/// value of the status effect is the cart type.
+const PUSH_CART_STATUS: StatusEffectId = StatusEffectId(673);
+
 pub fn register_login_server_packets<Callback>(
     packet_handler: &mut PacketHandler<NetworkEventList, (), Callback>,
 ) -> Result<(), DuplicateHandlerError>
@@ -28,6 +36,7 @@ where
             sex: packet.sex,
         },
     })?;
+    packet_handler.register(|packet: PasswordSaltPacket| NetworkEvent::LoginServerPasswordSalt { salt: packet.salt })?;
     packet_handler.register(|packet: LoginFailedPacket| {
         let (reason, message) = match packet.reason {
             LoginFailedReason::ServerClosed => (UnifiedLoginFailedReason::ServerClosed, "Server closed"),
@@ -276,7 +285,9 @@ where
         NetworkEvent::UpdateStat { stat_type }
     })?;
     packet_handler.register_noop::<UpdateAttackRangePacket>()?;
-    packet_handler.register_noop::<NewMailStatusPacket>()?;
+    packet_handler.register(|packet: NewMailStatusPacket| NetworkEvent::MailNotification {
+        has_new_mail: packet.new_available != 0,
+    })?;
     packet_handler.register_noop::<AchievementUpdatePacket>()?;
     packet_handler.register_noop::<AchievementListPacket>()?;
     packet_handler.register_noop::<CriticalWeightUpdatePacket>()?;
@@ -289,6 +300,38 @@ where
             account_id: packet.account_id,
             hair_id: packet.value,
         }),
+        SpriteChangeType::HairCollor => Some(NetworkEvent::ChangeHairColor {
+            account_id: packet.account_id,
+            hair_color: packet.value as u16,
+        }),
+        SpriteChangeType::ClothesColor => Some(NetworkEvent::ChangeClothesColor {
+            account_id: packet.account_id,
+            clothes_color: packet.value as u16,
+        }),
+        // The shield is sent together with the weapon.
+        SpriteChangeType::Weapon | SpriteChangeType::Shield => Some(NetworkEvent::ChangeEquipmentLook {
+            account_id: packet.account_id,
+            change: EquipmentLookChange::Weapon {
+                weapon: packet.value,
+                shield: packet.value2,
+            },
+        }),
+        SpriteChangeType::HeadTop => Some(NetworkEvent::ChangeEquipmentLook {
+            account_id: packet.account_id,
+            change: EquipmentLookChange::HeadTop(packet.value as u16),
+        }),
+        SpriteChangeType::HeadMiddle => Some(NetworkEvent::ChangeEquipmentLook {
+            account_id: packet.account_id,
+            change: EquipmentLookChange::HeadMiddle(packet.value as u16),
+        }),
+        SpriteChangeType::HeadBottom => Some(NetworkEvent::ChangeEquipmentLook {
+            account_id: packet.account_id,
+            change: EquipmentLookChange::HeadBottom(packet.value as u16),
+        }),
+        SpriteChangeType::Robe => Some(NetworkEvent::ChangeEquipmentLook {
+            account_id: packet.account_id,
+            change: EquipmentLookChange::Robe(packet.value as u16),
+        }),
         _ => None,
     })?;
     packet_handler.register({
@@ -388,9 +431,27 @@ where
     packet_handler.register({
         let inventory_items = inventory_items.clone();
 
-        move |_: InventoyEndPacket| {
+        move |packet: InventoyEndPacket| {
             let items = inventory_items.borrow_mut().take().expect("Unexpected inventory end packet");
-            NetworkEvent::SetInventory { items }
+
+            match packet.inventory_type {
+                InventoryType::Inventory => Some(NetworkEvent::SetInventory { items }),
+                InventoryType::Storage => {
+                    // The storage uses an offset of 1 instead of 2, so we need to correct the
+                    // index here.
+                    let items = items
+                        .into_iter()
+                        .map(|item| InventoryItem {
+                            index: InventoryIndex(item.index.0.wrapping_add(1)),
+                            ..item
+                        })
+                        .collect();
+
+                    Some(NetworkEvent::SetStorage { items })
+                }
+                InventoryType::Cart => Some(NetworkEvent::SetCart { items }),
+                InventoryType::GuildStorage => None,
+            }
         }
     })?;
     packet_handler.register_noop::<EquippableSwitchItemListPacket>()?;
@@ -452,15 +513,67 @@ where
 
         NetworkEvent::AddChoiceButtons { choices, npc_id }
     })?;
-    packet_handler.register_noop::<DisplaySpecialEffectPacket>()?;
+    packet_handler.register(|packet: DisplaySpecialEffectPacket| {
+        let DisplaySpecialEffectPacket { entity_id, effect_id } = packet;
+        NetworkEvent::SpecialEffect { effect_id, entity_id }
+    })?;
     packet_handler.register_noop::<DisplaySkillCooldownPacket>()?;
-    packet_handler.register_noop::<DisplaySkillEffectAndDamagePacket>()?;
-    packet_handler.register(|packet: DisplaySkillEffectNoDamagePacket| NetworkEvent::HealEffect {
-        entity_id: packet.destination_entity_id,
-        heal_amount: packet.heal_amount as usize,
+    packet_handler.register(|packet: DisplaySkillEffectAndDamagePacket| NetworkEvent::SkillEffect {
+        skill_id: packet.skill_id,
+        source_entity_id: packet.source_entity_id,
+        destination_entity_id: packet.destination_entity_id,
+    })?;
+    packet_handler.register(|packet: DisplaySkillEffectNoDamagePacket| {
+        vec![
+            NetworkEvent::SkillEffect {
+                skill_id: packet.skill_id,
+                source_entity_id: packet.source_entity_id,
+                destination_entity_id: packet.destination_entity_id,
+            },
+            NetworkEvent::HealEffect {
+                entity_id: packet.destination_entity_id,
+                heal_amount: packet.heal_amount as usize,
+            },
+        ]
     })?;
     packet_handler.register_noop::<DisplayPlayerHealEffect>()?;
-    packet_handler.register_noop::<StatusChangePacket>()?;
+    packet_handler.register(|packet: StatusChangePacket| match (packet.status_effect_id, packet.state) {
+        (PUSH_CART_STATUS, state) => NetworkEvent::ChangeCart {
+            entity_id: packet.entity_id,
+            cart_type: match state {
+                0 => 0,
+                _ => packet.value[0],
+            },
+        },
+        (status_effect_id, 0) => NetworkEvent::RemoveStatusEffect {
+            entity_id: packet.entity_id,
+            status_effect_id,
+        },
+        (status_effect_id, _) => NetworkEvent::AddStatusEffect {
+            entity_id: packet.entity_id,
+            status_effect: StatusEffect::new(
+                status_effect_id,
+                packet.duration_in_milliseconds,
+                packet.remaining_in_milliseconds,
+                packet.value,
+            ),
+        },
+    })?;
+    packet_handler.register(|packet: EntityStatusChangePacket| match packet.status_effect_id {
+        PUSH_CART_STATUS => NetworkEvent::ChangeCart {
+            entity_id: packet.entity_id,
+            cart_type: packet.value[0],
+        },
+        status_effect_id => NetworkEvent::AddStatusEffect {
+            entity_id: packet.entity_id,
+            status_effect: StatusEffect::new(
+                status_effect_id,
+                packet.duration_in_milliseconds,
+                packet.remaining_in_milliseconds,
+                packet.value,
+            ),
+        },
+    })?;
     packet_handler.register_noop::<QuestNotificationPacket1>()?;
     packet_handler.register_noop::<HuntingQuestNotificationPacket>()?;
     packet_handler.register_noop::<HuntingQuestUpdateObjectivePacket>()?;
@@ -468,25 +581,14 @@ where
     packet_handler.register_noop::<QuestListPacket>()?;
     packet_handler.register(|packet: VisualEffectPacket| {
         let VisualEffectPacket { entity_id, effect } = packet;
-
-        let effect_path = match effect {
-            VisualEffect::BaseLevelUp => "angel.str",
-            VisualEffect::JobLevelUp => "joblvup.str",
-            VisualEffect::RefineFailure => "bs_refinefailed.str",
-            VisualEffect::RefineSuccess => "bs_refinesuccess.str",
-            VisualEffect::GameOver => "help_angel\\help_angel\\help_angel.str",
-            VisualEffect::PharmacySuccess => "p_success.str",
-            VisualEffect::PharmacyFailure => "p_failed.str",
-            VisualEffect::BaseLevelUpSuperNovice => "help_angel\\help_angel\\help_angel.str",
-            VisualEffect::JobLevelUpSuperNovice => "help_angel\\help_angel\\help_angel.str",
-            VisualEffect::BaseLevelUpTaekwon => "help_angel\\help_angel\\help_angel.str",
-        };
-
-        NetworkEvent::VisualEffect { effect_path, entity_id }
+        NetworkEvent::VisualEffect { effect, entity_id }
     })?;
     packet_handler.register_noop::<DisplayGainedExperiencePacket>()?;
     packet_handler.register_noop::<DisplayImagePacket>()?;
-    packet_handler.register_noop::<StateChangePacket>()?;
+    packet_handler.register(|packet: StateChangePacket| NetworkEvent::ChangeEntityState {
+        entity_id: packet.entity_id,
+        state: EntityState::new(packet.body_state, packet.effect_state),
+    })?;
 
     packet_handler.register(|packet: QuestEffectPacket| match packet.effect {
         QuestEffect::None => NetworkEvent::RemoveQuestEffect {
@@ -686,7 +788,25 @@ where
             color: MessageColor::Error,
         },
     })?;
-    packet_handler.register_noop::<UseSkillSuccessPacket>()?;
+    packet_handler.register(|packet: UseSkillSuccessPacket| NetworkEvent::SkillCast {
+        source_entity_id: packet.source_entity,
+        destination_entity_id: packet.destination_entity,
+        position: (packet.destination_entity == EntityId(0)).then_some(packet.position),
+        skill_id: packet.skill_id,
+        element: packet.element,
+        cast_time: packet.delay_time,
+    })?;
+    packet_handler.register(|packet: UseSkillSuccessPacket2| NetworkEvent::SkillCast {
+        source_entity_id: packet.source_entity,
+        destination_entity_id: packet.destination_entity,
+        position: (packet.destination_entity == EntityId(0)).then_some(packet.position),
+        skill_id: packet.skill_id,
+        element: packet.element,
+        cast_time: packet.delay_time,
+    })?;
+    packet_handler.register(|packet: CastCancelPacket| NetworkEvent::CastCancel {
+        entity_id: packet.entity_id,
+    })?;
     packet_handler.register_noop::<ToUseSkillSuccessPacket>()?;
     packet_handler.register(|packet: NotifySkillUnitPacket| {
         let NotifySkillUnitPacket {
@@ -706,7 +826,11 @@ where
         let SkillUnitDisappearPacket { entity_id } = packet;
         NetworkEvent::RemoveSkillUnit { entity_id }
     })?;
-    packet_handler.register_noop::<NotifyGroundSkillPacket>()?;
+    packet_handler.register(|packet: NotifyGroundSkillPacket| NetworkEvent::GroundSkillEffect {
+        skill_id: packet.skill_id,
+        entity_id: packet.entity_id,
+        position: packet.position,
+    })?;
     packet_handler.register(|packet: FriendListPacket| NetworkEvent::SetFriendList {
         friend_list: packet.friend_list,
     })?;
@@ -737,8 +861,328 @@ where
         account_id: packet.account_id,
         character_id: packet.character_id,
     })?;
-    packet_handler.register_noop::<PartyInvitePacket>()?;
-    packet_handler.register_noop::<StatusChangeSequencePacket>()?;
+    packet_handler.register(|packet: PartyInvitePacket| NetworkEvent::PartyInvitation {
+        party_id: packet.party_id,
+        party_name: packet.party_name,
+    })?;
+    packet_handler.register(|packet: CreatePartyResultPacket| {
+        let text = match packet.result {
+            CreatePartyResult::Success => return None,
+            CreatePartyResult::NameAlreadyExists => "A party with this name already exists.",
+            CreatePartyResult::AlreadyInParty => "You are already in a party.",
+            CreatePartyResult::NotAllowedOnMap => "You cannot create a party on this map.",
+        };
+
+        Some(NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color: MessageColor::Error,
+        })
+    })?;
+    packet_handler.register(|packet: PartyInviteResultPacket| {
+        let name = packet.name;
+        let (text, color) = match packet.result {
+            PartyInviteResult::Accepted => (format!("{name} joined the party."), MessageColor::Information),
+            PartyInviteResult::Rejected => (format!("{name} rejected the party invitation."), MessageColor::Information),
+            PartyInviteResult::AlreadyInParty => (format!("{name} is already in a party."), MessageColor::Error),
+            PartyInviteResult::PartyFull => ("The party is full.".to_owned(), MessageColor::Error),
+            PartyInviteResult::SameAccount => (
+                "Characters of the same account cannot join the same party.".to_owned(),
+                MessageColor::Error,
+            ),
+            PartyInviteResult::InvitationsBlocked => (format!("{name} does not accept party invitations."), MessageColor::Error),
+            PartyInviteResult::UnknownError => ("Failed to send the party invitation.".to_owned(), MessageColor::Error),
+            PartyInviteResult::CharacterNotOnline => (format!("{name} is not online."), MessageColor::Error),
+            PartyInviteResult::NotAllowedOnMap => ("You cannot invite characters on this map.".to_owned(), MessageColor::Error),
+            PartyInviteResult::NotAllowedOnTargetMap => (format!("{name} cannot be invited on their current map."), MessageColor::Error),
+        };
+
+        NetworkEvent::ChatMessage { text, color }
+    })?;
+    packet_handler.register(|packet: PartyMemberLeftPacket| {
+        let PartyMemberLeftPacket { account_id, name, reason } = packet;
+
+        match reason {
+            PartyLeaveReason::Left | PartyLeaveReason::Expelled => {
+                let text = match reason {
+                    PartyLeaveReason::Left => format!("{name} left the party."),
+                    _ => format!("{name} was expelled from the party."),
+                };
+
+                vec![
+                    NetworkEvent::ChatMessage {
+                        text,
+                        color: MessageColor::Information,
+                    },
+                    NetworkEvent::PartyMemberLeft { account_id, name },
+                ]
+            }
+            PartyLeaveReason::CannotLeave => vec![NetworkEvent::ChatMessage {
+                text: "You cannot leave the party on this map.".to_owned(),
+                color: MessageColor::Error,
+            }],
+            PartyLeaveReason::CannotExpel => vec![NetworkEvent::ChatMessage {
+                text: "You cannot expel party members on this map.".to_owned(),
+                color: MessageColor::Error,
+            }],
+        }
+    })?;
+    packet_handler.register(|packet: PartyInformationPacket| NetworkEvent::SetParty {
+        party_name: packet.party_name,
+        members: packet.members.into_iter().map(PartyMember::from).collect(),
+    })?;
+    packet_handler.register(|packet: PartyMemberJoinedPacket| NetworkEvent::UpdatePartyMember {
+        party_name: packet.party_name.clone(),
+        item_pickup_rule: packet.item_pickup_rule,
+        item_distribution_rule: packet.item_distribution_rule,
+        member: PartyMember::from(packet),
+    })?;
+    packet_handler.register(|packet: UpdatePartyMemberHealthPacket| NetworkEvent::UpdatePartyMemberHealth {
+        account_id: packet.account_id,
+        health_points: packet.health_points,
+        maximum_health_points: packet.maximum_health_points,
+    })?;
+    packet_handler.register(
+        |packet: UpdatePartyMemberPositionPacket| NetworkEvent::UpdatePartyMemberPosition {
+            account_id: packet.account_id,
+            position: packet.position,
+        },
+    )?;
+    packet_handler.register(|packet: PartyMessagePacket| NetworkEvent::ChatMessage {
+        text: packet.message,
+        color: MessageColor::Party,
+    })?;
+    packet_handler.register(|packet: PartyOptionsPacket| NetworkEvent::UpdatePartyOptions {
+        experience_share: packet.experience_share,
+        item_pickup_rule: packet.item_pickup_rule,
+        item_distribution_rule: packet.item_distribution_rule,
+    })?;
+    packet_handler.register(|packet: PartyLeaderChangedPacket| NetworkEvent::PartyLeaderChanged {
+        old_leader_account_id: packet.old_leader_account_id,
+        new_leader_account_id: packet.new_leader_account_id,
+    })?;
+    packet_handler.register(|packet: UpdateGuildIdPacket| NetworkEvent::SetPlayerGuild {
+        guild_id: packet.guild_id,
+        guild_name: packet.guild_name,
+        emblem_id: packet.emblem_id,
+        is_master: packet.is_master != 0,
+    })?;
+    packet_handler.register(|packet: GuildInformationPacket| NetworkEvent::SetGuildInformation {
+        information: packet.information,
+    })?;
+    packet_handler.register(|packet: GuildRelationsPacket| NetworkEvent::SetGuildRelations {
+        relations: packet.relations,
+    })?;
+    packet_handler.register(|packet: GuildMemberListPacket| NetworkEvent::SetGuildMembers { members: packet.members })?;
+    packet_handler.register(|packet: GuildPositionListPacket| NetworkEvent::SetGuildPositions {
+        positions: packet.positions,
+    })?;
+    packet_handler.register(|packet: GuildPositionNamesPacket| NetworkEvent::SetGuildPositionNames { names: packet.names })?;
+    packet_handler.register(|packet: GuildNoticePacket| NetworkEvent::SetGuildNotice {
+        subject: packet.subject,
+        notice: packet.notice,
+    })?;
+    packet_handler.register(|packet: GuildEmblemPacket| NetworkEvent::GuildEmblem {
+        guild_id: packet.guild_id,
+        emblem_id: packet.emblem_id,
+        emblem_data: packet.emblem_data,
+    })?;
+    packet_handler.register(|packet: ChangeGuildPacket| NetworkEvent::EntityGuildChanged {
+        entity_id: packet.entity_id,
+        guild_id: (packet.guild_id.0 != 0).then_some(packet.guild_id),
+        emblem_id: packet.emblem_id as u32,
+    })?;
+    packet_handler.register(|packet: GuildMemberStatusPacket| NetworkEvent::GuildMemberStatus {
+        account_id: packet.account_id,
+        character_id: packet.character_id,
+        online: packet.online != 0,
+    })?;
+    packet_handler.register(|packet: GuildMessagePacket| NetworkEvent::ChatMessage {
+        text: packet.message,
+        color: MessageColor::Guild,
+    })?;
+    packet_handler.register(|packet: CharacterNamePacket| NetworkEvent::CharacterName {
+        character_id: packet.character_id,
+        name: packet.name,
+    })?;
+    packet_handler.register(|packet: TradeRequestPacket| NetworkEvent::TradeRequest {
+        character_name: packet.character_name,
+        character_id: packet.character_id,
+        base_level: packet.base_level,
+    })?;
+    packet_handler.register(|packet: TradeRequestResultPacket| {
+        let text = match packet.result {
+            TradeRequestResult::Accepted => {
+                return NetworkEvent::TradeStarted {
+                    character_id: packet.character_id,
+                    base_level: packet.base_level,
+                };
+            }
+            TradeRequestResult::TooFarAway => "The character is too far away to trade.",
+            TradeRequestResult::CharacterDoesNotExist => "The character does not exist.",
+            TradeRequestResult::Failed => "The character is already trading.",
+            TradeRequestResult::Cancelled => "The trade request was rejected.",
+            TradeRequestResult::Busy => "The character is busy.",
+        };
+
+        NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color: MessageColor::Error,
+        }
+    })?;
+    packet_handler.register(|packet: AddTradeItemResultPacket| {
+        // Zeny are added with an index of 0, items with their inventory index + 2.
+        let index = packet.index.checked_sub(2).map(InventoryIndex);
+
+        let text = match packet.result {
+            AddTradeItemResult::Success => return vec![NetworkEvent::OwnTradeItemAdded { index }],
+            AddTradeItemResult::Overweight => "The trade partner cannot carry that much weight.",
+            AddTradeItemResult::TradeClosed => "The trade is already closed.",
+            AddTradeItemResult::TooManyItems => "The trade partner cannot carry any more items.",
+            AddTradeItemResult::TooManyOfThisItem => "The trade partner cannot carry any more of this item.",
+        };
+
+        vec![
+            NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color: MessageColor::Error,
+            },
+            NetworkEvent::OwnTradeItemRejected { index },
+        ]
+    })?;
+    packet_handler.register(|packet: TradeItemAddedPacket| {
+        let TradeItemAddedPacket {
+            item_id,
+            item_type,
+            amount,
+            is_identified,
+            is_broken,
+            cards,
+            option_data,
+            equip_position,
+            look,
+            refinement_level,
+            enchantment_level,
+        } = packet;
+
+        if item_id.0 == 0 {
+            return NetworkEvent::PartnerTradeZenyAdded { amount };
+        }
+
+        let details = match equip_position.is_empty() {
+            true => InventoryItemDetails::Regular {
+                amount: amount as u16,
+                equipped_position: EquipPosition::empty(),
+                flags: {
+                    let mut flags = RegularItemFlags::empty();
+                    flags.set(RegularItemFlags::IDENTIFIED, is_identified != 0);
+                    flags
+                },
+            },
+            false => InventoryItemDetails::Equippable {
+                equip_position,
+                equipped_position: EquipPosition::empty(),
+                bind_on_equip_type: 0,
+                w_item_sprite_number: look,
+                option_count: option_data.len() as u8,
+                option_data,
+                refinement_level,
+                enchantment_level,
+                flags: {
+                    let mut flags = EquippableItemFlags::empty();
+                    flags.set(EquippableItemFlags::IDENTIFIED, is_identified != 0);
+                    flags.set(EquippableItemFlags::IS_BROKEN, is_broken != 0);
+                    flags
+                },
+            },
+        };
+
+        let item = InventoryItem {
+            metadata: NoMetadata,
+            index: InventoryIndex(0),
+            item_id,
+            item_type,
+            slot: cards,
+            hire_expiration_date: 0,
+            details,
+        };
+
+        NetworkEvent::PartnerTradeItemAdded { item }
+    })?;
+    packet_handler.register(|packet: TradeLockedPacket| NetworkEvent::TradeLocked {
+        participant: packet.participant,
+    })?;
+    packet_handler.register(|_: TradeCancelledPacket| NetworkEvent::TradeCancelled)?;
+    packet_handler.register(|packet: TradeCompletedPacket| match packet.result {
+        TradeResult::Success => vec![NetworkEvent::TradeCompleted],
+        TradeResult::Failed => vec![
+            NetworkEvent::ChatMessage {
+                text: "The trade failed.".to_owned(),
+                color: MessageColor::Error,
+            },
+            NetworkEvent::TradeCancelled,
+        ],
+    })?;
+    packet_handler.register(|packet: StorageItemCountPacket| NetworkEvent::StorageItemCount {
+        item_count: packet.item_count,
+        maximum_item_count: packet.maximum_item_count,
+    })?;
+    packet_handler.register(|packet: StorageItemAddedPacket| {
+        let StorageItemAddedPacket {
+            index,
+            amount,
+            item_id,
+            item_type,
+            is_identified,
+            is_broken,
+            cards,
+            option_data,
+            refinement_level,
+            enchantment_level,
+        } = packet;
+
+        let item = InventoryItem {
+            metadata: NoMetadata,
+            index: InventoryIndex(index.0),
+            item_id,
+            item_type,
+            slot: cards,
+            hire_expiration_date: 0,
+            details: added_item_details(
+                item_type,
+                amount,
+                is_identified,
+                is_broken,
+                option_data,
+                refinement_level,
+                enchantment_level,
+            ),
+        };
+
+        NetworkEvent::StorageItemAdded { item }
+    })?;
+    packet_handler.register(|packet: StorageItemRemovedPacket| NetworkEvent::StorageItemRemoved {
+        index: InventoryIndex(packet.index.0),
+        amount: packet.amount,
+    })?;
+    packet_handler.register(|_: StorageClosedPacket| NetworkEvent::StorageClosed)?;
+    packet_handler.register(
+        |packet: StatusChangeSequencePacket| match (packet.status_effect_id, packet.state) {
+            // The cart type is only known from the status change packets that have values.
+            (PUSH_CART_STATUS, 0) => Some(NetworkEvent::ChangeCart {
+                entity_id: packet.entity_id,
+                cart_type: 0,
+            }),
+            (PUSH_CART_STATUS, _) => None,
+            (status_effect_id, 0) => Some(NetworkEvent::RemoveStatusEffect {
+                entity_id: packet.entity_id,
+                status_effect_id,
+            }),
+            (status_effect_id, _) => Some(NetworkEvent::AddStatusEffect {
+                entity_id: packet.entity_id,
+                status_effect: StatusEffect::permanent(status_effect_id),
+            }),
+        },
+    )?;
     packet_handler.register_noop::<ReputationPacket>()?;
     packet_handler.register_noop::<ClanInfoPacket>()?;
     packet_handler.register_noop::<ClanOnlineCountPacket>()?;
@@ -769,6 +1213,342 @@ where
     packet_handler.register_noop::<RequestStatUpResponsePacket>()?;
     packet_handler.register_noop::<EquipAmmunitionPacket>()?;
     packet_handler.register_noop::<AmmunitionActionPacket>()?;
+    packet_handler.register(|packet: PetEggListPacket| NetworkEvent::PetEggList { eggs: packet.eggs })?;
+    packet_handler.register(|packet: PetInformationPacket| NetworkEvent::UpdatePetInformation {
+        information: PetInformation::from(packet),
+    })?;
+    packet_handler.register(|packet: PetFeedResultPacket| NetworkEvent::PetFed {
+        success: packet.success != 0,
+        item_id: packet.item_id,
+    })?;
+    packet_handler.register(|packet: PetStatePacket| match packet.state_type {
+        PetStateType::Initialize => Some(NetworkEvent::SetPet {
+            entity_id: packet.entity_id,
+        }),
+        PetStateType::Intimacy => Some(NetworkEvent::UpdatePetIntimacy {
+            intimacy: packet.value as u16,
+        }),
+        PetStateType::Hunger => Some(NetworkEvent::UpdatePetHunger {
+            hunger: packet.value as u16,
+        }),
+        _ => None,
+    })?;
+    packet_handler.register(
+        |packet: HomunculusInformationPacket| NetworkEvent::UpdateHomunculusInformation {
+            information: HomunculusInformation::from(packet),
+        },
+    )?;
+    packet_handler.register(|packet: HomunculusSkillListPacket| NetworkEvent::HomunculusSkillTree {
+        skill_information: packet.skill_information,
+    })?;
+    packet_handler.register(|packet: HomunculusStatePacket| match packet.state_type {
+        HomunculusStateType::Initialize => NetworkEvent::SetHomunculus {
+            entity_id: packet.entity_id,
+        },
+        HomunculusStateType::Intimacy => NetworkEvent::UpdateHomunculusIntimacy {
+            intimacy: packet.value as u16,
+        },
+        HomunculusStateType::Hunger => NetworkEvent::UpdateHomunculusHunger {
+            hunger: packet.value as u16,
+        },
+    })?;
+    packet_handler.register(|packet: HomunculusFeedResultPacket| NetworkEvent::HomunculusFed {
+        success: packet.success != 0,
+        item_id: packet.item_id,
+    })?;
+    packet_handler.register(|packet: MercenaryInformationPacket| NetworkEvent::UpdateMercenaryInformation {
+        entity_id: packet.entity_id,
+        information: MercenaryInformation::from(packet),
+    })?;
+    packet_handler.register(|packet: MercenarySkillListPacket| NetworkEvent::MercenarySkillTree {
+        skill_information: packet.skill_information,
+    })?;
+    packet_handler.register(|packet: MercenaryParameterPacket| {
+        let stat_type = match packet.parameter {
+            5 => StatType::HealthPoints(packet.value),
+            6 => StatType::MaximumHealthPoints(packet.value),
+            7 => StatType::SpellPoints(packet.value),
+            8 => StatType::MaximumSpellPoints(packet.value),
+            _ => return None,
+        };
+
+        Some(NetworkEvent::UpdateMercenaryStat { stat_type })
+    })?;
+    packet_handler.register(|packet: CartItemCountPacket| NetworkEvent::CartItemCount {
+        item_count: packet.item_count,
+        maximum_item_count: packet.maximum_item_count,
+        weight: packet.weight,
+        maximum_weight: packet.maximum_weight,
+    })?;
+    packet_handler.register(|packet: CartItemAddedPacket| {
+        let CartItemAddedPacket {
+            index,
+            amount,
+            item_id,
+            item_type,
+            is_identified,
+            is_broken,
+            cards,
+            option_data,
+            refinement_level,
+            enchantment_level,
+        } = packet;
+
+        let item = InventoryItem {
+            metadata: NoMetadata,
+            index,
+            item_id,
+            item_type,
+            slot: cards,
+            hire_expiration_date: 0,
+            details: added_item_details(
+                item_type,
+                amount,
+                is_identified,
+                is_broken,
+                option_data,
+                refinement_level,
+                enchantment_level,
+            ),
+        };
+
+        NetworkEvent::CartItemAdded { item }
+    })?;
+    packet_handler.register(|packet: CartItemRemovedPacket| NetworkEvent::CartItemRemoved {
+        index: packet.index,
+        amount: packet.amount,
+    })?;
+    packet_handler.register(|_: CartRemovedPacket| NetworkEvent::CartRemoved)?;
+    packet_handler.register(|packet: AddCartItemFailedPacket| {
+        let text = match packet.reason {
+            AddCartItemFailedReason::Overweight => "The cart is too heavy.",
+            AddCartItemFailedReason::TooManyItems => "The cart is full.",
+        };
+
+        NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color: MessageColor::Error,
+        }
+    })?;
+    packet_handler.register(|packet: OpenVendingPacket| NetworkEvent::OpenVendingSetup {
+        maximum_item_count: packet.maximum_item_count,
+    })?;
+    packet_handler.register(|packet: VendingOpenResultPacket| {
+        (packet.result != 0).then(|| NetworkEvent::ChatMessage {
+            text: "Failed to open the shop.".to_owned(),
+            color: MessageColor::Error,
+        })
+    })?;
+    packet_handler.register(|packet: OwnVendingItemListPacket| NetworkEvent::VendingOpened {
+        items: packet.items.into_iter().map(VendingItem::from).collect(),
+    })?;
+    packet_handler.register(|packet: VendingItemSoldPacket| NetworkEvent::VendingItemSold {
+        index: packet.index,
+        amount: packet.amount,
+        zeny: packet.zeny,
+    })?;
+    packet_handler.register(|packet: VendingShopOpenedPacket| NetworkEvent::VendingShopOpened {
+        entity_id: packet.entity_id,
+        shop_title: packet.shop_title,
+    })?;
+    packet_handler.register(|packet: VendingShopClosedPacket| NetworkEvent::VendingShopClosed {
+        entity_id: packet.entity_id,
+    })?;
+    packet_handler.register(|packet: VendingItemListPacket| NetworkEvent::VendingItemList {
+        entity_id: packet.entity_id,
+        vending_id: packet.vending_id,
+        items: packet.items.into_iter().map(VendingItem::from).collect(),
+    })?;
+    packet_handler.register(|packet: BuyVendingItemFailedPacket| {
+        let text = match packet.reason {
+            BuyVendingItemFailedReason::NotEnoughZeny => "You don't have enough zeny.",
+            BuyVendingItemFailedReason::Overweight => "You can't carry that much weight.",
+            BuyVendingItemFailedReason::OutOfStock => "The item is out of stock.",
+            BuyVendingItemFailedReason::VendorIsTrading => "The vendor is currently trading.",
+            BuyVendingItemFailedReason::InvalidShop | BuyVendingItemFailedReason::NoSales => "The item can't be bought.",
+        };
+
+        NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color: MessageColor::Error,
+        }
+    })?;
+    packet_handler.register(|packet: MailListPacket| NetworkEvent::MailList {
+        mails: packet.mails.into_iter().map(MailSummary::from).collect(),
+    })?;
+    packet_handler.register(|packet: ReadMailPacket| {
+        let items = packet
+            .items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| InventoryItem {
+                metadata: NoMetadata,
+                index: InventoryIndex(index as u16),
+                item_id: item.item_id,
+                item_type: item.item_type,
+                slot: item.cards,
+                hire_expiration_date: 0,
+                details: added_item_details(
+                    item.item_type,
+                    item.amount as u32,
+                    item.is_identified,
+                    item.is_broken,
+                    item.option_data,
+                    item.refinement_level,
+                    item.enchantment_level,
+                ),
+            })
+            .collect();
+
+        NetworkEvent::MailRead {
+            open_type: packet.open_type,
+            mail_id: packet.mail_id,
+            text: packet.text,
+            zeny: packet.zeny,
+            items,
+        }
+    })?;
+    packet_handler.register(|packet: MailZenyResultPacket| match packet.result {
+        0 => NetworkEvent::MailZenyRetrieved { mail_id: packet.mail_id },
+        _ => NetworkEvent::ChatMessage {
+            text: "Failed to take the zeny from the mail.".to_owned(),
+            color: MessageColor::Error,
+        },
+    })?;
+    packet_handler.register(|packet: MailItemsResultPacket| match packet.result {
+        MailItemsResult::Success => NetworkEvent::MailItemsRetrieved { mail_id: packet.mail_id },
+        MailItemsResult::Failure => NetworkEvent::ChatMessage {
+            text: "Failed to take the items from the mail.".to_owned(),
+            color: MessageColor::Error,
+        },
+        MailItemsResult::Overweight => NetworkEvent::ChatMessage {
+            text: "You can't carry the items from the mail.".to_owned(),
+            color: MessageColor::Error,
+        },
+    })?;
+    packet_handler.register(|packet: MailDeletedPacket| NetworkEvent::MailDeleted { mail_id: packet.mail_id })?;
+    packet_handler.register(|packet: WriteMailOpenedPacket| {
+        (packet.result != 1).then(|| NetworkEvent::ChatMessage {
+            text: "You can't write a mail right now.".to_owned(),
+            color: MessageColor::Error,
+        })
+    })?;
+    packet_handler.register(|packet: MailItemAddedPacket| {
+        let MailItemAddedPacket {
+            result,
+            index,
+            amount,
+            item_id,
+            item_type,
+            is_identified,
+            is_broken,
+            refinement_level,
+            cards,
+            option_data,
+            weight,
+            enchantment_level,
+            ..
+        } = packet;
+
+        let text = match result {
+            AddMailItemResult::Success => {
+                let item = InventoryItem {
+                    metadata: NoMetadata,
+                    index,
+                    item_id,
+                    item_type,
+                    slot: cards,
+                    hire_expiration_date: 0,
+                    details: added_item_details(
+                        item_type,
+                        amount as u32,
+                        is_identified,
+                        is_broken,
+                        option_data,
+                        refinement_level,
+                        enchantment_level,
+                    ),
+                };
+
+                return NetworkEvent::MailItemAttached { item, weight };
+            }
+            AddMailItemResult::Overweight => "The mail is too heavy.",
+            AddMailItemResult::Failure => "The item can't be attached.",
+            AddMailItemResult::TooManyItems => "No more items can be attached.",
+            AddMailItemResult::NotTradeable => "The item can't be traded.",
+        };
+
+        NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color: MessageColor::Error,
+        }
+    })?;
+    packet_handler.register(|packet: MailItemRemovedPacket| {
+        (packet.result == 1).then_some(NetworkEvent::MailItemDetached {
+            index: packet.index,
+            amount: packet.amount,
+            weight: packet.weight,
+        })
+    })?;
+    packet_handler.register(|packet: MailReceiverPacket| NetworkEvent::MailReceiver {
+        character_id: (packet.character_id.0 != 0).then_some(packet.character_id),
+        name: packet.name,
+    })?;
+    packet_handler.register(|packet: SendMailResultPacket| {
+        let text = match packet.result {
+            SendMailResult::Success => return NetworkEvent::MailSent,
+            SendMailResult::Failure => "Failed to send the mail.",
+            SendMailResult::TooManyMails => "You can't send any more mails today.",
+            SendMailResult::InvalidAttachment => "The attached items can't be sent.",
+            SendMailResult::InvalidReceiver => "The receiver of the mail doesn't exist.",
+        };
+
+        NetworkEvent::ChatMessage {
+            text: text.to_owned(),
+            color: MessageColor::Error,
+        }
+    })?;
 
     Ok(())
 }
+
+/// The packets for added storage and cart items don't contain the equip
+/// position, so we have to rely on the item type (armor, weapon, pet armor and
+/// shadow gear) instead.
+fn added_item_details(
+    item_type: u8,
+    amount: u32,
+    is_identified: u8,
+    is_broken: u8,
+    option_data: [ItemOptions; 5],
+    refinement_level: u8,
+    enchantment_level: u8,
+) -> InventoryItemDetails {
+    match matches!(item_type, 4 | 5 | 8 | 12) {
+        false => InventoryItemDetails::Regular {
+            amount: amount as u16,
+            equipped_position: EquipPosition::empty(),
+            flags: {
+                let mut flags = RegularItemFlags::empty();
+                flags.set(RegularItemFlags::IDENTIFIED, is_identified != 0);
+                flags
+            },
+        },
+        true => InventoryItemDetails::Equippable {
+            equip_position: EquipPosition::empty(),
+            equipped_position: EquipPosition::empty(),
+            bind_on_equip_type: 0,
+            w_item_sprite_number: 0,
+            option_count: option_data.len() as u8,
+            option_data,
+            refinement_level,
+            enchantment_level,
+            flags: {
+                let mut flags = EquippableItemFlags::empty();
+                flags.set(EquippableItemFlags::IDENTIFIED, is_identified != 0);
+                flags.set(EquippableItemFlags::IS_BROKEN, is_broken != 0);
+                flags
+            },
+        },
+    }
+}
@@ -0,0 +1,73 @@
+use ragnarok_packets::{
+    AccountId, CharacterId, PartyMemberInformation, PartyMemberJoinedPacket, PartyMemberRole, PartyMemberState, TilePosition,
+};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct PartyMember {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub name: String,
+    pub map_name: String,
+    pub job: u16,
+    pub base_level: u16,
+    pub role: PartyMemberRole,
+    pub state: PartyMemberState,
+    /// Only known for party members on the same map as the player.
+    pub position: Option<TilePosition>,
+    /// Only known for party members on the same map as the player.
+    pub health_points: Option<u32>,
+    /// Only known for party members on the same map as the player.
+    pub maximum_health_points: Option<u32>,
+}
+
+impl PartyMember {
+    pub fn is_leader(&self) -> bool {
+        self.role == PartyMemberRole::Leader
+    }
+
+    pub fn is_online(&self) -> bool {
+        self.state == PartyMemberState::Online
+    }
+}
+
+impl From<PartyMemberInformation> for PartyMember {
+    fn from(information: PartyMemberInformation) -> Self {
+        Self {
+            account_id: information.account_id,
+            character_id: information.character_id,
+            name: information.name,
+            map_name: information.map_name.replace(".gat", ""),
+            job: information.job,
+            base_level: information.base_level,
+            role: information.role,
+            state: information.state,
+            position: None,
+            health_points: None,
+            maximum_health_points: None,
+        }
+    }
+}
+
+impl From<PartyMemberJoinedPacket> for PartyMember {
+    fn from(packet: PartyMemberJoinedPacket) -> Self {
+        let role = match packet.role {
+            0 => PartyMemberRole::Leader,
+            _ => PartyMemberRole::Member,
+        };
+
+        Self {
+            account_id: packet.account_id,
+            character_id: packet.character_id,
+            name: packet.name,
+            map_name: packet.map_name.replace(".gat", ""),
+            job: packet.job,
+            base_level: packet.base_level,
+            role,
+            state: packet.state,
+            position: Some(packet.position),
+            health_points: None,
+            maximum_health_points: None,
+        }
+    }
+}
@@ -1,10 +1,13 @@
 use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use ragnarok_packets::{AccountId, CharacterId, Sex};
+use ragnarok_packets::{AccountId, CharacterId, CharacterServerInformation, Sex};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
 use crate::SupportedPacketVersion;
-use crate::event::NetworkEvent;
+use crate::event::{DisconnectReason, NetworkEvent};
+use crate::health::ConnectionHealth;
 
 #[derive(Debug, Clone, Copy)]
 pub struct LoginServerLoginData {
@@ -14,6 +17,28 @@ pub struct LoginServerLoginData {
     pub sex: Sex,
 }
 
+/// How the password is sent to the login server. The hashed methods
+/// correspond to `<passwordencrypt>` and `<passwordencrypt2>` in the
+/// `clientinfo.xml`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PasswordEncryption {
+    /// Send the password in plain text.
+    #[default]
+    None,
+    /// Send the MD5 hash of the salt followed by the password.
+    Method1,
+    /// Send the MD5 hash of the password followed by the salt.
+    Method2,
+}
+
+/// Credentials that are kept until the login server sends the salt for the
+/// hashed password.
+pub(crate) struct PendingHashedLogin {
+    pub username: String,
+    pub password: String,
+    pub password_encryption: PasswordEncryption,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum UnifiedLoginFailedReason {
     ServerClosed,
@@ -43,24 +68,66 @@ pub struct CharacterServerLoginData {
     pub character_id: CharacterId,
 }
 
+/// Timeouts and reconnect behavior of the server connections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionSettings {
+    /// How long to wait for a server to accept the connection.
+    pub connect_timeout: Duration,
+    /// How long to wait for the reply to a keep-alive packet before it counts
+    /// as missed. Only the map server replies to keep-alive packets, so the
+    /// connections to the login and character server are not monitored.
+    pub read_timeout: Duration,
+    /// Number of keep-alive replies in a row that can be missed before the
+    /// connection is considered stalled and closed with
+    /// [`DisconnectReason::TimedOut`].
+    pub max_missed_keepalives: usize,
+    /// Log back in to the map server when the connection is lost, instead of
+    /// reporting [`NetworkEvent::MapServerDisconnected`] right away.
+    pub reconnect_to_map_server: bool,
+    /// Number of reconnect attempts before giving up.
+    pub reconnect_attempts: usize,
+    /// Delay before the first reconnect attempt. It doubles with every failed
+    /// attempt.
+    pub reconnect_delay: Duration,
+}
+
+impl Default for ConnectionSettings {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(10),
+            read_timeout: Duration::from_secs(5),
+            max_missed_keepalives: 3,
+            reconnect_to_map_server: false,
+            reconnect_attempts: 5,
+            reconnect_delay: Duration::from_secs(1),
+        }
+    }
+}
+
 pub(crate) enum ServerConnectCommand {
     Login {
         address: SocketAddr,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
         packet_version: SupportedPacketVersion,
+        settings: ConnectionSettings,
+        health: Arc<Mutex<ConnectionHealth>>,
     },
     Character {
         address: SocketAddr,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
         packet_version: SupportedPacketVersion,
+        settings: ConnectionSettings,
+        health: Arc<Mutex<ConnectionHealth>>,
     },
     Map {
         address: SocketAddr,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
         packet_version: SupportedPacketVersion,
+        settings: ConnectionSettings,
+        health: Arc<Mutex<ConnectionHealth>>,
     },
 }
 
@@ -68,6 +135,7 @@ pub(crate) enum ServerConnectCommand {
 pub(crate) enum NetworkTaskError {
     FailedToConnect,
     ConnectionClosed,
+    TimedOut,
 }
 
 #[derive(Debug)]
@@ -78,6 +146,7 @@ pub(crate) enum ServerConnection {
         action_sender: UnboundedSender<Vec<u8>>,
         event_receiver: UnboundedReceiver<NetworkEvent>,
         packet_version: SupportedPacketVersion,
+        health: Arc<Mutex<ConnectionHealth>>,
     },
     ClosingManually,
     Disconnected,
@@ -88,3 +157,31 @@ impl ServerConnection {
         std::mem::replace(self, ServerConnection::Disconnected)
     }
 }
+
+/// Everything needed to log back in to the map server with the same
+/// character.
+#[derive(Clone)]
+pub(crate) struct MapServerSession {
+    pub packet_version: SupportedPacketVersion,
+    pub login_data: LoginServerLoginData,
+    pub character_server: CharacterServerInformation,
+    pub character_id: CharacterId,
+}
+
+pub(crate) enum ReconnectStage {
+    /// Waiting for the delay before the next attempt to pass.
+    Waiting { until: Instant },
+    /// Connected to the character server and selecting the character.
+    SelectingCharacter,
+    /// Logging in to the map server.
+    LoggingIn,
+}
+
+/// An ongoing attempt to reconnect to the map server.
+pub(crate) struct MapServerReconnect {
+    pub session: MapServerSession,
+    /// Why the connection was lost in the first place.
+    pub reason: DisconnectReason,
+    pub attempt: usize,
+    pub stage: ReconnectStage,
+}
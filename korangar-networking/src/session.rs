@@ -0,0 +1,308 @@
+//! Recording and replaying of network sessions.
+//!
+//! A session file starts with [`MAGIC`] and a version byte, followed by the
+//! records. Every record consists of the timestamp in microseconds (`u64`),
+//! the server (`u8`), the kind of record (`u8`) and the length of the packet
+//! (`u32`), followed by the raw bytes of the packet. All numbers are little
+//! endian.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::handler::{PacketCallback, PacketDirection, ServerKind};
+
+const MAGIC: &[u8; 4] = b"KSES";
+const VERSION: u8 = 1;
+
+/// What happened in a single [`SessionRecord`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionRecordKind {
+    /// A new connection to the server was established.
+    Connected,
+    /// Raw bytes of a packet received from the server.
+    Incoming(Vec<u8>),
+    /// Raw bytes of a packet sent to the server.
+    Outgoing(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionRecord {
+    /// Time since the start of the recording.
+    pub timestamp: Duration,
+    pub server: ServerKind,
+    pub kind: SessionRecordKind,
+}
+
+impl SessionRecord {
+    fn write(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        let server = match self.server {
+            ServerKind::Login => 0u8,
+            ServerKind::Character => 1,
+            ServerKind::Map => 2,
+        };
+
+        let (kind, bytes) = match &self.kind {
+            SessionRecordKind::Connected => (0u8, [].as_slice()),
+            SessionRecordKind::Incoming(bytes) => (1, bytes.as_slice()),
+            SessionRecordKind::Outgoing(bytes) => (2, bytes.as_slice()),
+        };
+
+        writer.write_all(&(self.timestamp.as_micros() as u64).to_le_bytes())?;
+        writer.write_all(&[server, kind])?;
+        writer.write_all(&(bytes.len() as u32).to_le_bytes())?;
+        writer.write_all(bytes)
+    }
+
+    /// Returns `None` at the end of the file.
+    fn read(reader: &mut impl Read) -> std::io::Result<Option<Self>> {
+        let mut timestamp = [0; 8];
+
+        match reader.read_exact(&mut timestamp) {
+            Ok(()) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => return Err(error),
+        }
+
+        let mut server_and_kind = [0; 2];
+        reader.read_exact(&mut server_and_kind)?;
+
+        let mut length = [0; 4];
+        reader.read_exact(&mut length)?;
+
+        let mut bytes = vec![0; u32::from_le_bytes(length) as usize];
+        reader.read_exact(&mut bytes)?;
+
+        let server = match server_and_kind[0] {
+            0 => ServerKind::Login,
+            1 => ServerKind::Character,
+            2 => ServerKind::Map,
+            _ => return Err(ErrorKind::InvalidData.into()),
+        };
+
+        let kind = match server_and_kind[1] {
+            0 => SessionRecordKind::Connected,
+            1 => SessionRecordKind::Incoming(bytes),
+            2 => SessionRecordKind::Outgoing(bytes),
+            _ => return Err(ErrorKind::InvalidData.into()),
+        };
+
+        Ok(Some(Self {
+            timestamp: Duration::from_micros(u64::from_le_bytes(timestamp)),
+            server,
+            kind,
+        }))
+    }
+}
+
+/// A recorded network session that can be replayed with
+/// [`NetworkingSystem::spawn_replay`](crate::NetworkingSystem::spawn_replay).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Session {
+    pub records: Vec<SessionRecord>,
+}
+
+impl Session {
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Self::read(&mut BufReader::new(File::open(path)?))
+    }
+
+    pub fn read(reader: &mut impl Read) -> std::io::Result<Self> {
+        let mut header = [0; 5];
+        reader.read_exact(&mut header)?;
+
+        if &header[..4] != MAGIC || header[4] != VERSION {
+            return Err(ErrorKind::InvalidData.into());
+        }
+
+        let mut records = Vec::new();
+
+        while let Some(record) = SessionRecord::read(reader)? {
+            records.push(record);
+        }
+
+        Ok(Self { records })
+    }
+
+    pub fn write(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+
+        self.records.iter().try_for_each(|record| record.write(writer))
+    }
+}
+
+struct RecorderState {
+    writer: BufWriter<File>,
+    start: Instant,
+}
+
+/// [`PacketCallback`] that writes every raw packet to a session file.
+///
+/// Records are flushed immediately, so the file stays usable if the client
+/// crashes.
+#[derive(Clone)]
+pub struct SessionRecorder {
+    state: Arc<Mutex<RecorderState>>,
+}
+
+impl SessionRecorder {
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.flush()?;
+
+        let state = RecorderState {
+            writer,
+            start: Instant::now(),
+        };
+
+        Ok(Self {
+            state: Arc::new(Mutex::new(state)),
+        })
+    }
+
+    fn record(&self, server: ServerKind, kind: SessionRecordKind) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        let record = SessionRecord {
+            timestamp: state.start.elapsed(),
+            server,
+            kind,
+        };
+
+        // NOTE: A failed write should never interrupt the session that is being
+        // recorded, so we discard the result.
+        let _ = record.write(&mut state.writer).and_then(|_| state.writer.flush());
+    }
+}
+
+impl PacketCallback for SessionRecorder {
+    fn server_connected(&self, server: ServerKind) {
+        self.record(server, SessionRecordKind::Connected);
+    }
+
+    fn raw_packet(&self, server: ServerKind, direction: PacketDirection, bytes: &[u8]) {
+        let kind = match direction {
+            PacketDirection::Incoming => SessionRecordKind::Incoming(bytes.to_vec()),
+            PacketDirection::Outgoing => SessionRecordKind::Outgoing(bytes.to_vec()),
+        };
+
+        self.record(server, kind);
+    }
+}
+
+/// The records of a [`Session`] split into the individual connections of each
+/// server, in the order they were established.
+pub(crate) struct SessionReplay {
+    login_server_connections: VecDeque<Vec<SessionRecord>>,
+    character_server_connections: VecDeque<Vec<SessionRecord>>,
+    map_server_connections: VecDeque<Vec<SessionRecord>>,
+}
+
+impl SessionReplay {
+    pub fn new(session: Session) -> Self {
+        let mut replay = Self {
+            login_server_connections: VecDeque::new(),
+            character_server_connections: VecDeque::new(),
+            map_server_connections: VecDeque::new(),
+        };
+
+        for record in session.records {
+            let connections = replay.connections(record.server);
+
+            match record.kind {
+                SessionRecordKind::Connected => connections.push_back(vec![record]),
+                // Records without a connection can't be replayed.
+                _ => {
+                    if let Some(connection) = connections.back_mut() {
+                        connection.push(record);
+                    }
+                }
+            }
+        }
+
+        replay
+    }
+
+    fn connections(&mut self, server: ServerKind) -> &mut VecDeque<Vec<SessionRecord>> {
+        match server {
+            ServerKind::Login => &mut self.login_server_connections,
+            ServerKind::Character => &mut self.character_server_connections,
+            ServerKind::Map => &mut self.map_server_connections,
+        }
+    }
+
+    /// Take the records of the next connection to the server.
+    pub fn next_connection(&mut self, server: ServerKind) -> Option<Vec<SessionRecord>> {
+        self.connections(server).pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ragnarok_packets::handler::ServerKind;
+
+    use super::{Session, SessionRecord, SessionRecordKind, SessionReplay};
+
+    fn record(milliseconds: u64, server: ServerKind, kind: SessionRecordKind) -> SessionRecord {
+        SessionRecord {
+            timestamp: Duration::from_millis(milliseconds),
+            server,
+            kind,
+        }
+    }
+
+    #[test]
+    fn write_read_round_trip() {
+        let session = Session {
+            records: vec![
+                record(0, ServerKind::Login, SessionRecordKind::Connected),
+                record(1, ServerKind::Login, SessionRecordKind::Outgoing(vec![0x64, 0x00, 1, 2])),
+                record(25, ServerKind::Login, SessionRecordKind::Incoming(vec![0xC4, 0x0A, 3])),
+            ],
+        };
+
+        let mut bytes = Vec::new();
+        session.write(&mut bytes).unwrap();
+
+        assert_eq!(Session::read(&mut bytes.as_slice()).unwrap(), session);
+    }
+
+    #[test]
+    fn read_rejects_other_files() {
+        assert!(Session::read(&mut b"GRAT\x01".as_slice()).is_err());
+    }
+
+    #[test]
+    fn split_connections() {
+        let session = Session {
+            records: vec![
+                record(0, ServerKind::Map, SessionRecordKind::Connected),
+                record(1, ServerKind::Map, SessionRecordKind::Incoming(vec![1])),
+                record(2, ServerKind::Character, SessionRecordKind::Connected),
+                record(3, ServerKind::Map, SessionRecordKind::Connected),
+                record(4, ServerKind::Map, SessionRecordKind::Incoming(vec![2])),
+            ],
+        };
+
+        let mut replay = SessionReplay::new(session);
+
+        assert_eq!(replay.next_connection(ServerKind::Map).unwrap().len(), 2);
+        assert_eq!(
+            replay.next_connection(ServerKind::Map).unwrap()[1].timestamp,
+            Duration::from_millis(4)
+        );
+        assert!(replay.next_connection(ServerKind::Map).is_none());
+        assert_eq!(replay.next_connection(ServerKind::Character).unwrap().len(), 1);
+        assert!(replay.next_connection(ServerKind::Login).is_none());
+    }
+}
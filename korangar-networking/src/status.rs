@@ -0,0 +1,40 @@
+use ragnarok_packets::StatusEffectId;
+
+/// Remaining time that the server sends for status effects that don't expire.
+const INFINITE_DURATION: u32 = 9999;
+
+/// A status effect, such as a buff or a debuff, that is active on an entity.
+#[derive(Debug, Clone)]
+pub struct StatusEffect {
+    pub status_effect_id: StatusEffectId,
+    /// Total duration in milliseconds. `None` if the status effect doesn't
+    /// expire.
+    pub duration: Option<u32>,
+    /// Time in milliseconds until the status effect expires, at the moment it
+    /// was received. `None` if the status effect doesn't expire.
+    pub remaining_time: Option<u32>,
+    pub values: [u32; 3],
+}
+
+impl StatusEffect {
+    pub fn new(status_effect_id: StatusEffectId, duration: u32, remaining_time: u32, values: [u32; 3]) -> Self {
+        let expires = remaining_time != 0 && remaining_time != INFINITE_DURATION;
+
+        Self {
+            status_effect_id,
+            duration: expires.then_some(duration.max(remaining_time)),
+            remaining_time: expires.then_some(remaining_time),
+            values,
+        }
+    }
+
+    /// Status effect without any information about its duration.
+    pub fn permanent(status_effect_id: StatusEffectId) -> Self {
+        Self {
+            status_effect_id,
+            duration: None,
+            remaining_time: None,
+            values: [0; 3],
+        }
+    }
+}
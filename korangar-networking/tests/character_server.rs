@@ -0,0 +1,149 @@
+mod fake_server;
+
+use korangar_networking::{NetworkEvent, SupportedPacketVersion};
+use ragnarok_packets::packet_versions::version_20200401;
+use ragnarok_packets::*;
+
+use self::fake_server::{
+    FakeClient, FakeServer, accept_character_server_login, character_information, character_server_information, login_data,
+};
+
+fn connect(client: &mut FakeClient, server: &FakeServer, packet_version: SupportedPacketVersion) {
+    client
+        .networking_system
+        .connect_to_character_server(packet_version, &login_data(), character_server_information(server.port()));
+
+    let NetworkEvent::AccountId { account_id } = client.wait_for_event(|event| matches!(event, NetworkEvent::AccountId { .. })) else {
+        unreachable!()
+    };
+    assert_eq!(account_id, login_data().account_id);
+
+    let NetworkEvent::CharacterServerConnected { normal_slot_count } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::CharacterServerConnected { .. }))
+    else {
+        unreachable!()
+    };
+    assert_eq!(normal_slot_count, 9);
+}
+
+#[test]
+fn select_character() {
+    let server = FakeServer::spawn(|connection| {
+        accept_character_server_login(connection);
+
+        connection.receive::<RequestCharacterListPacket>();
+        connection.send(RequestCharacterListSuccessPacket {
+            character_information: vec![character_information("Player")],
+        });
+
+        let packet = connection.receive::<SelectCharacterPacket>();
+        assert_eq!(packet.selected_slot, 0);
+
+        connection.send(CharacterSelectionSuccessPacket {
+            character_id: CharacterId(150000),
+            map_name: "prontera.gat".to_owned(),
+            map_server_ip: ServerAddress([127, 0, 0, 1]),
+            map_server_port: 5121,
+            unknown: [0; 128],
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server, SupportedPacketVersion::_20220406);
+
+    client.networking_system.request_character_list().unwrap();
+
+    let NetworkEvent::CharacterList { characters } = client.wait_for_event(|event| matches!(event, NetworkEvent::CharacterList { .. }))
+    else {
+        unreachable!()
+    };
+    assert_eq!(characters.len(), 1);
+    assert_eq!(characters[0].name, "Player");
+
+    client.networking_system.select_character(0).unwrap();
+
+    let NetworkEvent::CharacterSelected { login_data } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::CharacterSelected { .. }))
+    else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(login_data.character_id, CharacterId(150000));
+    assert_eq!(login_data.server_port, 5121);
+}
+
+#[test]
+fn character_list_20200401() {
+    let server = FakeServer::spawn(|connection| {
+        accept_character_server_login(connection);
+
+        connection.receive::<RequestCharacterListPacket>();
+        connection.send(version_20200401::RequestCharacterListSuccessPacket {
+            character_information: vec![version_20200401::CharacterInformation {
+                character_id: CharacterId(150000),
+                experience: 0,
+                money: 500,
+                job_experience: 0,
+                job_level: 1,
+                body_state: 0,
+                health_state: 0,
+                effect_state: 0,
+                virtue: 0,
+                honor: 0,
+                stat_points: 48,
+                health_points: 40,
+                maximum_health_points: 40,
+                spell_points: 11,
+                maximum_spell_points: 11,
+                movement_speed: 150,
+                job: 0,
+                head: 1,
+                body: 0,
+                weapon: 0,
+                base_level: 1,
+                sp_point: 0,
+                head_bottom: 0,
+                shield: 0,
+                head_top: 0,
+                head_middle: 0,
+                head_palette: 0,
+                body_palette: 0,
+                name: "Player".to_owned(),
+                strength: 1,
+                agility: 1,
+                vitality: 1,
+                intelligence: 1,
+                dexterity: 1,
+                luck: 1,
+                character_number: 0,
+                hair_color: 0,
+                b_is_changed_char: 0,
+                map_name: "new_1-1.gat".to_owned(),
+                deletion_reverse_date: 0,
+                robe: 0,
+                character_slot_change_count: 0,
+                character_name_change_count: 0,
+                sex: Sex::Female,
+            }],
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server, SupportedPacketVersion::_20200401);
+
+    client.networking_system.request_character_list().unwrap();
+
+    let NetworkEvent::CharacterList { characters } = client.wait_for_event(|event| matches!(event, NetworkEvent::CharacterList { .. }))
+    else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(characters.len(), 1);
+    assert_eq!(characters[0].name, "Player");
+    assert_eq!(characters[0].health_points, 40);
+    assert_eq!(characters[0].maximum_spell_points, 11);
+}
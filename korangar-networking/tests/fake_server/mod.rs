@@ -0,0 +1,406 @@
+//! Scriptable fake login, character and map server that speaks the protocol
+//! through the packets of `ragnarok-packets`. It allows testing the
+//! [`NetworkingSystem`] end-to-end without a real server.
+
+// Every test file only uses a part of this module.
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use korangar_networking::{NetworkEvent, NetworkEventBuffer, NetworkingSystem};
+use ragnarok_bytes::encoding::UTF_8;
+use ragnarok_bytes::{ByteReader, ByteWriter, ToBytes};
+use ragnarok_packets::handler::{NoPacketCallback, PacketCallback};
+use ragnarok_packets::*;
+
+/// How long the server and the client wait for something to happen before the
+/// test fails.
+pub const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A server that accepts a single connection and runs a script on it.
+pub struct FakeServer {
+    address: SocketAddr,
+    handle: JoinHandle<()>,
+}
+
+impl FakeServer {
+    /// Listen on a random local port and run the script as soon as a client
+    /// connects. Failing assertions inside the script fail the test when
+    /// calling [`FakeServer::join`].
+    pub fn spawn(script: impl FnOnce(&mut FakeConnection) + Send + 'static) -> Self {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).expect("failed to bind fake server");
+        let address = listener.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            let mut connection = accept_with_timeout(&listener);
+            script(&mut connection);
+        });
+
+        Self { address, handle }
+    }
+
+    /// Like [`FakeServer::spawn`], but accepts multiple connections one after
+    /// another. The script is called with the index of the connection.
+    pub fn spawn_repeated(connection_count: usize, script: impl Fn(usize, &mut FakeConnection) + Send + 'static) -> Self {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).expect("failed to bind fake server");
+        let address = listener.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            for index in 0..connection_count {
+                let mut connection = accept_with_timeout(&listener);
+                script(index, &mut connection);
+            }
+        });
+
+        Self { address, handle }
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    pub fn port(&self) -> u16 {
+        self.address.port()
+    }
+
+    /// Wait for the script to finish and propagate any panic.
+    pub fn join(self) {
+        if let Err(panic) = self.handle.join() {
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
+
+fn accept_with_timeout(listener: &TcpListener) -> FakeConnection {
+    listener.set_nonblocking(true).unwrap();
+
+    let start = Instant::now();
+
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                stream.set_nonblocking(false).unwrap();
+                stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+
+                return FakeConnection {
+                    stream,
+                    buffer: Vec::new(),
+                };
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock && start.elapsed() < TIMEOUT => {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            Err(error) => panic!("client never connected: {error}"),
+        }
+    }
+}
+
+/// The connection to the client as seen from a [`FakeServer`] script.
+pub struct FakeConnection {
+    stream: TcpStream,
+    buffer: Vec<u8>,
+}
+
+impl FakeConnection {
+    /// Receive the next packet from the client. Keep-alive packets are
+    /// skipped, unless they are the packet that is expected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client sends a different packet, closes the connection or
+    /// doesn't send anything within the [`TIMEOUT`].
+    pub fn receive<Packet>(&mut self) -> Packet
+    where
+        Packet: ClientPacket,
+    {
+        self.skip_keepalive_packets(Packet::HEADER);
+        self.read_packet()
+    }
+
+    /// Receive the next variable length packet from the client as raw bytes,
+    /// without the header and the packet length. This is needed for packets
+    /// like [`GlobalMessagePacket`] that can't be read back by
+    /// `ragnarok-packets` because of their off-by-one length.
+    pub fn receive_payload(&mut self, header: PacketHeader) -> Vec<u8> {
+        self.skip_keepalive_packets(header);
+
+        while self.buffer.len() < 4 {
+            self.fill_buffer();
+        }
+
+        let packet_length = u16::from_le_bytes([self.buffer[2], self.buffer[3]]) as usize;
+
+        while self.buffer.len() < packet_length {
+            self.fill_buffer();
+        }
+
+        self.buffer.drain(..packet_length).skip(4).collect()
+    }
+
+    /// Send a packet to the client.
+    pub fn send(&mut self, packet: impl ServerPacket) {
+        let mut byte_writer = ByteWriter::with_encoding(UTF_8);
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        self.stream.write_all(byte_writer.as_slice()).expect("failed to send packet");
+    }
+
+    /// The character server sends the account id right after the client
+    /// logged in, without any packet header.
+    pub fn send_account_id(&mut self, account_id: AccountId) {
+        let mut byte_writer = ByteWriter::new();
+        account_id.to_bytes(&mut byte_writer).unwrap();
+        self.stream.write_all(byte_writer.as_slice()).expect("failed to send account id");
+    }
+
+    /// Ignore everything the client sends until it closes the connection or
+    /// the [`TIMEOUT`] passes.
+    pub fn wait_for_close(&mut self) {
+        let mut buffer = [0; 4096];
+
+        while let Ok(1..) = self.stream.read(&mut buffer) {}
+    }
+
+    fn skip_keepalive_packets(&mut self, expected_header: PacketHeader) {
+        loop {
+            match self.peek_header() {
+                header if header == expected_header => return,
+                LoginServerKeepalivePacket::HEADER => drop(self.read_packet::<LoginServerKeepalivePacket>()),
+                CharacterServerKeepalivePacket::HEADER => drop(self.read_packet::<CharacterServerKeepalivePacket>()),
+                RequestServerTickPacket::HEADER => drop(self.read_packet::<RequestServerTickPacket>()),
+                header => panic!("expected packet {expected_header:?} but received {header:?}"),
+            }
+        }
+    }
+
+    fn peek_header(&mut self) -> PacketHeader {
+        while self.buffer.len() < 2 {
+            self.fill_buffer();
+        }
+
+        PacketHeader(u16::from_le_bytes([self.buffer[0], self.buffer[1]]))
+    }
+
+    fn read_packet<Packet>(&mut self) -> Packet
+    where
+        Packet: ragnarok_packets::Packet,
+    {
+        loop {
+            let mut byte_reader = ByteReader::without_metadata(&self.buffer);
+            byte_reader.set_encoding(UTF_8);
+
+            match Packet::packet_from_bytes(&mut byte_reader) {
+                Ok(packet) => {
+                    let packet_size = byte_reader.get_offset();
+                    self.buffer.drain(..packet_size);
+                    return packet;
+                }
+                Err(error) if error.is_byte_reader_too_short() => self.fill_buffer(),
+                Err(error) => panic!("failed to parse packet {:?}: {error:?}", Packet::HEADER),
+            }
+        }
+    }
+
+    fn fill_buffer(&mut self) {
+        let mut buffer = [0; 4096];
+
+        match self.stream.read(&mut buffer) {
+            Ok(0) => panic!("connection was closed by the client"),
+            Ok(received_bytes) => self.buffer.extend_from_slice(&buffer[..received_bytes]),
+            Err(error) => panic!("failed to receive packet: {error}"),
+        }
+    }
+}
+
+/// A [`NetworkingSystem`] together with the events it produced that were not
+/// consumed by the test yet.
+pub struct FakeClient<Callback = NoPacketCallback> {
+    pub networking_system: NetworkingSystem<Callback>,
+    event_buffer: NetworkEventBuffer,
+    pending_events: VecDeque<NetworkEvent>,
+}
+
+impl FakeClient {
+    pub fn new() -> Self {
+        Self::from_spawned(NetworkingSystem::spawn())
+    }
+}
+
+impl<Callback> FakeClient<Callback>
+where
+    Callback: PacketCallback + Send,
+{
+    pub fn from_spawned((networking_system, event_buffer): (NetworkingSystem<Callback>, NetworkEventBuffer)) -> Self {
+        Self {
+            networking_system,
+            event_buffer,
+            pending_events: VecDeque::new(),
+        }
+    }
+
+    /// Wait for the first event that matches the predicate. Events that don't
+    /// match are kept for later calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no matching event arrives within the [`TIMEOUT`].
+    pub fn wait_for_event(&mut self, predicate: impl Fn(&NetworkEvent) -> bool) -> NetworkEvent {
+        let start = Instant::now();
+
+        loop {
+            if let Some(index) = self.pending_events.iter().position(&predicate) {
+                return self.pending_events.remove(index).unwrap();
+            }
+
+            if start.elapsed() > TIMEOUT {
+                panic!("timed out waiting for event, received: {:#?}", self.pending_events);
+            }
+
+            self.networking_system.get_events(&mut self.event_buffer);
+            self.pending_events.extend(self.event_buffer.drain());
+
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+}
+
+/// Accept the login on the login server and send the [`login_data`] with a
+/// single character server listening on the given port. Returns the login
+/// packet for further assertions.
+pub fn accept_login_server_login(connection: &mut FakeConnection, character_server_port: u16) -> LoginServerLoginPacket {
+    let packet = connection.receive::<LoginServerLoginPacket>();
+
+    let login_data = login_data();
+    connection.send(LoginServerLoginSuccessPacket {
+        login_id1: login_data.login_id1,
+        account_id: login_data.account_id,
+        login_id2: login_data.login_id2,
+        ip_address: 0,
+        name: [0; 24],
+        unknown: 0,
+        sex: login_data.sex,
+        auth_token: AuthToken([0; 17]),
+        character_server_information: vec![character_server_information(character_server_port)],
+    });
+
+    packet
+}
+
+/// Accept the login on the character server. The client has to use the
+/// [`login_data`].
+pub fn accept_character_server_login(connection: &mut FakeConnection) {
+    let login_data = login_data();
+
+    let packet = connection.receive::<CharacterServerLoginPacket>();
+    assert_eq!(packet.account_id, login_data.account_id);
+    assert_eq!(packet.login_id1, login_data.login_id1);
+    assert_eq!(packet.login_id2, login_data.login_id2);
+
+    connection.send_account_id(login_data.account_id);
+    connection.send(CharacterServerLoginSuccessPacket {
+        unknown: 29,
+        normal_slot_count: 9,
+        vip_slot_count: 0,
+        billing_slot_count: 0,
+        producible_slot_count: 9,
+        valid_slot: 9,
+        unused: [0; 20],
+    });
+}
+
+/// Accept the login on the map server and place the player at the given
+/// position. The client has to use the [`login_data`] and the character of
+/// [`character_server_login_data`].
+pub fn accept_map_server_login(connection: &mut FakeConnection, position: TilePosition) {
+    let packet = connection.receive::<MapServerLoginPacket>();
+    assert_eq!(packet.account_id, login_data().account_id);
+    assert_eq!(packet.character_id, CharacterId(150000));
+
+    connection.send(MapServerLoginSuccessPacket {
+        client_tick: ClientTick(1234),
+        position: WorldPosition::new(position.x, position.y, Direction::North),
+        ignored: [5, 5],
+        font: 0,
+    });
+}
+
+pub fn login_data() -> korangar_networking::LoginServerLoginData {
+    korangar_networking::LoginServerLoginData {
+        account_id: AccountId(2000000),
+        login_id1: 11,
+        login_id2: 22,
+        sex: Sex::Female,
+    }
+}
+
+pub fn character_server_information(server_port: u16) -> CharacterServerInformation {
+    CharacterServerInformation {
+        server_ip: ServerAddress(Ipv4Addr::LOCALHOST.octets()),
+        server_port,
+        server_name: "Fake".to_owned(),
+        user_count: 0,
+        server_type: 0,
+        display_new: 0,
+        unknown: [0; 128],
+    }
+}
+
+pub fn character_server_login_data(server_port: u16) -> korangar_networking::CharacterServerLoginData {
+    korangar_networking::CharacterServerLoginData {
+        server_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+        server_port,
+        character_id: CharacterId(150000),
+    }
+}
+
+pub fn character_information(name: &str) -> CharacterInformation {
+    CharacterInformation {
+        character_id: CharacterId(150000),
+        experience: 0,
+        money: 500,
+        job_experience: 0,
+        job_level: 1,
+        body_state: 0,
+        health_state: 0,
+        effect_state: 0,
+        virtue: 0,
+        honor: 0,
+        stat_points: 48,
+        health_points: 40,
+        maximum_health_points: 40,
+        spell_points: 11,
+        maximum_spell_points: 11,
+        movement_speed: 150,
+        job: 0,
+        head: 1,
+        body: 0,
+        weapon: 0,
+        base_level: 1,
+        sp_point: 0,
+        head_bottom: 0,
+        shield: 0,
+        head_top: 0,
+        head_middle: 0,
+        head_palette: 0,
+        body_palette: 0,
+        name: name.to_owned(),
+        strength: 1,
+        agility: 1,
+        vitality: 1,
+        intelligence: 1,
+        dexterity: 1,
+        luck: 1,
+        character_number: 0,
+        hair_color: 0,
+        b_is_changed_char: 0,
+        map_name: "new_1-1.gat".to_owned(),
+        deletion_reverse_date: 0,
+        robe: 0,
+        character_slot_change_count: 0,
+        character_name_change_count: 0,
+        sex: Sex::Female,
+    }
+}
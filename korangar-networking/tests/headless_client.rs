@@ -0,0 +1,128 @@
+mod fake_server;
+
+use korangar_networking::{HeadlessClient, HeadlessError, NetworkEvent, PasswordEncryption, SupportedPacketVersion};
+use ragnarok_packets::*;
+
+use self::fake_server::{
+    FakeServer, TIMEOUT, accept_character_server_login, accept_login_server_login, accept_map_server_login, character_information,
+    login_data,
+};
+
+fn login_server(character_server_port: u16) -> FakeServer {
+    FakeServer::spawn(move |connection| {
+        let packet = accept_login_server_login(connection, character_server_port);
+        assert_eq!(packet.name, "username");
+    })
+}
+
+fn character_server(map_server_port: u16, select: bool) -> FakeServer {
+    FakeServer::spawn(move |connection| {
+        accept_character_server_login(connection);
+
+        connection.receive::<RequestCharacterListPacket>();
+        connection.send(RequestCharacterListSuccessPacket {
+            character_information: vec![character_information("Player")],
+        });
+
+        if select {
+            let packet = connection.receive::<SelectCharacterPacket>();
+            assert_eq!(packet.selected_slot, 0);
+
+            connection.send(CharacterSelectionSuccessPacket {
+                character_id: CharacterId(150000),
+                map_name: "new_1-1.gat".to_owned(),
+                map_server_ip: ServerAddress([127, 0, 0, 1]),
+                map_server_port,
+                unknown: [0; 128],
+            });
+        }
+
+        connection.wait_for_close();
+    })
+}
+
+#[tokio::test]
+async fn play() {
+    let map_server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, TilePosition { x: 50, y: 100 });
+        connection.receive::<MapLoadedPacket>();
+
+        let packet = connection.receive::<RequestPlayerMovePacket>();
+        assert_eq!(packet.position.tile_position(), TilePosition { x: 52, y: 101 });
+
+        connection.send(PlayerMovePacket {
+            starting_timestamp: ClientTick(1500),
+            from_to: WorldPosition2::new(50, 100, 52, 101),
+        });
+
+        let payload = connection.receive_payload(GlobalMessagePacket::HEADER);
+        assert_eq!(payload, b"Player : hello\0");
+    });
+    let character_server = character_server(map_server.port(), true);
+    let login_server = login_server(character_server.port());
+
+    let mut client = HeadlessClient::new(SupportedPacketVersion::_20220406, login_server.address());
+    client.set_timeout(TIMEOUT);
+
+    client.login("username", "password", PasswordEncryption::None).await.unwrap();
+    login_server.join();
+
+    assert_eq!(client.state().characters.len(), 1);
+    assert_eq!(client.state().login_data.unwrap().account_id, login_data().account_id);
+
+    client.select_character("Player").await.unwrap();
+    character_server.join();
+
+    assert_eq!(client.state().character.as_ref().unwrap().name, "Player");
+    assert_eq!(client.state().map_name.as_deref(), Some("new_1-1"));
+
+    let destination = client.walk_to(TilePosition { x: 52, y: 101 }).await.unwrap();
+    assert_eq!(destination, TilePosition { x: 52, y: 101 });
+    assert_eq!(client.state().position, Some(destination));
+
+    client.chat("hello").unwrap();
+    map_server.join();
+
+    // Every event is queued, including the ones that were handled by the client.
+    let mut saw_character_list = false;
+    while let Some(event) = client.try_next_event() {
+        saw_character_list |= matches!(event, NetworkEvent::CharacterList { .. });
+    }
+    assert!(saw_character_list);
+}
+
+#[tokio::test]
+async fn character_not_found() {
+    let character_server = character_server(0, false);
+    let login_server = login_server(character_server.port());
+
+    let mut client = HeadlessClient::new(SupportedPacketVersion::_20220406, login_server.address());
+    client.set_timeout(TIMEOUT);
+
+    client.login("username", "password", PasswordEncryption::None).await.unwrap();
+
+    let result = client.select_character("Nobody").await;
+    assert!(matches!(result, Err(HeadlessError::CharacterNotFound { name }) if name == "Nobody"));
+
+    client.networking_system().disconnect_from_character_server();
+    login_server.join();
+    character_server.join();
+}
+
+#[tokio::test]
+async fn login_failed() {
+    let login_server = FakeServer::spawn(|connection| {
+        connection.receive::<LoginServerLoginPacket>();
+        connection.send(LoginFailedPacket2 {
+            reason: LoginFailedReason2::IncorrectPassword,
+        });
+    });
+
+    let mut client = HeadlessClient::new(SupportedPacketVersion::_20220406, login_server.address());
+    client.set_timeout(TIMEOUT);
+
+    let result = client.login("username", "wrong", PasswordEncryption::None).await;
+    login_server.join();
+
+    assert!(matches!(result, Err(HeadlessError::LoginFailed { .. })));
+}
@@ -0,0 +1,102 @@
+mod fake_server;
+
+use korangar_networking::{NetworkEvent, PasswordEncryption, SupportedPacketVersion, UnifiedLoginFailedReason};
+use ragnarok_packets::*;
+
+use self::fake_server::{FakeClient, FakeServer, accept_login_server_login};
+
+#[test]
+fn login() {
+    let server = FakeServer::spawn(|connection| {
+        let packet = accept_login_server_login(connection, 6121);
+        assert_eq!(packet.name, "username");
+        assert_eq!(packet.password, "password");
+    });
+
+    let mut client = FakeClient::new();
+    client.networking_system.connect_to_login_server(
+        SupportedPacketVersion::_20220406,
+        server.address(),
+        "username",
+        "password",
+        PasswordEncryption::None,
+    );
+
+    let event = client.wait_for_event(|event| matches!(event, NetworkEvent::LoginServerConnected { .. }));
+    server.join();
+
+    let NetworkEvent::LoginServerConnected {
+        character_servers,
+        login_data,
+    } = event
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(character_servers.len(), 1);
+    assert_eq!(character_servers[0].server_name, "Fake");
+    assert_eq!(character_servers[0].server_port, 6121);
+    assert_eq!(login_data.account_id, self::fake_server::login_data().account_id);
+}
+
+#[test]
+fn login_failed() {
+    let server = FakeServer::spawn(|connection| {
+        connection.receive::<LoginServerLoginPacket>();
+        connection.send(LoginFailedPacket2 {
+            reason: LoginFailedReason2::IncorrectPassword,
+        });
+    });
+
+    let mut client = FakeClient::new();
+    client.networking_system.connect_to_login_server(
+        SupportedPacketVersion::_20220406,
+        server.address(),
+        "username",
+        "wrong",
+        PasswordEncryption::None,
+    );
+
+    let event = client.wait_for_event(|event| matches!(event, NetworkEvent::LoginServerConnectionFailed { .. }));
+    server.join();
+
+    assert!(matches!(event, NetworkEvent::LoginServerConnectionFailed {
+        reason: UnifiedLoginFailedReason::IncorrectPassword,
+        ..
+    }));
+}
+
+#[test]
+fn hashed_login() {
+    let server = FakeServer::spawn(|connection| {
+        connection.receive::<RequestPasswordSaltPacket>();
+        connection.send(PasswordSaltPacket { salt: vec![1, 2, 3, 4] });
+
+        let packet = connection.receive::<LoginServerHashedLoginPacket>();
+        assert_eq!(packet.name, "username");
+        // MD5 of the salt followed by "hunter2".
+        assert_eq!(packet.password_hash, [
+            0xA2, 0x14, 0x41, 0x55, 0x4E, 0xBB, 0x3C, 0xB6, 0x3D, 0x55, 0x04, 0xC2, 0x35, 0xA3, 0xE7, 0xF0
+        ]);
+    });
+
+    let mut client = FakeClient::new();
+    client.networking_system.connect_to_login_server(
+        SupportedPacketVersion::_20220406,
+        server.address(),
+        "username",
+        "hunter2",
+        PasswordEncryption::Method1,
+    );
+
+    let NetworkEvent::LoginServerPasswordSalt { salt } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::LoginServerPasswordSalt { .. }))
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(salt, [1, 2, 3, 4]);
+    client.networking_system.send_hashed_login(&salt).unwrap();
+
+    server.join();
+}
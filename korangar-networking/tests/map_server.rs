@@ -0,0 +1,897 @@
+mod fake_server;
+
+use std::time::Duration;
+
+use korangar_networking::{BodyState, ConnectionSettings, DisconnectReason, MessageColor, NetworkEvent, SupportedPacketVersion};
+use ragnarok_packets::packet_versions::version_20200401;
+use ragnarok_packets::*;
+
+use self::fake_server::{
+    FakeClient, FakeServer, accept_map_server_login, character_information, character_server_information, character_server_login_data,
+    login_data,
+};
+
+const SPAWN_POSITION: TilePosition = TilePosition { x: 150, y: 180 };
+
+fn connect(client: &mut FakeClient, server: &FakeServer) {
+    connect_with_packet_version(client, server, SupportedPacketVersion::_20220406);
+}
+
+fn connect_with_packet_version(client: &mut FakeClient, server: &FakeServer, packet_version: SupportedPacketVersion) {
+    client
+        .networking_system
+        .connect_to_map_server(packet_version, &login_data(), character_server_login_data(server.port()));
+
+    let NetworkEvent::UpdateClientTick { client_tick, .. } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::UpdateClientTick { .. }))
+    else {
+        unreachable!()
+    };
+    assert_eq!(client_tick.0, 1234);
+}
+
+#[test]
+fn change_map() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+        connection.receive::<MapLoadedPacket>();
+
+        let packet = connection.receive::<RequestWarpToMapPacket>();
+        assert_eq!(packet.map_name, "prontera.gat");
+
+        connection.send(ChangeMapPacket {
+            map_name: "prontera.gat".to_owned(),
+            position: packet.position,
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    client.networking_system.map_loaded().unwrap();
+    client
+        .networking_system
+        .warp_to_map("prontera.gat".to_owned(), TilePosition { x: 155, y: 185 })
+        .unwrap();
+
+    let NetworkEvent::ChangeMap { map_name, position } = client.wait_for_event(|event| matches!(event, NetworkEvent::ChangeMap { .. }))
+    else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(map_name, "prontera");
+    assert_eq!(position, TilePosition { x: 155, y: 185 });
+}
+
+#[test]
+fn chat() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        let payload = connection.receive_payload(GlobalMessagePacket::HEADER);
+        assert_eq!(payload, b"Player : hello\0");
+
+        connection.send(ServerMessagePacket {
+            message: "Welcome".to_owned(),
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    client.networking_system.send_chat_message("Player", "hello").unwrap();
+
+    let NetworkEvent::ChatMessage { text, color } = client.wait_for_event(|event| matches!(event, NetworkEvent::ChatMessage { .. })) else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(text, "Welcome");
+    assert!(matches!(color, MessageColor::Server));
+}
+
+#[test]
+fn shop() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        connection.send(BuyOrSellPacket { shop_id: ShopId(42) });
+
+        let packet = connection.receive::<SelectBuyOrSellPacket>();
+        assert_eq!(packet.shop_id, ShopId(42));
+        assert!(matches!(packet.option, BuyOrSellOption::Buy));
+
+        connection.send(ShopItemListPacket {
+            items: vec![ShopItemInformation {
+                item_id: ItemId(501),
+                price: Price(50),
+                discount_price: Price(45),
+                item_type: 0,
+                view_sprite: 0,
+                location: 0,
+            }],
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    let NetworkEvent::AskBuyOrSell { shop_id } = client.wait_for_event(|event| matches!(event, NetworkEvent::AskBuyOrSell { .. })) else {
+        unreachable!()
+    };
+    assert_eq!(shop_id, ShopId(42));
+
+    client.networking_system.select_buy_or_sell(shop_id, BuyOrSellOption::Buy).unwrap();
+
+    let NetworkEvent::OpenShop { items } = client.wait_for_event(|event| matches!(event, NetworkEvent::OpenShop { .. })) else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].item_id, ItemId(501));
+}
+
+#[test]
+fn homunculus() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        connection.send(HomunculusStatePacket {
+            unused: 0,
+            state_type: HomunculusStateType::Initialize,
+            entity_id: EntityId(60000),
+            value: 0,
+        });
+        connection.send(HomunculusInformationPacket {
+            name: "Lif".to_owned(),
+            flags: 0b001,
+            level: 12,
+            hunger: 20,
+            intimacy: 250,
+            equipped_item: 0,
+            attack: 30,
+            magic_attack: 40,
+            hit: 50,
+            critical: 1,
+            defense: 5,
+            magic_defense: 10,
+            flee: 60,
+            attack_speed: 150,
+            health_points: 180,
+            maximum_health_points: 200,
+            spell_points: 30,
+            maximum_spell_points: 45,
+            experience: 100,
+            next_level_experience: 500,
+            skill_points: 1,
+            attack_range: 1,
+        });
+
+        let packet = connection.receive::<HomunculusCommandPacket>();
+        assert_eq!(packet.command, HomunculusCommand::Feed);
+
+        connection.send(HomunculusFeedResultPacket {
+            success: 1,
+            item_id: ItemId(537),
+        });
+        connection.send(HomunculusStatePacket {
+            unused: 0,
+            state_type: HomunculusStateType::Hunger,
+            entity_id: EntityId(60000),
+            value: 30,
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    let NetworkEvent::SetHomunculus { entity_id } = client.wait_for_event(|event| matches!(event, NetworkEvent::SetHomunculus { .. }))
+    else {
+        unreachable!()
+    };
+    assert_eq!(entity_id, EntityId(60000));
+
+    let NetworkEvent::UpdateHomunculusInformation { information } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::UpdateHomunculusInformation { .. }))
+    else {
+        unreachable!()
+    };
+    assert_eq!(information.name, "Lif");
+    assert!(information.renamed);
+    assert!(!information.vaporized);
+    assert_eq!(information.maximum_spell_points, 45);
+
+    client.networking_system.homunculus_command(HomunculusCommand::Feed).unwrap();
+
+    let NetworkEvent::HomunculusFed { success, item_id } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::HomunculusFed { .. }))
+    else {
+        unreachable!()
+    };
+    assert!(success);
+    assert_eq!(item_id, ItemId(537));
+
+    let NetworkEvent::UpdateHomunculusHunger { hunger } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::UpdateHomunculusHunger { .. }))
+    else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(hunger, 30);
+}
+
+#[test]
+fn vending() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        connection.send(VendingShopOpenedPacket {
+            entity_id: EntityId(2000001),
+            shop_title: "Cheap potions".to_owned(),
+        });
+
+        let packet = connection.receive::<RequestVendingItemsPacket>();
+        assert_eq!(packet.entity_id, EntityId(2000001));
+
+        connection.send(VendingItemListPacket {
+            entity_id: EntityId(2000001),
+            vending_id: 7,
+            items: vec![VendingItemInformation {
+                price: Price(40),
+                amount: 25,
+                index: InventoryIndex(3),
+                item_type: 0,
+                item_id: ItemId(501),
+                is_identified: 1,
+                is_broken: 0,
+                refinement_level: 0,
+                cards: [0; 4],
+                option_data: std::array::from_fn(|_| ItemOptions {
+                    index: 0,
+                    value: 0,
+                    parameter: 0,
+                }),
+                location: 0,
+                sprite_number: 0,
+                enchantment_level: 0,
+            }],
+        });
+
+        let packet = connection.receive::<BuyVendingItemsPacket>();
+        assert_eq!(packet.vending_id, 7);
+        assert_eq!(packet.items.len(), 1);
+        assert_eq!(packet.items[0].index, InventoryIndex(3));
+        assert_eq!(packet.items[0].amount, 10);
+
+        connection.send(BuyVendingItemFailedPacket {
+            index: InventoryIndex(3),
+            amount: 10,
+            reason: BuyVendingItemFailedReason::NotEnoughZeny,
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    let NetworkEvent::VendingShopOpened { entity_id, shop_title } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::VendingShopOpened { .. }))
+    else {
+        unreachable!()
+    };
+    assert_eq!(entity_id, EntityId(2000001));
+    assert_eq!(shop_title, "Cheap potions");
+
+    client.networking_system.request_vending_items(entity_id).unwrap();
+
+    let NetworkEvent::VendingItemList { vending_id, items, .. } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::VendingItemList { .. }))
+    else {
+        unreachable!()
+    };
+    assert_eq!(vending_id, 7);
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].item_id, ItemId(501));
+    assert_eq!(items[0].price, Price(40));
+    assert!(items[0].is_identified);
+
+    client
+        .networking_system
+        .buy_vending_items(entity_id, vending_id, vec![VendingPurchase {
+            amount: 10,
+            index: items[0].index,
+        }])
+        .unwrap();
+
+    let NetworkEvent::ChatMessage { color, .. } = client.wait_for_event(|event| matches!(event, NetworkEvent::ChatMessage { .. })) else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert!(matches!(color, MessageColor::Error));
+}
+
+#[test]
+fn vending_20200401() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        connection.receive::<RequestVendingItemsPacket>();
+        connection.send(version_20200401::VendingItemListPacket {
+            entity_id: EntityId(2000001),
+            vending_id: 7,
+            items: vec![version_20200401::VendingItemInformation {
+                price: Price(40),
+                amount: 25,
+                index: InventoryIndex(3),
+                item_type: 0,
+                item_id: ItemId(501),
+                is_identified: 1,
+                is_broken: 0,
+                refinement_level: 0,
+                cards: [0; 4],
+                option_data: std::array::from_fn(|_| ItemOptions {
+                    index: 0,
+                    value: 0,
+                    parameter: 0,
+                }),
+                location: 0,
+                sprite_number: 0,
+            }],
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect_with_packet_version(&mut client, &server, SupportedPacketVersion::_20200401);
+
+    client.networking_system.request_vending_items(EntityId(2000001)).unwrap();
+
+    let NetworkEvent::VendingItemList { vending_id, items, .. } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::VendingItemList { .. }))
+    else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(vending_id, 7);
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].item_id, ItemId(501));
+    assert_eq!(items[0].price, Price(40));
+    assert_eq!(items[0].amount, 25);
+}
+
+#[test]
+fn mail() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        connection.send(NewMailStatusPacket { new_available: 1 });
+
+        connection.receive::<OpenMailboxPacket>();
+
+        connection.send(MailListPacket {
+            is_end: 1,
+            mails: vec![MailListEntry {
+                open_type: MailOpenType::Character,
+                mail_id: MailId(12),
+                is_read: 0,
+                mail_type: 0x2,
+                sender_name: "Kafra".to_owned(),
+                expiration_time: 3600,
+                title_length: 9,
+                title: "Welcome!".to_owned(),
+            }],
+        });
+
+        let packet = connection.receive::<RequestReadMailPacket>();
+        assert_eq!(packet.mail_id, MailId(12));
+
+        connection.send(ReadMailPacket {
+            open_type: MailOpenType::Character,
+            mail_id: MailId(12),
+            text_length: 6,
+            zeny: 500,
+            item_count: 0,
+            text: "Hello".to_owned(),
+            items: Vec::new(),
+        });
+
+        let packet = connection.receive::<CheckMailReceiverPacket>();
+        assert_eq!(packet.name, "Receiver");
+
+        connection.send(MailReceiverPacket {
+            character_id: CharacterId(150001),
+            job: 0,
+            base_level: 1,
+            name: "Receiver".to_owned(),
+        });
+
+        let packet = connection.receive::<SendMailPacket>();
+        assert_eq!(packet.receiver_character_id, CharacterId(150001));
+        assert_eq!(packet.title, "Title");
+        assert_eq!(packet.text, "Some text");
+        assert_eq!(packet.zeny, 100);
+
+        connection.send(SendMailResultPacket {
+            result: SendMailResult::Success,
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    let NetworkEvent::MailNotification { has_new_mail } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::MailNotification { .. }))
+    else {
+        unreachable!()
+    };
+    assert!(has_new_mail);
+
+    client.networking_system.open_mailbox().unwrap();
+
+    let NetworkEvent::MailList { mails } = client.wait_for_event(|event| matches!(event, NetworkEvent::MailList { .. })) else {
+        unreachable!()
+    };
+    assert_eq!(mails.len(), 1);
+    assert_eq!(mails[0].title, "Welcome!");
+    assert_eq!(mails[0].sender_name, "Kafra");
+    assert!(mails[0].has_zeny);
+    assert!(!mails[0].has_items);
+
+    client.networking_system.read_mail(mails[0].open_type, mails[0].mail_id).unwrap();
+
+    let NetworkEvent::MailRead { text, zeny, items, .. } = client.wait_for_event(|event| matches!(event, NetworkEvent::MailRead { .. }))
+    else {
+        unreachable!()
+    };
+    assert_eq!(text, "Hello");
+    assert_eq!(zeny, 500);
+    assert!(items.is_empty());
+
+    client.networking_system.check_mail_receiver("Receiver".to_owned()).unwrap();
+
+    let NetworkEvent::MailReceiver { character_id, name } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::MailReceiver { .. }))
+    else {
+        unreachable!()
+    };
+    let character_id = character_id.unwrap();
+
+    client
+        .networking_system
+        .send_mail("Sender", name, character_id, "Title".to_owned(), "Some text".to_owned(), 100)
+        .unwrap();
+
+    client.wait_for_event(|event| matches!(event, NetworkEvent::MailSent));
+
+    server.join();
+}
+
+#[test]
+fn read_mail_20200401() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        connection.receive::<RequestReadMailPacket>();
+        connection.send(version_20200401::ReadMailPacket {
+            open_type: MailOpenType::Character,
+            mail_id: MailId(12),
+            text_length: 6,
+            zeny: 0,
+            item_count: 1,
+            text: "Hello".to_owned(),
+            items: vec![version_20200401::MailItemInformation {
+                amount: 3,
+                item_id: ItemId(501),
+                is_identified: 1,
+                is_broken: 0,
+                refinement_level: 0,
+                cards: [0; 4],
+                location: 0,
+                item_type: 0,
+                sprite_number: 0,
+                bind_on_equip_type: 0,
+                option_data: std::array::from_fn(|_| ItemOptions {
+                    index: 0,
+                    value: 0,
+                    parameter: 0,
+                }),
+            }],
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect_with_packet_version(&mut client, &server, SupportedPacketVersion::_20200401);
+
+    client.networking_system.read_mail(MailOpenType::Character, MailId(12)).unwrap();
+
+    let NetworkEvent::MailRead { text, items, .. } = client.wait_for_event(|event| matches!(event, NetworkEvent::MailRead { .. })) else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(text, "Hello");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].item_id, ItemId(501));
+}
+
+#[test]
+fn skill_effects() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        connection.send(DisplaySkillEffectNoDamagePacket {
+            skill_id: SkillId(34),
+            heal_amount: 10,
+            destination_entity_id: EntityId(2000),
+            source_entity_id: EntityId(1000),
+            result: 1,
+        });
+        connection.send(NotifyGroundSkillPacket {
+            skill_id: SkillId(18),
+            entity_id: EntityId(1000),
+            level: SkillLevel(10),
+            position: TilePosition { x: 150, y: 181 },
+            start_time: ClientTick(1234),
+        });
+        connection.send(VisualEffectPacket {
+            entity_id: EntityId(1000),
+            effect: VisualEffect::JobLevelUp,
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    let NetworkEvent::SkillEffect {
+        skill_id,
+        source_entity_id,
+        destination_entity_id,
+    } = client.wait_for_event(|event| matches!(event, NetworkEvent::SkillEffect { .. }))
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(skill_id, SkillId(34));
+    assert_eq!(source_entity_id, EntityId(1000));
+    assert_eq!(destination_entity_id, EntityId(2000));
+
+    let NetworkEvent::HealEffect { heal_amount, .. } = client.wait_for_event(|event| matches!(event, NetworkEvent::HealEffect { .. }))
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(heal_amount, 10);
+
+    let NetworkEvent::GroundSkillEffect { skill_id, position, .. } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::GroundSkillEffect { .. }))
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(skill_id, SkillId(18));
+    assert_eq!(position, TilePosition { x: 150, y: 181 });
+
+    let NetworkEvent::VisualEffect { effect, entity_id } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::VisualEffect { .. }))
+    else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert!(matches!(effect, VisualEffect::JobLevelUp));
+    assert_eq!(entity_id, EntityId(1000));
+}
+
+#[test]
+fn skill_cast() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        connection.send(UseSkillSuccessPacket2 {
+            source_entity: EntityId(1000),
+            destination_entity: EntityId(2000),
+            position: TilePosition { x: 0, y: 0 },
+            skill_id: SkillId(19),
+            element: 4,
+            delay_time: 1500,
+            disposable: 0,
+            attack_motion: 0,
+        });
+        connection.send(UseSkillSuccessPacket {
+            source_entity: EntityId(1000),
+            destination_entity: EntityId(0),
+            position: TilePosition { x: 150, y: 181 },
+            skill_id: SkillId(89),
+            element: 1,
+            delay_time: 6000,
+            disposable: 0,
+        });
+        connection.send(CastCancelPacket { entity_id: EntityId(1000) });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    let NetworkEvent::SkillCast {
+        destination_entity_id,
+        position,
+        skill_id,
+        element,
+        cast_time,
+        ..
+    } = client.wait_for_event(|event| matches!(event, NetworkEvent::SkillCast { .. }))
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(destination_entity_id, EntityId(2000));
+    assert_eq!(position, None);
+    assert_eq!(skill_id, SkillId(19));
+    assert_eq!(element, 4);
+    assert_eq!(cast_time, 1500);
+
+    let NetworkEvent::SkillCast { position, skill_id, .. } = client.wait_for_event(|event| matches!(event, NetworkEvent::SkillCast { .. }))
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(position, Some(TilePosition { x: 150, y: 181 }));
+    assert_eq!(skill_id, SkillId(89));
+
+    let NetworkEvent::CastCancel { entity_id } = client.wait_for_event(|event| matches!(event, NetworkEvent::CastCancel { .. })) else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(entity_id, EntityId(1000));
+}
+
+#[test]
+fn status_effects() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        connection.send(StatusChangePacket {
+            status_effect_id: StatusEffectId(10),
+            entity_id: EntityId(1000),
+            state: 1,
+            duration_in_milliseconds: 240000,
+            remaining_in_milliseconds: 180000,
+            value: [10, 0, 0],
+        });
+        connection.send(EntityStatusChangePacket {
+            entity_id: EntityId(2000),
+            status_effect_id: StatusEffectId(35),
+            duration_in_milliseconds: 9999,
+            remaining_in_milliseconds: 9999,
+            value: [0, 0, 0],
+        });
+        connection.send(StatusChangeSequencePacket {
+            status_effect_id: StatusEffectId(10),
+            entity_id: EntityId(1000),
+            state: 0,
+        });
+        connection.send(StateChangePacket {
+            entity_id: EntityId(2000),
+            body_state: 2,
+            health_state: 0,
+            effect_state: 0x2,
+            is_pk_mode_on: 0,
+        });
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    let NetworkEvent::AddStatusEffect { entity_id, status_effect } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::AddStatusEffect { .. }))
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(entity_id, EntityId(1000));
+    assert_eq!(status_effect.status_effect_id, StatusEffectId(10));
+    assert_eq!(status_effect.duration, Some(240000));
+    assert_eq!(status_effect.remaining_time, Some(180000));
+    assert_eq!(status_effect.values, [10, 0, 0]);
+
+    let NetworkEvent::AddStatusEffect { entity_id, status_effect } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::AddStatusEffect { .. }))
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(entity_id, EntityId(2000));
+    assert_eq!(status_effect.status_effect_id, StatusEffectId(35));
+    assert_eq!(status_effect.remaining_time, None);
+
+    let NetworkEvent::RemoveStatusEffect {
+        entity_id,
+        status_effect_id,
+    } = client.wait_for_event(|event| matches!(event, NetworkEvent::RemoveStatusEffect { .. }))
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(entity_id, EntityId(1000));
+    assert_eq!(status_effect_id, StatusEffectId(10));
+
+    let NetworkEvent::ChangeEntityState { entity_id, state } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::ChangeEntityState { .. }))
+    else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(entity_id, EntityId(2000));
+    assert_eq!(state.body_state, BodyState::Frozen);
+    assert!(state.hidden);
+}
+
+#[test]
+fn latency() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        let packet = connection.receive::<RequestServerTickPacket>();
+        connection.send(ServerTickPacket {
+            client_tick: packet.client_tick,
+        });
+
+        connection.wait_for_close();
+    });
+
+    let mut client = FakeClient::new();
+    connect(&mut client, &server);
+
+    client.networking_system.request_client_tick().unwrap();
+    client.wait_for_event(|event| matches!(event, NetworkEvent::UpdateClientTick { .. }));
+
+    let latency = client.networking_system.map_server_latency().expect("no round trip was measured");
+
+    drop(client);
+    server.join();
+
+    assert!(latency.samples >= 1);
+    assert!(latency.minimum <= latency.average && latency.average <= latency.maximum);
+}
+
+#[test]
+fn stalled_connection() {
+    let server = FakeServer::spawn(|connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        // Never reply to any keep-alive packet.
+        connection.wait_for_close();
+    });
+
+    let mut client = FakeClient::new();
+    client.networking_system.set_connection_settings(ConnectionSettings {
+        read_timeout: Duration::from_millis(100),
+        max_missed_keepalives: 1,
+        ..Default::default()
+    });
+    connect(&mut client, &server);
+
+    client.networking_system.request_client_tick().unwrap();
+
+    let NetworkEvent::MapServerDisconnected { reason } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::MapServerDisconnected { .. }))
+    else {
+        unreachable!()
+    };
+
+    server.join();
+
+    assert_eq!(reason, DisconnectReason::TimedOut);
+}
+
+#[test]
+fn reconnect() {
+    let map_server = FakeServer::spawn_repeated(2, |index, connection| {
+        accept_map_server_login(connection, SPAWN_POSITION);
+
+        // The first connection is dropped right after logging in.
+        if index == 1 {
+            connection.send(ServerMessagePacket {
+                message: "Welcome back".to_owned(),
+            });
+            connection.wait_for_close();
+        }
+    });
+
+    let map_server_port = map_server.port();
+    let character_server = FakeServer::spawn_repeated(2, move |_, connection| {
+        connection.receive::<CharacterServerLoginPacket>();
+        connection.send_account_id(login_data().account_id);
+        connection.send(CharacterServerLoginSuccessPacket {
+            unknown: 29,
+            normal_slot_count: 9,
+            vip_slot_count: 0,
+            billing_slot_count: 0,
+            producible_slot_count: 9,
+            valid_slot: 9,
+            unused: [0; 20],
+        });
+
+        connection.receive::<RequestCharacterListPacket>();
+        connection.send(RequestCharacterListSuccessPacket {
+            character_information: vec![character_information("Player")],
+        });
+
+        let packet = connection.receive::<SelectCharacterPacket>();
+        assert_eq!(packet.selected_slot, 0);
+
+        connection.send(CharacterSelectionSuccessPacket {
+            character_id: CharacterId(150000),
+            map_name: "prontera.gat".to_owned(),
+            map_server_ip: ServerAddress([127, 0, 0, 1]),
+            map_server_port,
+            unknown: [0; 128],
+        });
+
+        connection.wait_for_close();
+    });
+
+    let mut client = FakeClient::new();
+    client.networking_system.set_connection_settings(ConnectionSettings {
+        reconnect_to_map_server: true,
+        reconnect_delay: Duration::from_millis(10),
+        ..Default::default()
+    });
+
+    client.networking_system.connect_to_character_server(
+        SupportedPacketVersion::_20220406,
+        &login_data(),
+        character_server_information(character_server.port()),
+    );
+    client.wait_for_event(|event| matches!(event, NetworkEvent::CharacterServerConnected { .. }));
+    client.networking_system.request_character_list().unwrap();
+    client.wait_for_event(|event| matches!(event, NetworkEvent::CharacterList { .. }));
+    client.networking_system.select_character(0).unwrap();
+
+    let NetworkEvent::CharacterSelected {
+        login_data: character_server_login_data,
+    } = client.wait_for_event(|event| matches!(event, NetworkEvent::CharacterSelected { .. }))
+    else {
+        unreachable!()
+    };
+
+    client.networking_system.disconnect_from_character_server();
+    client
+        .networking_system
+        .connect_to_map_server(SupportedPacketVersion::_20220406, &login_data(), character_server_login_data);
+    client.wait_for_event(|event| matches!(event, NetworkEvent::UpdateClientTick { .. }));
+
+    let NetworkEvent::MapServerReconnecting { attempt } =
+        client.wait_for_event(|event| matches!(event, NetworkEvent::MapServerReconnecting { .. }))
+    else {
+        unreachable!()
+    };
+    assert_eq!(attempt, 1);
+
+    client.wait_for_event(|event| matches!(event, NetworkEvent::MapServerReconnected));
+
+    let NetworkEvent::ChatMessage { text, .. } = client.wait_for_event(|event| matches!(event, NetworkEvent::ChatMessage { .. })) else {
+        unreachable!()
+    };
+    assert_eq!(text, "Welcome back");
+    assert!(client.networking_system.is_map_server_connected());
+
+    drop(client);
+    character_server.join();
+    map_server.join();
+}
@@ -0,0 +1,70 @@
+mod fake_server;
+
+use korangar_networking::{
+    NetworkEvent, NetworkingSystem, PasswordEncryption, Session, SessionRecordKind, SessionRecorder, SupportedPacketVersion,
+};
+use ragnarok_packets::handler::ServerKind;
+use ragnarok_packets::*;
+
+use self::fake_server::{FakeClient, FakeServer, accept_login_server_login};
+
+fn log_in<Callback>(client: &mut FakeClient<Callback>, server_address: std::net::SocketAddr) -> NetworkEvent
+where
+    Callback: handler::PacketCallback + Send,
+{
+    client.networking_system.connect_to_login_server(
+        SupportedPacketVersion::_20220406,
+        server_address,
+        "username",
+        "password",
+        PasswordEncryption::None,
+    );
+
+    client.wait_for_event(|event| matches!(event, NetworkEvent::LoginServerConnected { .. }))
+}
+
+#[test]
+fn record_and_replay() {
+    let path = std::env::temp_dir().join(format!("korangar-session-{}.bin", std::process::id()));
+
+    let server = FakeServer::spawn(|connection| {
+        accept_login_server_login(connection, 6121);
+    });
+    let server_address = server.address();
+
+    let recorder = SessionRecorder::create(&path).unwrap();
+    let mut client = FakeClient::from_spawned(NetworkingSystem::spawn_with_callback(recorder));
+    let NetworkEvent::LoginServerConnected { character_servers, .. } = log_in(&mut client, server_address) else {
+        unreachable!()
+    };
+    server.join();
+
+    let session = Session::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let kinds: Vec<_> = session
+        .records
+        .iter()
+        .filter(|record| record.server == ServerKind::Login)
+        .map(|record| match &record.kind {
+            SessionRecordKind::Connected => "connected",
+            SessionRecordKind::Incoming(..) => "incoming",
+            SessionRecordKind::Outgoing(..) => "outgoing",
+        })
+        .collect();
+    assert_eq!(kinds, ["connected", "outgoing", "incoming"]);
+
+    // The server is gone, so the events have to come from the recording.
+    let mut replay_client = FakeClient::from_spawned(NetworkingSystem::spawn_replay(session));
+    let NetworkEvent::LoginServerConnected {
+        character_servers: replayed_character_servers,
+        login_data,
+    } = log_in(&mut replay_client, server_address)
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(login_data.account_id, self::fake_server::login_data().account_id);
+    assert_eq!(replayed_character_servers.len(), character_servers.len());
+    assert_eq!(replayed_character_servers[0].server_port, character_servers[0].server_port);
+}
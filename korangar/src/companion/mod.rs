@@ -0,0 +1,155 @@
+use korangar_interface::element::StateElement;
+use korangar_networking::{CompanionType, HomunculusInformation, MercenaryInformation, PetInformation};
+use ragnarok_packets::{EntityId, InventoryIndex, StatType};
+use rust_state::RustState;
+
+/// The pet of the player. A pet without an entity id means that the player
+/// currently has no pet out.
+#[derive(Default, RustState, StateElement)]
+pub struct Pet {
+    #[hidden_element]
+    entity_id: Option<EntityId>,
+    pub information: PetInformation,
+}
+
+impl Pet {
+    pub fn entity_id(&self) -> Option<EntityId> {
+        self.entity_id
+    }
+
+    pub fn set_entity_id(&mut self, entity_id: EntityId) {
+        self.entity_id = Some(entity_id);
+    }
+
+    pub fn update_information(&mut self, information: PetInformation) {
+        self.information = information;
+    }
+
+    pub fn set_hunger(&mut self, hunger: u16) {
+        self.information.hunger = hunger;
+    }
+
+    pub fn set_intimacy(&mut self, intimacy: u16) {
+        self.information.intimacy = intimacy;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// The homunculus of the player. The information is kept while the
+/// homunculus is vaporized.
+#[derive(Default, RustState, StateElement)]
+pub struct Homunculus {
+    #[hidden_element]
+    entity_id: Option<EntityId>,
+    pub information: HomunculusInformation,
+}
+
+impl Homunculus {
+    pub fn entity_id(&self) -> Option<EntityId> {
+        self.entity_id
+    }
+
+    pub fn set_entity_id(&mut self, entity_id: EntityId) {
+        self.entity_id = Some(entity_id);
+    }
+
+    pub fn update_information(&mut self, information: HomunculusInformation) {
+        // The homunculus is no longer on the map once it is vaporized.
+        if information.vaporized {
+            self.entity_id = None;
+        }
+
+        self.information = information;
+    }
+
+    pub fn set_hunger(&mut self, hunger: u16) {
+        self.information.hunger = hunger;
+    }
+
+    pub fn set_intimacy(&mut self, intimacy: u16) {
+        self.information.intimacy = intimacy;
+    }
+}
+
+/// The mercenary of the player. A mercenary without an entity id means that
+/// the player currently has no contract.
+#[derive(Default, RustState, StateElement)]
+pub struct Mercenary {
+    #[hidden_element]
+    entity_id: Option<EntityId>,
+    pub information: MercenaryInformation,
+}
+
+impl Mercenary {
+    pub fn entity_id(&self) -> Option<EntityId> {
+        self.entity_id
+    }
+
+    pub fn update_information(&mut self, entity_id: EntityId, information: MercenaryInformation) {
+        self.entity_id = Some(entity_id);
+        self.information = information;
+    }
+
+    pub fn update_stat(&mut self, stat_type: StatType) {
+        match stat_type {
+            StatType::HealthPoints(value) => self.information.health_points = value,
+            StatType::MaximumHealthPoints(value) => self.information.maximum_health_points = value,
+            StatType::SpellPoints(value) => self.information.spell_points = value,
+            StatType::MaximumSpellPoints(value) => self.information.maximum_spell_points = value,
+            _ => {}
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// All companions of the player.
+#[derive(Default, RustState, StateElement)]
+pub struct Companions {
+    pub pet: Pet,
+    pub homunculus: Homunculus,
+    pub mercenary: Mercenary,
+}
+
+impl Companions {
+    /// The type of companion if the entity belongs to the player.
+    pub fn owned_companion_type(&self, entity_id: EntityId) -> Option<CompanionType> {
+        if self.pet.entity_id == Some(entity_id) {
+            Some(CompanionType::Pet)
+        } else if self.homunculus.entity_id == Some(entity_id) {
+            Some(CompanionType::Homunculus)
+        } else if self.mercenary.entity_id == Some(entity_id) {
+            Some(CompanionType::Mercenary)
+        } else {
+            None
+        }
+    }
+
+    /// Called when the companion is removed from the map for good, for
+    /// example when a pet is returned to its egg or a mercenary is dismissed.
+    pub fn remove(&mut self, entity_id: EntityId) {
+        match self.owned_companion_type(entity_id) {
+            Some(CompanionType::Pet) => self.pet.clear(),
+            Some(CompanionType::Homunculus) => self.homunculus.entity_id = None,
+            Some(CompanionType::Mercenary) => self.mercenary.clear(),
+            None => {}
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// A pet egg in the inventory of the player that can be hatched.
+#[derive(Clone, RustState, StateElement)]
+pub struct PetEgg {
+    #[hidden_element]
+    pub index: InventoryIndex,
+    pub name: String,
+}
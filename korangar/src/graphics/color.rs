@@ -195,6 +195,19 @@ impl Mul<f32> for Color {
     }
 }
 
+impl Mul for Color {
+    type Output = Color;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            red: self.red * rhs.red,
+            blue: self.blue * rhs.blue,
+            green: self.green * rhs.green,
+            alpha: self.alpha * rhs.alpha,
+        }
+    }
+}
+
 impl From<Color> for [f32; 3] {
     fn from(val: Color) -> Self {
         [val.red, val.green, val.blue]
@@ -20,12 +20,13 @@ use winit::window::Window;
 use super::BindlessSupport;
 use super::{
     AntiAliasingResources, Capabilities, DirectionalShadowPartition, FramePacer, FrameStage, GlobalContext, LimitFramerate, Msaa,
-    PARTITION_COUNT, Partition, Prepare, PresentModeInfo, RENDER_TO_TEXTURE_FORMAT, ScreenSpaceAntiAliasing, ShadowResolution, Ssaa,
-    Surface, TextureSamplerType,
+    PARTITION_COUNT, Partition, Prepare, PresentModeInfo, RENDER_TO_TEXTURE_FORMAT, ScreenSpaceAntiAliasing, ScreenshotRequest,
+    ScreenshotScale, ShadowResolution, Ssaa, Surface, TextureSamplerType,
 };
 use crate::graphics::ScreenSize;
 use crate::graphics::instruction::RenderInstruction;
 use crate::graphics::passes::*;
+use crate::graphics::screenshot::ScreenshotTarget;
 use crate::graphics::shader_compiler::ShaderCompiler;
 use crate::loaders::TextureLoader;
 use crate::{NUMBER_OF_POINT_LIGHTS_WITH_SHADOWS, init_tls_rand};
@@ -73,6 +74,7 @@ pub struct GraphicsEngine {
     surface: Option<Surface>,
     thread_pool: ThreadPool,
     shader_compiler: ShaderCompiler,
+    screenshot_request: Option<ScreenshotRequest>,
 }
 
 struct EngineContext {
@@ -98,6 +100,7 @@ struct EngineContext {
     point_shadow_model_drawer: PointShadowModelDrawer,
     point_shadow_indicator_drawer: PointShadowIndicatorDrawer,
     light_culling_dispatcher: LightCullingDispatcher,
+    forward_cloud_drawer: ForwardCloudDrawer,
     forward_entity_drawer: ForwardEntityDrawer,
     forward_indicator_drawer: ForwardIndicatorDrawer,
     forward_model_drawer: ForwardModelDrawer,
@@ -169,6 +172,7 @@ impl GraphicsEngine {
             surface: None,
             thread_pool,
             shader_compiler: descriptor.shader_compiler,
+            screenshot_request: None,
         }
     }
 
@@ -326,6 +330,7 @@ impl GraphicsEngine {
                             &light_culling_pass_context,
                         );
                         let ForwardResources {
+                            forward_cloud_drawer,
                             forward_entity_drawer,
                             forward_indicator_drawer,
                             forward_model_drawer,
@@ -455,6 +460,7 @@ impl GraphicsEngine {
                         point_shadow_indicator_drawer,
                         point_shadow_entity_drawer,
                         light_culling_dispatcher,
+                        forward_cloud_drawer,
                         forward_entity_drawer,
                         forward_indicator_drawer,
                         forward_model_drawer,
@@ -537,6 +543,26 @@ impl GraphicsEngine {
         ssaa
     }
 
+    fn check_screenshot_requirements(&self, mut scale: ScreenshotScale, screen_size: ScreenSize) -> ScreenshotScale {
+        if scale.upscaling_activated() {
+            let max_texture_dimension_2d = self.capabilities.get_max_texture_dimension_2d();
+            let screenshot_size = scale.calculate_size(screen_size);
+
+            if max_texture_dimension_2d < screenshot_size.width as u32 || max_texture_dimension_2d < screenshot_size.height as u32 {
+                #[cfg(feature = "debug")]
+                print_debug!(
+                    "[{}] can't take screenshot at {} resolution because texture would be too large",
+                    "error".red(),
+                    scale.magenta()
+                );
+
+                scale = ScreenshotScale::X1;
+            }
+        }
+
+        scale
+    }
+
     pub fn on_suspended(&mut self) {
         // Android devices are expected to drop their surface view.
         if cfg!(target_os = "android") {
@@ -595,6 +621,7 @@ impl GraphicsEngine {
             engine_context.global_context.update_msaa(&self.device, msaa);
 
             let ForwardResources {
+                forward_cloud_drawer,
                 forward_entity_drawer,
                 forward_indicator_drawer,
                 forward_model_drawer,
@@ -630,6 +657,7 @@ impl GraphicsEngine {
                 &engine_context.post_processing_pass_context,
             );
 
+            engine_context.forward_cloud_drawer = forward_cloud_drawer;
             engine_context.forward_entity_drawer = forward_entity_drawer;
             engine_context.forward_indicator_drawer = forward_indicator_drawer;
             engine_context.forward_model_drawer = forward_model_drawer;
@@ -750,6 +778,11 @@ impl GraphicsEngine {
         self.capabilities.get_supported_msaa().to_vec()
     }
 
+    /// Take a screenshot of the next frame that is rendered.
+    pub fn request_screenshot(&mut self, screenshot_request: ScreenshotRequest) {
+        self.screenshot_request = Some(screenshot_request);
+    }
+
     pub fn get_window_size(&self) -> Vector2<usize> {
         self.surface.as_ref().unwrap().window_size()
     }
@@ -817,6 +850,11 @@ impl GraphicsEngine {
         // Reclaim all staging buffers that the GPU has finished reading from.
         self.staging_belt.recall();
 
+        let screenshot_target = self
+            .screenshot_request
+            .take()
+            .and_then(|screenshot_request| self.prepare_screenshot(screenshot_request));
+
         // Calculate and stage the uploading of GPU data that is needed for the frame.
         let prepare_command_buffer = self.prepare_frame_data(&instruction);
 
@@ -830,7 +868,7 @@ impl GraphicsEngine {
             forward_command_buffer,
             post_processing_command_buffer,
             sdsm_command_buffer,
-        ) = self.draw_frame(&frame, &instruction);
+        ) = self.draw_frame(&frame, &instruction, screenshot_target.as_ref());
 
         // Queue all staging belt writes.
         self.staging_belt.finish();
@@ -848,6 +886,11 @@ impl GraphicsEngine {
             post_processing_command_buffer,
         );
 
+        if let Some(screenshot_target) = screenshot_target {
+            screenshot_target.queue_save();
+            self.restore_forward_size();
+        }
+
         // Schedule the presentation of the frame.
         // We do not call `Windows::pre_present_notify()` here, since it will force a
         // framerate limit under Wayland, even when the user would want to have
@@ -857,6 +900,40 @@ impl GraphicsEngine {
         self.frame_pacer.end_frame_stage(self.cpu_stage, Instant::now());
     }
 
+    /// Creates the target for the screenshot. If the screenshot should have a
+    /// higher resolution than the window, the forward pass is rendered at that
+    /// resolution for this frame.
+    fn prepare_screenshot(&mut self, screenshot_request: ScreenshotRequest) -> Option<ScreenshotTarget> {
+        let screen_size = self.engine_context.as_ref()?.global_context.screen_size;
+        let scale = self.check_screenshot_requirements(screenshot_request.scale, screen_size);
+        let screenshot_size = scale.calculate_size(screen_size);
+
+        let engine_context = self.engine_context.as_mut()?;
+
+        if scale.upscaling_activated() {
+            engine_context.global_context.update_forward_size(&self.device, screenshot_size);
+        }
+
+        Some(ScreenshotTarget::new(
+            &self.device,
+            screenshot_size,
+            screenshot_request.include_interface,
+        ))
+    }
+
+    /// Goes back to the resolution of the forward pass that is used when no
+    /// screenshot is taken.
+    fn restore_forward_size(&mut self) {
+        if let Some(engine_context) = self.engine_context.as_mut() {
+            let global_context = &mut engine_context.global_context;
+            let forward_size = global_context.ssaa.calculate_size(global_context.screen_size);
+
+            if global_context.forward_size != forward_size {
+                global_context.update_forward_size(&self.device, forward_size);
+            }
+        }
+    }
+
     // We currently use unstable sort, since it's the only way to sort without
     // allocating using std. For models, this isn't a problem, since models
     // normally have a proper order and don't "overlay" each other, except in
@@ -967,6 +1044,7 @@ impl GraphicsEngine {
             scope.spawn(|_| {
                 context.interface_rectangle_drawer.prepare(&self.device, instruction);
                 context.water_wave_drawer.prepare(&self.device, instruction);
+                context.forward_cloud_drawer.prepare(&self.device, instruction);
             });
             scope.spawn(|_| {
                 context.point_shadow_entity_drawer.prepare(&self.device, instruction);
@@ -1016,6 +1094,7 @@ impl GraphicsEngine {
         visitor.upload(&mut context.forward_entity_drawer);
         visitor.upload(&mut context.forward_model_drawer);
         visitor.upload(&mut context.water_wave_drawer);
+        visitor.upload(&mut context.forward_cloud_drawer);
         visitor.upload(&mut context.post_processing_rectangle_drawer);
 
         #[cfg(feature = "debug")]
@@ -1035,6 +1114,7 @@ impl GraphicsEngine {
         &mut self,
         frame: &SurfaceTexture,
         instruction: &RenderInstruction,
+        screenshot_target: Option<&ScreenshotTarget>,
     ) -> (
         CommandBuffer,
         CommandBuffer,
@@ -1220,10 +1300,11 @@ impl GraphicsEngine {
                 drop(compute_pass);
 
                 // Forward Pass
-                let mut render_pass =
-                    engine_context
-                        .forward_pass_context
-                        .create_pass(&mut forward_encoder, &engine_context.global_context, None);
+                let mut render_pass = engine_context.forward_pass_context.create_pass(
+                    &mut forward_encoder,
+                    &engine_context.global_context,
+                    instruction.sky.as_ref(),
+                );
 
                 let batch_data = &ModelBatchDrawData {
                     batches: instruction.model_batches,
@@ -1267,6 +1348,8 @@ impl GraphicsEngine {
                     engine_context.water_wave_drawer.draw(&mut render_pass, water_instruction);
                 }
 
+                engine_context.forward_cloud_drawer.draw(&mut render_pass, None);
+
                 // SDSM Pass
                 if instruction.uniforms.sdsm_enabled {
                     let mut compute_pass =
@@ -1321,6 +1404,69 @@ impl GraphicsEngine {
                         .draw(&mut render_pass, blitter_data);
                 }
 
+                // The screenshot is composed before anything else is drawn on top of the
+                // forward texture, since the forward texture is also the color texture if
+                // no anti-aliasing is active.
+                if let Some(screenshot_target) = screenshot_target {
+                    let mut render_pass = engine_context.post_processing_pass_context.create_pass(
+                        &mut post_processing_encoder,
+                        &engine_context.global_context,
+                        screenshot_target.get_texture(),
+                    );
+
+                    let blitter_data = PostProcessingBlitterDrawData {
+                        target_texture_format: RENDER_TO_TEXTURE_FORMAT,
+                        source_texture: engine_context.global_context.get_forward_texture(),
+                        luma_in_alpha: false,
+                        alpha_blending: false,
+                    };
+                    engine_context.post_processing_blitter_drawer.draw(&mut render_pass, blitter_data);
+
+                    let rectangle_data = PostProcessingRectangleDrawData {
+                        layer: PostProcessingRectangleLayer::Bottom,
+                        instructions: instruction.bottom_layer_rectangles,
+                    };
+                    engine_context
+                        .post_processing_rectangle_drawer
+                        .draw(&mut render_pass, rectangle_data);
+
+                    engine_context
+                        .post_processing_effect_drawer
+                        .draw(&mut render_pass, instruction.effects);
+
+                    let rectangle_data = PostProcessingRectangleDrawData {
+                        layer: PostProcessingRectangleLayer::Middle,
+                        instructions: instruction.middle_layer_rectangles,
+                    };
+                    engine_context
+                        .post_processing_rectangle_drawer
+                        .draw(&mut render_pass, rectangle_data);
+
+                    if screenshot_target.include_interface() {
+                        if instruction.show_interface {
+                            let blitter_data = PostProcessingBlitterDrawData {
+                                target_texture_format: RENDER_TO_TEXTURE_FORMAT,
+                                source_texture: &engine_context.global_context.interface_buffer_texture,
+                                luma_in_alpha: false,
+                                alpha_blending: true,
+                            };
+                            engine_context.post_processing_blitter_drawer.draw(&mut render_pass, blitter_data);
+                        }
+
+                        let rectangle_data = PostProcessingRectangleDrawData {
+                            layer: PostProcessingRectangleLayer::Top,
+                            instructions: instruction.top_layer_rectangles,
+                        };
+                        engine_context
+                            .post_processing_rectangle_drawer
+                            .draw(&mut render_pass, rectangle_data);
+                    }
+
+                    drop(render_pass);
+
+                    screenshot_target.copy_to_buffer(&mut post_processing_encoder);
+                }
+
                 let render_pass = match engine_context.global_context.supersampled_color_texture.as_ref() {
                     Some(supersampled_color_texture) => {
                         let mut render_pass = engine_context.post_processing_pass_context.create_pass(
@@ -1483,6 +1629,7 @@ impl UploadVisitor<'_> {
 }
 
 struct ForwardResources {
+    forward_cloud_drawer: ForwardCloudDrawer,
     forward_entity_drawer: ForwardEntityDrawer,
     forward_indicator_drawer: ForwardIndicatorDrawer,
     forward_model_drawer: ForwardModelDrawer,
@@ -1497,6 +1644,14 @@ impl ForwardResources {
         global_context: &GlobalContext,
         forward_pass_context: &ForwardRenderPassContext,
     ) -> Self {
+        let forward_cloud_drawer = ForwardCloudDrawer::new(
+            capabilities,
+            device,
+            queue,
+            shader_compiler,
+            global_context,
+            forward_pass_context,
+        );
         let forward_entity_drawer = ForwardEntityDrawer::new(
             capabilities,
             device,
@@ -1523,6 +1678,7 @@ impl ForwardResources {
         );
 
         Self {
+            forward_cloud_drawer,
             forward_entity_drawer,
             forward_indicator_drawer,
             forward_model_drawer,
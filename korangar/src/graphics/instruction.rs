@@ -40,6 +40,9 @@ pub struct RenderInstruction<'a> {
     pub point_shadow_entities: &'a [EntityInstruction],
     pub effects: &'a [EffectInstruction],
     pub water: Option<WaterInstruction<'a>>,
+    pub sky: Option<SkyInstruction>,
+    pub fog: Option<FogInstruction>,
+    pub clouds: &'a [CloudInstruction],
     pub map_picker_tile_vertex_buffer: Option<&'a Buffer<TileVertex>>,
     pub map_picker_tile_index_buffer: Option<&'a Buffer<u32>>,
     pub font_map_texture: Option<&'a Texture>,
@@ -98,6 +101,26 @@ pub struct WaterInstruction<'a> {
     pub water_opacity: f32,
 }
 
+#[derive(Clone, Copy, Debug)]
+pub struct SkyInstruction {
+    pub color: Color,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FogInstruction {
+    pub color: Color,
+    pub start: f32,
+    pub end: f32,
+    pub density: f32,
+}
+
+#[derive(Clone, Debug)]
+pub struct CloudInstruction {
+    pub position: Point3<f32>,
+    pub size: f32,
+    pub color: Color,
+}
+
 #[derive(Clone, Debug)]
 pub struct DirectionalLightInstruction {
     pub view_projection_matrix: Matrix4<f32>,
@@ -287,6 +310,8 @@ pub struct EntityInstruction {
     pub depth_offset: f32,
     pub curvature: f32,
     pub color: Color,
+    /// How much the sprite is desaturated, from `0.0` to `1.0`.
+    pub greyscale: f32,
     pub mirror: bool,
     pub entity_id: EntityId,
     pub add_to_picker: bool,
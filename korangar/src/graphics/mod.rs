@@ -11,6 +11,7 @@ mod picker_target;
 mod primitives;
 mod projection;
 mod sampler;
+mod screenshot;
 mod settings;
 mod shader_compiler;
 mod surface;
@@ -43,6 +44,7 @@ pub use self::passes::{Lanczos3Drawer, MipMapRenderPassContext};
 pub use self::picker_target::PickerTarget;
 pub use self::primitives::*;
 pub use self::projection::*;
+pub use self::screenshot::ScreenshotRequest;
 pub use self::settings::*;
 pub use self::shader_compiler::ShaderCompiler;
 pub use self::surface::*;
@@ -88,6 +90,7 @@ pub(crate) struct GlobalUniforms {
     indicator_color: [f32; 4],
     ambient_color: [f32; 4],
     camera_position: [f32; 4],
+    fog_color: [f32; 4],
     forward_size: [u32; 2],
     interface_size: [u32; 2],
     pointer_position: [u32; 2],
@@ -97,6 +100,10 @@ pub(crate) struct GlobalUniforms {
     shadow_method: u32,
     shadow_detail: u32,
     use_sdsm: u32,
+    fog_start: f32,
+    fog_end: f32,
+    fog_density: f32,
+    padding: u32,
 }
 
 #[derive(Copy, Clone, Pod, Zeroable)]
@@ -450,6 +457,10 @@ impl Prepare for GlobalContext {
                 )
             });
 
+        let (fog_color, fog_start, fog_end, fog_density) = instructions.fog.as_ref().map_or((Color::BLACK, 0.0, 0.0, 0.0), |fog| {
+            (fog.color, fog.start, fog.end, fog.density)
+        });
+
         let view_projection = instructions.uniforms.projection_matrix * instructions.uniforms.view_matrix;
 
         self.global_uniforms = GlobalUniforms {
@@ -467,6 +478,7 @@ impl Prepare for GlobalContext {
             indicator_color: indicator_color.components_linear(),
             ambient_color: ambient_light_color.components_linear(),
             camera_position: instructions.uniforms.camera_position.into(),
+            fog_color: fog_color.components_linear(),
             forward_size: [self.forward_size.width as u32, self.forward_size.height as u32],
             interface_size: [self.interface_size.width as u32, self.interface_size.height as u32],
             pointer_position: [instructions.picker_position.left as u32, instructions.picker_position.top as u32],
@@ -476,6 +488,10 @@ impl Prepare for GlobalContext {
             shadow_method: instructions.uniforms.shadow_method.into(),
             shadow_detail: instructions.uniforms.shadow_detail.into(),
             use_sdsm: instructions.uniforms.use_sdsm as u32,
+            fog_start,
+            fog_end,
+            fog_density,
+            padding: 0,
         };
 
         self.directional_light_uniforms = DirectionalLightUniforms {
@@ -1034,13 +1050,36 @@ impl GlobalContext {
 
     fn update_screen_size_resources(&mut self, device: &Device, screen_size: ScreenSize) {
         self.screen_size = screen_size;
-        self.forward_size = self.ssaa.calculate_size(self.screen_size);
         self.interface_size = if self.high_quality_interface {
             self.screen_size * 2.0
         } else {
             self.screen_size
         };
 
+        let PickerTextures {
+            picker_buffer_texture,
+            picker_depth_texture,
+        } = Self::create_picker_textures(device, self.screen_size);
+
+        let supersampled_color_texture = Self::create_supersampled_texture(device, self.screen_size, self.ssaa);
+
+        self.picker_buffer_texture = picker_buffer_texture;
+        self.picker_depth_texture = picker_depth_texture;
+        self.supersampled_color_texture = supersampled_color_texture;
+
+        self.interface_buffer_texture = Self::create_interface_texture(device, self.interface_size);
+
+        self.anti_aliasing_resources = Self::create_anti_aliasing_resources(device, self.screen_space_anti_aliasing, self.screen_size);
+
+        self.update_forward_size(device, self.ssaa.calculate_size(self.screen_size));
+    }
+
+    /// Re-creates all resources that depend on the size of the forward pass.
+    /// Besides resizing the window, this is used to render screenshots at a
+    /// higher resolution than the window.
+    fn update_forward_size(&mut self, device: &Device, forward_size: ScreenSize) {
+        self.forward_size = forward_size;
+
         let ForwardTextures {
             forward_color_texture,
             forward_depth_texture,
@@ -1049,31 +1088,17 @@ impl GlobalContext {
             tile_light_count_texture,
         } = Self::create_forward_textures(device, self.forward_size, self.msaa);
 
-        let PickerTextures {
-            picker_buffer_texture,
-            picker_depth_texture,
-        } = Self::create_picker_textures(device, self.screen_size);
-
         let resolved_color_texture = Self::create_resolved_color_texture(device, self.forward_size, self.msaa);
 
-        let supersampled_color_texture = Self::create_supersampled_texture(device, self.screen_size, self.ssaa);
-
         self.forward_color_texture = forward_color_texture;
         self.forward_depth_texture = forward_depth_texture;
         self.forward_accumulation_texture = forward_accumulation_texture;
         self.forward_revealage_texture = forward_revealage_texture;
-        self.picker_buffer_texture = picker_buffer_texture;
-        self.picker_depth_texture = picker_depth_texture;
         self.resolved_color_texture = resolved_color_texture;
-        self.supersampled_color_texture = supersampled_color_texture;
         self.tile_light_count_texture = tile_light_count_texture;
 
-        self.interface_buffer_texture = Self::create_interface_texture(device, self.interface_size);
-
         self.tile_light_indices_buffer = Self::create_tile_light_indices_buffer(device, self.forward_size);
 
-        self.anti_aliasing_resources = Self::create_anti_aliasing_resources(device, self.screen_space_anti_aliasing, self.screen_size);
-
         // We need to update this bind group, because it's content changed, and it isn't
         // re-created each frame.
         self.light_culling_bind_group = Self::create_light_culling_bind_group(
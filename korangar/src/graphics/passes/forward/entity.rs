@@ -37,7 +37,7 @@ pub(crate) struct InstanceData {
     curvature: f32,
     mirror: u32,
     texture_index: i32,
-    padding: u32,
+    greyscale: f32,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -267,7 +267,7 @@ impl Prepare for ForwardEntityDrawer {
                     curvature: instruction.curvature,
                     mirror: instruction.mirror as u32,
                     texture_index,
-                    padding: Default::default(),
+                    greyscale: instruction.greyscale,
                 });
             }
 
@@ -291,7 +291,7 @@ impl Prepare for ForwardEntityDrawer {
                     curvature: instruction.curvature,
                     mirror: instruction.mirror as u32,
                     texture_index: 0,
-                    padding: Default::default(),
+                    greyscale: instruction.greyscale,
                 });
             }
 
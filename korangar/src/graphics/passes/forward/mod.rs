@@ -1,8 +1,10 @@
+mod cloud;
 mod entity;
 mod indicator;
 mod model;
 mod wave;
 
+pub(crate) use cloud::ForwardCloudDrawer;
 pub(crate) use entity::{EntityPassMode, ForwardEntityDrawData, ForwardEntityDrawer};
 pub(crate) use indicator::ForwardIndicatorDrawer;
 pub(crate) use model::{ForwardModelDrawData, ForwardModelDrawer, ModelPassMode};
@@ -13,7 +15,7 @@ use wgpu::{
 };
 
 use super::{BindGroupCount, ColorAttachmentCount, DepthAttachmentCount, RenderPassContext};
-use crate::graphics::GlobalContext;
+use crate::graphics::{GlobalContext, SkyInstruction};
 use crate::loaders::TextureLoader;
 const PASS_NAME: &str = "forward render pass";
 
@@ -27,7 +29,7 @@ pub(crate) struct ForwardRenderPassContext {
 impl RenderPassContext<{ BindGroupCount::Two }, { ColorAttachmentCount::Three }, { DepthAttachmentCount::One }>
     for ForwardRenderPassContext
 {
-    type PassData<'data> = Option<()>;
+    type PassData<'data> = Option<&'data SkyInstruction>;
 
     fn new(_device: &Device, _queue: &Queue, _texture_loader: &TextureLoader, global_context: &GlobalContext) -> Self {
         let color_texture_format = global_context.forward_color_texture.get_format();
@@ -47,8 +49,18 @@ impl RenderPassContext<{ BindGroupCount::Two }, { ColorAttachmentCount::Three },
         &mut self,
         encoder: &'encoder mut CommandEncoder,
         global_context: &GlobalContext,
-        _pass_data: Option<()>,
+        pass_data: Option<&SkyInstruction>,
     ) -> RenderPass<'encoder> {
+        let clear_color = pass_data.map_or(Color::BLACK, |sky| {
+            let [r, g, b, _] = sky.color.components_linear();
+            Color {
+                r: r as f64,
+                g: g as f64,
+                b: b as f64,
+                a: 1.0,
+            }
+        });
+
         let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
             label: Some(PASS_NAME),
             color_attachments: &[
@@ -60,7 +72,7 @@ impl RenderPassContext<{ BindGroupCount::Two }, { ColorAttachmentCount::Three },
                         .as_ref()
                         .map(|texture| texture.get_texture_view()),
                     ops: Operations {
-                        load: LoadOp::Clear(Color::BLACK),
+                        load: LoadOp::Clear(clear_color),
                         store: StoreOp::Store,
                     },
                 }),
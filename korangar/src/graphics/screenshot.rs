@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Local};
+use image::{ImageFormat, RgbaImage};
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
+use wgpu::{
+    BufferDescriptor, BufferUsages, COPY_BYTES_PER_ROW_ALIGNMENT, CommandEncoder, Device, MapMode, Origin3d, TexelCopyBufferInfo,
+    TexelCopyBufferLayout, TexelCopyTextureInfo, TextureAspect,
+};
+
+use super::{AttachmentTexture, AttachmentTextureFactory, AttachmentTextureType, RENDER_TO_TEXTURE_FORMAT, ScreenSize, ScreenshotScale};
+
+/// Folder that the screenshots are saved to.
+const SCREENSHOT_FOLDER: &str = "screenshots";
+
+/// A screenshot that should be taken of the next frame.
+#[derive(Debug, Clone, Copy)]
+pub struct ScreenshotRequest {
+    pub scale: ScreenshotScale,
+    /// Include the interface, the mouse cursor and the overlay in the
+    /// screenshot.
+    pub include_interface: bool,
+}
+
+/// Texture that a frame is composed into, together with the buffer that it is
+/// read back through.
+pub(crate) struct ScreenshotTarget {
+    texture: AttachmentTexture,
+    buffer: Arc<wgpu::Buffer>,
+    padded_bytes_per_row: u32,
+    include_interface: bool,
+    timestamp: DateTime<Local>,
+}
+
+impl ScreenshotTarget {
+    pub(crate) fn new(device: &Device, size: ScreenSize, include_interface: bool) -> Self {
+        let factory = AttachmentTextureFactory::new(device, size, 1, None);
+        let texture = factory.new_attachment(
+            "screenshot",
+            RENDER_TO_TEXTURE_FORMAT,
+            AttachmentTextureType::ScreenshotAttachment,
+        );
+
+        // Every row that is copied into a buffer needs to be aligned to
+        // COPY_BYTES_PER_ROW_ALIGNMENT, so the rows are padded and the padding is
+        // removed again when reading the buffer.
+        let texture_size = texture.get_unpadded_size();
+        let block_size = RENDER_TO_TEXTURE_FORMAT.block_copy_size(None).unwrap();
+        let padded_bytes_per_row = (texture_size.width * block_size).next_multiple_of(COPY_BYTES_PER_ROW_ALIGNMENT);
+
+        let buffer = Arc::new(device.create_buffer(&BufferDescriptor {
+            label: Some("screenshot"),
+            size: padded_bytes_per_row as u64 * texture_size.height as u64,
+            usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        }));
+
+        Self {
+            texture,
+            buffer,
+            padded_bytes_per_row,
+            include_interface,
+            timestamp: Local::now(),
+        }
+    }
+
+    pub(crate) fn get_texture(&self) -> &AttachmentTexture {
+        &self.texture
+    }
+
+    pub(crate) fn include_interface(&self) -> bool {
+        self.include_interface
+    }
+
+    pub(crate) fn copy_to_buffer(&self, encoder: &mut CommandEncoder) {
+        encoder.copy_texture_to_buffer(
+            TexelCopyTextureInfo {
+                texture: self.texture.get_texture(),
+                mip_level: 0,
+                origin: Origin3d::ZERO,
+                aspect: TextureAspect::All,
+            },
+            TexelCopyBufferInfo {
+                buffer: &self.buffer,
+                layout: TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(self.padded_bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            self.texture.get_unpadded_size(),
+        );
+    }
+
+    /// Reads back the buffer once the GPU finished the frame and saves it as a
+    /// PNG. Must only be called after the commands of the frame were submitted.
+    pub(crate) fn queue_save(self) {
+        let texture_size = self.texture.get_unpadded_size();
+        let padded_bytes_per_row = self.padded_bytes_per_row as usize;
+        let file_path = format!("{SCREENSHOT_FOLDER}/{}.png", self.timestamp.format("%Y-%m-%d_%H-%M-%S-%3f"));
+
+        let captured_buffer = Arc::clone(&self.buffer);
+        self.buffer.slice(..).map_async(MapMode::Read, move |result| match result {
+            Ok(_) => {
+                let mapped = captured_buffer.slice(..).get_mapped_range();
+                let bytes_per_row = texture_size.width as usize * 4;
+                let mut pixels = Vec::with_capacity(bytes_per_row * texture_size.height as usize);
+
+                for row in mapped.chunks_exact(padded_bytes_per_row) {
+                    pixels.extend_from_slice(&row[..bytes_per_row]);
+                }
+
+                drop(mapped);
+                captured_buffer.unmap();
+
+                // Encoding the image takes a while, so we don't want to block the render
+                // thread.
+                std::thread::spawn(move || save_screenshot(file_path, texture_size.width, texture_size.height, pixels));
+            }
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!("[{}] failed to map screenshot buffer: {:?}", "error".red(), _error);
+            }
+        });
+    }
+}
+
+fn save_screenshot(file_path: String, width: u32, height: u32, mut pixels: Vec<u8>) {
+    // The alpha channel of the frame has no meaning, so the screenshot is always
+    // opaque.
+    pixels.chunks_exact_mut(4).for_each(|pixel| pixel[3] = u8::MAX);
+
+    let Some(image) = RgbaImage::from_raw(width, height, pixels) else {
+        return;
+    };
+
+    if let Err(_error) = std::fs::create_dir_all(SCREENSHOT_FOLDER) {
+        #[cfg(feature = "debug")]
+        print_debug!(
+            "[{}] failed to create screenshot folder {}: {:?}",
+            "error".red(),
+            SCREENSHOT_FOLDER.magenta(),
+            _error
+        );
+        return;
+    }
+
+    match image.save_with_format(&file_path, ImageFormat::Png) {
+        Ok(()) => {
+            #[cfg(feature = "debug")]
+            print_debug!("saved screenshot to {}", file_path.magenta());
+        }
+        Err(_error) => {
+            #[cfg(feature = "debug")]
+            print_debug!(
+                "[{}] failed to save screenshot to {}: {:?}",
+                "error".red(),
+                file_path.magenta(),
+                _error
+            );
+        }
+    }
+}
@@ -275,6 +275,57 @@ impl Ssaa {
     }
 }
 
+/// Resolution of screenshots, relative to the size of the window.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, StateElement)]
+pub enum ScreenshotScale {
+    X1,
+    X2,
+    X3,
+    X4,
+}
+
+impl DropDownItem<ScreenshotScale> for ScreenshotScale {
+    fn text(&self) -> &str {
+        match self {
+            ScreenshotScale::X1 => "x1",
+            ScreenshotScale::X2 => "x2",
+            ScreenshotScale::X3 => "x3",
+            ScreenshotScale::X4 => "x4",
+        }
+    }
+
+    fn value(&self) -> ScreenshotScale {
+        *self
+    }
+}
+
+impl Display for ScreenshotScale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScreenshotScale::X1 => "x1".fmt(f),
+            ScreenshotScale::X2 => "x2".fmt(f),
+            ScreenshotScale::X3 => "x3".fmt(f),
+            ScreenshotScale::X4 => "x4".fmt(f),
+        }
+    }
+}
+
+impl ScreenshotScale {
+    pub fn calculate_size(self, base_size: ScreenSize) -> ScreenSize {
+        match self {
+            ScreenshotScale::X1 => base_size,
+            ScreenshotScale::X2 => base_size * 2.0,
+            ScreenshotScale::X3 => base_size * 3.0,
+            ScreenshotScale::X4 => base_size * 4.0,
+        }
+    }
+
+    /// The scene needs to be rendered at a higher resolution than the window.
+    pub fn upscaling_activated(self) -> bool {
+        self != ScreenshotScale::X1
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum ScreenSpaceAntiAliasing {
     Off,
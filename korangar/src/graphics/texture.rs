@@ -389,6 +389,7 @@ impl CubeArrayTexture {
 pub(crate) enum AttachmentTextureType {
     PickerAttachment,
     ColorAttachment,
+    ScreenshotAttachment,
     DepthAttachment,
     Depth,
 }
@@ -400,6 +401,9 @@ impl From<AttachmentTextureType> for TextureUsages {
                 TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC
             }
             AttachmentTextureType::ColorAttachment => TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT,
+            AttachmentTextureType::ScreenshotAttachment => {
+                TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC
+            }
             AttachmentTextureType::DepthAttachment => TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT,
             AttachmentTextureType::Depth => TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT,
         }
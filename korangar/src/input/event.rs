@@ -3,9 +3,10 @@ use cgmath::Vector2;
 #[cfg(feature = "debug")]
 use korangar_debug::profiling::FrameMeasurement;
 use korangar_interface::event::{ClickHandler, Event, EventQueue};
-use korangar_networking::{InventoryItem, ShopItem};
+use korangar_networking::{CompanionType, InventoryItem, ShopItem};
 use ragnarok_packets::{
-    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, HotbarSlot, ShopId, SoldItemInformation, StatUpType,
+    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, HomunculusCommand, HotbarSlot, InventoryIndex, MailId,
+    MailOpenType, MercenaryCommand, PartyExperienceShare, PartyId, PartyItemShare, PetCommand, ShopId, SoldItemInformation, StatUpType,
     TilePosition,
 };
 use rust_state::Context;
@@ -13,6 +14,7 @@ use rust_state::Context;
 use crate::interface::resource::{ItemSource, SkillSource};
 use crate::inventory::Skill;
 use crate::loaders::ServiceId;
+use crate::settings::KeyAction;
 use crate::state::ClientState;
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
@@ -73,12 +75,42 @@ pub enum InputEvent {
     ToggleGraphicsSettingsWindow,
     /// Open or close the audio settings window.
     ToggleAudioSettingsWindow,
+    /// Open or close the keybindings window.
+    ToggleKeybindingsWindow,
     /// Open or close the friend list window. Only works while playing.
     ToggleFriendListWindow,
+    /// Open or close the party window. Only works while playing.
+    TogglePartyWindow,
+    /// Open or close the guild window. Only works while playing.
+    ToggleGuildWindow,
+    /// Open or close the pet window. Only works while playing.
+    TogglePetWindow,
+    /// Open or close the homunculus window. Only works while playing.
+    ToggleHomunculusWindow,
+    /// Open or close the mercenary window. Only works while playing.
+    ToggleMercenaryWindow,
+    /// Open or close the cart window. Only works while playing with a cart.
+    ToggleCartWindow,
+    /// Open or close the mailbox. Only works while playing.
+    ToggleMailboxWindow,
     /// Close the most recently opened or clicked closable window.
     CloseTopWindow,
     /// Toggle if the user interface should be rendered or not.
     ToggleShowInterface,
+    /// Save a screenshot of the next frame.
+    TakeScreenshot,
+    /// Wait for the next key combination and bind it to the action.
+    StartKeyRebind {
+        /// Action that should be rebound.
+        action: KeyAction,
+    },
+    /// Stop waiting for a key combination.
+    CancelKeyRebind,
+    /// Remove the key combination from the action that is currently being
+    /// rebound.
+    UnbindKey,
+    /// Restore the default keybindings.
+    ResetKeybindings,
     /// Select a character to start playing.
     SelectCharacter {
         /// Slot that the selected character is in.
@@ -195,6 +227,104 @@ pub enum InputEvent {
         /// Character id of the requestor.
         character_id: CharacterId,
     },
+    /// Create a new party.
+    CreateParty {
+        /// Name of the new party.
+        party_name: String,
+    },
+    /// Invite a character into the party.
+    InviteToParty {
+        /// Name of the character to invite.
+        character_name: String,
+    },
+    /// Reject a pending party invitation.
+    RejectPartyInvite {
+        /// Id of the party.
+        party_id: PartyId,
+    },
+    /// Accept a pending party invitation.
+    AcceptPartyInvite {
+        /// Id of the party.
+        party_id: PartyId,
+    },
+    /// Leave the current party.
+    LeaveParty,
+    /// Remove a member from the party.
+    ExpelPartyMember {
+        /// Account id of the party member.
+        account_id: AccountId,
+        /// Name of the party member.
+        character_name: String,
+    },
+    /// Make another party member the leader of the party.
+    ChangePartyLeader {
+        /// Account id of the new party leader.
+        account_id: AccountId,
+    },
+    /// Change how experience and items are shared in the party.
+    ChangePartyOptions {
+        /// How experience is shared.
+        experience_share: PartyExperienceShare,
+        /// How picked up items are shared.
+        item_pickup_rule: PartyItemShare,
+        /// How dropped items are distributed.
+        item_distribution_rule: PartyItemShare,
+    },
+    /// Reject a pending trade request.
+    RejectTradeRequest,
+    /// Accept a pending trade request.
+    AcceptTradeRequest,
+    /// Offer zeny in the current trade.
+    AddTradeZeny {
+        /// Amount of zeny to offer.
+        amount: u32,
+    },
+    /// Lock the offer of the player in the current trade.
+    LockTrade,
+    /// Complete the current trade once both sides are locked.
+    CommitTrade,
+    /// Cancel the current trade.
+    CancelTrade,
+    /// Close the storage.
+    CloseStorage,
+    /// Remove an item from the vending shop that is being set up.
+    RemoveVendingSetupItem {
+        /// Cart index of the item.
+        index: InventoryIndex,
+    },
+    /// Open a vending shop with the items that were set up.
+    OpenVending,
+    /// Stop setting up a vending shop.
+    CancelVendingSetup,
+    /// Close the vending shop of the player.
+    CloseVending,
+    /// Buy the selected items from the vending shop of another player.
+    BuyVendingItems,
+    /// Stop browsing the vending shop of another player.
+    CloseVendingShop,
+    /// Close the mailbox.
+    CloseMailbox,
+    /// Request the list of mails again.
+    RefreshMailbox,
+    /// Read a mail from the mailbox.
+    ReadMail {
+        /// Type of the mailbox the mail is in.
+        open_type: MailOpenType,
+        /// Id of the mail.
+        mail_id: MailId,
+    },
+    /// Retrieve the zeny attached to the mail that is being read.
+    TakeMailZeny,
+    /// Retrieve the items attached to the mail that is being read.
+    TakeMailItems,
+    /// Delete the mail that is being read.
+    DeleteMail,
+    /// Start writing a new mail.
+    WriteMail,
+    /// Send the mail that is being written.
+    SendMail,
+    /// Discard the mail that is being written.
+    CancelMail,
     /// Buy items from a shop.
     BuyItems {
         /// Items to buy.
@@ -216,6 +346,19 @@ pub enum InputEvent {
     },
     /// Up a stat.
     StatUp { stat_type: StatUpType },
+    /// Hatch a pet egg from the inventory.
+    SelectPetEgg {
+        /// Inventory index of the egg.
+        index: InventoryIndex,
+    },
+    /// Use the pet menu.
+    PetCommand { command: PetCommand },
+    /// Use the homunculus menu.
+    HomunculusCommand { command: HomunculusCommand },
+    /// Use the mercenary menu.
+    MercenaryCommand { command: MercenaryCommand },
+    /// Make the homunculus or mercenary of the player return to the player.
+    CallBackCompanion { companion_type: CompanionType },
     /// Reload the language from disk.
     #[cfg(feature = "debug")]
     ReloadLanguage,
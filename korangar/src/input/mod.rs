@@ -2,11 +2,12 @@ mod event;
 mod key;
 mod mode;
 
+use std::collections::HashSet;
 use std::mem::variant_count;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use ragnarok_packets::{ClientTick, HotbarSlot};
+use ragnarok_packets::ClientTick;
 use winit::dpi::PhysicalPosition;
 use winit::event::{ElementState, MouseButton, MouseScrollDelta};
 use winit::keyboard::KeyCode;
@@ -15,11 +16,26 @@ pub use self::event::InputEvent;
 pub use self::key::Key;
 pub use self::mode::{Grabbed, MouseInputMode, MouseModeExt};
 use crate::graphics::{PickerTarget, ScreenPosition, ScreenSize};
+use crate::settings::{KeyAction, KeyCombination, KeyModifiers, Keybindings};
 
 const MOUSE_SCOLL_MULTIPLIER: f32 = 30.0;
 const KEY_COUNT: usize = variant_count::<KeyCode>();
 const DOUBLE_CLICK_TIME_MS: u32 = 250;
 
+fn is_modifier(key_code: KeyCode) -> bool {
+    matches!(
+        key_code,
+        KeyCode::ControlLeft
+            | KeyCode::ControlRight
+            | KeyCode::AltLeft
+            | KeyCode::AltRight
+            | KeyCode::ShiftLeft
+            | KeyCode::ShiftRight
+            | KeyCode::SuperLeft
+            | KeyCode::SuperRight
+    )
+}
+
 #[derive(Debug, Clone, Copy)]
 struct PreviousMouseButton {
     button: MouseButton,
@@ -49,6 +65,10 @@ pub struct InputSystem {
     left_mouse_button: Key,
     right_mouse_button: Key,
     keys: [Key; KEY_COUNT],
+    last_pressed_key: Option<KeyCode>,
+    /// Bound actions whose key combination was pressed and whose key is not
+    /// released yet. Only these actions get a release event.
+    held_actions: HashSet<KeyAction>,
     input_buffer: Vec<char>,
     picker_value: Arc<AtomicU64>,
     previous_mouse_button: Option<PreviousMouseButton>,
@@ -67,6 +87,8 @@ impl InputSystem {
         let left_mouse_button = Key::default();
         let right_mouse_button = Key::default();
         let keys = [Key::default(); KEY_COUNT];
+        let last_pressed_key = None;
+        let held_actions = HashSet::new();
 
         let input_buffer = Vec::new();
         let previous_mouse_button = None;
@@ -81,6 +103,8 @@ impl InputSystem {
             left_mouse_button,
             right_mouse_button,
             keys,
+            last_pressed_key,
+            held_actions,
             input_buffer,
             picker_value,
             previous_mouse_button,
@@ -91,6 +115,7 @@ impl InputSystem {
         self.left_mouse_button.reset();
         self.right_mouse_button.reset();
         self.keys.iter_mut().for_each(|key| key.reset());
+        self.held_actions.clear();
     }
 
     pub fn update_mouse_position(&mut self, position: PhysicalPosition<f64>) {
@@ -120,6 +145,10 @@ impl InputSystem {
     pub fn update_keyboard(&mut self, key_code: KeyCode, state: ElementState) {
         let pressed = matches!(state, ElementState::Pressed);
         self.keys[key_code as usize].set_down(pressed);
+
+        if pressed && !is_modifier(key_code) {
+            self.last_pressed_key = Some(key_code);
+        }
     }
 
     pub fn buffer_character(&mut self, character: char) {
@@ -196,87 +225,52 @@ impl InputSystem {
         &self.keys[key_code as usize]
     }
 
+    fn modifiers(&self) -> KeyModifiers {
+        let down = |left, right| self.get_key(left).down() || self.get_key(right).down();
+
+        KeyModifiers {
+            control: down(KeyCode::ControlLeft, KeyCode::ControlRight),
+            alt: down(KeyCode::AltLeft, KeyCode::AltRight),
+            shift: down(KeyCode::ShiftLeft, KeyCode::ShiftRight),
+        }
+    }
+
+    /// Get the key combination that was pressed this frame, if any. Pressing
+    /// only modifier keys does not count as a key combination.
+    pub fn pressed_key_combination(&self) -> Option<KeyCombination> {
+        self.last_pressed_key
+            .filter(|key_code| self.get_key(*key_code).pressed())
+            .map(|key_code| KeyCombination::new(key_code, self.modifiers()))
+    }
+
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
     pub fn handle_keyboard_input(
         &mut self,
         events: &mut Vec<InputEvent>,
+        keybindings: &Keybindings,
         #[cfg(feature = "debug")] process_mouse: bool,
         #[cfg(feature = "debug")] use_debug_camera: bool,
     ) {
-        let alt_down = self.get_key(KeyCode::AltLeft).down();
-        let control_down = self.get_key(KeyCode::ControlLeft).down();
-
-        if self.get_key(KeyCode::Escape).pressed() {
-            events.push(InputEvent::ToggleMenuWindow);
-        }
+        let modifiers = self.modifiers();
 
-        if alt_down && self.get_key(KeyCode::KeyE).pressed() {
-            events.push(InputEvent::ToggleInventoryWindow);
-        }
-
-        if alt_down && self.get_key(KeyCode::KeyS).pressed() {
-            events.push(InputEvent::ToggleSkillTreeWindow);
-        }
-
-        if alt_down && self.get_key(KeyCode::KeyA).pressed() {
-            events.push(InputEvent::ToggleStatsWindow);
-        }
-
-        if alt_down && self.get_key(KeyCode::KeyZ).pressed() {
-            events.push(InputEvent::ToggleFriendListWindow);
-        }
-
-        if alt_down && self.get_key(KeyCode::KeyQ).pressed() {
-            events.push(InputEvent::ToggleEquipmentWindow);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyS).pressed() {
-            events.push(InputEvent::ToggleGameSettingsWindow);
-        }
+        for (action, combination) in keybindings.bound_actions() {
+            let key = self.get_key(combination.key);
 
-        if control_down && self.get_key(KeyCode::KeyI).pressed() {
-            events.push(InputEvent::ToggleInterfaceSettingsWindow);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyG).pressed() {
-            events.push(InputEvent::ToggleGraphicsSettingsWindow);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyA).pressed() {
-            events.push(InputEvent::ToggleAudioSettingsWindow);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyH).pressed() {
-            events.push(InputEvent::ToggleShowInterface);
-        }
-
-        if control_down && self.get_key(KeyCode::KeyQ).pressed() {
-            events.push(InputEvent::CloseTopWindow);
-        }
-
-        if self.get_key(KeyCode::KeyJ).pressed() {
-            events.push(InputEvent::CastSkill { slot: HotbarSlot(0) });
-        }
-
-        if self.get_key(KeyCode::KeyJ).released() {
-            events.push(InputEvent::StopSkill { slot: HotbarSlot(0) });
-        }
-
-        if self.get_key(KeyCode::KeyL).pressed() {
-            events.push(InputEvent::CastSkill { slot: HotbarSlot(1) });
-        }
-
-        if self.get_key(KeyCode::KeyL).released() {
-            events.push(InputEvent::StopSkill { slot: HotbarSlot(1) });
-        }
+            if key.pressed() && combination.modifiers == modifiers {
+                events.push(action.pressed_event());
+                self.held_actions.insert(action);
+            }
 
-        if self.get_key(KeyCode::KeyU).pressed() {
-            events.push(InputEvent::CastSkill { slot: HotbarSlot(2) });
+            if key.released()
+                && self.held_actions.remove(&action)
+                && let Some(event) = action.released_event()
+            {
+                events.push(event);
+            }
         }
 
-        if self.get_key(KeyCode::KeyU).released() {
-            events.push(InputEvent::StopSkill { slot: HotbarSlot(2) });
-        }
+        #[cfg(feature = "debug")]
+        let control_down = modifiers.control;
 
         #[cfg(feature = "debug")]
         if control_down && self.get_key(KeyCode::KeyM).pressed() {
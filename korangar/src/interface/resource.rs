@@ -4,6 +4,15 @@ use ragnarok_packets::{EquipPosition, HotbarSlot};
 pub enum ItemSource {
     Inventory,
     Equipment { position: EquipPosition },
+    Trade,
+    TradePartner,
+    Storage,
+    Cart,
+    VendingSetup,
+    /// Items attached to the mail that is being read.
+    Mail,
+    /// Items attached to the mail that is being written.
+    MailAttachment,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
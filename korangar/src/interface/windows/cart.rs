@@ -0,0 +1,81 @@
+use korangar_components::item_box;
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Path, VecIndexExt};
+
+use crate::ItemSource;
+use crate::interface::windows::WindowClass;
+use crate::inventory::{Cart, CartPathExt, InventoryPathExt};
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+pub struct CartWindow<P> {
+    cart_path: P,
+}
+
+impl<P> CartWindow<P> {
+    pub fn new(cart_path: P) -> Self {
+        Self { cart_path }
+    }
+}
+
+impl<P> CustomWindow<ClientState> for CartWindow<P>
+where
+    P: Path<ClientState, Cart>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Cart)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        // TODO: Probably this should be more dynamic
+        const CART_ROWS: usize = 10;
+        const CART_COLUMNS: usize = 10;
+
+        let cart_details = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let cart = self.cart_path.follow(state).unwrap();
+            format!(
+                "{}/{}    {}/{}",
+                cart.item_count,
+                cart.maximum_item_count,
+                cart.weight / 10,
+                cart.maximum_weight / 10
+            )
+        });
+
+        window! {
+            title: client_state().localization().cart_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                fragment! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, CART_ROWS, _>(|row| {
+                        split! {
+                            gaps: theme().window().gaps(),
+                            children: std::array::from_fn::<_, CART_COLUMNS, _>(|column| {
+                                let path = self.cart_path.inventory().items().index(row * CART_COLUMNS + column);
+
+                                item_box! {
+                                    item_path: path,
+                                    source: ItemSource::Cart,
+                                }
+                            }),
+                        }
+                    }),
+                },
+                text! {
+                    text: cart_details,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+            ),
+        }
+    }
+}
@@ -112,6 +112,34 @@ where
                     text: client_state().localization().friend_list_button_text(),
                     event: InputEvent::ToggleFriendListWindow,
                 },
+                button! {
+                    text: client_state().localization().party_button_text(),
+                    event: InputEvent::TogglePartyWindow,
+                },
+                button! {
+                    text: client_state().localization().guild_button_text(),
+                    event: InputEvent::ToggleGuildWindow,
+                },
+                button! {
+                    text: client_state().localization().pet_button_text(),
+                    event: InputEvent::TogglePetWindow,
+                },
+                button! {
+                    text: client_state().localization().homunculus_button_text(),
+                    event: InputEvent::ToggleHomunculusWindow,
+                },
+                button! {
+                    text: client_state().localization().mercenary_button_text(),
+                    event: InputEvent::ToggleMercenaryWindow,
+                },
+                button! {
+                    text: client_state().localization().cart_button_text(),
+                    event: InputEvent::ToggleCartWindow,
+                },
+                button! {
+                    text: client_state().localization().mail_button_text(),
+                    event: InputEvent::ToggleMailboxWindow,
+                },
                 button! {
                     text: client_state().localization().menu_button_text(),
                     event: InputEvent::ToggleMenuWindow,
@@ -69,6 +69,8 @@ where
                     MessageColor::Error => Color::monochrome_u8(255),
                     // TODO: Make the color right.
                     MessageColor::Information => Color::monochrome_u8(255),
+                    MessageColor::Party => Color::rgb_u8(255, 200, 200),
+                    MessageColor::Guild => Color::rgb_u8(180, 255, 180),
                 };
 
                 let (size, _) = resolver.get_text_dimensions(
@@ -122,6 +124,8 @@ where
                     MessageColor::Error => Color::monochrome_u8(255),
                     // TODO: Make the color right.
                     MessageColor::Information => Color::monochrome_u8(255),
+                    MessageColor::Party => Color::rgb_u8(255, 200, 200),
+                    MessageColor::Guild => Color::rgb_u8(180, 255, 180),
                 };
 
                 if offset != 0.0 {
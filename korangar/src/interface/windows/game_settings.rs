@@ -40,6 +40,16 @@ where
                     state: self.game_settings_path.auto_attack(),
                     event: Toggle(self.game_settings_path.auto_attack()),
                 },
+                state_button! {
+                    text: client_state().localization().reconnect_button_text(),
+                    state: self.game_settings_path.reconnect_to_map_server(),
+                    event: Toggle(self.game_settings_path.reconnect_to_map_server()),
+                },
+                state_button! {
+                    text: client_state().localization().show_latency_button_text(),
+                    state: self.game_settings_path.show_latency(),
+                    event: Toggle(self.game_settings_path.show_latency()),
+                },
             ),
         }
     }
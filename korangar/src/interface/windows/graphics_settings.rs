@@ -165,6 +165,23 @@ where
                 state: self.settings_path.high_quality_interface(),
                 event: Toggle(self.settings_path.high_quality_interface()),
             },
+            split! {
+                children: (
+                    text! {
+                        text: "Screenshot resolution",
+                        overflow_behavior: OverflowBehavior::Shrink,
+                    },
+                    drop_down! {
+                        selected: self.settings_path.screenshot_scale(),
+                        options: self.capabilities_path.screenshot_scale_options(),
+                    }
+                )
+            },
+            state_button! {
+                text: "Interface in screenshots",
+                state: self.settings_path.screenshot_interface(),
+                event: Toggle(self.settings_path.screenshot_interface()),
+            },
         );
 
         window! {
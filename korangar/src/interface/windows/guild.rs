@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{Element, ElementBox};
+use korangar_interface::layout::{Resolver, WindowLayout};
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Context, ManuallyAssertExt, Path, VecIndexExt};
+
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::social::{Guild, GuildMember, GuildPathExt};
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+fn guild_details(guild: &Guild) -> String {
+    let mut details = format!(
+        "Lv. {} - {}/{} members - average Lv. {} - EXP {}/{}",
+        guild.level, guild.member_count, guild.maximum_member_count, guild.average_level, guild.experience, guild.next_level_experience
+    );
+
+    if !guild.managed_territory.is_empty() {
+        details.push_str(&format!(" - {}", guild.managed_territory));
+    }
+
+    details
+}
+
+fn member_details(member: &GuildMember) -> String {
+    let name = match member.name.is_empty() {
+        true => "...",
+        false => member.name.as_str(),
+    };
+
+    let mut details = format!("{name} - {} - Lv. {}", member.position_name, member.base_level);
+
+    if !member.online {
+        details.push_str(" (offline)");
+    }
+
+    details
+}
+
+fn position_details(guild: &Guild) -> String {
+    guild
+        .positions
+        .iter()
+        .filter(|position| !position.name.is_empty())
+        .map(|position| format!("{} ({}%)", position.name, position.tax_rate))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+struct GuildMemberList<A> {
+    members_path: A,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A> GuildMemberList<A> {
+    fn new(members_path: A) -> Self {
+        Self {
+            members_path,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A> Element<ClientState> for GuildMemberList<A>
+where
+    A: Path<ClientState, Vec<GuildMember>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let members = state.get(&self.members_path);
+
+        match members.len().cmp(&self.elements.len()) {
+            Ordering::Less => {
+                self.elements.truncate(members.len());
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                for index in self.elements.len()..members.len() {
+                    let member_path = self.members_path.index(index).manually_asserted();
+
+                    let details = ComputedSelector::new_default(move |state: &ClientState| {
+                        member_path.follow(state).map(member_details).unwrap_or_default()
+                    });
+
+                    self.elements.push(ErasedElement::new(text! {
+                        text: details,
+                        overflow_behavior: OverflowBehavior::Shrink,
+                    }));
+                }
+            }
+        }
+
+        self.elements.iter_mut().zip(members.iter()).for_each(|(element, member)| {
+            element.create_layout_info(state, store.child_store(member.character_id.0 as u64), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let members = state.get(&self.members_path);
+
+        self.elements.iter().zip(members.iter()).for_each(|(element, member)| {
+            element.lay_out(state, store.child_store(member.character_id.0 as u64), &(), layout);
+        });
+    }
+}
+
+pub struct GuildWindow<A> {
+    guild_path: A,
+}
+
+impl<A> GuildWindow<A> {
+    pub fn new(guild_path: A) -> Self {
+        Self { guild_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for GuildWindow<A>
+where
+    A: Path<ClientState, Guild>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Guild)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        let details = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            guild_details(self.guild_path.follow(state).unwrap())
+        });
+
+        let positions = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            position_details(self.guild_path.follow(state).unwrap())
+        });
+
+        let allies = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            self.guild_path.follow(state).unwrap().allies.join(", ")
+        });
+
+        let antagonists = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            self.guild_path.follow(state).unwrap().antagonists.join(", ")
+        });
+
+        window! {
+            title: client_state().localization().guild_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: self.guild_path.name(),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                text! {
+                    text: details,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                collapsable! {
+                    text: client_state().localization().guild_notice_text(),
+                    initially_expanded: true,
+                    children: (
+                        text! {
+                            text: self.guild_path.notice_subject(),
+                            overflow_behavior: OverflowBehavior::Shrink,
+                        },
+                        text! {
+                            text: self.guild_path.notice(),
+                        },
+                    ),
+                },
+                collapsable! {
+                    text: client_state().localization().guild_members_text(),
+                    initially_expanded: true,
+                    children: (
+                        GuildMemberList::new(self.guild_path.members()),
+                    ),
+                },
+                collapsable! {
+                    text: client_state().localization().guild_positions_text(),
+                    children: (
+                        text! {
+                            text: positions,
+                        },
+                    ),
+                },
+                collapsable! {
+                    text: client_state().localization().guild_alliances_text(),
+                    children: (
+                        text! {
+                            text: allies,
+                        },
+                    ),
+                },
+                collapsable! {
+                    text: client_state().localization().guild_antagonists_text(),
+                    children: (
+                        text! {
+                            text: antagonists,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
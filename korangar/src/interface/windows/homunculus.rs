@@ -0,0 +1,116 @@
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::{CompanionType, HomunculusInformationPathExt};
+use ragnarok_packets::HomunculusCommand;
+use rust_state::{Path, Selector};
+
+use crate::companion::{Homunculus, HomunculusPathExt};
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+pub struct HomunculusWindow<A> {
+    homunculus_path: A,
+}
+
+impl<A> HomunculusWindow<A> {
+    pub fn new(homunculus_path: A) -> Self {
+        Self { homunculus_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for HomunculusWindow<A>
+where
+    A: Path<ClientState, Homunculus>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Homunculus)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        fn no_homunculus<A>(homunculus_path: A) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, Homunculus>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                homunculus_path.follow(state).unwrap().entity_id().is_none()
+            })
+        }
+
+        macro_rules! information_text {
+            ($format:literal, $($field:ident),+) => {
+                ComputedSelector::new_default(move |state: &ClientState| {
+                    // SAFETY:
+                    //
+                    // Unwrap is safe here because of the bounds.
+                    let information = &self.homunculus_path.follow(state).unwrap().information;
+                    format!($format, $(information.$field),+)
+                })
+            };
+        }
+
+        macro_rules! value_row {
+            ($text_name:ident, $value:expr) => {
+                split! {
+                    children: (
+                        text! {
+                            text: client_state().localization().$text_name(),
+                            overflow_behavior: OverflowBehavior::Shrink,
+                        },
+                        text! {
+                            text: $value,
+                            horizontal_alignment: HorizontalAlignment::Right { offset: 5.0, border: 5.0 },
+                            overflow_behavior: OverflowBehavior::Shrink,
+                        },
+                    ),
+                }
+            };
+        }
+
+        window! {
+            title: client_state().localization().homunculus_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: self.homunculus_path.information().name(),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                value_row!(companion_level_text, PartialEqDisplaySelector::new(self.homunculus_path.information().level())),
+                value_row!(health_points_text, information_text!("{}/{}", health_points, maximum_health_points)),
+                value_row!(spell_points_text, information_text!("{}/{}", spell_points, maximum_spell_points)),
+                value_row!(experience_text, information_text!("{}/{}", experience, next_level_experience)),
+                value_row!(hunger_text, information_text!("{}/100", hunger)),
+                value_row!(intimacy_text, information_text!("{}/1000", intimacy)),
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().feed_button_text(),
+                            event: InputEvent::HomunculusCommand { command: HomunculusCommand::Feed },
+                            disabled: no_homunculus(self.homunculus_path),
+                        },
+                        button! {
+                            text: client_state().localization().call_back_button_text(),
+                            event: InputEvent::CallBackCompanion { companion_type: CompanionType::Homunculus },
+                            disabled: no_homunculus(self.homunculus_path),
+                        },
+                    ),
+                },
+                button! {
+                    text: client_state().localization().vaporize_button_text(),
+                    event: InputEvent::HomunculusCommand { command: HomunculusCommand::Delete },
+                    disabled: no_homunculus(self.homunculus_path),
+                },
+            ),
+        }
+    }
+}
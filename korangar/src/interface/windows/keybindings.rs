@@ -0,0 +1,240 @@
+use korangar_interface::element::{Element, StateElement};
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Path, RustState, Selector};
+
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::inventory::HOTBAR_SLOT_COUNT;
+use crate::loaders::OverflowBehavior;
+use crate::settings::{KeyAction, KeyCombination, Keybindings};
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+fn action_name(state: &ClientState, action: KeyAction) -> String {
+    let localization = client_state().localization();
+
+    let name = match action {
+        KeyAction::ToggleMenuWindow => localization.menu_button_text().follow(state),
+        KeyAction::ToggleInventoryWindow => localization.inventory_button_text().follow(state),
+        KeyAction::ToggleEquipmentWindow => localization.equipment_button_text().follow(state),
+        KeyAction::ToggleSkillTreeWindow => localization.skill_tree_button_text().follow(state),
+        KeyAction::ToggleStatsWindow => localization.stats_button_text().follow(state),
+        KeyAction::ToggleFriendListWindow => localization.friend_list_button_text().follow(state),
+        KeyAction::TogglePartyWindow => localization.party_button_text().follow(state),
+        KeyAction::ToggleGuildWindow => localization.guild_button_text().follow(state),
+        KeyAction::TogglePetWindow => localization.pet_button_text().follow(state),
+        KeyAction::ToggleHomunculusWindow => localization.homunculus_button_text().follow(state),
+        KeyAction::ToggleMercenaryWindow => localization.mercenary_button_text().follow(state),
+        KeyAction::ToggleCartWindow => localization.cart_button_text().follow(state),
+        KeyAction::ToggleMailboxWindow => localization.mail_button_text().follow(state),
+        KeyAction::ToggleGameSettingsWindow => localization.game_settings_button_text().follow(state),
+        KeyAction::ToggleInterfaceSettingsWindow => localization.interface_settings_button_text().follow(state),
+        KeyAction::ToggleGraphicsSettingsWindow => localization.graphics_settings_button_text().follow(state),
+        KeyAction::ToggleAudioSettingsWindow => localization.audio_settings_button_text().follow(state),
+        KeyAction::ToggleKeybindingsWindow => localization.keybindings_button_text().follow(state),
+        KeyAction::ToggleShowInterface => localization.show_interface_text().follow(state),
+        KeyAction::TakeScreenshot => localization.take_screenshot_text().follow(state),
+        KeyAction::CloseTopWindow => localization.close_window_text().follow(state),
+        KeyAction::UseHotbarSlot(slot) => {
+            let text = localization
+                .hotbar_slot_text()
+                .follow(state)
+                .map(String::as_str)
+                .unwrap_or_default();
+            return format!("{text} {}", slot + 1);
+        }
+    };
+
+    name.cloned().unwrap_or_default()
+}
+
+/// Internal state of the keybindings window.
+#[derive(Default, RustState, StateElement)]
+pub struct KeybindingsWindowState {
+    /// Action that is waiting for a new key combination.
+    #[hidden_element]
+    rebinding: Option<KeyAction>,
+    /// Key combination that could not be bound, together with the action that
+    /// is already using it.
+    #[hidden_element]
+    conflict: Option<(KeyCombination, KeyAction)>,
+}
+
+impl KeybindingsWindowState {
+    pub fn get_rebinding(&self) -> Option<KeyAction> {
+        self.rebinding
+    }
+
+    pub fn start_rebinding(&mut self, action: KeyAction) {
+        self.rebinding = Some(action);
+        self.conflict = None;
+    }
+
+    pub fn stop_rebinding(&mut self) {
+        self.rebinding = None;
+        self.conflict = None;
+    }
+
+    pub fn set_conflict(&mut self, combination: KeyCombination, action: KeyAction) {
+        self.conflict = Some((combination, action));
+    }
+}
+
+pub struct KeybindingsWindow<A, B> {
+    window_state_path: A,
+    keybindings_path: B,
+}
+
+impl<A, B> KeybindingsWindow<A, B> {
+    pub fn new(window_state_path: A, keybindings_path: B) -> Self {
+        Self {
+            window_state_path,
+            keybindings_path,
+        }
+    }
+}
+
+impl<A, B> KeybindingsWindow<A, B>
+where
+    A: Path<ClientState, KeybindingsWindowState>,
+    B: Path<ClientState, Keybindings>,
+{
+    fn keybinding_row(&self, action: KeyAction) -> impl Element<ClientState> + use<A, B> {
+        use korangar_interface::prelude::*;
+
+        let window_state_path = self.window_state_path;
+        let keybindings_path = self.keybindings_path;
+
+        let name = ComputedSelector::new_default(move |state: &ClientState| action_name(state, action));
+
+        let binding = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let window_state = window_state_path.follow(state).unwrap();
+
+            if window_state.rebinding == Some(action) {
+                return "...".to_owned();
+            }
+
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            match keybindings_path.follow(state).unwrap().get(action) {
+                Some(combination) => combination.to_string(),
+                None => client_state()
+                    .localization()
+                    .unbound_key_text()
+                    .follow(state)
+                    .cloned()
+                    .unwrap_or_default(),
+            }
+        });
+
+        split! {
+            gaps: theme().window().gaps(),
+            children: (
+                text! {
+                    text: name,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                button! {
+                    text: binding,
+                    event: InputEvent::StartKeyRebind { action },
+                },
+            ),
+        }
+    }
+}
+
+impl<A, B> CustomWindow<ClientState> for KeybindingsWindow<A, B>
+where
+    A: Path<ClientState, KeybindingsWindowState>,
+    B: Path<ClientState, Keybindings>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Keybindings)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        let status = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let window_state = self.window_state_path.follow(state).unwrap();
+            let localization = client_state().localization();
+
+            match (window_state.rebinding, window_state.conflict) {
+                (_, Some((combination, action))) => {
+                    let text = localization
+                        .keybinding_conflict_text()
+                        .follow(state)
+                        .map(String::as_str)
+                        .unwrap_or_default();
+                    format!("{combination} {text} {}", action_name(state, action))
+                }
+                (Some(_), None) => localization.press_key_text().follow(state).cloned().unwrap_or_default(),
+                (None, None) => String::new(),
+            }
+        });
+
+        fn is_not_rebinding<A>(window_state_path: A) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, KeybindingsWindowState>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                window_state_path.follow(state).unwrap().rebinding.is_none()
+            })
+        }
+
+        window! {
+            title: client_state().localization().keybindings_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                collapsable! {
+                    text: client_state().localization().keybinding_shortcuts_text(),
+                    initially_expanded: true,
+                    children: KeyAction::SHORTCUTS.map(|action| self.keybinding_row(action)),
+                },
+                collapsable! {
+                    text: client_state().localization().hotbar_window_title(),
+                    initially_expanded: true,
+                    children: std::array::from_fn::<_, HOTBAR_SLOT_COUNT, _>(|slot| {
+                        self.keybinding_row(KeyAction::UseHotbarSlot(slot as u16))
+                    }),
+                },
+                text! {
+                    text: status,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().unbind_key_button_text(),
+                            event: InputEvent::UnbindKey,
+                            disabled: is_not_rebinding(self.window_state_path),
+                        },
+                        button! {
+                            text: client_state().localization().cancel_rebind_button_text(),
+                            event: InputEvent::CancelKeyRebind,
+                            disabled: is_not_rebinding(self.window_state_path),
+                        },
+                        button! {
+                            text: client_state().localization().reset_keybindings_button_text(),
+                            event: InputEvent::ResetKeybindings,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use korangar_components::item_box;
+use korangar_interface::components::text_box::DefaultHandler;
+use korangar_interface::element::StateElement;
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Path, RustState, Selector, VecIndexExt};
+
+use crate::ItemSource;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::social::{MailComposer, MailComposerPathExt};
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+/// Maximum number of items that can be attached to a mail.
+const MAIL_ITEM_SLOTS: usize = 5;
+/// The receiver name is sent in 24 bytes, including the terminator.
+const MAXIMUM_RECEIVER_LENGTH: usize = 23;
+/// Maximum length of the title, excluding the terminator.
+const MAXIMUM_TITLE_LENGTH: usize = 39;
+/// Maximum length of the text, excluding the terminator.
+const MAXIMUM_TEXT_LENGTH: usize = 499;
+/// Maximum number of digits of the attached zeny.
+const MAXIMUM_ZENY_LENGTH: usize = 10;
+
+/// Internal state of the mail compose window.
+#[derive(Default, RustState, StateElement)]
+pub struct MailComposeWindowState {
+    pub receiver: String,
+    pub title: String,
+    pub text: String,
+    pub zeny_input: String,
+}
+
+impl MailComposeWindowState {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+pub struct MailComposeWindow<A, B> {
+    window_state_path: A,
+    composer_path: B,
+}
+
+impl<A, B> MailComposeWindow<A, B> {
+    pub fn new(window_state_path: A, composer_path: B) -> Self {
+        Self {
+            window_state_path,
+            composer_path,
+        }
+    }
+}
+
+impl<A, B> CustomWindow<ClientState> for MailComposeWindow<A, B>
+where
+    A: Path<ClientState, MailComposeWindowState>,
+    B: Path<ClientState, MailComposer>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::MailCompose)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        struct MailReceiverTextBox;
+        struct MailTitleTextBox;
+        struct MailTextTextBox;
+        struct MailZenyTextBox;
+
+        let weight = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let composer = self.composer_path.follow(state).unwrap();
+            format!("Weight: {}", composer.weight)
+        });
+
+        fn cannot_send<A>(window_state_path: A) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, MailComposeWindowState>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                let window_state = window_state_path.follow(state).unwrap();
+                window_state.receiver.is_empty() || window_state.title.is_empty()
+            })
+        }
+
+        let receiver_path = self.window_state_path.receiver();
+        let title_path = self.window_state_path.title();
+        let text_path = self.window_state_path.text();
+        let zeny_input_path = self.window_state_path.zeny_input();
+
+        window! {
+            title: client_state().localization().mail_compose_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: false,
+            resizable: true,
+            elements: (
+                text_box! {
+                    ghost_text: client_state().localization().mail_receiver_text_box_message(),
+                    state: receiver_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_RECEIVER_LENGTH>::new(receiver_path, Event::Unfocus),
+                    focus_id: MailReceiverTextBox,
+                },
+                text_box! {
+                    ghost_text: client_state().localization().mail_title_text_box_message(),
+                    state: title_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_TITLE_LENGTH>::new(title_path, Event::Unfocus),
+                    focus_id: MailTitleTextBox,
+                },
+                text_box! {
+                    ghost_text: client_state().localization().mail_text_text_box_message(),
+                    state: text_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_TEXT_LENGTH>::new(text_path, Event::Unfocus),
+                    focus_id: MailTextTextBox,
+                },
+                text_box! {
+                    ghost_text: client_state().localization().mail_zeny_text_box_message(),
+                    state: zeny_input_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_ZENY_LENGTH>::new(zeny_input_path, Event::Unfocus),
+                    focus_id: MailZenyTextBox,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, MAIL_ITEM_SLOTS, _>(|slot| {
+                        item_box! {
+                            item_path: self.composer_path.attachments().index(slot),
+                            source: ItemSource::MailAttachment,
+                        }
+                    }),
+                },
+                text! {
+                    text: weight,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().send_mail_button_text(),
+                            event: InputEvent::SendMail,
+                            disabled: cannot_send(self.window_state_path),
+                        },
+                        button! {
+                            text: client_state().localization().cancel_mail_button_text(),
+                            event: InputEvent::CancelMail,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use std::cmp::Ordering;
+
+use korangar_components::item_box;
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{Element, ElementBox};
+use korangar_interface::layout::{Resolver, WindowLayout};
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::MailSummary;
+use rust_state::{Context, ManuallyAssertExt, Path, Selector, VecIndexExt};
+
+use crate::ItemSource;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::social::{Mailbox, MailboxPathExt, OpenedMailPathExt};
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+/// Maximum number of items that can be attached to a mail.
+const MAIL_ITEM_SLOTS: usize = 5;
+
+struct MailList<A> {
+    mails_path: A,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A> MailList<A> {
+    fn new(mails_path: A) -> Self {
+        Self {
+            mails_path,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A> Element<ClientState> for MailList<A>
+where
+    A: Path<ClientState, Vec<MailSummary>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let mails = state.get(&self.mails_path);
+
+        match mails.len().cmp(&self.elements.len()) {
+            Ordering::Less => {
+                self.elements.truncate(mails.len());
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                for index in self.elements.len()..mails.len() {
+                    let mail_path = self.mails_path.index(index).manually_asserted();
+
+                    let mail_text = ComputedSelector::new_default(move |state: &ClientState| {
+                        mail_path
+                            .follow(state)
+                            .map(|mail| {
+                                let attachments = match (mail.has_zeny, mail.has_items) {
+                                    (true, true) => " (Zeny, Items)",
+                                    (true, false) => " (Zeny)",
+                                    (false, true) => " (Items)",
+                                    (false, false) => "",
+                                };
+
+                                match mail.is_read {
+                                    true => format!("{}: {}{attachments}", mail.sender_name, mail.title),
+                                    false => format!("[New] {}: {}{attachments}", mail.sender_name, mail.title),
+                                }
+                            })
+                            .unwrap_or_default()
+                    });
+
+                    self.elements.push(ErasedElement::new(button! {
+                        text: mail_text,
+                        event: move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+                            let mail = state.get(&mail_path);
+
+                            queue.queue(InputEvent::ReadMail {
+                                open_type: mail.open_type,
+                                mail_id: mail.mail_id,
+                            });
+                        },
+                    }));
+                }
+            }
+        }
+
+        self.elements.iter_mut().enumerate().for_each(|(index, element)| {
+            element.create_layout_info(state, store.child_store(index as u64), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        self.elements.iter().enumerate().for_each(|(index, element)| {
+            element.lay_out(state, store.child_store(index as u64), &(), layout);
+        });
+    }
+}
+
+pub struct MailboxWindow<A> {
+    mailbox_path: A,
+}
+
+impl<A> MailboxWindow<A> {
+    pub fn new(mailbox_path: A) -> Self {
+        Self { mailbox_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for MailboxWindow<A>
+where
+    A: Path<ClientState, Mailbox>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Mailbox)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        let opened_mail_path = self.mailbox_path.opened_mail();
+
+        let sender = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let mailbox = self.mailbox_path.follow(state).unwrap();
+
+            match mailbox.opened_mail_id().is_some() {
+                true => format!("{}: {}", mailbox.opened_mail.sender_name, mailbox.opened_mail.title),
+                false => String::new(),
+            }
+        });
+
+        let zeny = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let opened_mail = opened_mail_path.follow(state).unwrap();
+            format!("{} Zeny", opened_mail.zeny)
+        });
+
+        fn no_zeny<A>(mailbox_path: A) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, Mailbox>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                mailbox_path.follow(state).unwrap().opened_mail.zeny == 0
+            })
+        }
+
+        fn no_items<A>(mailbox_path: A) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, Mailbox>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                !mailbox_path.follow(state).unwrap().has_opened_items()
+            })
+        }
+
+        fn no_opened_mail<A>(mailbox_path: A) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, Mailbox>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                mailbox_path.follow(state).unwrap().opened_mail_id().is_none()
+            })
+        }
+
+        window! {
+            title: client_state().localization().mailbox_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: false,
+            resizable: true,
+            elements: (
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().refresh_mailbox_button_text(),
+                            event: InputEvent::RefreshMailbox,
+                        },
+                        button! {
+                            text: client_state().localization().write_mail_button_text(),
+                            event: InputEvent::WriteMail,
+                        },
+                        button! {
+                            text: client_state().localization().close_button_text(),
+                            event: InputEvent::CloseMailbox,
+                        },
+                    ),
+                },
+                scroll_view! {
+                    children: (
+                        MailList::new(self.mailbox_path.mails()),
+                    ),
+                },
+                text! {
+                    text: sender,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                text! {
+                    text: opened_mail_path.text(),
+                    overflow_behavior: OverflowBehavior::LineBreak,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, MAIL_ITEM_SLOTS, _>(|slot| {
+                        item_box! {
+                            item_path: opened_mail_path.items().index(slot),
+                            source: ItemSource::Mail,
+                        }
+                    }),
+                },
+                text! {
+                    text: zeny,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().take_mail_zeny_button_text(),
+                            event: InputEvent::TakeMailZeny,
+                            disabled: no_zeny(self.mailbox_path),
+                        },
+                        button! {
+                            text: client_state().localization().take_mail_items_button_text(),
+                            event: InputEvent::TakeMailItems,
+                            disabled: no_items(self.mailbox_path),
+                        },
+                        button! {
+                            text: client_state().localization().delete_mail_button_text(),
+                            event: InputEvent::DeleteMail,
+                            disabled: no_opened_mail(self.mailbox_path),
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
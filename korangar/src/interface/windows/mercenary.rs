@@ -0,0 +1,110 @@
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::{CompanionType, MercenaryInformationPathExt};
+use ragnarok_packets::MercenaryCommand;
+use rust_state::{Path, Selector};
+
+use crate::companion::{Mercenary, MercenaryPathExt};
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+pub struct MercenaryWindow<A> {
+    mercenary_path: A,
+}
+
+impl<A> MercenaryWindow<A> {
+    pub fn new(mercenary_path: A) -> Self {
+        Self { mercenary_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for MercenaryWindow<A>
+where
+    A: Path<ClientState, Mercenary>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Mercenary)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        fn no_mercenary<A>(mercenary_path: A) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, Mercenary>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                mercenary_path.follow(state).unwrap().entity_id().is_none()
+            })
+        }
+
+        macro_rules! information_text {
+            ($format:literal, $($field:ident),+) => {
+                ComputedSelector::new_default(move |state: &ClientState| {
+                    // SAFETY:
+                    //
+                    // Unwrap is safe here because of the bounds.
+                    let information = &self.mercenary_path.follow(state).unwrap().information;
+                    format!($format, $(information.$field),+)
+                })
+            };
+        }
+
+        macro_rules! value_row {
+            ($text_name:ident, $value:expr) => {
+                split! {
+                    children: (
+                        text! {
+                            text: client_state().localization().$text_name(),
+                            overflow_behavior: OverflowBehavior::Shrink,
+                        },
+                        text! {
+                            text: $value,
+                            horizontal_alignment: HorizontalAlignment::Right { offset: 5.0, border: 5.0 },
+                            overflow_behavior: OverflowBehavior::Shrink,
+                        },
+                    ),
+                }
+            };
+        }
+
+        window! {
+            title: client_state().localization().mercenary_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: self.mercenary_path.information().name(),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                value_row!(companion_level_text, PartialEqDisplaySelector::new(self.mercenary_path.information().level())),
+                value_row!(health_points_text, information_text!("{}/{}", health_points, maximum_health_points)),
+                value_row!(spell_points_text, information_text!("{}/{}", spell_points, maximum_spell_points)),
+                value_row!(faith_text, PartialEqDisplaySelector::new(self.mercenary_path.information().faith())),
+                value_row!(kill_count_text, PartialEqDisplaySelector::new(self.mercenary_path.information().kill_count())),
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().call_back_button_text(),
+                            event: InputEvent::CallBackCompanion { companion_type: CompanionType::Mercenary },
+                            disabled: no_mercenary(self.mercenary_path),
+                        },
+                        button! {
+                            text: client_state().localization().dismiss_button_text(),
+                            event: InputEvent::MercenaryCommand { command: MercenaryCommand::Dismiss },
+                            disabled: no_mercenary(self.mercenary_path),
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
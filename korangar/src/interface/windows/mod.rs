@@ -3,6 +3,7 @@ mod buy;
 mod buy_cart;
 mod buy_or_sell;
 mod cache;
+mod cart;
 mod character_creation;
 mod character_overview;
 mod character_selection;
@@ -18,15 +19,25 @@ mod friend_list;
 mod friend_request;
 mod game_settings;
 mod graphics_settings;
+mod guild;
+mod homunculus;
 mod hotbar;
 mod interface_settings;
 mod inventory;
+mod keybindings;
 mod login;
+mod mail_compose;
+mod mailbox;
 #[cfg(feature = "debug")]
 mod maps;
 mod menu;
+mod mercenary;
 #[cfg(feature = "debug")]
 mod packet_inspector;
+mod party;
+mod party_invitation;
+mod pet;
+mod pet_egg;
 #[cfg(feature = "debug")]
 mod profiler;
 #[cfg(feature = "debug")]
@@ -37,8 +48,14 @@ mod sell_cart;
 mod server_selection;
 mod skill_tree;
 mod stats;
+mod storage;
 #[cfg(feature = "debug")]
 mod theme_inspector;
+mod trade;
+mod trade_request;
+mod vending;
+mod vending_setup;
+mod vending_shop;
 
 use serde::{Deserialize, Serialize};
 
@@ -47,6 +64,7 @@ pub use self::buy::BuyWindow;
 pub use self::buy_cart::BuyCartWindow;
 pub use self::buy_or_sell::BuyOrSellWindow;
 pub use self::cache::WindowCache;
+pub use self::cart::CartWindow;
 pub use self::character_creation::CharacterCreationWindow;
 pub use self::character_overview::CharacterOverviewWindow;
 pub use self::character_selection::CharacterSelectionWindow;
@@ -62,15 +80,25 @@ pub use self::friend_list::{FriendListWindow, FriendListWindowState};
 pub use self::friend_request::FriendRequestWindow;
 pub use self::game_settings::GameSettingsWindow;
 pub use self::graphics_settings::GraphicsSettingsWindow;
+pub use self::guild::GuildWindow;
+pub use self::homunculus::HomunculusWindow;
 pub use self::hotbar::HotbarWindow;
 pub use self::interface_settings::InterfaceSettingsWindow;
 pub use self::inventory::InventoryWindow;
+pub use self::keybindings::{KeybindingsWindow, KeybindingsWindowState};
 pub use self::login::{LoginWindow, LoginWindowState};
+pub use self::mail_compose::{MailComposeWindow, MailComposeWindowState};
+pub use self::mailbox::MailboxWindow;
 #[cfg(feature = "debug")]
 pub use self::maps::MapsWindow;
 pub use self::menu::MenuWindow;
+pub use self::mercenary::MercenaryWindow;
 #[cfg(feature = "debug")]
 pub use self::packet_inspector::PacketInspectorWindow;
+pub use self::party::{PartyWindow, PartyWindowState};
+pub use self::party_invitation::PartyInvitationWindow;
+pub use self::pet::PetWindow;
+pub use self::pet_egg::PetEggWindow;
 #[cfg(feature = "debug")]
 pub use self::profiler::{ProfilerWindow, ProfilerWindowState};
 #[cfg(feature = "debug")]
@@ -81,8 +109,14 @@ pub use self::sell_cart::SellCartWindow;
 pub use self::server_selection::ServerSelectionWindow;
 pub use self::skill_tree::SkillTreeWindow;
 pub use self::stats::StatsWindow;
+pub use self::storage::StorageWindow;
 #[cfg(feature = "debug")]
 pub use self::theme_inspector::{ThemeInspectorWindow, ThemeInspectorWindowState};
+pub use self::trade::{TradeWindow, TradeWindowState};
+pub use self::trade_request::TradeRequestWindow;
+pub use self::vending::VendingWindow;
+pub use self::vending_setup::{VendingSetupWindow, VendingSetupWindowState};
+pub use self::vending_shop::VendingShopWindow;
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WindowClass {
@@ -98,6 +132,7 @@ pub enum WindowClass {
     GameSettings,
     InterfaceSettings,
     GraphicsSettings,
+    Keybindings,
     Hotbar,
     Inventory,
     Equipment,
@@ -105,6 +140,22 @@ pub enum WindowClass {
     Stats,
     FriendList,
     FriendRequest,
+    Party,
+    PartyInvitation,
+    Guild,
+    Trade,
+    TradeRequest,
+    Storage,
+    Cart,
+    VendingSetup,
+    Vending,
+    VendingShop,
+    Mailbox,
+    MailCompose,
+    Pet,
+    PetEgg,
+    Homunculus,
+    Mercenary,
     Login,
     Menu,
     Respawn,
@@ -0,0 +1,306 @@
+use std::cmp::Ordering;
+
+use korangar_interface::components::text_box::DefaultHandler;
+use korangar_interface::element::store::{ElementStore, ElementStoreMut};
+use korangar_interface::element::{Element, ElementBox, StateElement};
+use korangar_interface::layout::{Resolver, WindowLayout};
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::{PartyMember, PartyMemberPathExt};
+use ragnarok_packets::{PartyExperienceShare, PartyItemShare};
+use rust_state::{Context, ManuallyAssertExt, Path, RustState, Selector, VecIndexExt};
+
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::social::{Party, PartyPathExt};
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+// TODO: These constants are duplicated troughout the code base. Unify this
+// somewhere, maybe a `consts.rs` would be a good idea at this point?
+const MINIMUM_NAME_LENGTH: usize = 4;
+const MAXIMUM_NAME_LENGTH: usize = 24;
+
+fn member_details(member: &PartyMember) -> String {
+    let mut details = member.map_name.clone();
+
+    if let Some(position) = member.position {
+        details.push_str(&format!(" ({}, {})", position.x, position.y));
+    }
+
+    details.push_str(&format!(" - Lv. {}", member.base_level));
+
+    if let (Some(health_points), Some(maximum_health_points)) = (member.health_points, member.maximum_health_points) {
+        details.push_str(&format!(" - HP {health_points}/{maximum_health_points}"));
+    }
+
+    if !member.is_online() {
+        details.push_str(" (offline)");
+    }
+
+    details
+}
+
+fn toggle_item_share(item_share: PartyItemShare) -> PartyItemShare {
+    match item_share {
+        PartyItemShare::EachTake => PartyItemShare::EvenShare,
+        PartyItemShare::EvenShare => PartyItemShare::EachTake,
+    }
+}
+
+struct PartyMemberList<A> {
+    members_path: A,
+    elements: Vec<ElementBox<ClientState>>,
+}
+
+impl<A> PartyMemberList<A> {
+    fn new(members_path: A) -> Self {
+        Self {
+            members_path,
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<A> Element<ClientState> for PartyMemberList<A>
+where
+    A: Path<ClientState, Vec<PartyMember>>,
+{
+    type LayoutInfo = ();
+
+    fn create_layout_info(
+        &mut self,
+        state: &Context<ClientState>,
+        mut store: ElementStoreMut<'_>,
+        resolver: &mut Resolver<'_, ClientState>,
+    ) -> Self::LayoutInfo {
+        use korangar_interface::prelude::*;
+
+        let members = state.get(&self.members_path);
+
+        match members.len().cmp(&self.elements.len()) {
+            Ordering::Less => {
+                self.elements.truncate(members.len());
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                for index in self.elements.len()..members.len() {
+                    let member_path = self.members_path.index(index).manually_asserted();
+                    let name_path = member_path.name();
+
+                    let details = ComputedSelector::new_default(move |state: &ClientState| {
+                        member_path.follow(state).map(member_details).unwrap_or_default()
+                    });
+
+                    self.elements.push(ErasedElement::new(collapsable! {
+                        text: name_path,
+                        children: (
+                            text! {
+                                text: details,
+                                overflow_behavior: OverflowBehavior::Shrink,
+                            },
+                            split! {
+                                gaps: theme().window().gaps(),
+                                children: (
+                                    button! {
+                                        text: client_state().localization().party_leader_button_text(),
+                                        event: move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+                                            let account_id = state.get(&member_path).account_id;
+
+                                            queue.queue(InputEvent::ChangePartyLeader { account_id });
+                                        },
+                                    },
+                                    button! {
+                                        text: client_state().localization().expel_button_text(),
+                                        event: move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+                                            let PartyMember { account_id, name, .. } = state.get(&member_path);
+
+                                            queue.queue(InputEvent::ExpelPartyMember {
+                                                account_id: *account_id,
+                                                character_name: name.clone(),
+                                            });
+                                        },
+                                    },
+                                ),
+                            },
+                        ),
+                    }));
+                }
+            }
+        }
+
+        self.elements.iter_mut().zip(members.iter()).for_each(|(element, member)| {
+            element.create_layout_info(state, store.child_store(member.character_id.0 as u64), resolver);
+        });
+    }
+
+    fn lay_out<'a>(
+        &'a self,
+        state: &'a Context<ClientState>,
+        store: ElementStore<'a>,
+        _: &'a Self::LayoutInfo,
+        layout: &mut WindowLayout<'a, ClientState>,
+    ) {
+        let members = state.get(&self.members_path);
+
+        self.elements.iter().zip(members.iter()).for_each(|(element, member)| {
+            element.lay_out(state, store.child_store(member.character_id.0 as u64), &(), layout);
+        });
+    }
+}
+
+/// Internal state of the party window.
+#[derive(Default, RustState, StateElement)]
+pub struct PartyWindowState {
+    currently_creating: String,
+    currently_inviting: String,
+}
+
+pub struct PartyWindow<A, B> {
+    window_state_path: A,
+    party_path: B,
+}
+
+impl<A, B> PartyWindow<A, B> {
+    pub fn new(window_state_path: A, party_path: B) -> Self {
+        Self {
+            window_state_path,
+            party_path,
+        }
+    }
+}
+
+impl<A, B> CustomWindow<ClientState> for PartyWindow<A, B>
+where
+    A: Path<ClientState, PartyWindowState>,
+    B: Path<ClientState, Party>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Party)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        struct CreatePartyTextBox;
+        struct InvitePartyMemberTextBox;
+
+        let create_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let party_name = state.get(&self.window_state_path.currently_creating()).clone();
+
+            if !party_name.is_empty() {
+                state.update_value_with(self.window_state_path.currently_creating(), |input| input.clear());
+                queue.queue(InputEvent::CreateParty { party_name });
+                queue.queue(Event::Unfocus);
+            }
+        };
+
+        let invite_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let character_name = state.get(&self.window_state_path.currently_inviting()).clone();
+
+            // TODO: Give some sort of error if the name is too short.
+            if character_name.len() >= MINIMUM_NAME_LENGTH {
+                state.update_value_with(self.window_state_path.currently_inviting(), |input| input.clear());
+                queue.queue(InputEvent::InviteToParty { character_name });
+                queue.queue(Event::Unfocus);
+            }
+        };
+
+        fn not_in_party<B>(party_path: B) -> impl Selector<ClientState, bool>
+        where
+            B: Path<ClientState, Party>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                !party_path.follow(state).unwrap().is_in_party()
+            })
+        }
+
+        let toggle_experience_share = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let party = state.get(&self.party_path);
+
+            let experience_share = match party.experience_share() {
+                PartyExperienceShare::EachTake => PartyExperienceShare::EvenShare,
+                PartyExperienceShare::EvenShare => PartyExperienceShare::EachTake,
+            };
+
+            queue.queue(InputEvent::ChangePartyOptions {
+                experience_share,
+                item_pickup_rule: party.item_pickup_rule(),
+                item_distribution_rule: party.item_distribution_rule(),
+            });
+        };
+
+        let toggle_item_pickup = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let party = state.get(&self.party_path);
+
+            queue.queue(InputEvent::ChangePartyOptions {
+                experience_share: party.experience_share(),
+                item_pickup_rule: toggle_item_share(party.item_pickup_rule()),
+                item_distribution_rule: party.item_distribution_rule(),
+            });
+        };
+
+        let toggle_item_distribution = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let party = state.get(&self.party_path);
+
+            queue.queue(InputEvent::ChangePartyOptions {
+                experience_share: party.experience_share(),
+                item_pickup_rule: party.item_pickup_rule(),
+                item_distribution_rule: toggle_item_share(party.item_distribution_rule()),
+            });
+        };
+
+        window! {
+            title: client_state().localization().party_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: self.party_path.name(),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                text_box! {
+                    ghost_text: client_state().localization().create_party_text_box_message(),
+                    state: self.window_state_path.currently_creating(),
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_NAME_LENGTH>::new(self.window_state_path.currently_creating(), create_action),
+                    focus_id: CreatePartyTextBox,
+                },
+                text_box! {
+                    ghost_text: client_state().localization().invite_to_party_text_box_message(),
+                    state: self.window_state_path.currently_inviting(),
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_NAME_LENGTH>::new(self.window_state_path.currently_inviting(), invite_action),
+                    focus_id: InvitePartyMemberTextBox,
+                },
+                state_button! {
+                    text: client_state().localization().party_even_experience_share_text(),
+                    state: self.party_path.even_experience_share(),
+                    event: toggle_experience_share,
+                    disabled: not_in_party(self.party_path),
+                },
+                state_button! {
+                    text: client_state().localization().party_even_item_pickup_text(),
+                    state: self.party_path.even_item_pickup(),
+                    event: toggle_item_pickup,
+                    disabled: not_in_party(self.party_path),
+                },
+                state_button! {
+                    text: client_state().localization().party_even_item_distribution_text(),
+                    state: self.party_path.even_item_distribution(),
+                    event: toggle_item_distribution,
+                    disabled: not_in_party(self.party_path),
+                },
+                PartyMemberList::new(self.party_path.members()),
+                button! {
+                    text: client_state().localization().leave_party_button_text(),
+                    event: InputEvent::LeaveParty,
+                    disabled: not_in_party(self.party_path),
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use korangar_interface::window::{CustomWindow, Window};
+use korangar_networking::PetInformationPathExt;
+use ragnarok_packets::PetCommand;
+use rust_state::{Path, Selector};
+
+use crate::companion::{Pet, PetPathExt};
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+pub struct PetWindow<A> {
+    pet_path: A,
+}
+
+impl<A> PetWindow<A> {
+    pub fn new(pet_path: A) -> Self {
+        Self { pet_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for PetWindow<A>
+where
+    A: Path<ClientState, Pet>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Pet)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        fn no_pet<A>(pet_path: A) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, Pet>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                pet_path.follow(state).unwrap().entity_id().is_none()
+            })
+        }
+
+        macro_rules! information_text {
+            ($format:literal, $($field:ident),+) => {
+                ComputedSelector::new_default(move |state: &ClientState| {
+                    // SAFETY:
+                    //
+                    // Unwrap is safe here because of the bounds.
+                    let information = &self.pet_path.follow(state).unwrap().information;
+                    format!($format, $(information.$field),+)
+                })
+            };
+        }
+
+        macro_rules! value_row {
+            ($text_name:ident, $value:expr) => {
+                split! {
+                    children: (
+                        text! {
+                            text: client_state().localization().$text_name(),
+                            overflow_behavior: OverflowBehavior::Shrink,
+                        },
+                        text! {
+                            text: $value,
+                            horizontal_alignment: HorizontalAlignment::Right { offset: 5.0, border: 5.0 },
+                            overflow_behavior: OverflowBehavior::Shrink,
+                        },
+                    ),
+                }
+            };
+        }
+
+        window! {
+            title: client_state().localization().pet_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: true,
+            elements: (
+                text! {
+                    text: self.pet_path.information().name(),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                value_row!(companion_level_text, PartialEqDisplaySelector::new(self.pet_path.information().level())),
+                value_row!(hunger_text, information_text!("{}/100", hunger)),
+                value_row!(intimacy_text, information_text!("{}/1000", intimacy)),
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().feed_button_text(),
+                            event: InputEvent::PetCommand { command: PetCommand::Feed },
+                            disabled: no_pet(self.pet_path),
+                        },
+                        button! {
+                            text: client_state().localization().performance_button_text(),
+                            event: InputEvent::PetCommand { command: PetCommand::Performance },
+                            disabled: no_pet(self.pet_path),
+                        },
+                    ),
+                },
+                button! {
+                    text: client_state().localization().unequip_accessory_button_text(),
+                    event: InputEvent::PetCommand { command: PetCommand::UnequipAccessory },
+                    disabled: no_pet(self.pet_path),
+                },
+                button! {
+                    text: client_state().localization().return_to_egg_button_text(),
+                    event: InputEvent::PetCommand { command: PetCommand::ReturnToEgg },
+                    disabled: no_pet(self.pet_path),
+                },
+            ),
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use korangar_components::item_box;
+use korangar_interface::components::text_box::DefaultHandler;
+use korangar_interface::element::StateElement;
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Context, Path, RustState, Selector, VecIndexExt};
+
+use crate::ItemSource;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::inventory::{Trade, TradePathExt};
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+// TODO: Probably this should be more dynamic
+const TRADE_ROWS: usize = 2;
+const TRADE_COLUMNS: usize = 5;
+/// Maximum number of digits of the zeny amount.
+const MAXIMUM_ZENY_LENGTH: usize = 10;
+
+fn zeny_details(zeny: u32, locked: bool) -> String {
+    match locked {
+        true => format!("{zeny} Zeny (locked)"),
+        false => format!("{zeny} Zeny"),
+    }
+}
+
+/// Internal state of the trade window.
+#[derive(Default, RustState, StateElement)]
+pub struct TradeWindowState {
+    zeny_input: String,
+}
+
+pub struct TradeWindow<A, B> {
+    window_state_path: A,
+    trade_path: B,
+}
+
+impl<A, B> TradeWindow<A, B> {
+    pub fn new(window_state_path: A, trade_path: B) -> Self {
+        Self {
+            window_state_path,
+            trade_path,
+        }
+    }
+}
+
+impl<A, B> CustomWindow<ClientState> for TradeWindow<A, B>
+where
+    A: Path<ClientState, TradeWindowState>,
+    B: Path<ClientState, Trade>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::Trade)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        struct TradeZenyTextBox;
+
+        let add_zeny_action = move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+            let zeny_input = state.get(&self.window_state_path.zeny_input()).clone();
+
+            // TODO: Give some sort of error if the input is not a number.
+            if let Ok(amount) = zeny_input.parse::<u32>() {
+                state.update_value_with(self.window_state_path.zeny_input(), |input| input.clear());
+                queue.queue(InputEvent::AddTradeZeny { amount });
+                queue.queue(Event::Unfocus);
+            }
+        };
+
+        let partner = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let trade = self.trade_path.follow(state).unwrap();
+            format!("{} (Lv. {})", trade.partner_name, trade.partner_base_level)
+        });
+
+        let own_zeny = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let trade = self.trade_path.follow(state).unwrap();
+            zeny_details(trade.own_zeny, trade.own_locked)
+        });
+
+        let partner_zeny = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let trade = self.trade_path.follow(state).unwrap();
+            zeny_details(trade.partner_zeny, trade.partner_locked)
+        });
+
+        fn cannot_commit<B>(trade_path: B) -> impl Selector<ClientState, bool>
+        where
+            B: Path<ClientState, Trade>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                let trade = trade_path.follow(state).unwrap();
+                !trade.own_locked || !trade.partner_locked
+            })
+        }
+
+        window! {
+            title: client_state().localization().trade_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: false,
+            elements: (
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        fragment! {
+                            gaps: theme().window().gaps(),
+                            children: (
+                                text! {
+                                    text: client_state().localization().trade_own_offer_text(),
+                                    overflow_behavior: OverflowBehavior::Shrink,
+                                },
+                                fragment! {
+                                    gaps: theme().window().gaps(),
+                                    children: std::array::from_fn::<_, TRADE_ROWS, _>(|row| {
+                                        split! {
+                                            gaps: theme().window().gaps(),
+                                            children: std::array::from_fn::<_, TRADE_COLUMNS, _>(|column| {
+                                                item_box! {
+                                                    item_path: self.trade_path.own_items().index(row * TRADE_COLUMNS + column),
+                                                    source: ItemSource::Trade,
+                                                }
+                                            }),
+                                        }
+                                    }),
+                                },
+                                text! {
+                                    text: own_zeny,
+                                    overflow_behavior: OverflowBehavior::Shrink,
+                                },
+                            ),
+                        },
+                        fragment! {
+                            gaps: theme().window().gaps(),
+                            children: (
+                                text! {
+                                    text: partner,
+                                    overflow_behavior: OverflowBehavior::Shrink,
+                                },
+                                fragment! {
+                                    gaps: theme().window().gaps(),
+                                    children: std::array::from_fn::<_, TRADE_ROWS, _>(|row| {
+                                        split! {
+                                            gaps: theme().window().gaps(),
+                                            children: std::array::from_fn::<_, TRADE_COLUMNS, _>(|column| {
+                                                item_box! {
+                                                    item_path: self.trade_path.partner_items().index(row * TRADE_COLUMNS + column),
+                                                    source: ItemSource::TradePartner,
+                                                }
+                                            }),
+                                        }
+                                    }),
+                                },
+                                text! {
+                                    text: partner_zeny,
+                                    overflow_behavior: OverflowBehavior::Shrink,
+                                },
+                            ),
+                        },
+                    ),
+                },
+                text_box! {
+                    ghost_text: client_state().localization().trade_zeny_text_box_message(),
+                    state: self.window_state_path.zeny_input(),
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_ZENY_LENGTH>::new(self.window_state_path.zeny_input(), add_zeny_action),
+                    focus_id: TradeZenyTextBox,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().lock_trade_button_text(),
+                            event: InputEvent::LockTrade,
+                            disabled: self.trade_path.own_locked(),
+                        },
+                        button! {
+                            text: client_state().localization().commit_trade_button_text(),
+                            event: InputEvent::CommitTrade,
+                            disabled: cannot_commit(self.trade_path),
+                        },
+                        button! {
+                            text: client_state().localization().cancel_trade_button_text(),
+                            event: InputEvent::CancelTrade,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
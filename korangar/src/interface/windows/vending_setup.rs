@@ -0,0 +1,162 @@
+use korangar_components::item_box;
+use korangar_interface::components::text_box::DefaultHandler;
+use korangar_interface::element::StateElement;
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Context, ManuallyAssertExt, Path, RustState, Selector, VecIndexExt};
+
+use super::vending::VendingItemList;
+use crate::ItemSource;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::inventory::{VendingSetup, VendingSetupPathExt};
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+// TODO: Probably this should be more dynamic
+const VENDING_ROWS: usize = 2;
+const VENDING_COLUMNS: usize = 6;
+/// The shop title is sent in 80 bytes, including the terminator.
+const MAXIMUM_SHOP_TITLE_LENGTH: usize = 79;
+/// Maximum number of digits of the price.
+const MAXIMUM_PRICE_LENGTH: usize = 10;
+
+/// Internal state of the vending setup window.
+#[derive(Default, RustState, StateElement)]
+pub struct VendingSetupWindowState {
+    pub shop_title: String,
+    pub price_input: String,
+}
+
+pub struct VendingSetupWindow<A, B> {
+    window_state_path: A,
+    setup_path: B,
+}
+
+impl<A, B> VendingSetupWindow<A, B> {
+    pub fn new(window_state_path: A, setup_path: B) -> Self {
+        Self {
+            window_state_path,
+            setup_path,
+        }
+    }
+}
+
+impl<A, B> CustomWindow<ClientState> for VendingSetupWindow<A, B>
+where
+    A: Path<ClientState, VendingSetupWindowState>,
+    B: Path<ClientState, VendingSetup>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::VendingSetup)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        struct VendingTitleTextBox;
+        struct VendingPriceTextBox;
+
+        let item_count = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let setup = self.setup_path.follow(state).unwrap();
+            format!("{}/{}", setup.item_count(), setup.maximum_item_count)
+        });
+
+        fn cannot_open<A, B>(window_state_path: A, setup_path: B) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, VendingSetupWindowState>,
+            B: Path<ClientState, VendingSetup>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                let window_state = window_state_path.follow(state).unwrap();
+
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                let setup = setup_path.follow(state).unwrap();
+
+                window_state.shop_title.is_empty() || setup.item_count() == 0
+            })
+        }
+
+        let shop_title_path = self.window_state_path.shop_title();
+        let price_input_path = self.window_state_path.price_input();
+
+        window! {
+            title: client_state().localization().vending_setup_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: false,
+            resizable: true,
+            elements: (
+                text_box! {
+                    ghost_text: client_state().localization().shop_title_text_box_message(),
+                    state: shop_title_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_SHOP_TITLE_LENGTH>::new(shop_title_path, Event::Unfocus),
+                    focus_id: VendingTitleTextBox,
+                },
+                text_box! {
+                    ghost_text: client_state().localization().item_price_text_box_message(),
+                    state: price_input_path,
+                    input_handler: DefaultHandler::<_, _, MAXIMUM_PRICE_LENGTH>::new(price_input_path, Event::Unfocus),
+                    focus_id: VendingPriceTextBox,
+                },
+                fragment! {
+                    gaps: theme().window().gaps(),
+                    children: std::array::from_fn::<_, VENDING_ROWS, _>(|row| {
+                        split! {
+                            gaps: theme().window().gaps(),
+                            children: std::array::from_fn::<_, VENDING_COLUMNS, _>(|column| {
+                                item_box! {
+                                    item_path: self.setup_path.items().index(row * VENDING_COLUMNS + column),
+                                    source: ItemSource::VendingSetup,
+                                }
+                            }),
+                        }
+                    }),
+                },
+                text! {
+                    text: item_count,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                scroll_view! {
+                    children: (
+                        VendingItemList::new(self.setup_path.offers(), move |position: usize| {
+                            let offer_path = self.setup_path.offers().index(position).manually_asserted();
+
+                            (button! {
+                                text: client_state().localization().remove_button_text(),
+                                event: move |state: &Context<ClientState>, queue: &mut EventQueue<ClientState>| {
+                                    let index = state.get(&offer_path).index;
+
+                                    queue.queue(InputEvent::RemoveVendingSetupItem { index });
+                                },
+                            },)
+                        }),
+                    ),
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().open_vending_button_text(),
+                            event: InputEvent::OpenVending,
+                            disabled: cannot_open(self.window_state_path, self.setup_path),
+                        },
+                        button! {
+                            text: client_state().localization().cancel_vending_button_text(),
+                            event: InputEvent::CancelVendingSetup,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
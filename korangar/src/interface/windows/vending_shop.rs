@@ -0,0 +1,135 @@
+use korangar_interface::window::{CustomWindow, Window};
+use rust_state::{Context, Path, Selector};
+
+use super::vending::VendingItemList;
+use crate::input::InputEvent;
+use crate::interface::windows::WindowClass;
+use crate::inventory::{VendingShop, VendingShopPathExt};
+use crate::loaders::OverflowBehavior;
+use crate::state::localization::LocalizationPathExt;
+use crate::state::theme::InterfaceThemeType;
+use crate::state::{ClientState, ClientStatePathExt, client_state};
+
+/// The vending shop of another player.
+pub struct VendingShopWindow<A> {
+    shop_path: A,
+}
+
+impl<A> VendingShopWindow<A> {
+    pub fn new(shop_path: A) -> Self {
+        Self { shop_path }
+    }
+}
+
+impl<A> CustomWindow<ClientState> for VendingShopWindow<A>
+where
+    A: Path<ClientState, VendingShop>,
+{
+    fn window_class() -> Option<WindowClass> {
+        Some(WindowClass::VendingShop)
+    }
+
+    fn to_window<'a>(self) -> impl Window<ClientState> + 'a {
+        use korangar_interface::prelude::*;
+
+        let shop_path = self.shop_path;
+
+        let total_price = ComputedSelector::new_default(move |state: &ClientState| {
+            // SAFETY:
+            //
+            // Unwrap is safe here because of the bounds.
+            let shop = shop_path.follow(state).unwrap();
+            format!("{} Zeny", shop.total_price())
+        });
+
+        fn nothing_selected<A>(shop_path: A) -> impl Selector<ClientState, bool>
+        where
+            A: Path<ClientState, VendingShop>,
+        {
+            ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                shop_path.follow(state).unwrap().total_price() == 0
+            })
+        }
+
+        let create_children = move |position: usize| {
+            let purchase_amount = ComputedSelector::new_default(move |state: &ClientState| {
+                // SAFETY:
+                //
+                // Unwrap is safe here because of the bounds.
+                shop_path.follow(state).unwrap().purchase_amount(position).to_string()
+            });
+
+            let change_amount = move |change: i32| {
+                move |state: &Context<ClientState>, _: &mut EventQueue<ClientState>| {
+                    state.update_value_with(shop_path, move |shop| shop.change_purchase_amount(position, change));
+                }
+            };
+
+            (split! {
+                gaps: theme().window().gaps(),
+                children: (
+                    text! {
+                        text: purchase_amount,
+                        overflow_behavior: OverflowBehavior::Shrink,
+                    },
+                    button! {
+                        text: "-1",
+                        event: change_amount(-1),
+                    },
+                    button! {
+                        text: "+1",
+                        event: change_amount(1),
+                    },
+                    button! {
+                        text: "+10",
+                        event: change_amount(10),
+                    },
+                    button! {
+                        text: "+All",
+                        event: change_amount(u16::MAX as i32),
+                    },
+                ),
+            },)
+        };
+
+        window! {
+            title: client_state().localization().vending_shop_window_title(),
+            class: Self::window_class(),
+            theme: InterfaceThemeType::InGame,
+            closable: false,
+            resizable: true,
+            elements: (
+                text! {
+                    text: shop_path.shop_title(),
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                scroll_view! {
+                    children: (
+                        VendingItemList::new(shop_path.items(), create_children),
+                    ),
+                },
+                text! {
+                    text: total_price,
+                    overflow_behavior: OverflowBehavior::Shrink,
+                },
+                split! {
+                    gaps: theme().window().gaps(),
+                    children: (
+                        button! {
+                            text: client_state().localization().buy_vending_items_button_text(),
+                            event: InputEvent::BuyVendingItems,
+                            disabled: nothing_selected(shop_path),
+                        },
+                        button! {
+                            text: client_state().localization().close_button_text(),
+                            event: InputEvent::CloseVendingShop,
+                        },
+                    ),
+                },
+            ),
+        }
+    }
+}
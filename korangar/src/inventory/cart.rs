@@ -0,0 +1,41 @@
+use korangar_interface::element::StateElement;
+use rust_state::RustState;
+
+use super::Inventory;
+use crate::loaders::ItemLocation;
+
+/// The pushcart of a merchant class character. It is filled when the player
+/// enters the map with a cart or rents a new one.
+#[derive(RustState, StateElement)]
+pub struct Cart {
+    pub item_count: u16,
+    pub maximum_item_count: u16,
+    pub weight: u32,
+    pub maximum_weight: u32,
+    pub inventory: Inventory,
+}
+
+impl Default for Cart {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            maximum_item_count: 0,
+            weight: 0,
+            maximum_weight: 0,
+            inventory: Inventory::new(ItemLocation::Cart),
+        }
+    }
+}
+
+impl Cart {
+    pub fn set_item_count(&mut self, item_count: u16, maximum_item_count: u16, weight: u32, maximum_weight: u32) {
+        self.item_count = item_count;
+        self.maximum_item_count = maximum_item_count;
+        self.weight = weight;
+        self.maximum_weight = maximum_weight;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -6,9 +6,12 @@ use rust_state::RustState;
 
 use super::Skill;
 
+/// Number of skill slots in the hotbar.
+pub const HOTBAR_SLOT_COUNT: usize = 10;
+
 #[derive(Default, RustState, StateElement)]
 pub struct Hotbar {
-    skills: [Option<Skill>; 10],
+    skills: [Option<Skill>; HOTBAR_SLOT_COUNT],
 }
 
 impl Hotbar {
@@ -1,5 +1,9 @@
+mod cart;
 mod hotbar;
 mod skills;
+mod storage;
+mod trade;
+mod vending;
 
 use std::sync::Arc;
 
@@ -8,24 +12,40 @@ use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata};
 use ragnarok_packets::{EquipPosition, InventoryIndex, ItemId};
 use rust_state::RustState;
 
-pub use self::hotbar::{Hotbar, HotbarPathExt};
+pub use self::cart::{Cart, CartPathExt};
+pub use self::hotbar::{HOTBAR_SLOT_COUNT, Hotbar, HotbarPathExt};
 pub use self::skills::{Skill, SkillTree, SkillTreePathExt};
+pub use self::storage::{Storage, StoragePathExt};
+pub use self::trade::{Trade, TradePathExt};
+pub use self::vending::{Vending, VendingPathExt, VendingSetup, VendingSetupPathExt, VendingShop, VendingShopPathExt};
 use crate::graphics::Texture;
-use crate::loaders::AsyncLoader;
+use crate::loaders::{AsyncLoader, ItemLocation};
 use crate::world::ResourceMetadata;
 
-#[derive(Default, RustState, StateElement)]
+/// A container of items, like the player inventory or the storage.
+#[derive(RustState, StateElement)]
 pub struct Inventory {
+    /// Where the items of this container are located. Used to route the
+    /// loaded item sprites back to the right container.
+    #[hidden_element]
+    location: ItemLocation,
     // TODO: Unhide this.
     #[hidden_element]
     items: Vec<InventoryItem<ResourceMetadata>>,
 }
 
 impl Inventory {
+    pub fn new(location: ItemLocation) -> Self {
+        Self {
+            location,
+            items: Vec::new(),
+        }
+    }
+
     pub fn fill(&mut self, async_loader: &AsyncLoader, items: Vec<InventoryItem<NoMetadata>>) {
         self.items = items
             .into_iter()
-            .map(|item| async_loader.request_inventory_item_metadata_load(item))
+            .map(|item| async_loader.request_item_metadata_load(self.location, item))
             .collect();
     }
 
@@ -41,7 +61,7 @@ impl Inventory {
 
             *amount += added_amount;
         } else {
-            let item = async_loader.request_inventory_item_metadata_load(item);
+            let item = async_loader.request_item_metadata_load(self.location, item);
 
             self.items.push(item);
         }
@@ -58,7 +78,7 @@ impl Inventory {
             .items
             .iter()
             .position(|item| item.index == index)
-            .expect("item not in inventory");
+            .expect("item not in container");
 
         if let InventoryItemDetails::Regular { amount, .. } = &mut self.items[position].details
             && *amount > remove_amount
@@ -0,0 +1,35 @@
+use korangar_interface::element::StateElement;
+use rust_state::RustState;
+
+use super::Inventory;
+use crate::loaders::ItemLocation;
+
+/// The account storage of the player. It is only filled while the storage is
+/// open.
+#[derive(RustState, StateElement)]
+pub struct Storage {
+    pub item_count: u16,
+    pub maximum_item_count: u16,
+    pub inventory: Inventory,
+}
+
+impl Default for Storage {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            maximum_item_count: 0,
+            inventory: Inventory::new(ItemLocation::Storage),
+        }
+    }
+}
+
+impl Storage {
+    pub fn set_item_count(&mut self, item_count: u16, maximum_item_count: u16) {
+        self.item_count = item_count;
+        self.maximum_item_count = maximum_item_count;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use korangar_interface::element::StateElement;
+use korangar_networking::{InventoryItem, NoMetadata};
+use ragnarok_packets::{InventoryIndex, ItemId, TradeParticipant};
+use rust_state::RustState;
+
+use crate::graphics::Texture;
+use crate::loaders::{AsyncLoader, ItemLocation};
+use crate::world::ResourceMetadata;
+
+/// State of the current trade between the player and another player.
+#[derive(Default, RustState, StateElement)]
+pub struct Trade {
+    pub partner_name: String,
+    pub partner_base_level: u16,
+    // TODO: Unhide this.
+    #[hidden_element]
+    own_items: Vec<InventoryItem<ResourceMetadata>>,
+    // TODO: Unhide this.
+    #[hidden_element]
+    partner_items: Vec<InventoryItem<ResourceMetadata>>,
+    pub own_zeny: u32,
+    pub partner_zeny: u32,
+    pub own_locked: bool,
+    pub partner_locked: bool,
+    /// Items that were sent to the server but not yet confirmed.
+    #[hidden_element]
+    pending_items: Vec<InventoryItem<ResourceMetadata>>,
+    /// Zeny that were sent to the server but not yet confirmed.
+    #[hidden_element]
+    pending_zeny: Option<u32>,
+}
+
+impl Trade {
+    pub fn set_partner_name(&mut self, partner_name: String) {
+        self.partner_name = partner_name;
+    }
+
+    /// Starts a new trade, keeping only the name of the trade partner.
+    pub fn start(&mut self, partner_base_level: u16) {
+        let partner_name = std::mem::take(&mut self.partner_name);
+
+        *self = Self {
+            partner_name,
+            partner_base_level,
+            ..Default::default()
+        };
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn contains_item(&self, index: InventoryIndex) -> bool {
+        self.own_items
+            .iter()
+            .chain(self.pending_items.iter())
+            .any(|item| item.index == index)
+    }
+
+    pub fn add_pending_item(&mut self, item: InventoryItem<ResourceMetadata>) {
+        self.pending_items.push(item);
+    }
+
+    pub fn set_pending_zeny(&mut self, amount: u32) {
+        self.pending_zeny = Some(amount);
+    }
+
+    /// Called once the server confirmed that the item or zeny were added.
+    pub fn confirm_own_item(&mut self, index: Option<InventoryIndex>) {
+        match index {
+            Some(index) => {
+                if let Some(position) = self.pending_items.iter().position(|item| item.index == index) {
+                    let item = self.pending_items.remove(position);
+                    self.own_items.push(item);
+                }
+            }
+            None => {
+                if let Some(amount) = self.pending_zeny.take() {
+                    self.own_zeny = amount;
+                }
+            }
+        }
+    }
+
+    /// Called if the server refused to add the item or zeny.
+    pub fn reject_own_item(&mut self, index: Option<InventoryIndex>) {
+        match index {
+            Some(index) => self.pending_items.retain(|item| item.index != index),
+            None => self.pending_zeny = None,
+        }
+    }
+
+    pub fn add_partner_item(&mut self, async_loader: &AsyncLoader, item: InventoryItem<NoMetadata>) {
+        // The server does not tell us the inventory index of the item, so we use the
+        // position in the trade instead.
+        let index = InventoryIndex(self.partner_items.len() as u16);
+        let item = async_loader.request_item_metadata_load(ItemLocation::Trade, InventoryItem { index, ..item });
+
+        self.partner_items.push(item);
+    }
+
+    pub fn set_partner_zeny(&mut self, amount: u32) {
+        self.partner_zeny = amount;
+    }
+
+    pub fn lock(&mut self, participant: TradeParticipant) {
+        match participant {
+            TradeParticipant::Player => self.own_locked = true,
+            TradeParticipant::Partner => self.partner_locked = true,
+        }
+    }
+
+    pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
+        self.partner_items
+            .iter_mut()
+            .filter(|item| item.item_id == item_id)
+            .for_each(|item| item.metadata.texture = Some(texture.clone()));
+    }
+}
@@ -0,0 +1,223 @@
+use std::sync::Arc;
+
+use korangar_interface::element::StateElement;
+use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata, VendingItem};
+use ragnarok_packets::{EntityId, InventoryIndex, ItemId, Price, VendingItemSetup, VendingPurchase};
+use rust_state::RustState;
+
+use crate::graphics::Texture;
+use crate::loaders::AsyncLoader;
+use crate::world::ResourceMetadata;
+
+fn update_item_sprite(items: &mut [VendingItem<ResourceMetadata>], item_id: ItemId, texture: &Arc<Texture>) {
+    items
+        .iter_mut()
+        .filter(|item| item.item_id == item_id)
+        .for_each(|item| item.metadata.texture = Some(texture.clone()));
+}
+
+/// Items the player picked from the cart while setting up a vending shop.
+#[derive(Default, RustState, StateElement)]
+pub struct VendingSetup {
+    pub maximum_item_count: u16,
+    /// The cart items that will be offered.
+    // TODO: Unhide this.
+    #[hidden_element]
+    items: Vec<InventoryItem<ResourceMetadata>>,
+    /// The offers for the items, at the same position as the item.
+    // TODO: Unhide this.
+    #[hidden_element]
+    offers: Vec<VendingItem<ResourceMetadata>>,
+}
+
+impl VendingSetup {
+    pub fn start(&mut self, maximum_item_count: u16) {
+        *self = Self {
+            maximum_item_count,
+            ..Default::default()
+        };
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.item_count() >= self.maximum_item_count as usize
+    }
+
+    pub fn contains_item(&self, index: InventoryIndex) -> bool {
+        self.items.iter().any(|item| item.index == index)
+    }
+
+    /// Offers the whole stack of the cart item for the given price.
+    pub fn add_item(&mut self, item: InventoryItem<ResourceMetadata>, price: Price) {
+        let refinement_level = match &item.details {
+            InventoryItemDetails::Regular { .. } => 0,
+            InventoryItemDetails::Equippable { refinement_level, .. } => *refinement_level,
+        };
+
+        self.offers.push(VendingItem {
+            metadata: item.metadata.clone(),
+            index: item.index,
+            item_id: item.item_id,
+            item_type: item.item_type,
+            price,
+            amount: item.amount(),
+            is_identified: item.is_identified(),
+            refinement_level,
+            cards: item.slot,
+        });
+        self.items.push(item);
+    }
+
+    pub fn remove_item(&mut self, index: InventoryIndex) {
+        self.items.retain(|item| item.index != index);
+        self.offers.retain(|offer| offer.index != index);
+    }
+
+    pub fn setup_items(&self) -> Vec<VendingItemSetup> {
+        self.offers
+            .iter()
+            .map(|offer| VendingItemSetup {
+                index: offer.index,
+                amount: offer.amount,
+                price: offer.price,
+            })
+            .collect()
+    }
+}
+
+/// The vending shop of the player while it is open.
+#[derive(Default, RustState, StateElement)]
+pub struct Vending {
+    pub shop_title: String,
+    // TODO: Unhide this.
+    #[hidden_element]
+    items: Vec<VendingItem<ResourceMetadata>>,
+}
+
+impl Vending {
+    pub fn open(&mut self, async_loader: &AsyncLoader, shop_title: String, items: Vec<VendingItem<NoMetadata>>) {
+        self.shop_title = shop_title;
+        self.items = items
+            .into_iter()
+            .map(|item| async_loader.request_vending_item_metadata_load(item))
+            .collect();
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Removes the sold amount from the shop and returns the name of the item.
+    pub fn sell_item(&mut self, index: InventoryIndex, amount: u16) -> Option<String> {
+        let position = self.items.iter().position(|item| item.index == index)?;
+        let item = &mut self.items[position];
+        let name = item.metadata.name.clone();
+
+        item.amount = item.amount.saturating_sub(amount);
+
+        if item.amount == 0 {
+            self.items.remove(position);
+        }
+
+        Some(name)
+    }
+
+    pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
+        update_item_sprite(&mut self.items, item_id, &texture);
+    }
+}
+
+/// The vending shop of another player that the player is currently browsing.
+#[derive(Default, RustState, StateElement)]
+pub struct VendingShop {
+    #[hidden_element]
+    entity_id: Option<EntityId>,
+    #[hidden_element]
+    vending_id: u32,
+    pub shop_title: String,
+    // TODO: Unhide this.
+    #[hidden_element]
+    items: Vec<VendingItem<ResourceMetadata>>,
+    /// Amount the player wants to buy, at the same position as the item.
+    #[hidden_element]
+    purchase_amounts: Vec<u16>,
+}
+
+impl VendingShop {
+    pub fn open(
+        &mut self,
+        async_loader: &AsyncLoader,
+        entity_id: EntityId,
+        vending_id: u32,
+        shop_title: String,
+        items: Vec<VendingItem<NoMetadata>>,
+    ) {
+        *self = Self {
+            entity_id: Some(entity_id),
+            vending_id,
+            shop_title,
+            purchase_amounts: vec![0; items.len()],
+            items: items
+                .into_iter()
+                .map(|item| async_loader.request_vending_item_metadata_load(item))
+                .collect(),
+        };
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn entity_id(&self) -> Option<EntityId> {
+        self.entity_id
+    }
+
+    pub fn vending_id(&self) -> u32 {
+        self.vending_id
+    }
+
+    pub fn purchase_amount(&self, position: usize) -> u16 {
+        self.purchase_amounts.get(position).copied().unwrap_or_default()
+    }
+
+    /// Changes the amount the player wants to buy, staying within the amount
+    /// offered.
+    pub fn change_purchase_amount(&mut self, position: usize, change: i32) {
+        let (Some(item), Some(purchase_amount)) = (self.items.get(position), self.purchase_amounts.get_mut(position)) else {
+            return;
+        };
+
+        *purchase_amount = (*purchase_amount as i32 + change).clamp(0, item.amount as i32) as u16;
+    }
+
+    pub fn total_price(&self) -> u64 {
+        self.items
+            .iter()
+            .zip(self.purchase_amounts.iter())
+            .map(|(item, amount)| item.price.0 as u64 * *amount as u64)
+            .sum()
+    }
+
+    pub fn purchases(&self) -> Vec<VendingPurchase> {
+        self.items
+            .iter()
+            .zip(self.purchase_amounts.iter())
+            .filter(|(_, amount)| **amount > 0)
+            .map(|(item, amount)| VendingPurchase {
+                amount: *amount,
+                index: item.index,
+            })
+            .collect()
+    }
+
+    pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
+        update_item_sprite(&mut self.items, item_id, &texture);
+    }
+}
@@ -14,7 +14,7 @@ use num::Zero;
 
 use super::error::LoadError;
 use crate::loaders::{ActionLoader, SpriteLoader};
-use crate::world::{ActionEvent, Animation, AnimationData, AnimationFrame, AnimationFramePart, AnimationPair};
+use crate::world::{ActionEvent, Animation, AnimationData, AnimationFrame, AnimationFramePart, AnimationPair, EntityPartFile};
 use crate::{Color, EntityType};
 
 const MAX_CACHE_COUNT: u32 = 256;
@@ -22,7 +22,7 @@ const MAX_CACHE_COUNT: u32 = 256;
 const MAX_CACHE_SIZE: usize = usize::MAX;
 
 pub struct AnimationLoader {
-    cache: Mutex<SimpleCache<Vec<String>, Arc<AnimationData>>>,
+    cache: Mutex<SimpleCache<Vec<EntityPartFile>, Arc<AnimationData>>>,
 }
 
 impl AnimationLoader {
@@ -45,13 +45,32 @@ impl AnimationLoader {
         sprite_loader: &SpriteLoader,
         action_loader: &ActionLoader,
         entity_type: EntityType,
-        entity_part_files: &[String],
+        entity_part_files: &[EntityPartFile],
     ) -> Result<Arc<AnimationData>, LoadError> {
-        let animation_pairs: Vec<AnimationPair> = entity_part_files
+        // Not every job has a sprite for every piece of equipment, so we skip
+        // everything but the body if the sprite is missing.
+        let part_files: Vec<&EntityPartFile> = entity_part_files
             .iter()
-            .map(|file_path| AnimationPair {
-                sprites: sprite_loader.get_or_load(&format!("{file_path}.spr")).unwrap(),
-                actions: action_loader.get_or_load(&format!("{file_path}.act")).unwrap(),
+            .enumerate()
+            .filter(|(index, part_file)| *index == 0 || sprite_loader.sprite_exists(&format!("{}.spr", part_file.file_path)))
+            .map(|(_, part_file)| part_file)
+            .collect();
+
+        let behind_body: Vec<bool> = part_files.iter().map(|part_file| part_file.behind_body).collect();
+
+        let animation_pairs: Vec<AnimationPair> = part_files
+            .into_iter()
+            .map(|part_file| {
+                let sprite_path = format!("{}.spr", part_file.file_path);
+                let sprites = match &part_file.palette_path {
+                    Some(palette_path) => sprite_loader.get_or_load_with_palette(&sprite_path, &format!("{palette_path}.pal")),
+                    None => sprite_loader.get_or_load(&sprite_path),
+                };
+
+                AnimationPair {
+                    sprites: sprites.unwrap(),
+                    actions: action_loader.get_or_load(&format!("{}.act", part_file.file_path)).unwrap(),
+                }
             })
             .collect();
 
@@ -131,14 +150,14 @@ impl AnimationLoader {
                         let mirror = sprite_clip.mirror_on != 0;
 
                         // Attach points have a different offset calculation.
-                        // Currently, this is hardcoded for the player heads. An `animation_index` of
-                        // `0` corresponds to the head, and `1` for the body.
+                        // An `animation_index` of `0` corresponds to the body, every other part
+                        // (head, headgears, ...) with an attach point is attached to it.
                         let has_attach_point = match motion.attach_point_count {
                             Some(value) => value == 1,
                             None => false,
                         };
 
-                        if entity_type == EntityType::Player && has_attach_point && animation_index == 1 {
+                        if entity_type == EntityType::Player && has_attach_point && animation_index > 0 {
                             let parent_animation_pair = &animation_pairs[0];
                             let parent_action = &parent_animation_pair.actions.actions[action_index];
                             // TODO: Precompute the size of each motion from the animation pair.
@@ -148,7 +167,10 @@ impl AnimationLoader {
                                 continue;
                             }
                             let parent_motion = &parent_action.motions[motion_index];
-                            let parent_attach_point = parent_motion.attach_points[0].position;
+                            let Some(parent_attach_point) = parent_motion.attach_points.first().map(|attach_point| attach_point.position)
+                            else {
+                                continue;
+                            };
                             let attach_point = motion.attach_points[0].position;
                             let new_offset = -attach_point + parent_attach_point;
                             offset += new_offset;
@@ -216,7 +238,30 @@ impl AnimationLoader {
             for motion_index in 0..motion_size {
                 let mut generate: Vec<AnimationFrame> = Vec::new();
 
-                for pair in &animations_list[0..animation_pair_size] {
+                // Parts behind the body, like the cart of a merchant, need to come first so
+                // they are drawn behind everything else. They don't have an action for every
+                // action of the body, so they repeat the actions they have.
+                for (pair, _) in animations_list[0..animation_pair_size]
+                    .iter()
+                    .zip(behind_body.iter())
+                    .filter(|(_, behind_body)| **behind_body)
+                {
+                    if pair.is_empty() {
+                        continue;
+                    }
+
+                    let action = &pair[action_index % pair.len()];
+
+                    if !action.is_empty() {
+                        generate.push(action[motion_index % action.len()].clone());
+                    }
+                }
+
+                for (pair, _) in animations_list[0..animation_pair_size]
+                    .iter()
+                    .zip(behind_body.iter())
+                    .filter(|(_, behind_body)| !**behind_body)
+                {
                     if pair.len() <= action_index || pair[action_index].len() <= motion_index {
                         continue;
                     }
@@ -322,7 +367,11 @@ impl AnimationLoader {
             print_debug!(
                 "[{}] animation could not be added to cache. Entity Files: '{}': {:?}",
                 "error".red(),
-                &entity_part_files.join(";"),
+                &entity_part_files
+                    .iter()
+                    .map(|part_file| part_file.file_path.as_str())
+                    .collect::<Vec<_>>()
+                    .join(";"),
                 error
             );
         }
@@ -330,7 +379,7 @@ impl AnimationLoader {
         Ok(animation_data)
     }
 
-    pub fn get(&self, entity_part_files: &[String]) -> Option<Arc<AnimationData>> {
+    pub fn get(&self, entity_part_files: &[EntityPartFile]) -> Option<Arc<AnimationData>> {
         let mut lock = self.cache.lock().unwrap();
         lock.get(entity_part_files).cloned()
     }
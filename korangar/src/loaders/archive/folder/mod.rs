@@ -12,6 +12,7 @@ use korangar_debug::logging::print_debug;
 use walkdir::WalkDir;
 
 use super::{Archive, Compression, Writable, os_specific_path};
+use crate::loaders::error::LoadError;
 
 pub struct FolderArchive {
     folder_path: PathBuf,
@@ -66,11 +67,11 @@ impl FolderArchive {
 }
 
 impl Archive for FolderArchive {
-    fn from_path(path: &Path) -> Self {
+    fn from_path(path: &Path) -> Result<Self, LoadError> {
         let folder_path = PathBuf::from(path);
         let file_mapping = Self::load_mapping(&folder_path);
 
-        Self { folder_path, file_mapping }
+        Ok(Self { folder_path, file_mapping })
     }
 
     fn file_exists(&self, asset_path: &str) -> bool {
@@ -4,8 +4,11 @@ pub mod seven_zip;
 
 use std::path::{Path, PathBuf};
 
+use super::error::LoadError;
+
 pub trait Archive: Send + Sync {
-    fn from_path(path: &Path) -> Self
+    /// Opens the archive at the given path.
+    fn from_path(path: &Path) -> Result<Self, LoadError>
     where
         Self: Sized;
 
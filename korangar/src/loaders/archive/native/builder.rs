@@ -13,7 +13,6 @@ use flate2::bufread::ZlibEncoder;
 use ragnarok_bytes::{ByteWriter, FixedByteSize, ToBytes};
 use ragnarok_formats::archive::{AssetTable, FileTableRow, Header};
 
-use super::FileTable;
 use crate::loaders::archive::{Compression, Writable};
 
 struct FileTableEntry {
@@ -48,7 +47,7 @@ impl Writable for NativeArchiveBuilder {
     fn finish(&mut self) -> Result<(), std::io::Error> {
         let file = File::create(self.os_file_path.as_path())?;
         let mut file_writer = BufWriter::new(file);
-        let mut file_table = FileTable::new();
+        let mut file_table = HashMap::new();
 
         let dummy_header_bytes = vec![0; Header::size_in_bytes()];
         file_writer.write_all(&dummy_header_bytes)?;
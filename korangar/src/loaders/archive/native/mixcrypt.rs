@@ -1,7 +1,5 @@
 //! Implements the mixcrypt scheme use by the original client.
 
-use ragnarok_formats::archive::FileTableRow;
-
 /// File uses a mixed crypto (Simple DES + Shuffle):
 /// - Encrypts the first 0x14 blocks
 /// - Encrypts blocks at interval N, where N equals the digit count of original
@@ -17,13 +15,52 @@ const HEADER_BLOCKS_SIZE: usize = 0x14;
 
 const BLOCK_SIZE: usize = 8;
 
+/// Files with these extensions only have their header encrypted in version
+/// 0x103 archives.
+const LEGACY_HEADER_CRYPT_EXTENSIONS: [&str; 4] = [".gnd", ".gat", ".act", ".str"];
+
 /// Decrypts a file using the appropriate decryption method.
-pub fn decrypt_file(file_information: &FileTableRow, data: &mut [u8]) {
-    if let Some((is_limited_crypt, cycle)) = determine_encryption_scheme(file_information.flags, file_information.compressed_size) {
+pub fn decrypt_file(flags: u8, compressed_size: u32, data: &mut [u8]) {
+    if let Some((is_limited_crypt, cycle)) = determine_encryption_scheme(flags, compressed_size) {
         decrypt_data(data, is_limited_crypt, cycle);
     }
 }
 
+/// Version 0x103 archives don't store the encryption scheme of a file, it
+/// depends on the file extension instead. Every file is encrypted.
+pub fn legacy_encryption_flags(file_name: &str) -> u8 {
+    match LEGACY_HEADER_CRYPT_EXTENSIONS
+        .iter()
+        .any(|extension| file_name.ends_with(extension))
+    {
+        true => GRF_FLAG_HEADER_DES_CRYPT,
+        false => GRF_FLAG_FULL_MIX_CRYPT,
+    }
+}
+
+/// Decrypts a file name of a version 0x103 archive in place. Every block has
+/// its nibbles swapped and is then decoded the same way as the file content.
+pub fn decrypt_file_name(data: &mut [u8]) {
+    for block_data in data.chunks_exact_mut(BLOCK_SIZE) {
+        block_data.iter_mut().for_each(|byte| *byte = byte.rotate_left(4));
+
+        let mut block = u64::from_be_bytes(block_data.try_into().unwrap());
+        block = decode_des_block(block);
+        block_data.copy_from_slice(&block.to_be_bytes());
+    }
+}
+
+/// Inverse of [`decrypt_file_name`]. The block decoding is its own inverse,
+/// so only the order of the steps is reversed.
+#[cfg(test)]
+pub fn encrypt_file_name(data: &mut [u8]) {
+    for block_data in data.chunks_exact_mut(BLOCK_SIZE) {
+        let block = decode_des_block(u64::from_be_bytes(block_data.try_into().unwrap()));
+        block_data.copy_from_slice(&block.to_be_bytes());
+        block_data.iter_mut().for_each(|byte| *byte = byte.rotate_left(4));
+    }
+}
+
 /// Determines the encryption scheme used for the file.
 /// Returns `Some((only_header_is_encrypted, cycle_length))` if the file is
 /// encrypted, `None` otherwise.
@@ -310,4 +347,53 @@ mod tests {
             expected, result
         );
     }
+
+    #[test]
+    fn decode_des_block_is_its_own_inverse() {
+        let block: u64 = 0x0123_4567_89AB_CDEF;
+
+        assert_eq!(decode_des_block(decode_des_block(block)), block);
+    }
+
+    #[test]
+    fn decrypt_file_name_restores_name() {
+        let file_name = *b"data\\prontera.gat\0\0\0\0\0\0\0";
+        let mut data = file_name;
+
+        encrypt_file_name(&mut data);
+        assert_ne!(data, file_name);
+
+        decrypt_file_name(&mut data);
+        assert_eq!(data, file_name);
+    }
+
+    #[test]
+    fn decrypt_file_name_swaps_nibbles_before_decoding() {
+        let mut data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+        let expected = decode_des_block(0x2143_6587_A9CB_ED0F).to_be_bytes();
+
+        decrypt_file_name(&mut data);
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn decrypt_file_name_ignores_incomplete_block() {
+        let mut data = *b"abcdefghij";
+
+        decrypt_file_name(&mut data);
+        assert_eq!(&data[BLOCK_SIZE..], b"ij");
+    }
+
+    #[test]
+    fn legacy_encryption_flags_by_extension() {
+        assert_eq!(legacy_encryption_flags("data\\prontera.gnd"), GRF_FLAG_HEADER_DES_CRYPT);
+        assert_eq!(legacy_encryption_flags("data\\prontera.gat"), GRF_FLAG_HEADER_DES_CRYPT);
+        assert_eq!(legacy_encryption_flags("data\\sprite\\poring.act"), GRF_FLAG_HEADER_DES_CRYPT);
+        assert_eq!(
+            legacy_encryption_flags("data\\texture\\effect\\heal.str"),
+            GRF_FLAG_HEADER_DES_CRYPT
+        );
+        assert_eq!(legacy_encryption_flags("data\\sprite\\poring.spr"), GRF_FLAG_FULL_MIX_CRYPT);
+        assert_eq!(legacy_encryption_flags("data\\prontera.rsw"), GRF_FLAG_FULL_MIX_CRYPT);
+    }
 }
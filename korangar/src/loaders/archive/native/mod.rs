@@ -13,75 +13,247 @@ use blake3::Hasher;
 use flate2::bufread::ZlibDecoder;
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, Timer, print_debug};
+use korangar_loaders::FileNotFoundError;
 use ragnarok_bytes::{ByteReader, FixedByteSize, FromBytes};
-use ragnarok_formats::archive::{AssetTable, FileTableRow, Header};
+use ragnarok_formats::archive::{AssetTable, FileTableRow, Header, LargeFileTableRow};
 
 pub use self::builder::NativeArchiveBuilder;
 use crate::loaders::archive::Archive;
-use crate::loaders::archive::native::mixcrypt::decrypt_file;
+use crate::loaders::archive::native::mixcrypt::{decrypt_file, decrypt_file_name, legacy_encryption_flags};
+use crate::loaders::error::LoadError;
+
+/// Flag of file table entries that are files rather than directories.
+const GRF_FLAG_FILE: u8 = 1;
+/// Number of bytes that follow the file name in a row of a version 0x103
+/// file table.
+const LEGACY_ROW_SIZE: usize = 17;
+
+/// Location and encryption of a single asset inside the archive. The file
+/// table rows differ between archive versions, so they are all converted to
+/// this.
+#[derive(Clone, Debug)]
+struct AssetInformation {
+    compressed_size: u32,
+    compressed_size_aligned: u32,
+    uncompressed_size: u32,
+    flags: u8,
+    offset: u64,
+}
+
+/// A row of a compressed file table.
+trait FileTableEntry: FromBytes {
+    /// Number of unknown bytes in front of the file table of the archive
+    /// version using this row.
+    const TABLE_PADDING: i64;
+
+    fn into_asset(self) -> (String, AssetInformation);
+}
+
+impl FileTableEntry for FileTableRow {
+    const TABLE_PADDING: i64 = 0;
+
+    fn into_asset(self) -> (String, AssetInformation) {
+        (self.file_name, AssetInformation {
+            compressed_size: self.compressed_size,
+            compressed_size_aligned: self.compressed_size_aligned,
+            uncompressed_size: self.uncompressed_size,
+            flags: self.flags,
+            offset: self.offset as u64,
+        })
+    }
+}
+
+impl FileTableEntry for LargeFileTableRow {
+    const TABLE_PADDING: i64 = 4;
+
+    fn into_asset(self) -> (String, AssetInformation) {
+        (self.file_name, AssetInformation {
+            compressed_size: self.compressed_size,
+            compressed_size_aligned: self.compressed_size_aligned,
+            uncompressed_size: self.uncompressed_size,
+            flags: self.flags,
+            offset: self.offset,
+        })
+    }
+}
 
 /// Represents a GRF file. GRF Files are an archive to store game assets.
 /// Each GRF contains a [`Header`] with metadata (number of files, size,
 /// etc.) and a table [`AssetTable`] with information about individual assets.
-type FileTable = HashMap<String, FileTableRow>;
+type FileTable = HashMap<String, AssetInformation>;
 
 pub struct NativeArchive {
     file_table: FileTable,
     file_handle: Mutex<File>,
 }
 
-impl Archive for NativeArchive {
-    fn from_path(path: &Path) -> Self {
-        #[cfg(feature = "debug")]
-        let timer = Timer::new_dynamic(format!("load game data from {}", path.display().magenta()));
+impl NativeArchive {
+    fn read_header(file: &mut impl Read) -> Result<Header, LoadError> {
+        let mut file_header_buffer = vec![0u8; Header::size_in_bytes()];
+        file.read_exact(&mut file_header_buffer)
+            .map_err(|error| LoadError::UnsupportedFormat(format!("can't read archive header: {error}")))?;
 
-        let mut file = match File::open(path) {
-            Ok(file) => file,
-            Err(error) => {
-                panic!("Unable to find archive {path:?}, does the file exist in the `archive` directory?\nError: {error:?}");
-            }
-        };
+        Header::from_bytes(&mut ByteReader::without_metadata(&file_header_buffer)).map_err(LoadError::Conversion)
+    }
 
-        let mut file_header_buffer = vec![0u8; Header::size_in_bytes()];
-        file.read_exact(&mut file_header_buffer).unwrap();
-        let file_header = Header::from_bytes(&mut ByteReader::without_metadata(&file_header_buffer)).unwrap();
+    fn seek_file_table(file: &mut impl Seek, file_header: &Header) -> Result<(), LoadError> {
+        let file_table_position = (Header::size_in_bytes() as u64)
+            .checked_add(file_header.get_file_table_offset())
+            .ok_or_else(|| LoadError::UnsupportedFormat("file table offset of the archive is out of range".to_owned()))?;
+
+        file.seek(SeekFrom::Start(file_table_position))
+            .map_err(|error| LoadError::UnsupportedFormat(format!("can't seek to file table: {error}")))?;
 
-        assert_eq!(file_header.version, 0x200, "invalid grf version");
+        Ok(())
+    }
 
-        let _ = file.seek(SeekFrom::Current(file_header.file_table_offset as i64)).unwrap();
+    /// Reads the compressed file table used by version 0x200 and 0x300
+    /// archives.
+    fn read_file_table<Row: FileTableEntry>(file: &mut (impl Read + Seek), file_header: &Header) -> Result<FileTable, LoadError> {
+        let truncated = || LoadError::UnsupportedFormat("file table of the archive is truncated or corrupt".to_owned());
+
+        Self::seek_file_table(file, file_header)?;
+        file.seek(SeekFrom::Current(Row::TABLE_PADDING))
+            .map_err(|error| LoadError::UnsupportedFormat(format!("can't seek to file table: {error}")))?;
+
+        let file_count = file_header.get_file_count();
         let mut file_table_buffer = vec![0; AssetTable::size_in_bytes()];
 
-        file.read_exact(&mut file_table_buffer).unwrap();
-        let file_table = AssetTable::from_bytes(&mut ByteReader::without_metadata(&file_table_buffer)).expect("can't read file table");
+        file.read_exact(&mut file_table_buffer).map_err(|_| truncated())?;
+        let file_table = AssetTable::from_bytes(&mut ByteReader::without_metadata(&file_table_buffer)).map_err(LoadError::Conversion)?;
 
-        let mut compressed_file_table_buffer = vec![0u8; file_table.compressed_size as usize];
-        file.read_exact(&mut compressed_file_table_buffer).unwrap();
+        // Read through `take` so that a corrupt size can't make us allocate
+        // more than what is actually in the archive.
+        let mut compressed_file_table_buffer = Vec::new();
+        file.by_ref()
+            .take(file_table.compressed_size as u64)
+            .read_to_end(&mut compressed_file_table_buffer)
+            .map_err(|_| truncated())?;
 
-        let mut decoder = ZlibDecoder::new(compressed_file_table_buffer.as_slice());
-        let mut decompressed = Vec::with_capacity(file_table.uncompressed_size as usize);
-        decoder.read_to_end(&mut decompressed).expect("can't decompress file table");
+        if compressed_file_table_buffer.len() != file_table.compressed_size as usize {
+            return Err(truncated());
+        }
 
-        let file_count = file_header.get_file_count();
+        let mut decompressed = Vec::new();
+        ZlibDecoder::new(compressed_file_table_buffer.as_slice())
+            .take(file_table.uncompressed_size as u64)
+            .read_to_end(&mut decompressed)
+            .map_err(|error| LoadError::UnsupportedFormat(format!("can't decompress file table: {error}")))?;
 
         let mut file_table_byte_reader = ByteReader::without_metadata(&decompressed);
-        let mut assets = HashMap::with_capacity(file_count);
+        // Don't trust the file count of the header with the allocation, a corrupt
+        // archive could claim any number of files. Rows are never smaller than the
+        // fixed part of a legacy row.
+        let mut assets = HashMap::with_capacity(file_count.min(decompressed.len() / LEGACY_ROW_SIZE));
 
         for _index in 0..file_count {
-            let file_information = FileTableRow::from_bytes(&mut file_table_byte_reader).unwrap();
-            let file_name = file_information.file_name.to_lowercase();
+            let (file_name, asset_information) = Row::from_bytes(&mut file_table_byte_reader)
+                .map_err(LoadError::Conversion)?
+                .into_asset();
+            assets.insert(file_name.to_lowercase(), asset_information);
+        }
+
+        Ok(assets)
+    }
+
+    /// Reads the file table of version 0x103 archives. The file table is not
+    /// compressed, but the file names are encrypted and the sizes and offsets
+    /// are obfuscated.
+    fn read_legacy_file_table(file: &mut (impl Read + Seek), file_header: &Header) -> Result<FileTable, LoadError> {
+        Self::seek_file_table(file, file_header)?;
+
+        // The file table reaches until the end of the archive.
+        let mut file_table_buffer = Vec::new();
+        file.read_to_end(&mut file_table_buffer)
+            .map_err(|error| LoadError::UnsupportedFormat(format!("can't read file table: {error}")))?;
+
+        parse_legacy_file_table(&file_table_buffer, file_header.get_file_count())
+    }
+}
 
-            assets.insert(file_name, file_information);
+/// Parses the rows of a version 0x103 file table. Every row starts with the
+/// length of the encrypted file name, followed by the name and
+/// [`LEGACY_ROW_SIZE`] bytes of sizes, flags and offset.
+fn parse_legacy_file_table(file_table: &[u8], file_count: usize) -> Result<FileTable, LoadError> {
+    let truncated = || LoadError::UnsupportedFormat("file table of the archive is truncated or corrupt".to_owned());
+    let read_u32 = |position: usize| {
+        file_table
+            .get(position..position + 4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or_else(truncated)
+    };
+
+    // Don't trust the file count of the header with the allocation, a corrupt
+    // archive could claim any number of files.
+    let mut assets = HashMap::with_capacity(file_count.min(file_table.len() / LEGACY_ROW_SIZE));
+    let mut position = 0;
+
+    for _index in 0..file_count {
+        let name_length = read_u32(position)? as usize;
+        let entry_position = position
+            .checked_add(name_length)
+            .and_then(|position| position.checked_add(4))
+            .ok_or_else(truncated)?;
+        let flags = *file_table.get(entry_position + 12).ok_or_else(truncated)?;
+
+        if flags & GRF_FLAG_FILE != 0 {
+            let mut file_name_buffer = file_table.get(position + 6..position + name_length).ok_or_else(truncated)?.to_vec();
+            decrypt_file_name(&mut file_name_buffer);
+
+            let file_name = String::from_bytes(&mut ByteReader::without_metadata(&file_name_buffer))
+                .map_err(LoadError::Conversion)?
+                .to_lowercase();
+            let uncompressed_size = read_u32(entry_position + 8)?;
+
+            // The sizes are obfuscated by adding constant values.
+            let asset_information = AssetInformation {
+                compressed_size: read_u32(entry_position)?.wrapping_sub(uncompressed_size).wrapping_sub(715),
+                compressed_size_aligned: read_u32(entry_position + 4)?.wrapping_sub(37579),
+                uncompressed_size,
+                flags: flags | legacy_encryption_flags(&file_name),
+                offset: read_u32(entry_position + 13)? as u64,
+            };
+
+            assets.insert(file_name, asset_information);
         }
 
+        position = entry_position + LEGACY_ROW_SIZE;
+    }
+
+    Ok(assets)
+}
+
+impl Archive for NativeArchive {
+    fn from_path(path: &Path) -> Result<Self, LoadError> {
+        #[cfg(feature = "debug")]
+        let timer = Timer::new_dynamic(format!("load game data from {}", path.display().magenta()));
+
+        let mut file = File::open(path).map_err(|_| LoadError::File(FileNotFoundError::new(path.display().to_string())))?;
+
+        let file_header = Self::read_header(&mut file)?;
+
+        let file_table = match file_header.version {
+            0x103 => Self::read_legacy_file_table(&mut file, &file_header)?,
+            0x200 => Self::read_file_table::<FileTableRow>(&mut file, &file_header)?,
+            0x300 => Self::read_file_table::<LargeFileTableRow>(&mut file, &file_header)?,
+            version => {
+                return Err(LoadError::UnsupportedFormat(format!(
+                    "unsupported GRF version 0x{version:X} in {}",
+                    path.display()
+                )));
+            }
+        };
+
         #[cfg(feature = "debug")]
         timer.stop();
 
         // TODO: only take 64..? bytes so that loaded game archives can be extended
         //       as well.
-        Self {
-            file_table: assets,
+        Ok(Self {
+            file_table,
             file_handle: Mutex::new(file),
-        }
+        })
     }
 
     fn file_exists(&self, asset_path: &str) -> bool {
@@ -92,7 +264,7 @@ impl Archive for NativeArchive {
         self.file_table.get(asset_path).map(|file_information| {
             let mut compressed_file_buffer = vec![0u8; file_information.compressed_size_aligned as usize];
 
-            let position = file_information.offset as u64 + Header::size_in_bytes() as u64;
+            let position = file_information.offset + Header::size_in_bytes() as u64;
 
             {
                 // Since the calling threads are sharing the IO bandwidth anyhow, I don't think
@@ -104,7 +276,11 @@ impl Archive for NativeArchive {
                     .expect("can't read archive content");
             }
 
-            decrypt_file(file_information, &mut compressed_file_buffer);
+            decrypt_file(
+                file_information.flags,
+                file_information.compressed_size,
+                &mut compressed_file_buffer,
+            );
 
             let mut decoder = ZlibDecoder::new(compressed_file_buffer.as_slice());
             let mut decompressed = Vec::with_capacity(file_information.uncompressed_size as usize);
@@ -132,3 +308,144 @@ impl Archive for NativeArchive {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use flate2::bufread::ZlibEncoder;
+    use ragnarok_bytes::{ByteReader, ByteWriter, FromBytes, ToBytes};
+    use ragnarok_formats::archive::{AssetTable, FileTableRow, Header};
+
+    use super::mixcrypt::{GRF_FLAG_FULL_MIX_CRYPT, GRF_FLAG_HEADER_DES_CRYPT, encrypt_file_name};
+    use super::{FileTable, GRF_FLAG_FILE, NativeArchive, parse_legacy_file_table};
+    use crate::loaders::error::LoadError;
+
+    fn archive(rows: &[FileTableRow]) -> Vec<u8> {
+        let mut byte_writer = ByteWriter::new();
+
+        for row in rows {
+            row.to_bytes(&mut byte_writer).unwrap();
+        }
+
+        let mut compressed = Vec::new();
+        ZlibEncoder::new(byte_writer.as_slice(), flate2::Compression::default())
+            .read_to_end(&mut compressed)
+            .unwrap();
+
+        let asset_table = AssetTable {
+            compressed_size: compressed.len() as u32,
+            uncompressed_size: byte_writer.len() as u32,
+        };
+
+        let mut archive_writer = ByteWriter::new();
+        let raw_file_count = rows.len() as u32 + Header::FILE_OFFSET as u32;
+        Header::new(0, 0, raw_file_count, 0x200).to_bytes(&mut archive_writer).unwrap();
+        asset_table.to_bytes(&mut archive_writer).unwrap();
+
+        let mut archive = archive_writer.as_slice().to_vec();
+        archive.extend(compressed);
+        archive
+    }
+
+    fn read_archive(archive: &[u8]) -> Result<FileTable, LoadError> {
+        let mut cursor = Cursor::new(archive);
+        let file_header = NativeArchive::read_header(&mut cursor)?;
+        NativeArchive::read_file_table::<FileTableRow>(&mut cursor, &file_header)
+    }
+
+    fn legacy_row(file_name: &str, flags: u8, compressed_size: u32, uncompressed_size: u32, offset: u32) -> Vec<u8> {
+        let mut name = file_name.as_bytes().to_vec();
+        name.resize(name.len().next_multiple_of(8).max(name.len() + 1), 0);
+        encrypt_file_name(&mut name);
+
+        let name_length = name.len() as u32 + 6;
+        let mut row = name_length.to_le_bytes().to_vec();
+        row.extend_from_slice(&[0; 2]);
+        row.extend_from_slice(&name);
+        row.extend_from_slice(&[0; 4]);
+        row.extend_from_slice(&(compressed_size + uncompressed_size + 715).to_le_bytes());
+        row.extend_from_slice(&(compressed_size.next_multiple_of(8) + 37579).to_le_bytes());
+        row.extend_from_slice(&uncompressed_size.to_le_bytes());
+        row.push(flags);
+        row.extend_from_slice(&offset.to_le_bytes());
+        row
+    }
+
+    #[test]
+    fn legacy_file_table() {
+        let mut file_table = legacy_row("data\\Prontera.gat", GRF_FLAG_FILE, 100, 250, 46);
+        file_table.extend(legacy_row("data\\texture", 0, 0, 0, 0));
+        file_table.extend(legacy_row("data\\poring.spr", GRF_FLAG_FILE, 10, 20, 146));
+
+        let assets = parse_legacy_file_table(&file_table, 3).unwrap();
+
+        assert_eq!(assets.len(), 2);
+
+        let map = &assets["data\\prontera.gat"];
+        assert_eq!(map.compressed_size, 100);
+        assert_eq!(map.compressed_size_aligned, 104);
+        assert_eq!(map.uncompressed_size, 250);
+        assert_eq!(map.offset, 46);
+        assert_eq!(map.flags, GRF_FLAG_FILE | GRF_FLAG_HEADER_DES_CRYPT);
+
+        let sprite = &assets["data\\poring.spr"];
+        assert_eq!(sprite.offset, 146);
+        assert_eq!(sprite.flags, GRF_FLAG_FILE | GRF_FLAG_FULL_MIX_CRYPT);
+    }
+
+    #[test]
+    fn truncated_legacy_file_table() {
+        let file_table = legacy_row("data\\prontera.gat", GRF_FLAG_FILE, 100, 250, 46);
+
+        for length in 0..file_table.len() {
+            assert!(parse_legacy_file_table(&file_table[..length], 1).is_err());
+        }
+
+        // More files than there are rows.
+        assert!(parse_legacy_file_table(&file_table, 2).is_err());
+    }
+
+    #[test]
+    fn corrupt_legacy_name_length() {
+        let mut file_table = legacy_row("data\\prontera.gat", GRF_FLAG_FILE, 100, 250, 46);
+        file_table[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        assert!(parse_legacy_file_table(&file_table, 1).is_err());
+    }
+
+    #[test]
+    fn truncated_archive() {
+        let archive = archive(&[FileTableRow {
+            file_name: "data\\Prontera.gat".to_owned(),
+            compressed_size: 100,
+            compressed_size_aligned: 104,
+            uncompressed_size: 250,
+            flags: GRF_FLAG_FILE,
+            offset: 46,
+        }]);
+
+        assert_eq!(read_archive(&archive).unwrap()["data\\prontera.gat"].offset, 46);
+
+        for length in 0..archive.len() {
+            assert!(read_archive(&archive[..length]).is_err());
+        }
+    }
+
+    #[test]
+    fn large_archive_header() {
+        let mut header = b"Master of Magic\0".to_vec();
+        header.extend_from_slice(&[0; 14]);
+        header.extend_from_slice(&0x2000_0000u32.to_le_bytes());
+        header.extend_from_slice(&1u32.to_le_bytes());
+        header.extend_from_slice(&107u32.to_le_bytes());
+        header.extend_from_slice(&0x300u32.to_le_bytes());
+
+        let header = Header::from_bytes(&mut ByteReader::without_metadata(&header)).unwrap();
+
+        assert_eq!(header.version, 0x300);
+        // The upper half of the offset is stored in place of the reserved files.
+        assert_eq!(header.get_file_table_offset(), 0x1_2000_0000);
+        assert_eq!(header.get_file_count(), 100);
+    }
+}
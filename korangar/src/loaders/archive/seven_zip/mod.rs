@@ -11,10 +11,12 @@ use blake3::Hasher;
 use hashbrown::HashMap;
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, Timer, print_debug};
+use korangar_loaders::FileNotFoundError;
 use sevenz_rust2::BlockDecoder;
 
 pub use self::builder::SevenZipArchiveBuilder;
 use crate::loaders::archive::{Archive, Compression};
+use crate::loaders::error::LoadError;
 
 const MB_1: u64 = 1024 * 1024;
 const MB_4: u64 = 4 * MB_1;
@@ -49,10 +51,10 @@ impl SevenZipArchive {
 }
 
 impl Archive for SevenZipArchive {
-    fn from_path(path: &Path) -> Self {
+    fn from_path(path: &Path) -> Result<Self, LoadError> {
         #[cfg(feature = "debug")]
         let timer = Timer::new_dynamic(format!("load game data from {}", path.display().magenta()));
-        let mut archive_file = File::open(path).expect("can't open archive");
+        let mut archive_file = File::open(path).map_err(|_| LoadError::File(FileNotFoundError::new(path.display().to_string())))?;
         let password = sevenz_rust2::Password::empty();
 
         let archive = sevenz_rust2::Archive::read(&mut archive_file, &password).expect("can't read archive");
@@ -90,12 +92,12 @@ impl Archive for SevenZipArchive {
         #[cfg(feature = "debug")]
         timer.stop();
 
-        Self {
+        Ok(Self {
             archive,
             password,
             file_lookup,
             file_path: PathBuf::from(path),
-        }
+        })
     }
 
     fn file_exists(&self, asset_path: &str) -> bool {
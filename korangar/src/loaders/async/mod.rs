@@ -6,7 +6,7 @@ use hashbrown::HashMap;
 use korangar_debug::logging::print_debug;
 #[cfg(feature = "debug")]
 use korangar_debug::profiling::Profiler;
-use korangar_networking::{InventoryItem, NoMetadata, ShopItem};
+use korangar_networking::{InventoryItem, NoMetadata, ShopItem, VendingItem};
 use ragnarok_packets::{EntityId, ItemId, TilePosition};
 use rayon::{ThreadPool, ThreadPoolBuilder};
 
@@ -16,12 +16,19 @@ use crate::loaders::error::LoadError;
 use crate::loaders::{ActionLoader, AnimationLoader, ImageType, MapLoader, ModelLoader, SpriteLoader, TextureLoader, VideoLoader};
 #[cfg(feature = "debug")]
 use crate::threads;
-use crate::world::{AnimationData, EntityType, ItemName, ItemNameKey, ItemResource, ItemResourceKey, Library, Map, ResourceMetadata};
+use crate::world::{
+    AnimationData, EntityPartFile, EntityType, ItemName, ItemNameKey, ItemResource, ItemResourceKey, Library, Map, ResourceMetadata,
+};
 
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
 pub enum ItemLocation {
     Inventory,
     Shop,
+    Trade,
+    Storage,
+    Cart,
+    Vending,
+    Mail,
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
@@ -99,7 +106,7 @@ impl AsyncLoader {
         &self,
         entity_id: EntityId,
         entity_type: EntityType,
-        entity_part_files: Vec<String>,
+        entity_part_files: Vec<EntityPartFile>,
     ) -> Option<Arc<AnimationData>> {
         match self.animation_loader.get(&entity_part_files) {
             Some(animation_data) => Some(animation_data),
@@ -157,7 +164,11 @@ impl AsyncLoader {
         }
     }
 
-    pub fn request_inventory_item_metadata_load(&self, item: InventoryItem<NoMetadata>) -> InventoryItem<ResourceMetadata> {
+    pub fn request_item_metadata_load(
+        &self,
+        item_location: ItemLocation,
+        item: InventoryItem<NoMetadata>,
+    ) -> InventoryItem<ResourceMetadata> {
         let is_identified = item.is_identified();
 
         let resource_name = self.library.get::<ItemResource>(ItemResourceKey {
@@ -165,7 +176,7 @@ impl AsyncLoader {
             is_identified,
         });
         let full_path = format!("유저인터페이스\\item\\{resource_name}.bmp");
-        let texture = self.request_item_sprite_load(ItemLocation::Inventory, item.item_id, &full_path, ImageType::Color);
+        let texture = self.request_item_sprite_load(item_location, item.item_id, &full_path, ImageType::Color);
         let name = self
             .library
             .get::<ItemName>(ItemNameKey {
@@ -199,6 +210,26 @@ impl AsyncLoader {
         ShopItem { metadata, ..item }
     }
 
+    pub fn request_vending_item_metadata_load(&self, item: VendingItem<NoMetadata>) -> VendingItem<ResourceMetadata> {
+        let resource_name = self.library.get::<ItemResource>(ItemResourceKey {
+            item_id: item.item_id,
+            is_identified: item.is_identified,
+        });
+        let full_path = format!("유저인터페이스\\item\\{resource_name}.bmp");
+        let texture = self.request_item_sprite_load(ItemLocation::Vending, item.item_id, &full_path, ImageType::Color);
+        let name = self
+            .library
+            .get::<ItemName>(ItemNameKey {
+                item_id: item.item_id,
+                is_identified: item.is_identified,
+            })
+            .to_string();
+
+        let metadata = ResourceMetadata { texture, name };
+
+        VendingItem { metadata, ..item }
+    }
+
     pub fn request_map_load(&self, map_name: String, position: Option<TilePosition>) {
         let map_loader = self.map_loader.clone();
         let model_loader = self.model_loader.clone();
@@ -95,7 +95,7 @@ pub fn sync_cache_archive(game_file_loader: &GameFileLoader, texture_loader: Arc
     builder.add_file(HASH_FILE_PATH, game_file_hash.to_hex().as_bytes().to_vec(), Compression::Off);
 
     if current_archive_exists {
-        let current_archive = Box::new(SevenZipArchive::from_path(path));
+        let current_archive = Box::new(SevenZipArchive::from_path(path).expect("can't open cache archive"));
         copy_existing_files(
             &mut builder,
             &current_archive,
@@ -169,7 +169,7 @@ fn analyze_files(
         return source_files.to_vec();
     }
 
-    let current_archive = SevenZipArchive::from_path(archive_path);
+    let current_archive = SevenZipArchive::from_path(archive_path).expect("can't open cache archive");
 
     let extension = match media_type {
         MediaType::Texture => DDS_FILE_EXTENSION,
@@ -19,6 +19,7 @@ use self::list::GameArchiveList;
 use super::archive::folder::FolderArchive;
 use super::archive::native::{NativeArchive, NativeArchiveBuilder};
 use super::archive::{Archive, ArchiveType, Compression, Writable};
+use super::error::LoadError;
 use crate::loaders::archive::seven_zip::{SevenZipArchive, SevenZipArchiveBuilder};
 
 pub(crate) const CACHE_FILE_NAME: &str = "cache.7z";
@@ -90,14 +91,14 @@ impl GameFileLoader {
         }
     }
 
-    fn load_archive_from_path(path: &str) -> Box<dyn Archive> {
+    fn load_archive_from_path(path: &str) -> Result<Box<dyn Archive>, LoadError> {
         let path = Path::new(path);
 
-        match GameFileLoader::get_archive_type_by_path(path) {
-            ArchiveType::Folder => Box::new(FolderArchive::from_path(path)),
-            ArchiveType::Native => Box::new(NativeArchive::from_path(path)),
-            ArchiveType::SevenZip => Box::new(SevenZipArchive::from_path(path)),
-        }
+        Ok(match GameFileLoader::get_archive_type_by_path(path) {
+            ArchiveType::Folder => Box::new(FolderArchive::from_path(path)?),
+            ArchiveType::Native => Box::new(NativeArchive::from_path(path)?),
+            ArchiveType::SevenZip => Box::new(SevenZipArchive::from_path(path)?),
+        })
     }
 
     pub fn load_archives_from_settings(&self) {
@@ -106,10 +107,16 @@ impl GameFileLoader {
 
         let game_archive_list = GameArchiveList::load();
 
-        game_archive_list.archives.iter().for_each(|path| {
-            let game_archive = Self::load_archive_from_path(path);
-            self.add_archive(game_archive, true);
-        });
+        game_archive_list
+            .archives
+            .iter()
+            .for_each(|path| match Self::load_archive_from_path(path) {
+                Ok(game_archive) => self.add_archive(game_archive, true),
+                Err(_error) => {
+                    #[cfg(feature = "debug")]
+                    print_debug!("[{}] failed to load archive {}: {:?}", "error".red(), path.magenta(), _error);
+                }
+            });
 
         #[cfg(feature = "debug")]
         timer.stop();
@@ -137,7 +144,7 @@ impl GameFileLoader {
             self.patch_lua_files();
         }
 
-        let lua_archive = Self::load_archive_from_path(LUA_ARCHIVE_FILE_NAME);
+        let lua_archive = Self::load_archive_from_path(LUA_ARCHIVE_FILE_NAME).expect("can't load lua archive");
         self.add_archive(lua_archive, false);
     }
 
@@ -163,7 +170,7 @@ impl GameFileLoader {
 
         let path = Path::new(LUA_ARCHIVE_FILE_NAME);
         let mut lua_archive: Box<dyn Writable> = match GameFileLoader::get_archive_type_by_path(path) {
-            ArchiveType::Folder => Box::new(FolderArchive::from_path(path)),
+            ArchiveType::Folder => Box::new(FolderArchive::from_path(path).expect("can't create lua archive")),
             ArchiveType::Native => Box::new(NativeArchiveBuilder::from_path(path)),
             ArchiveType::SevenZip => Box::new(SevenZipArchiveBuilder::from_path(path)),
         };
@@ -230,7 +237,14 @@ impl GameFileLoader {
             return;
         }
 
-        let archive = Box::new(SevenZipArchive::from_path(path));
+        let archive = match SevenZipArchive::from_path(path) {
+            Ok(archive) => Box::new(archive),
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!("Can't open cache archive: {:?}. Using empty cache", _error);
+                return;
+            }
+        };
 
         let Some(hash_file) = archive.get_file_by_path(HASH_FILE_PATH) else {
             #[cfg(feature = "debug")]
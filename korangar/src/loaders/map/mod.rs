@@ -22,7 +22,7 @@ use self::water_plane::generate_water_plane;
 use super::error::LoadError;
 use crate::graphics::{BindlessSupport, Buffer, ModelVertex, TextureSet};
 use crate::loaders::{GameFileLoader, ModelLoader, TextureLoader, TextureSetBuilder, VideoLoader, split_mesh_by_texture};
-use crate::world::{Library, LightSourceKey, Lighting, MapSkyData, Model, SubMesh, Video};
+use crate::world::{FogParameter, Library, LightSourceKey, Lighting, MapSkyData, Model, Sky, SubMesh, Video};
 use crate::{EffectSourceExt, LightSourceExt, Map, Object, ObjectKey, SoundSourceExt};
 
 pub const GROUND_TILE_SIZE: f32 = 10.0;
@@ -84,8 +84,10 @@ impl MapLoader {
         let map_file_name = format!("data\\{}.rsw", &resource_file);
         let mut map_data: MapData = parse_generic_data(&map_file_name, &self.game_file_loader)?;
 
-        // TODO: NHA Implement sky rendering
-        let _map_sky_data = library.get::<MapSkyData>(&resource_file);
+        let sky = Sky::new(
+            library.get::<MapSkyData>(&resource_file),
+            library.try_get::<FogParameter>(&resource_file),
+        );
 
         let ground_file = format!("data\\{}", map_data.ground_file);
         let ground_data: GroundData = parse_generic_data(&ground_file, &self.game_file_loader)?;
@@ -263,6 +265,7 @@ impl MapLoader {
             gat_data.map_height as u16,
             object_kdtree.root_boundary(),
             lighting,
+            sky,
             water_plane,
             gat_data.tiles,
             sub_meshes,
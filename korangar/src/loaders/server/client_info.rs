@@ -2,6 +2,7 @@ use std::hash::{DefaultHasher, Hash, Hasher};
 
 use korangar_interface::components::drop_down::DropDownItem;
 use korangar_interface::element::StateElement;
+use korangar_networking::PasswordEncryption;
 use rust_state::RustState;
 use serde::de::Error;
 use serde::{Deserialize, Deserializer};
@@ -72,6 +73,18 @@ pub struct ClientInfo {
     pub services: Vec<Service>,
 }
 
+impl ClientInfo {
+    /// How the password has to be sent to the login server. Method 2 takes
+    /// precedence if both methods are enabled.
+    pub fn password_encryption(&self) -> PasswordEncryption {
+        match (self.password_encrypt, self.password_encrypt2) {
+            (_, true) => PasswordEncryption::Method2,
+            (true, false) => PasswordEncryption::Method1,
+            (false, false) => PasswordEncryption::None,
+        }
+    }
+}
+
 /// The ClientInfo's Service structure
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default, Deserialize, RustState, StateElement)]
@@ -299,6 +312,7 @@ impl ServerType {
 
 #[derive(Debug, Clone, Copy, Deserialize, StateElement)]
 pub enum PacketVersion {
+    _20200401,
     _20220406,
     Unsupported(u64),
 }
@@ -310,6 +324,7 @@ where
     let version = Deserialize::deserialize(deserializer)?;
 
     match version {
+        20200401 => Ok(Some(PacketVersion::_20200401)),
         20220406 => Ok(Some(PacketVersion::_20220406)),
         _ => Ok(Some(PacketVersion::Unsupported(version))),
     }
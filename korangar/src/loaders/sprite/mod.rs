@@ -10,7 +10,8 @@ use korangar_debug::logging::{Colorize, Timer, print_debug};
 use korangar_interface::element::StateElement;
 use korangar_loaders::FileLoader;
 use ragnarok_bytes::{ByteReader, FromBytes};
-use ragnarok_formats::sprite::{PaletteColor, RgbaImageData, SpriteData};
+use ragnarok_formats::palette::PaletteData;
+use ragnarok_formats::sprite::{Palette, PaletteColor, PaletteImageData, RgbaImageData, SpriteData};
 use ragnarok_formats::version::InternalVersion;
 use rust_state::RustState;
 
@@ -28,16 +29,34 @@ pub struct Sprite {
     pub palette_size: usize,
     #[hidden_element]
     pub textures: Vec<Arc<Texture>>,
+    /// Palette indices of the palette images, so we can create the textures
+    /// for a different palette without decoding the sprite again.
+    #[hidden_element]
+    palette_images: Arc<Vec<PaletteImageData>>,
+    /// Sprites with a swapped palette share the RGBA textures with the
+    /// original sprite, so only the first textures are owned by them.
+    #[hidden_element]
+    owned_texture_count: usize,
     #[cfg(feature = "debug")]
     sprite_data: SpriteData,
 }
 
 impl Cacheable for Sprite {
     fn size(&self) -> usize {
-        self.textures.iter().map(|t| t.get_byte_size()).sum()
+        self.textures[..self.owned_texture_count].iter().map(|t| t.get_byte_size()).sum()
     }
 }
 
+// TODO: Move this to an extension trait in `korangar_loaders`.
+fn color_bytes(palette: &PaletteColor, index: u8) -> [u8; 4] {
+    let alpha = match index {
+        0 => 0,
+        _ => 255,
+    };
+
+    [palette.red, palette.green, palette.blue, alpha]
+}
+
 pub struct SpriteLoader {
     game_file_loader: Arc<GameFileLoader>,
     texture_loader: Arc<TextureLoader>,
@@ -124,36 +143,12 @@ impl SpriteLoader {
             })
             .collect();
 
-        // TODO: Move this to an extension trait in `korangar_loaders`.
-        pub fn color_bytes(palette: &PaletteColor, index: u8) -> [u8; 4] {
-            let alpha = match index {
-                0 => 0,
-                _ => 255,
-            };
-
-            [palette.red, palette.green, palette.blue, alpha]
-        }
-
-        let palette_images = sprite_data.palette_image_data.iter().map(|image_data| {
-            // Decode palette image data if necessary
-            let data: Vec<u8> = image_data
-                .data
-                .0
-                .iter()
-                .flat_map(|palette_index| color_bytes(&palette.colors[*palette_index as usize], *palette_index))
-                .collect();
-
-            RgbaImageData {
-                width: image_data.width,
-                height: image_data.height,
-                data,
-            }
-        });
+        let palette_images = self.create_palette_textures(path, &sprite_data.palette_image_data, &palette);
         let palette_size = palette_images.len();
 
-        let textures = palette_images
-            .chain(rgba_images)
-            .map(|mut image_data| {
+        let textures: Vec<Arc<Texture>> = palette_images
+            .into_iter()
+            .chain(rgba_images.into_iter().map(|mut image_data| {
                 premultiply_alpha(&mut image_data.data);
 
                 self.texture_loader.create_color(
@@ -161,12 +156,14 @@ impl SpriteLoader {
                     RgbaImage::from_raw(image_data.width as u32, image_data.height as u32, image_data.data).unwrap(),
                     false,
                 )
-            })
+            }))
             .collect();
 
         let sprite = Arc::new(Sprite {
             palette_size,
+            owned_texture_count: textures.len(),
             textures,
+            palette_images: Arc::new(sprite_data.palette_image_data),
             #[cfg(feature = "debug")]
             sprite_data: cloned_sprite_data,
         });
@@ -189,6 +186,106 @@ impl SpriteLoader {
         Ok(sprite)
     }
 
+    fn create_palette_textures(&self, path: &str, palette_images: &[PaletteImageData], palette: &Palette) -> Vec<Arc<Texture>> {
+        palette_images
+            .iter()
+            .map(|image_data| {
+                // Decode palette image data if necessary
+                let mut data: Vec<u8> = image_data
+                    .data
+                    .0
+                    .iter()
+                    .flat_map(|palette_index| color_bytes(&palette.colors[*palette_index as usize], *palette_index))
+                    .collect();
+
+                premultiply_alpha(&mut data);
+
+                self.texture_loader.create_color(
+                    path,
+                    RgbaImage::from_raw(image_data.width as u32, image_data.height as u32, data).unwrap(),
+                    false,
+                )
+            })
+            .collect()
+    }
+
+    fn load_palette(&self, palette_path: &str) -> Option<Palette> {
+        let bytes = match self.game_file_loader.get(&format!("data\\palette\\{palette_path}")) {
+            Ok(bytes) => bytes,
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!("Failed to load palette: {:?}", _error);
+
+                return None;
+            }
+        };
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+
+        match PaletteData::from_bytes(&mut byte_reader) {
+            Ok(palette_data) => Some(palette_data.palette),
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!("Failed to load palette: {:?}", _error);
+
+                None
+            }
+        }
+    }
+
+    fn load_with_palette(&self, path: &str, palette_path: &str, cache_key: String) -> Result<Arc<Sprite>, LoadError> {
+        let sprite = self.get_or_load(path)?;
+
+        // If the palette is missing we simply keep the colors of the sprite.
+        let Some(palette) = self.load_palette(palette_path) else {
+            return Ok(sprite);
+        };
+
+        #[cfg(feature = "debug")]
+        let timer = Timer::new_dynamic(format!("swap palette of {} with {}", path.magenta(), palette_path.magenta()));
+
+        // Only the palette images need new textures, the RGBA textures are shared
+        // with the original sprite.
+        let palette_textures = self.create_palette_textures(path, &sprite.palette_images, &palette);
+        let owned_texture_count = palette_textures.len();
+        let textures = palette_textures
+            .into_iter()
+            .chain(sprite.textures[sprite.palette_size..].iter().cloned())
+            .collect();
+
+        let sprite = Arc::new(Sprite {
+            palette_size: sprite.palette_size,
+            textures,
+            palette_images: sprite.palette_images.clone(),
+            owned_texture_count,
+            #[cfg(feature = "debug")]
+            sprite_data: sprite.sprite_data.clone(),
+        });
+
+        let _result = self.cache.lock().unwrap().insert(cache_key, sprite.clone());
+
+        #[cfg(feature = "debug")]
+        if let Err(error) = _result {
+            print_debug!(
+                "[{}] sprite could not be added to cache. Path: '{}' Palette: '{}': {:?}",
+                "error".red(),
+                &path,
+                &palette_path,
+                error
+            );
+        }
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        Ok(sprite)
+    }
+
+    /// Checks if a sprite exists without loading it. Useful for optional
+    /// sprites that should not be replaced by the fallback sprite.
+    pub fn sprite_exists(&self, path: &str) -> bool {
+        self.game_file_loader.file_exists(&format!("data\\sprite\\{path}").to_lowercase())
+    }
+
     pub fn get_or_load(&self, path: &str) -> Result<Arc<Sprite>, LoadError> {
         let Some(sprite) = self.cache.lock().unwrap().get(path).cloned() else {
             return self.load(path);
@@ -196,4 +293,16 @@ impl SpriteLoader {
 
         Ok(sprite)
     }
+
+    /// Loads a sprite with its embedded palette replaced by the palette at
+    /// `palette_path`.
+    pub fn get_or_load_with_palette(&self, path: &str, palette_path: &str) -> Result<Arc<Sprite>, LoadError> {
+        let cache_key = format!("{path}:{palette_path}");
+
+        let Some(sprite) = self.cache.lock().unwrap().get(&cache_key).cloned() else {
+            return self.load_with_palette(path, palette_path, cache_key);
+        };
+
+        Ok(sprite)
+    }
 }
@@ -5,6 +5,7 @@ use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use block_compression::{BC7Settings, CompressionVariant, GpuBlockCompressor};
+use flate2::bufread::ZlibDecoder;
 use hashbrown::HashMap;
 use image::{GrayImage, ImageBuffer, ImageFormat, ImageReader, Rgba, RgbaImage};
 #[cfg(feature = "debug")]
@@ -32,6 +33,10 @@ use crate::world::Video;
 
 const MAX_CACHE_COUNT: u32 = 4096;
 const MAX_CACHE_SIZE: usize = 512 << 20;
+/// Maximum size of a decompressed guild emblem. Emblems are 24x24 pixels, but
+/// this leaves room for larger emblems of up to 128x128 pixels with 24 bits
+/// per pixel.
+const MAXIMUM_EMBLEM_SIZE: u64 = 64 << 10;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum ImageType {
@@ -616,6 +621,37 @@ impl TextureLoader {
         Ok((image_buffer, transparent))
     }
 
+    /// Creates a texture from the raw emblem data sent by the map server.
+    /// Guild emblems are zlib compressed BMP files that use magenta as the
+    /// transparent color.
+    pub fn create_guild_emblem(&self, name: &str, emblem_data: &[u8]) -> Result<Arc<Texture>, LoadError> {
+        // Read one byte more than allowed so we can tell if the emblem is too large
+        // without inflating all of it.
+        let mut decoder = ZlibDecoder::new(emblem_data).take(MAXIMUM_EMBLEM_SIZE + 1);
+        let mut bitmap_data = Vec::new();
+        decoder
+            .read_to_end(&mut bitmap_data)
+            .map_err(|error| LoadError::UnsupportedFormat(format!("failed to decompress guild emblem: {error}")))?;
+
+        if bitmap_data.len() as u64 > MAXIMUM_EMBLEM_SIZE {
+            return Err(LoadError::UnsupportedFormat(format!(
+                "guild emblem is larger than {MAXIMUM_EMBLEM_SIZE} bytes"
+            )));
+        }
+
+        let mut image_buffer = ImageReader::with_format(Cursor::new(bitmap_data), ImageFormat::Bmp)
+            .decode()
+            .map_err(|error| LoadError::UnsupportedFormat(format!("failed to decode guild emblem: {error}")))?
+            .to_rgba8();
+
+        image_buffer
+            .pixels_mut()
+            .filter(|pixel| pixel.0[0] > 0xF0 && pixel.0[1] < 0x10 && pixel.0[2] > 0x0F)
+            .for_each(|pixel| *pixel = Rgba([0; 4]));
+
+        Ok(self.create_color(name, image_buffer, true))
+    }
+
     pub fn load_grayscale_texture_data(&self, path: &str) -> Result<GrayImage, LoadError> {
         #[cfg(feature = "debug")]
         let timer = Timer::new_dynamic(format!("load grayscale texture data from {}", path.magenta()));
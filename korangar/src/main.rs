@@ -29,6 +29,7 @@ macro_rules! time_phase {
     }
 }
 
+mod companion;
 mod graphics;
 mod input;
 mod state;
@@ -40,6 +41,7 @@ mod loaders;
 mod networking;
 mod renderer;
 mod settings;
+mod social;
 mod system;
 mod world;
 
@@ -49,10 +51,10 @@ use std::ops::Deref;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock, Mutex};
 
-use cgmath::{Point3, Vector3};
+use cgmath::{EuclideanSpace, Point3, Vector3};
 use image::{EncodableLayout, ImageFormat, ImageReader};
 use input::{MouseInputMode, MouseModeExt};
-use inventory::{HotbarPathExt, InventoryPathExt, SkillTreePathExt};
+use inventory::{CartPathExt, HotbarPathExt, InventoryPathExt, SkillTreePathExt, StoragePathExt};
 use korangar_audio::{AudioEngine, SoundEffectKey};
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, print_debug};
@@ -63,16 +65,14 @@ use korangar_debug::profiling::Profiler;
 use korangar_interface::Interface;
 use korangar_interface::layout::MouseButton;
 use korangar_networking::{
-    DisconnectReason, HotkeyState, LoginServerLoginData, MessageColor, NetworkEvent, NetworkEventBuffer, NetworkingSystem, SellItem,
-    SupportedPacketVersion,
+    CompanionType, ConnectionSettings, DisconnectReason, HotkeyState, LoginServerLoginData, MessageColor, NetworkEvent, NetworkEventBuffer,
+    NetworkingSystem, SellItem, Session, SessionRecorder, SupportedPacketVersion,
 };
 #[cfg(feature = "debug")]
 use networking::{PacketHistory, PacketHistoryCallback};
-#[cfg(not(feature = "debug"))]
-use ragnarok_packets::handler::NoPacketCallback;
 use ragnarok_packets::{
-    BuyShopItemsResult, CharacterServerInformation, Direction, DisappearanceReason, HotbarSlot, SellItemsResult, SkillId, SkillType,
-    TilePosition, UnitId, WorldPosition,
+    BuyShopItemsResult, CharacterServerInformation, Direction, DisappearanceReason, EntityId, GuildInformationType, HomunculusCommand,
+    HotbarSlot, MercenaryCommand, PetCommand, Price, SellItemsResult, SkillId, SkillType, TilePosition, WorldPosition,
 };
 use renderer::InterfaceRenderer;
 use rust_state::{Context, ManuallyAssertExt};
@@ -99,6 +99,7 @@ use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
 use winit::keyboard::PhysicalKey;
 use winit::window::{Icon, Window, WindowId};
 
+use crate::companion::{CompanionsPathExt, PetEgg};
 use crate::graphics::*;
 use crate::input::{InputEvent, InputSystem};
 use crate::interface::cursor::{MouseCursor, MouseCursorState};
@@ -109,6 +110,7 @@ use crate::loaders::*;
 use crate::renderer::DebugMarkerRenderer;
 use crate::renderer::{AlignHorizontal, EffectRenderer, GameInterfaceRenderer};
 use crate::settings::{GameSettingsPathExt, GraphicsSettings, IN_GAME_THEMES_PATH, LightingMode, MENU_THEMES_PATH, WORLD_THEMES_PATH};
+use crate::social::GuildEmblems;
 use crate::state::theme::{InterfaceTheme, InterfaceThemeType, WorldTheme};
 use crate::system::GameTimer;
 #[cfg(feature = "debug")]
@@ -320,8 +322,13 @@ fn main() {
 
     let args: Vec<String> = std::env::args().collect();
     let sync_cache = args.len() > 1 && &args[1] == "sync-cache";
+    let session_mode = match (args.get(1).map(String::as_str), args.get(2)) {
+        (Some("record-session"), Some(path)) => SessionMode::Record(path.clone()),
+        (Some("replay-session"), Some(path)) => SessionMode::Replay(path.clone()),
+        _ => SessionMode::Live,
+    };
 
-    let Some(mut client) = Client::init(sync_cache) else {
+    let Some(mut client) = Client::init(sync_cache, session_mode) else {
         return;
     };
 
@@ -338,6 +345,25 @@ fn initialize_shutdown_signal() {
     .expect("Error setting Ctrl-C handler");
 }
 
+/// Get the position of an entity. Falls back to the origin if the entity
+/// doesn't exist.
+fn entity_position(entities: &[Entity], entity_id: EntityId) -> Point3<f32> {
+    entities
+        .iter()
+        .find(|entity| entity.get_entity_id() == entity_id)
+        .map(|entity| entity.get_position())
+        .unwrap_or_else(Point3::origin)
+}
+
+/// Where the networking system gets its packets from.
+enum SessionMode {
+    Live,
+    /// Write every packet to a session file.
+    Record(String),
+    /// Replay a session file instead of connecting to the servers.
+    Replay(String),
+}
+
 struct Client {
     game_file_loader: Arc<GameFileLoader>,
     action_loader: Arc<ActionLoader>,
@@ -374,6 +400,7 @@ struct Client {
     point_shadow_entity_instructions: Vec<EntityInstruction>,
     point_light_with_shadow_instructions: Vec<PointLightWithShadowInstruction>,
     point_light_instructions: Vec<PointLightInstruction>,
+    cloud_instructions: Vec<CloudInstruction>,
 
     input_system: InputSystem,
 
@@ -408,6 +435,7 @@ struct Client {
     particle_holder: ParticleHolder,
     point_light_manager: PointLightManager,
     effect_holder: EffectHolder,
+    guild_emblems: GuildEmblems,
     path_finder: PathFinder,
 
     point_light_set_buffer: ResourceSetBuffer<LightSourceKey>,
@@ -425,9 +453,9 @@ struct Client {
     main_menu_click_sound_effect: SoundEffectKey,
 
     #[cfg(feature = "debug")]
-    networking_system: NetworkingSystem<PacketHistoryCallback>,
+    networking_system: NetworkingSystem<(PacketHistoryCallback, Option<SessionRecorder>)>,
     #[cfg(not(feature = "debug"))]
-    networking_system: NetworkingSystem<NoPacketCallback>,
+    networking_system: NetworkingSystem<Option<SessionRecorder>>,
     audio_engine: Arc<AudioEngine<GameFileLoader>>,
     active_interface_settings: InterfaceSettings,
     active_graphics_settings: GraphicsSettings,
@@ -442,7 +470,7 @@ struct Client {
 }
 
 impl Client {
-    fn init(sync_cache: bool) -> Option<Self> {
+    fn init(sync_cache: bool, session_mode: SessionMode) -> Option<Self> {
         time_phase!("load graphics settings", {
             let picker_value = Arc::new(AtomicU64::new(0));
             let directional_shadow_partitions = Arc::new(Mutex::new([DirectionalShadowPartition::default(); PARTITION_COUNT]));
@@ -629,6 +657,7 @@ impl Client {
             let point_shadow_entity_instructions = Vec::default();
             let point_light_with_shadow_instructions = Vec::default();
             let point_light_instructions = Vec::default();
+            let cloud_instructions = Vec::default();
         });
 
         time_phase!("create graphics engine", {
@@ -674,13 +703,48 @@ impl Client {
         let saved_packet_version = FALLBACK_PACKET_VERSION;
 
         time_phase!("initialize networking", {
+            let session_recorder = match &session_mode {
+                SessionMode::Record(path) => SessionRecorder::create(path)
+                    .inspect_err(|_error| {
+                        #[cfg(feature = "debug")]
+                        print_debug!(
+                            "[{}] failed to create session file {}: {:?}",
+                            "error".red(),
+                            path.magenta(),
+                            _error
+                        );
+                    })
+                    .ok(),
+                _ => None,
+            };
+
+            let session = match &session_mode {
+                SessionMode::Replay(path) => Session::load(path)
+                    .inspect_err(|_error| {
+                        #[cfg(feature = "debug")]
+                        print_debug!(
+                            "[{}] failed to load session file {}: {:?}",
+                            "error".red(),
+                            path.magenta(),
+                            _error
+                        );
+                    })
+                    .ok(),
+                _ => None,
+            };
+
             #[cfg(not(feature = "debug"))]
-            let (networking_system, network_event_buffer) = NetworkingSystem::spawn();
+            let packet_callback = session_recorder;
 
             #[cfg(feature = "debug")]
             let (packet_history, packet_history_callback) = PacketHistory::new();
             #[cfg(feature = "debug")]
-            let (networking_system, network_event_buffer) = NetworkingSystem::spawn_with_callback(packet_history_callback);
+            let packet_callback = (packet_history_callback, session_recorder);
+
+            let (networking_system, network_event_buffer) = match session {
+                Some(session) => NetworkingSystem::spawn_replay_with_callback(session, packet_callback),
+                None => NetworkingSystem::spawn_with_callback(packet_callback),
+            };
         });
 
         time_phase!("create resources", {
@@ -689,6 +753,7 @@ impl Client {
             let particle_holder = ParticleHolder::default();
             let point_light_manager = PointLightManager::new();
             let effect_holder = EffectHolder::default();
+            let guild_emblems = GuildEmblems::default();
             let path_finder = PathFinder::default();
 
             let point_light_set_buffer = ResourceSetBuffer::default();
@@ -792,6 +857,7 @@ impl Client {
             point_shadow_entity_instructions,
             point_light_with_shadow_instructions,
             point_light_instructions,
+            cloud_instructions,
             input_system,
             interface,
             mouse_cursor,
@@ -815,6 +881,7 @@ impl Client {
             particle_holder,
             point_light_manager,
             effect_holder,
+            guild_emblems,
             path_finder,
             point_light_set_buffer,
             directional_shadow_object_set_buffer,
@@ -881,6 +948,7 @@ impl Client {
         self.point_shadow_entity_instructions.clear();
         self.point_light_with_shadow_instructions.clear();
         self.point_light_instructions.clear();
+        self.cloud_instructions.clear();
 
         #[cfg(feature = "debug")]
         clear_measurement.stop();
@@ -912,6 +980,12 @@ impl Client {
         // TODO: Rename
         let input_report = self.input_system.update_delta(client_tick);
 
+        let reconnect_to_map_server = *self.client_state.follow(client_state().game_settings().reconnect_to_map_server());
+        self.networking_system.set_connection_settings(ConnectionSettings {
+            reconnect_to_map_server,
+            ..self.networking_system.connection_settings()
+        });
+
         self.networking_system.get_events(&mut self.network_event_buffer);
 
         #[cfg(feature = "debug")]
@@ -961,6 +1035,9 @@ impl Client {
 
                     self.interface.open_window(ErrorWindow::new(message.to_owned()));
                 }
+                NetworkEvent::LoginServerPasswordSalt { salt } => {
+                    let _ = self.networking_system.send_hashed_login(&salt);
+                }
                 NetworkEvent::LoginServerDisconnected { reason } => {
                     if reason != DisconnectReason::ClosedByClient {
                         // TODO: Make this an on-screen popup.
@@ -968,11 +1045,13 @@ impl Client {
                         print_debug!("Disconnection from the character server with error");
 
                         let socket_address = self.saved_login_server_address.unwrap();
+                        let password_encryption = self.client_state.follow(client_state().client_info()).password_encryption();
                         self.networking_system.connect_to_login_server(
                             self.saved_packet_version,
                             socket_address,
                             &self.saved_username,
                             &self.saved_password,
+                            password_encryption,
                         );
                     }
                 }
@@ -1032,6 +1111,20 @@ impl Client {
 
                     self.client_state.follow_mut(client_state().entities()).clear();
                     self.client_state.follow_mut(client_state().dead_entities()).clear();
+                    self.client_state.follow_mut(client_state().party()).clear();
+                    self.client_state.follow_mut(client_state().guild()).clear();
+                    self.guild_emblems.clear();
+                    self.client_state.follow_mut(client_state().companions()).clear();
+                    self.client_state.follow_mut(client_state().pet_eggs()).clear();
+                    self.client_state.follow_mut(client_state().trade()).clear();
+                    self.client_state.follow_mut(client_state().storage()).clear();
+                    self.client_state.follow_mut(client_state().cart()).clear();
+                    self.client_state.follow_mut(client_state().vending_setup()).clear();
+                    self.client_state.follow_mut(client_state().vending()).clear();
+                    self.client_state.follow_mut(client_state().vending_shop()).clear();
+                    self.client_state.follow_mut(client_state().mailbox()).clear();
+                    self.client_state.follow_mut(client_state().mail_composer()).clear();
+                    self.client_state.follow_mut(client_state().mail_compose_window()).clear();
 
                     self.audio_engine.play_background_music_track(None);
 
@@ -1044,6 +1137,25 @@ impl Client {
                     self.async_loader
                         .request_map_load(DEFAULT_MAP.to_string(), Some(TilePosition::new(0, 0)));
                 }
+                NetworkEvent::MapServerReconnecting { attempt } => {
+                    let text = format!("Lost connection to the map server, reconnecting (attempt {attempt})");
+
+                    self.client_state
+                        .follow_mut(client_state().chat_messages())
+                        .push(ChatMessage::new(text, MessageColor::Error));
+                }
+                NetworkEvent::MapServerReconnected => {
+                    // Trades and storage are closed by the server when the connection is lost.
+                    self.client_state.follow_mut(client_state().trade()).clear();
+                    self.client_state.follow_mut(client_state().storage()).clear();
+                    self.interface.close_window_with_class(WindowClass::Trade);
+                    self.interface.close_window_with_class(WindowClass::Storage);
+
+                    self.client_state.follow_mut(client_state().chat_messages()).push(ChatMessage::new(
+                        "Reconnected to the map server".to_owned(),
+                        MessageColor::Information,
+                    ));
+                }
                 NetworkEvent::InitialStats {
                     strength_stat_points_cost,
                     agility_stat_points_cost,
@@ -1231,6 +1343,12 @@ impl Client {
                             npc.set_animation_data(animation_data);
                         }
 
+                        if let Some((guild_id, emblem_id)) = npc.get_guild()
+                            && self.guild_emblems.should_request(guild_id, emblem_id)
+                        {
+                            let _ = self.networking_system.request_guild_emblem(guild_id);
+                        }
+
                         #[cfg(feature = "debug")]
                         npc.generate_pathing_mesh(&self.device, &self.queue, self.graphics_engine.bindless_support(), map);
 
@@ -1283,6 +1401,12 @@ impl Client {
                         }
                     }
 
+                    // Companions of the player are only removed when they are returned to their
+                    // egg, vaporized or dismissed. They follow the player when teleporting.
+                    if reason != DisappearanceReason::Teleported {
+                        self.client_state.follow_mut(client_state().companions()).remove(entity_id);
+                    }
+
                     // If the entity that was removed had an attack buffered we remove the entity
                     // from the buffer.
                     let buffered_attack_entity = self.client_state.follow_mut(client_state().buffered_attack_entity());
@@ -1576,6 +1700,60 @@ impl Client {
                         entity.set_animation_data(animation_data);
                     }
                 }
+                NetworkEvent::ChangeHairColor { account_id, hair_color } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id().0 == account_id.0)
+                    {
+                        entity.set_hair_color(hair_color);
+
+                        if let Some(animation_data) = self.async_loader.request_animation_data_load(
+                            entity.get_entity_id(),
+                            entity.get_entity_type(),
+                            entity.get_entity_part_files(&self.library),
+                        ) {
+                            entity.set_animation_data(animation_data);
+                        }
+                    }
+                }
+                NetworkEvent::ChangeClothesColor { account_id, clothes_color } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id().0 == account_id.0)
+                    {
+                        entity.set_clothes_color(clothes_color);
+
+                        if let Some(animation_data) = self.async_loader.request_animation_data_load(
+                            entity.get_entity_id(),
+                            entity.get_entity_type(),
+                            entity.get_entity_part_files(&self.library),
+                        ) {
+                            entity.set_animation_data(animation_data);
+                        }
+                    }
+                }
+                NetworkEvent::ChangeEquipmentLook { account_id, change } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id().0 == account_id.0)
+                    {
+                        entity.update_equipment_look(change);
+
+                        if let Some(animation_data) = self.async_loader.request_animation_data_load(
+                            entity.get_entity_id(),
+                            entity.get_entity_type(),
+                            entity.get_entity_part_files(&self.library),
+                        ) {
+                            entity.set_animation_data(animation_data);
+                        }
+                    }
+                }
                 NetworkEvent::LoggedOut => {
                     self.networking_system.disconnect_from_map_server();
                 }
@@ -1590,26 +1768,705 @@ impl Client {
                 NetworkEvent::FriendAdded { friend } => {
                     self.client_state.follow_mut(client_state().friend_list()).push(friend);
                 }
-                NetworkEvent::VisualEffect { effect_path, entity_id } => {
-                    let effect = self.effect_loader.get_or_load(effect_path, &self.texture_loader).unwrap();
-                    let frame_timer = effect.new_frame_timer();
+                NetworkEvent::PartyInvitation { party_id, party_name } => {
+                    self.interface.open_window(PartyInvitationWindow::new(party_id, party_name));
+                }
+                NetworkEvent::SetParty { party_name, members } => {
+                    self.client_state.follow_mut(client_state().party()).set(party_name, members);
+                }
+                NetworkEvent::UpdatePartyMember {
+                    party_name,
+                    member,
+                    item_pickup_rule,
+                    item_distribution_rule,
+                } => {
+                    let party = self.client_state.follow_mut(client_state().party());
+                    party.update_member(party_name, member);
+                    party.set_item_rules(item_pickup_rule, item_distribution_rule);
+                }
+                NetworkEvent::PartyMemberLeft { account_id, .. } => {
+                    let saved_login_data = self.saved_login_data.as_ref().unwrap();
+
+                    match account_id == saved_login_data.account_id {
+                        true => self.client_state.follow_mut(client_state().party()).clear(),
+                        false => self.client_state.follow_mut(client_state().party()).remove_member(account_id),
+                    }
+                }
+                NetworkEvent::UpdatePartyMemberHealth {
+                    account_id,
+                    health_points,
+                    maximum_health_points,
+                } => {
+                    self.client_state.follow_mut(client_state().party()).update_member_health(
+                        account_id,
+                        health_points,
+                        maximum_health_points,
+                    );
+                }
+                NetworkEvent::UpdatePartyMemberPosition { account_id, position } => {
+                    self.client_state
+                        .follow_mut(client_state().party())
+                        .update_member_position(account_id, position);
+                }
+                NetworkEvent::UpdatePartyOptions {
+                    experience_share,
+                    item_pickup_rule,
+                    item_distribution_rule,
+                } => {
+                    self.client_state.follow_mut(client_state().party()).set_options(
+                        experience_share,
+                        item_pickup_rule,
+                        item_distribution_rule,
+                    );
+                }
+                NetworkEvent::PartyLeaderChanged {
+                    old_leader_account_id,
+                    new_leader_account_id,
+                } => {
+                    self.client_state
+                        .follow_mut(client_state().party())
+                        .change_leader(old_leader_account_id, new_leader_account_id);
+                }
+                NetworkEvent::SetPlayerGuild {
+                    guild_id,
+                    guild_name,
+                    emblem_id,
+                    is_master,
+                } => {
+                    self.client_state
+                        .follow_mut(client_state().guild())
+                        .set_player_guild(guild_id, guild_name, emblem_id, is_master);
+
+                    if let Some(player) = self.client_state.try_follow_mut(this_entity()) {
+                        player.set_guild(Some(guild_id), emblem_id);
+                    }
+
+                    if self.guild_emblems.should_request(guild_id, emblem_id) {
+                        let _ = self.networking_system.request_guild_emblem(guild_id);
+                    }
+
+                    let _ = self.networking_system.request_guild_information(GuildInformationType::Basic);
+                }
+                NetworkEvent::SetGuildInformation { information } => {
+                    self.client_state.follow_mut(client_state().guild()).set_information(information);
+                }
+                NetworkEvent::SetGuildRelations { relations } => {
+                    self.client_state.follow_mut(client_state().guild()).set_relations(relations);
+                }
+                NetworkEvent::SetGuildMembers { members } => {
+                    let unknown_names = self.client_state.follow_mut(client_state().guild()).set_members(members);
+
+                    for character_id in unknown_names {
+                        let _ = self.networking_system.request_character_name(character_id);
+                    }
+                }
+                NetworkEvent::SetGuildPositions { positions } => {
+                    self.client_state.follow_mut(client_state().guild()).set_positions(positions);
+                }
+                NetworkEvent::SetGuildPositionNames { names } => {
+                    self.client_state.follow_mut(client_state().guild()).set_position_names(names);
+                }
+                NetworkEvent::SetGuildNotice { subject, notice } => {
+                    self.client_state.follow_mut(client_state().guild()).set_notice(subject, notice);
+                }
+                NetworkEvent::GuildEmblem {
+                    guild_id,
+                    emblem_id,
+                    emblem_data,
+                } => {
+                    let name = format!("guild emblem {} {}", guild_id.0, emblem_id);
+
+                    match self.texture_loader.create_guild_emblem(&name, &emblem_data) {
+                        Ok(texture) => self.guild_emblems.set_emblem(guild_id, emblem_id, texture),
+                        Err(_error) => {
+                            #[cfg(feature = "debug")]
+                            print_debug!("[{}] failed to load guild emblem: {:?}", "error".red(), _error);
+                        }
+                    }
+                }
+                NetworkEvent::EntityGuildChanged {
+                    entity_id,
+                    guild_id,
+                    emblem_id,
+                } => {
+                    let is_player = self
+                        .client_state
+                        .try_follow(this_entity())
+                        .is_some_and(|player| player.get_entity_id() == entity_id);
+
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.set_guild(guild_id, emblem_id);
+                    }
+
+                    match guild_id {
+                        Some(guild_id) => {
+                            if self.guild_emblems.should_request(guild_id, emblem_id) {
+                                let _ = self.networking_system.request_guild_emblem(guild_id);
+                            }
+                        }
+                        None if is_player => self.client_state.follow_mut(client_state().guild()).clear(),
+                        None => {}
+                    }
+                }
+                NetworkEvent::GuildMemberStatus {
+                    account_id,
+                    character_id,
+                    online,
+                } => {
+                    self.client_state
+                        .follow_mut(client_state().guild())
+                        .set_member_online(account_id, character_id, online);
+                }
+                NetworkEvent::CharacterName { character_id, name } => {
+                    self.client_state
+                        .follow_mut(client_state().guild())
+                        .set_member_name(character_id, name);
+                }
+                NetworkEvent::TradeRequest {
+                    character_name,
+                    base_level,
+                    ..
+                } => {
+                    self.client_state
+                        .follow_mut(client_state().trade())
+                        .set_partner_name(character_name.clone());
+                    self.interface.open_window(TradeRequestWindow::new(character_name, base_level));
+                }
+                NetworkEvent::TradeStarted { base_level, .. } => {
+                    self.client_state.follow_mut(client_state().trade()).start(base_level);
+                    self.interface
+                        .open_window(TradeWindow::new(client_state().trade_window(), client_state().trade()));
+                }
+                NetworkEvent::OwnTradeItemAdded { index } => {
+                    self.client_state.follow_mut(client_state().trade()).confirm_own_item(index);
+                }
+                NetworkEvent::OwnTradeItemRejected { index } => {
+                    self.client_state.follow_mut(client_state().trade()).reject_own_item(index);
+                }
+                NetworkEvent::PartnerTradeItemAdded { item } => {
+                    self.client_state
+                        .follow_mut(client_state().trade())
+                        .add_partner_item(&self.async_loader, item);
+                }
+                NetworkEvent::PartnerTradeZenyAdded { amount } => {
+                    self.client_state.follow_mut(client_state().trade()).set_partner_zeny(amount);
+                }
+                NetworkEvent::TradeLocked { participant } => {
+                    self.client_state.follow_mut(client_state().trade()).lock(participant);
+                }
+                NetworkEvent::TradeCancelled => {
+                    self.client_state.follow_mut(client_state().trade()).clear();
+                    self.interface.close_window_with_class(WindowClass::Trade);
+                }
+                NetworkEvent::TradeCompleted => {
+                    self.client_state.follow_mut(client_state().trade()).clear();
+                    self.interface.close_window_with_class(WindowClass::Trade);
+                }
+                NetworkEvent::SetStorage { items } => {
+                    self.client_state
+                        .follow_mut(client_state().storage().inventory())
+                        .fill(&self.async_loader, items);
+
+                    self.interface.open_window(StorageWindow::new(client_state().storage()));
+
+                    // Open the inventory as well, so the player can move items between the two.
+                    if !self.interface.is_window_with_class_open(WindowClass::Inventory) {
+                        self.interface.open_window(InventoryWindow::new(client_state().inventory().items()));
+                    }
+                }
+                NetworkEvent::StorageItemCount {
+                    item_count,
+                    maximum_item_count,
+                } => {
+                    self.client_state
+                        .follow_mut(client_state().storage())
+                        .set_item_count(item_count, maximum_item_count);
+                }
+                NetworkEvent::StorageItemAdded { item } => {
+                    self.client_state
+                        .follow_mut(client_state().storage().inventory())
+                        .add_item(&self.async_loader, item);
+                }
+                NetworkEvent::StorageItemRemoved { index, amount } => {
+                    self.client_state
+                        .follow_mut(client_state().storage().inventory())
+                        .remove_item(index, amount as u16);
+                }
+                NetworkEvent::StorageClosed => {
+                    self.client_state.follow_mut(client_state().storage()).clear();
+                    self.interface.close_window_with_class(WindowClass::Storage);
+                }
+                NetworkEvent::SetCart { items } => {
+                    self.client_state
+                        .follow_mut(client_state().cart().inventory())
+                        .fill(&self.async_loader, items);
+                }
+                NetworkEvent::CartItemCount {
+                    item_count,
+                    maximum_item_count,
+                    weight,
+                    maximum_weight,
+                } => {
+                    self.client_state.follow_mut(client_state().cart()).set_item_count(
+                        item_count,
+                        maximum_item_count,
+                        weight,
+                        maximum_weight,
+                    );
+                }
+                NetworkEvent::CartItemAdded { item } => {
+                    self.client_state
+                        .follow_mut(client_state().cart().inventory())
+                        .add_item(&self.async_loader, item);
+                }
+                NetworkEvent::CartItemRemoved { index, amount } => {
+                    self.client_state
+                        .follow_mut(client_state().cart().inventory())
+                        .remove_item(index, amount as u16);
+                }
+                NetworkEvent::CartRemoved => {
+                    self.client_state.follow_mut(client_state().cart()).clear();
+                    self.interface.close_window_with_class(WindowClass::Cart);
+                }
+                NetworkEvent::ChangeCart { entity_id, cart_type } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.set_cart_type(cart_type);
+
+                        if let Some(animation_data) = self.async_loader.request_animation_data_load(
+                            entity.get_entity_id(),
+                            entity.get_entity_type(),
+                            entity.get_entity_part_files(&self.library),
+                        ) {
+                            entity.set_animation_data(animation_data);
+                        }
+                    }
+                }
+                NetworkEvent::OpenVendingSetup { maximum_item_count } => {
+                    self.client_state
+                        .follow_mut(client_state().vending_setup())
+                        .start(maximum_item_count);
+
+                    self.interface.open_window(VendingSetupWindow::new(
+                        client_state().vending_setup_window(),
+                        client_state().vending_setup(),
+                    ));
+
+                    // Open the cart as well, since the items are taken from there.
+                    if !self.interface.is_window_with_class_open(WindowClass::Cart) {
+                        self.interface.open_window(CartWindow::new(client_state().cart()));
+                    }
+                }
+                NetworkEvent::VendingOpened { items } => {
+                    let shop_title = self.client_state.follow(client_state().vending_setup_window()).shop_title.clone();
+
+                    self.client_state
+                        .follow_mut(client_state().vending())
+                        .open(&self.async_loader, shop_title, items);
+                    self.client_state.follow_mut(client_state().vending_setup()).clear();
+
+                    self.interface.close_window_with_class(WindowClass::VendingSetup);
+                    self.interface.open_window(VendingWindow::new(client_state().vending()));
+                }
+                NetworkEvent::VendingItemSold { index, amount, zeny } => {
+                    let name = self.client_state.follow_mut(client_state().vending()).sell_item(index, amount);
+
+                    if let Some(name) = name {
+                        let text = format!("Sold {amount}x {name} for {zeny} Zeny");
+
+                        self.client_state
+                            .follow_mut(client_state().chat_messages())
+                            .push(ChatMessage::new(text, MessageColor::Information));
+                    }
+                }
+                NetworkEvent::VendingShopOpened { entity_id, shop_title } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.set_shop_title(Some(shop_title));
+                    }
+                }
+                NetworkEvent::VendingShopClosed { entity_id } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.set_shop_title(None);
+                    }
+
+                    // The shop of the player was closed, for example because everything was
+                    // sold.
+                    if self
+                        .client_state
+                        .try_follow(this_entity())
+                        .is_some_and(|player| player.get_entity_id() == entity_id)
+                    {
+                        self.client_state.follow_mut(client_state().vending()).clear();
+                        self.interface.close_window_with_class(WindowClass::Vending);
+                    }
+
+                    if self.client_state.follow(client_state().vending_shop()).entity_id() == Some(entity_id) {
+                        self.client_state.follow_mut(client_state().vending_shop()).clear();
+                        self.interface.close_window_with_class(WindowClass::VendingShop);
+                    }
+                }
+                NetworkEvent::VendingItemList {
+                    entity_id,
+                    vending_id,
+                    items,
+                } => {
+                    let shop_title = self
+                        .client_state
+                        .follow(client_state().entities())
+                        .iter()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                        .and_then(|entity| entity.get_shop_title())
+                        .unwrap_or_default()
+                        .to_owned();
+
+                    self.client_state.follow_mut(client_state().vending_shop()).open(
+                        &self.async_loader,
+                        entity_id,
+                        vending_id,
+                        shop_title,
+                        items,
+                    );
+
+                    self.interface.close_window_with_class(WindowClass::VendingShop);
+                    self.interface.open_window(VendingShopWindow::new(client_state().vending_shop()));
+                }
+                NetworkEvent::MailNotification { has_new_mail } => {
+                    let had_new_mail = self.client_state.follow(client_state().mailbox()).has_new_mail;
+
+                    self.client_state
+                        .follow_mut(client_state().mailbox())
+                        .set_has_new_mail(has_new_mail);
+
+                    if has_new_mail && !had_new_mail {
+                        self.client_state
+                            .follow_mut(client_state().chat_messages())
+                            .push(ChatMessage::new("You have new mail.".to_owned(), MessageColor::Information));
+                    }
+                }
+                NetworkEvent::MailList { mails } => {
+                    self.client_state.follow_mut(client_state().mailbox()).set_mails(mails);
+                }
+                NetworkEvent::MailRead {
+                    open_type,
+                    mail_id,
+                    text,
+                    zeny,
+                    items,
+                } => {
+                    self.client_state.follow_mut(client_state().mailbox()).open_mail(
+                        &self.async_loader,
+                        open_type,
+                        mail_id,
+                        text,
+                        zeny,
+                        items,
+                    );
+                }
+                NetworkEvent::MailZenyRetrieved { mail_id } => {
+                    self.client_state.follow_mut(client_state().mailbox()).zeny_retrieved(mail_id);
+                }
+                NetworkEvent::MailItemsRetrieved { mail_id } => {
+                    self.client_state.follow_mut(client_state().mailbox()).items_retrieved(mail_id);
+                }
+                NetworkEvent::MailDeleted { mail_id } => {
+                    self.client_state.follow_mut(client_state().mailbox()).remove_mail(mail_id);
+                }
+                NetworkEvent::MailItemAttached { item, weight } => {
+                    self.client_state
+                        .follow_mut(client_state().mail_composer())
+                        .attach_item(&self.async_loader, item, weight);
+                }
+                NetworkEvent::MailItemDetached { index, amount, weight } => {
+                    self.client_state
+                        .follow_mut(client_state().mail_composer())
+                        .detach_item(index, amount, weight);
+                }
+                NetworkEvent::MailReceiver { character_id, name } => {
+                    let send_mail = self
+                        .client_state
+                        .follow_mut(client_state().mail_composer())
+                        .take_awaiting_receiver();
+
+                    match character_id {
+                        Some(character_id) if send_mail => {
+                            let player_name = self.client_state.follow(client_state().player_name());
+                            let window_state = self.client_state.follow(client_state().mail_compose_window());
+
+                            // TODO: Give some sort of error if the input is not a number.
+                            let zeny = window_state.zeny_input.parse::<u64>().unwrap_or_default();
+
+                            let _ = self.networking_system.send_mail(
+                                player_name,
+                                name,
+                                character_id,
+                                window_state.title.clone(),
+                                window_state.text.clone(),
+                                zeny,
+                            );
+                        }
+                        Some(_) => {}
+                        None => {
+                            let text = format!("Could not find a character named {name}");
+
+                            self.client_state
+                                .follow_mut(client_state().chat_messages())
+                                .push(ChatMessage::new(text, MessageColor::Error));
+                        }
+                    }
+                }
+                NetworkEvent::MailSent => {
+                    self.client_state.follow_mut(client_state().mail_composer()).clear();
+                    self.client_state.follow_mut(client_state().mail_compose_window()).clear();
+                    self.interface.close_window_with_class(WindowClass::MailCompose);
 
-                    self.effect_holder.add_effect(Box::new(EffectWithLight::new(
-                        effect,
-                        frame_timer,
-                        EffectCenter::Entity(entity_id, Point3::new(0.0, 0.0, 0.0)),
-                        Vector3::new(0.0, 9.0, 0.0),
+                    self.client_state
+                        .follow_mut(client_state().chat_messages())
+                        .push(ChatMessage::new("The mail was sent.".to_owned(), MessageColor::Information));
+                }
+                NetworkEvent::VisualEffect { effect, entity_id } => {
+                    let effect_info = self.library.get::<EffectInfo>(EffectKey::VisualEffect(&effect));
+                    let position = entity_position(self.client_state.follow(client_state().entities()), entity_id);
+
+                    self.effect_holder.add_from_info(
+                        effect_info,
+                        &self.effect_loader,
+                        &self.texture_loader,
+                        &self.animation_loader,
+                        &self.sprite_loader,
+                        &self.action_loader,
+                        &self.audio_engine,
+                        EffectCenter::Entity(entity_id, position),
                         // FIX: The point light ID needs to be unique.
                         // The point light manager uses the ID to decide which point light
                         // renders with a shadow. Having duplicate IDs might cause some
                         // visual artifacts, such as flickering, as the point lights switch
                         // between shadows and no shadows.
                         PointLightId::new(entity_id.0),
-                        Vector3::new(0.0, 12.0, 0.0),
-                        Color::WHITE,
-                        50.0,
-                        false,
-                    )));
+                        None,
+                    );
+                }
+                NetworkEvent::SpecialEffect { effect_id, entity_id } => {
+                    let effect_info = self.library.get::<EffectInfo>(EffectKey::SpecialEffect(effect_id));
+                    let position = entity_position(self.client_state.follow(client_state().entities()), entity_id);
+
+                    self.effect_holder.add_from_info(
+                        effect_info,
+                        &self.effect_loader,
+                        &self.texture_loader,
+                        &self.animation_loader,
+                        &self.sprite_loader,
+                        &self.action_loader,
+                        &self.audio_engine,
+                        EffectCenter::Entity(entity_id, position),
+                        PointLightId::new(entity_id.0),
+                        None,
+                    );
+                }
+                NetworkEvent::SkillCast {
+                    source_entity_id,
+                    destination_entity_id,
+                    position,
+                    element,
+                    cast_time,
+                    ..
+                } => {
+                    // Skills without cast time are used right away, so there is nothing to show.
+                    if cast_time == 0 {
+                        continue;
+                    }
+
+                    let entities = self.client_state.follow_mut(client_state().entities());
+
+                    let target_position = match position {
+                        Some(position) => Some(position),
+                        None => entities
+                            .iter()
+                            .find(|entity| entity.get_entity_id() == destination_entity_id)
+                            .map(|entity| entity.get_tile_position()),
+                    };
+
+                    if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == source_entity_id) {
+                        if let Some(target_position) = target_position
+                            && target_position != entity.get_tile_position()
+                        {
+                            entity.rotate_towards(target_position);
+                        }
+
+                        entity.set_casting(cast_time, client_tick);
+                    }
+
+                    let Some(position) = position else {
+                        continue;
+                    };
+
+                    let Some(position) = self.map.as_ref().and_then(|map| map.get_world_position(position)) else {
+                        continue;
+                    };
+
+                    let effect_info = self.library.get::<EffectInfo>(EffectKey::GroundCast(element));
+
+                    // The magic circle is tied to the caster, so it can be removed once the
+                    // cast is over.
+                    self.effect_holder.add_from_info(
+                        effect_info,
+                        &self.effect_loader,
+                        &self.texture_loader,
+                        &self.animation_loader,
+                        &self.sprite_loader,
+                        &self.action_loader,
+                        &self.audio_engine,
+                        EffectCenter::Position(position),
+                        PointLightId::new(source_entity_id.0),
+                        Some(source_entity_id),
+                    );
+                }
+                NetworkEvent::CastCancel { entity_id } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.stop_casting(client_tick);
+                    }
+
+                    self.effect_holder.remove_unit(entity_id);
+                }
+                NetworkEvent::AddStatusEffect { entity_id, status_effect } => {
+                    let is_player = self
+                        .client_state
+                        .try_follow(this_entity())
+                        .is_some_and(|player| player.get_entity_id() == entity_id);
+
+                    // Only the icons of the player are shown, so there is no need to load
+                    // them for other entities.
+                    let icon = match is_player {
+                        true => self
+                            .library
+                            .try_get::<StatusIcon>(status_effect.status_effect_id)
+                            .and_then(|icon| self.texture_loader.get_or_load(&format!("effect\\{icon}"), ImageType::Color).ok()),
+                        false => None,
+                    };
+
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.add_status_effect(status_effect, icon, client_tick);
+                    }
+                }
+                NetworkEvent::RemoveStatusEffect {
+                    entity_id,
+                    status_effect_id,
+                } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.remove_status_effect(status_effect_id);
+                    }
+                }
+                NetworkEvent::ChangeEntityState { entity_id, state } => {
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.set_state(state);
+                    }
+                }
+                NetworkEvent::SkillEffect {
+                    skill_id,
+                    source_entity_id,
+                    destination_entity_id,
+                } => {
+                    // The skill was used, so the cast is over.
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == source_entity_id)
+                    {
+                        entity.stop_casting(client_tick);
+                    }
+
+                    self.effect_holder.remove_unit(source_entity_id);
+
+                    let effect_info = self.library.get::<EffectInfo>(EffectKey::Skill(skill_id));
+                    let position = entity_position(self.client_state.follow(client_state().entities()), destination_entity_id);
+
+                    self.effect_holder.add_from_info(
+                        effect_info,
+                        &self.effect_loader,
+                        &self.texture_loader,
+                        &self.animation_loader,
+                        &self.sprite_loader,
+                        &self.action_loader,
+                        &self.audio_engine,
+                        EffectCenter::Entity(destination_entity_id, position),
+                        PointLightId::new(destination_entity_id.0),
+                        None,
+                    );
+                }
+                NetworkEvent::GroundSkillEffect {
+                    skill_id,
+                    entity_id,
+                    position,
+                } => {
+                    // The skill was used, so the cast is over.
+                    if let Some(entity) = self
+                        .client_state
+                        .follow_mut(client_state().entities())
+                        .iter_mut()
+                        .find(|entity| entity.get_entity_id() == entity_id)
+                    {
+                        entity.stop_casting(client_tick);
+                    }
+
+                    self.effect_holder.remove_unit(entity_id);
+
+                    let Some(position) = self.map.as_ref().and_then(|map| map.get_world_position(position)) else {
+                        continue;
+                    };
+
+                    let effect_info = self.library.get::<EffectInfo>(EffectKey::Skill(skill_id));
+
+                    self.effect_holder.add_from_info(
+                        effect_info,
+                        &self.effect_loader,
+                        &self.texture_loader,
+                        &self.animation_loader,
+                        &self.sprite_loader,
+                        &self.action_loader,
+                        &self.audio_engine,
+                        EffectCenter::Position(position),
+                        PointLightId::new(entity_id.0),
+                        None,
+                    );
                 }
                 NetworkEvent::AddSkillUnit {
                     entity_id,
@@ -1620,59 +2477,28 @@ impl Client {
                         continue;
                     };
 
-                    match unit_id {
-                        UnitId::Firewall => {
-                            let Some(position) = map.get_world_position(position) else {
-                                #[cfg(feature = "debug")]
-                                print_debug!("[{}] entity with id {:?} is out of map bounds", "error".red(), entity_id);
-                                continue;
-                            };
+                    let Some(effect_info) = self.library.try_get::<EffectInfo>(EffectKey::Unit(&unit_id)) else {
+                        continue;
+                    };
 
-                            let effect = self.effect_loader.get_or_load("firewall.str", &self.texture_loader).unwrap();
-                            let frame_timer = effect.new_frame_timer();
-
-                            self.effect_holder.add_unit(
-                                Box::new(EffectWithLight::new(
-                                    effect,
-                                    frame_timer,
-                                    EffectCenter::Position(position),
-                                    Vector3::new(0.0, 0.0, 0.0),
-                                    PointLightId::new(unit_id as u32),
-                                    Vector3::new(0.0, 6.0, 0.0),
-                                    Color::rgb_u8(255, 30, 0),
-                                    60.0,
-                                    true,
-                                )),
-                                entity_id,
-                            );
-                        }
-                        UnitId::Pneuma => {
-                            let Some(position) = map.get_world_position(position) else {
-                                #[cfg(feature = "debug")]
-                                print_debug!("[{}] entity with id {:?} is out of map bounds", "error".red(), entity_id);
-                                continue;
-                            };
+                    let Some(position) = map.get_world_position(position) else {
+                        #[cfg(feature = "debug")]
+                        print_debug!("[{}] entity with id {:?} is out of map bounds", "error".red(), entity_id);
+                        continue;
+                    };
 
-                            let effect = self.effect_loader.get_or_load("pneuma1.str", &self.texture_loader).unwrap();
-                            let frame_timer = effect.new_frame_timer();
-
-                            self.effect_holder.add_unit(
-                                Box::new(EffectWithLight::new(
-                                    effect,
-                                    frame_timer,
-                                    EffectCenter::Position(position),
-                                    Vector3::new(0.0, 0.0, 0.0),
-                                    PointLightId::new(unit_id as u32),
-                                    Vector3::new(0.0, 6.0, 0.0),
-                                    Color::rgb_u8(83, 220, 108),
-                                    40.0,
-                                    false,
-                                )),
-                                entity_id,
-                            );
-                        }
-                        _ => {}
-                    }
+                    self.effect_holder.add_from_info(
+                        effect_info,
+                        &self.effect_loader,
+                        &self.texture_loader,
+                        &self.animation_loader,
+                        &self.sprite_loader,
+                        &self.action_loader,
+                        &self.audio_engine,
+                        EffectCenter::Position(position),
+                        PointLightId::new(entity_id.0),
+                        Some(entity_id),
+                    );
                 }
                 NetworkEvent::RemoveSkillUnit { entity_id } => {
                     self.effect_holder.remove_unit(entity_id);
@@ -1829,6 +2655,92 @@ impl Client {
                         *self.client_state.follow_mut(client_state().buffered_attack_entity()) = Some(target_entity_id);
                     }
                 }
+                NetworkEvent::PetEggList { eggs } => {
+                    let inventory_items = self.client_state.follow(client_state().inventory().items());
+                    let pet_eggs = eggs
+                        .into_iter()
+                        .filter_map(|index| {
+                            let inventory_item = inventory_items.iter().find(|inventory_item| inventory_item.index == index)?;
+
+                            Some(PetEgg {
+                                index,
+                                name: inventory_item.metadata.name.clone(),
+                            })
+                        })
+                        .collect();
+
+                    *self.client_state.follow_mut(client_state().pet_eggs()) = pet_eggs;
+
+                    if !self.interface.is_window_with_class_open(WindowClass::PetEgg) {
+                        self.interface.open_window(PetEggWindow::new(client_state().pet_eggs()));
+                    }
+                }
+                NetworkEvent::SetPet { entity_id } => {
+                    self.client_state
+                        .follow_mut(client_state().companions().pet())
+                        .set_entity_id(entity_id);
+                }
+                NetworkEvent::UpdatePetInformation { information } => {
+                    self.client_state
+                        .follow_mut(client_state().companions().pet())
+                        .update_information(information);
+                }
+                NetworkEvent::UpdatePetHunger { hunger } => {
+                    self.client_state.follow_mut(client_state().companions().pet()).set_hunger(hunger);
+                }
+                NetworkEvent::UpdatePetIntimacy { intimacy } => {
+                    self.client_state
+                        .follow_mut(client_state().companions().pet())
+                        .set_intimacy(intimacy);
+                }
+                NetworkEvent::PetFed { success, .. } => {
+                    if !success {
+                        self.client_state.follow_mut(client_state().chat_messages()).push(ChatMessage::new(
+                            "You don't have the food for your pet".to_owned(),
+                            MessageColor::Error,
+                        ));
+                    }
+                }
+                NetworkEvent::SetHomunculus { entity_id } => {
+                    self.client_state
+                        .follow_mut(client_state().companions().homunculus())
+                        .set_entity_id(entity_id);
+                }
+                NetworkEvent::UpdateHomunculusInformation { information } => {
+                    self.client_state
+                        .follow_mut(client_state().companions().homunculus())
+                        .update_information(information);
+                }
+                NetworkEvent::UpdateHomunculusHunger { hunger } => {
+                    self.client_state
+                        .follow_mut(client_state().companions().homunculus())
+                        .set_hunger(hunger);
+                }
+                NetworkEvent::UpdateHomunculusIntimacy { intimacy } => {
+                    self.client_state
+                        .follow_mut(client_state().companions().homunculus())
+                        .set_intimacy(intimacy);
+                }
+                NetworkEvent::HomunculusSkillTree { .. } => {}
+                NetworkEvent::HomunculusFed { success, .. } => {
+                    if !success {
+                        self.client_state.follow_mut(client_state().chat_messages()).push(ChatMessage::new(
+                            "You don't have the food for your homunculus".to_owned(),
+                            MessageColor::Error,
+                        ));
+                    }
+                }
+                NetworkEvent::UpdateMercenaryInformation { entity_id, information } => {
+                    self.client_state
+                        .follow_mut(client_state().companions().mercenary())
+                        .update_information(entity_id, information);
+                }
+                NetworkEvent::UpdateMercenaryStat { stat_type } => {
+                    self.client_state
+                        .follow_mut(client_state().companions().mercenary())
+                        .update_stat(stat_type);
+                }
+                NetworkEvent::MercenarySkillTree { .. } => {}
             }
         }
 
@@ -1847,9 +2759,30 @@ impl Client {
             self.input_event_buffer.push(InputEvent::RotateCamera { rotation });
         }
 
-        if !interface_has_focus {
+        let rebinding = self.client_state.follow(client_state().keybindings_window()).get_rebinding();
+
+        if let Some(action) = rebinding
+            && self.interface.is_window_with_class_open(WindowClass::Keybindings)
+        {
+            // While waiting for a new key combination we don't handle any shortcuts, so
+            // the pressed keys don't trigger their current action.
+            if let Some(combination) = self.input_system.pressed_key_combination() {
+                match self
+                    .client_state
+                    .follow_mut(client_state().keybindings())
+                    .rebind(action, combination)
+                {
+                    Ok(()) => self.client_state.follow_mut(client_state().keybindings_window()).stop_rebinding(),
+                    Err(bound_action) => self
+                        .client_state
+                        .follow_mut(client_state().keybindings_window())
+                        .set_conflict(combination, bound_action),
+                }
+            }
+        } else if !interface_has_focus {
             self.input_system.handle_keyboard_input(
                 &mut self.input_event_buffer,
+                self.client_state.follow(client_state().keybindings()),
                 #[cfg(feature = "debug")]
                 self.interface.get_mouse_mode().is_default(),
                 #[cfg(feature = "debug")]
@@ -1879,6 +2812,7 @@ impl Client {
 
                     let packet_version = match service.packet_version {
                         Some(packet_version) => match packet_version {
+                            PacketVersion::_20200401 => SupportedPacketVersion::_20200401,
                             PacketVersion::_20220406 => SupportedPacketVersion::_20220406,
                             PacketVersion::Unsupported(packet_version) => {
                                 self.interface.open_window(ErrorWindow::new(format!(
@@ -1895,8 +2829,10 @@ impl Client {
                     self.saved_password = password.clone();
                     self.saved_packet_version = packet_version;
 
+                    let password_encryption = self.client_state.follow(client_state().client_info()).password_encryption();
+
                     self.networking_system
-                        .connect_to_login_server(packet_version, socket_address, username, password);
+                        .connect_to_login_server(packet_version, socket_address, username, password, password_encryption);
                 }
                 InputEvent::SelectServer {
                     character_server_information,
@@ -1993,6 +2929,17 @@ impl Client {
                         .interface
                         .open_window(AudioSettingsWindow::new(client_state().audio_settings())),
                 },
+                InputEvent::ToggleKeybindingsWindow => match self.interface.is_window_with_class_open(WindowClass::Keybindings) {
+                    true => self.interface.close_window_with_class(WindowClass::Keybindings),
+                    false => {
+                        self.client_state.follow_mut(client_state().keybindings_window()).stop_rebinding();
+
+                        self.interface.open_window(KeybindingsWindow::new(
+                            client_state().keybindings_window(),
+                            client_state().keybindings(),
+                        ));
+                    }
+                },
                 InputEvent::ToggleFriendListWindow => {
                     if self.client_state.try_follow(this_entity()).is_some() {
                         match self.interface.is_window_with_class_open(WindowClass::FriendList) {
@@ -2004,8 +2951,143 @@ impl Client {
                         }
                     }
                 }
+                InputEvent::TogglePartyWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Party) {
+                            true => self.interface.close_window_with_class(WindowClass::Party),
+                            false => self
+                                .interface
+                                .open_window(PartyWindow::new(client_state().party_window(), client_state().party())),
+                        }
+                    }
+                }
+                InputEvent::ToggleGuildWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Guild) {
+                            true => self.interface.close_window_with_class(WindowClass::Guild),
+                            false => {
+                                // Refresh the guild information every time the window is opened.
+                                if self.client_state.follow(client_state().guild()).is_in_guild() {
+                                    let _ = self.networking_system.request_guild_information(GuildInformationType::Basic);
+                                    let _ = self.networking_system.request_guild_information(GuildInformationType::Members);
+                                    let _ = self.networking_system.request_guild_information(GuildInformationType::Positions);
+                                }
+
+                                self.interface.open_window(GuildWindow::new(client_state().guild()));
+                            }
+                        }
+                    }
+                }
+                InputEvent::TogglePetWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Pet) {
+                            true => self.interface.close_window_with_class(WindowClass::Pet),
+                            false => {
+                                if self.client_state.follow(client_state().companions().pet()).entity_id().is_some() {
+                                    let _ = self.networking_system.pet_command(PetCommand::Information);
+                                }
+
+                                self.interface.open_window(PetWindow::new(client_state().companions().pet()));
+                            }
+                        }
+                    }
+                }
+                InputEvent::ToggleHomunculusWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Homunculus) {
+                            true => self.interface.close_window_with_class(WindowClass::Homunculus),
+                            false => {
+                                if self
+                                    .client_state
+                                    .follow(client_state().companions().homunculus())
+                                    .entity_id()
+                                    .is_some()
+                                {
+                                    let _ = self.networking_system.homunculus_command(HomunculusCommand::Information);
+                                }
+
+                                self.interface
+                                    .open_window(HomunculusWindow::new(client_state().companions().homunculus()));
+                            }
+                        }
+                    }
+                }
+                InputEvent::ToggleMercenaryWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Mercenary) {
+                            true => self.interface.close_window_with_class(WindowClass::Mercenary),
+                            false => {
+                                if self
+                                    .client_state
+                                    .follow(client_state().companions().mercenary())
+                                    .entity_id()
+                                    .is_some()
+                                {
+                                    let _ = self.networking_system.mercenary_command(MercenaryCommand::Information);
+                                }
+
+                                self.interface
+                                    .open_window(MercenaryWindow::new(client_state().companions().mercenary()));
+                            }
+                        }
+                    }
+                }
+                InputEvent::ToggleCartWindow => {
+                    // The server only sends the cart details if the player has a cart.
+                    let has_cart = self.client_state.follow(client_state().cart()).maximum_item_count > 0;
+
+                    if self.client_state.try_follow(this_entity()).is_some() && has_cart {
+                        match self.interface.is_window_with_class_open(WindowClass::Cart) {
+                            true => self.interface.close_window_with_class(WindowClass::Cart),
+                            false => self.interface.open_window(CartWindow::new(client_state().cart())),
+                        }
+                    }
+                }
+                InputEvent::ToggleMailboxWindow => {
+                    if self.client_state.try_follow(this_entity()).is_some() {
+                        match self.interface.is_window_with_class_open(WindowClass::Mailbox) {
+                            true => {
+                                let _ = self.networking_system.close_mailbox();
+
+                                self.client_state.follow_mut(client_state().mailbox()).close();
+                                self.interface.close_window_with_class(WindowClass::Mailbox);
+                            }
+                            false => {
+                                let _ = self.networking_system.open_mailbox();
+
+                                self.interface.open_window(MailboxWindow::new(client_state().mailbox()));
+                            }
+                        }
+                    }
+                }
                 InputEvent::CloseTopWindow => self.interface.close_top_window(&self.client_state),
                 InputEvent::ToggleShowInterface => self.show_interface = !self.show_interface,
+                InputEvent::TakeScreenshot => {
+                    let graphics_settings = self.client_state.follow(client_state().graphics_settings());
+
+                    self.graphics_engine.request_screenshot(ScreenshotRequest {
+                        scale: graphics_settings.screenshot_scale,
+                        include_interface: graphics_settings.screenshot_interface,
+                    });
+                }
+                InputEvent::StartKeyRebind { action } => {
+                    self.client_state
+                        .follow_mut(client_state().keybindings_window())
+                        .start_rebinding(action);
+                }
+                InputEvent::CancelKeyRebind => {
+                    self.client_state.follow_mut(client_state().keybindings_window()).stop_rebinding();
+                }
+                InputEvent::UnbindKey => {
+                    if let Some(action) = self.client_state.follow(client_state().keybindings_window()).get_rebinding() {
+                        self.client_state.follow_mut(client_state().keybindings()).unbind(action);
+                        self.client_state.follow_mut(client_state().keybindings_window()).stop_rebinding();
+                    }
+                }
+                InputEvent::ResetKeybindings => {
+                    self.client_state.follow_mut(client_state().keybindings()).reset();
+                    self.client_state.follow_mut(client_state().keybindings_window()).stop_rebinding();
+                }
                 InputEvent::SelectCharacter { slot } => {
                     let _ = self.networking_system.select_character(slot);
                 }
@@ -2044,13 +3126,37 @@ impl Client {
                     *self.client_state.follow_mut(client_state().buffered_attack_entity()) = None;
                 }
                 InputEvent::PlayerInteract { entity_id } => {
+                    let player_entity_id = self.client_state.try_follow(this_entity()).map(|player| player.get_entity_id());
+
                     let entity = self
                         .client_state
                         .follow_mut(client_state().entities())
                         .iter_mut()
                         .find(|entity| entity.get_entity_id() == entity_id);
 
-                    if let Some(entity) = entity {
+                    let is_companion = entity.as_ref().is_some_and(|entity| entity.get_companion_type().is_some());
+
+                    if is_companion {
+                        // Companions can't be attacked or talked to. Clicking one of the companions
+                        // of the player opens its window instead.
+                        let companion_type = self
+                            .client_state
+                            .follow(client_state().companions())
+                            .owned_companion_type(entity_id);
+
+                        match companion_type {
+                            Some(CompanionType::Pet) if !self.interface.is_window_with_class_open(WindowClass::Pet) => {
+                                self.interface.open_window(PetWindow::new(client_state().companions().pet()))
+                            }
+                            Some(CompanionType::Homunculus) if !self.interface.is_window_with_class_open(WindowClass::Homunculus) => self
+                                .interface
+                                .open_window(HomunculusWindow::new(client_state().companions().homunculus())),
+                            Some(CompanionType::Mercenary) if !self.interface.is_window_with_class_open(WindowClass::Mercenary) => self
+                                .interface
+                                .open_window(MercenaryWindow::new(client_state().companions().mercenary())),
+                            _ => {}
+                        }
+                    } else if let Some(entity) = entity {
                         let _ = match entity.get_entity_type() {
                             EntityType::Npc => self.networking_system.start_dialog(entity_id),
                             EntityType::Monster => {
@@ -2063,6 +3169,16 @@ impl Client {
 
                                 self.networking_system.player_attack(entity_id)
                             }
+                            EntityType::Player if player_entity_id != Some(entity_id) && entity.get_shop_title().is_some() => {
+                                self.networking_system.request_vending_items(entity_id)
+                            }
+                            EntityType::Player if player_entity_id != Some(entity_id) => {
+                                // The name is not part of the trade packets, so we remember it here.
+                                let partner_name = entity.get_details().cloned().unwrap_or_default();
+                                self.client_state.follow_mut(client_state().trade()).set_partner_name(partner_name);
+
+                                self.networking_system.request_trade(entity_id)
+                            }
                             EntityType::Warp => self.networking_system.player_move({
                                 let position = entity.get_tile_position();
                                 WorldPosition {
@@ -2087,6 +3203,22 @@ impl Client {
                         continue;
                     }
 
+                    // Messages starting with a '%' are sent to the party chat.
+                    if let Some(text) = text.strip_prefix('%') {
+                        let _ = self
+                            .networking_system
+                            .send_party_message(self.client_state.follow(client_state().player_name()), text);
+                        continue;
+                    }
+
+                    // Messages starting with a '$' are sent to the guild chat.
+                    if let Some(text) = text.strip_prefix('$') {
+                        let _ = self
+                            .networking_system
+                            .send_guild_message(self.client_state.follow(client_state().player_name()), text);
+                        continue;
+                    }
+
                     let _ = self
                         .networking_system
                         .send_chat_message(self.client_state.follow(client_state().player_name()), &text);
@@ -2113,6 +3245,59 @@ impl Client {
                     (ItemSource::Equipment { .. }, ItemSource::Inventory) => {
                         let _ = self.networking_system.request_item_unequip(item.index);
                     }
+                    (ItemSource::Inventory, ItemSource::Trade) => {
+                        let trade = self.client_state.follow_mut(client_state().trade());
+
+                        if !trade.own_locked && !trade.contains_item(item.index) {
+                            // TODO: Ask the player for the amount.
+                            let _ = self.networking_system.add_trade_item(item.index, item.amount() as u32);
+                            trade.add_pending_item(item);
+                        }
+                    }
+                    (ItemSource::Inventory, ItemSource::Storage) => {
+                        // TODO: Ask the player for the amount.
+                        let _ = self.networking_system.add_storage_item(item.index, item.amount() as u32);
+                    }
+                    (ItemSource::Storage, ItemSource::Inventory) => {
+                        // TODO: Ask the player for the amount.
+                        let _ = self.networking_system.remove_storage_item(item.index, item.amount() as u32);
+                    }
+                    (ItemSource::Inventory, ItemSource::Cart) => {
+                        // TODO: Ask the player for the amount.
+                        let _ = self.networking_system.add_cart_item(item.index, item.amount() as u32);
+                    }
+                    (ItemSource::Cart, ItemSource::Inventory) => {
+                        // TODO: Ask the player for the amount.
+                        let _ = self.networking_system.remove_cart_item(item.index, item.amount() as u32);
+                    }
+                    (ItemSource::Cart, ItemSource::Storage) => {
+                        // TODO: Ask the player for the amount.
+                        let _ = self.networking_system.add_storage_item_from_cart(item.index, item.amount() as u32);
+                    }
+                    (ItemSource::Cart, ItemSource::VendingSetup) => {
+                        let price_input = &self.client_state.follow(client_state().vending_setup_window()).price_input;
+
+                        // TODO: Give some sort of error if the input is not a number.
+                        if let Ok(price) = price_input.parse::<u32>() {
+                            let vending_setup = self.client_state.follow_mut(client_state().vending_setup());
+
+                            if !vending_setup.is_full() && !vending_setup.contains_item(item.index) {
+                                vending_setup.add_item(item, Price(price));
+                            }
+                        }
+                    }
+                    (ItemSource::VendingSetup, ItemSource::Cart) => {
+                        self.client_state.follow_mut(client_state().vending_setup()).remove_item(item.index);
+                    }
+                    (ItemSource::Inventory, ItemSource::MailAttachment) => {
+                        if self.interface.is_window_with_class_open(WindowClass::MailCompose) {
+                            // TODO: Ask the player for the amount.
+                            let _ = self.networking_system.add_mail_item(item.index, item.amount() as u16);
+                        }
+                    }
+                    (ItemSource::MailAttachment, ItemSource::Inventory) => {
+                        let _ = self.networking_system.remove_mail_item(item.index, item.amount() as u16);
+                    }
                     _ => {}
                 },
                 InputEvent::MoveSkill {
@@ -2206,6 +3391,161 @@ impl Client {
                     let _ = self.networking_system.accept_friend_request(account_id, character_id);
                     self.interface.close_window_with_class(WindowClass::FriendRequest);
                 }
+                InputEvent::CreateParty { party_name } => {
+                    if party_name.len() > 24 {
+                        #[cfg(feature = "debug")]
+                        print_debug!("[{}] party name {} is too long", "error".red(), party_name.magenta());
+                    } else {
+                        let _ = self.networking_system.create_party(party_name);
+                    }
+                }
+                InputEvent::InviteToParty { character_name } => {
+                    let _ = self.networking_system.invite_to_party(character_name);
+                }
+                InputEvent::RejectPartyInvite { party_id } => {
+                    let _ = self.networking_system.reject_party_invite(party_id);
+                    self.interface.close_window_with_class(WindowClass::PartyInvitation);
+                }
+                InputEvent::AcceptPartyInvite { party_id } => {
+                    let _ = self.networking_system.accept_party_invite(party_id);
+                    self.interface.close_window_with_class(WindowClass::PartyInvitation);
+                }
+                InputEvent::LeaveParty => {
+                    let _ = self.networking_system.leave_party();
+                }
+                InputEvent::ExpelPartyMember {
+                    account_id,
+                    character_name,
+                } => {
+                    let _ = self.networking_system.expel_party_member(account_id, character_name);
+                }
+                InputEvent::ChangePartyLeader { account_id } => {
+                    let _ = self.networking_system.change_party_leader(account_id);
+                }
+                InputEvent::ChangePartyOptions {
+                    experience_share,
+                    item_pickup_rule,
+                    item_distribution_rule,
+                } => {
+                    let _ = self
+                        .networking_system
+                        .change_party_options(experience_share, item_pickup_rule, item_distribution_rule);
+                }
+                InputEvent::RejectTradeRequest => {
+                    let _ = self.networking_system.reject_trade_request();
+                    self.client_state.follow_mut(client_state().trade()).clear();
+                    self.interface.close_window_with_class(WindowClass::TradeRequest);
+                }
+                InputEvent::AcceptTradeRequest => {
+                    let _ = self.networking_system.accept_trade_request();
+                    self.interface.close_window_with_class(WindowClass::TradeRequest);
+                }
+                InputEvent::AddTradeZeny { amount } => {
+                    let trade = self.client_state.follow_mut(client_state().trade());
+
+                    if !trade.own_locked {
+                        let _ = self.networking_system.add_trade_zeny(amount);
+                        trade.set_pending_zeny(amount);
+                    }
+                }
+                InputEvent::LockTrade => {
+                    let _ = self.networking_system.lock_trade();
+                }
+                InputEvent::CommitTrade => {
+                    let _ = self.networking_system.commit_trade();
+                }
+                InputEvent::CancelTrade => {
+                    let _ = self.networking_system.cancel_trade();
+                }
+                InputEvent::CloseStorage => {
+                    let _ = self.networking_system.close_storage();
+                }
+                InputEvent::RemoveVendingSetupItem { index } => {
+                    self.client_state.follow_mut(client_state().vending_setup()).remove_item(index);
+                }
+                InputEvent::OpenVending => {
+                    let shop_title = self.client_state.follow(client_state().vending_setup_window()).shop_title.clone();
+                    let items = self.client_state.follow(client_state().vending_setup()).setup_items();
+
+                    let _ = self.networking_system.open_vending(shop_title, items);
+                }
+                InputEvent::CancelVendingSetup => {
+                    let _ = self.networking_system.cancel_vending_setup();
+
+                    self.client_state.follow_mut(client_state().vending_setup()).clear();
+                    self.interface.close_window_with_class(WindowClass::VendingSetup);
+                }
+                InputEvent::CloseVending => {
+                    let _ = self.networking_system.close_vending();
+                }
+                InputEvent::BuyVendingItems => {
+                    let vending_shop = self.client_state.follow(client_state().vending_shop());
+
+                    if let Some(entity_id) = vending_shop.entity_id() {
+                        let _ = self
+                            .networking_system
+                            .buy_vending_items(entity_id, vending_shop.vending_id(), vending_shop.purchases());
+                    }
+
+                    self.client_state.follow_mut(client_state().vending_shop()).clear();
+                    self.interface.close_window_with_class(WindowClass::VendingShop);
+                }
+                InputEvent::CloseVendingShop => {
+                    self.client_state.follow_mut(client_state().vending_shop()).clear();
+                    self.interface.close_window_with_class(WindowClass::VendingShop);
+                }
+                InputEvent::CloseMailbox => {
+                    let _ = self.networking_system.close_mailbox();
+
+                    self.client_state.follow_mut(client_state().mailbox()).close();
+                    self.interface.close_window_with_class(WindowClass::Mailbox);
+                }
+                InputEvent::RefreshMailbox => {
+                    let _ = self.networking_system.refresh_mailbox();
+                }
+                InputEvent::ReadMail { open_type, mail_id } => {
+                    let _ = self.networking_system.read_mail(open_type, mail_id);
+                }
+                InputEvent::TakeMailZeny => {
+                    if let Some((open_type, mail_id)) = self.client_state.follow(client_state().mailbox()).opened_mail_id() {
+                        let _ = self.networking_system.request_mail_zeny(open_type, mail_id);
+                    }
+                }
+                InputEvent::TakeMailItems => {
+                    if let Some((open_type, mail_id)) = self.client_state.follow(client_state().mailbox()).opened_mail_id() {
+                        let _ = self.networking_system.request_mail_items(open_type, mail_id);
+                    }
+                }
+                InputEvent::DeleteMail => {
+                    if let Some((open_type, mail_id)) = self.client_state.follow(client_state().mailbox()).opened_mail_id() {
+                        let _ = self.networking_system.delete_mail(open_type, mail_id);
+                    }
+                }
+                InputEvent::WriteMail => {
+                    if !self.interface.is_window_with_class_open(WindowClass::MailCompose) {
+                        let _ = self.networking_system.start_writing_mail(String::new());
+
+                        self.interface.open_window(MailComposeWindow::new(
+                            client_state().mail_compose_window(),
+                            client_state().mail_composer(),
+                        ));
+                    }
+                }
+                InputEvent::SendMail => {
+                    let receiver = self.client_state.follow(client_state().mail_compose_window()).receiver.clone();
+
+                    // The server needs to find the character id of the receiver before the mail
+                    // can be sent.
+                    let _ = self.networking_system.check_mail_receiver(receiver);
+                    self.client_state.follow_mut(client_state().mail_composer()).await_receiver();
+                }
+                InputEvent::CancelMail => {
+                    let _ = self.networking_system.cancel_writing_mail();
+
+                    self.client_state.follow_mut(client_state().mail_composer()).clear();
+                    self.client_state.follow_mut(client_state().mail_compose_window()).clear();
+                    self.interface.close_window_with_class(WindowClass::MailCompose);
+                }
                 InputEvent::BuyItems { items } => {
                     let _ = self.networking_system.purchase_items(items);
                 }
@@ -2231,6 +3571,33 @@ impl Client {
                 InputEvent::StatUp { stat_type } => {
                     let _ = self.networking_system.request_stat_up(stat_type);
                 }
+                InputEvent::SelectPetEgg { index } => {
+                    let _ = self.networking_system.select_pet_egg(index);
+
+                    self.client_state.follow_mut(client_state().pet_eggs()).clear();
+                    self.interface.close_window_with_class(WindowClass::PetEgg);
+                }
+                InputEvent::PetCommand { command } => {
+                    let _ = self.networking_system.pet_command(command);
+                }
+                InputEvent::HomunculusCommand { command } => {
+                    let _ = self.networking_system.homunculus_command(command);
+                }
+                InputEvent::MercenaryCommand { command } => {
+                    let _ = self.networking_system.mercenary_command(command);
+                }
+                InputEvent::CallBackCompanion { companion_type } => {
+                    let companions = self.client_state.follow(client_state().companions());
+                    let entity_id = match companion_type {
+                        CompanionType::Pet => None,
+                        CompanionType::Homunculus => companions.homunculus.entity_id(),
+                        CompanionType::Mercenary => companions.mercenary.entity_id(),
+                    };
+
+                    if let Some(entity_id) = entity_id {
+                        let _ = self.networking_system.move_companion_to_owner(entity_id);
+                    }
+                }
                 #[cfg(feature = "debug")]
                 InputEvent::ReloadLanguage => {
                     let language = *self.client_state.follow(client_state().interface_settings().language());
@@ -2417,6 +3784,37 @@ impl Client {
                             .filter(|item| item.item_id == item_id)
                             .for_each(|item| item.metadata.texture = Some(texture.clone()));
                     }
+                    ItemLocation::Trade => {
+                        self.client_state
+                            .follow_mut(client_state().trade())
+                            .update_item_sprite(item_id, texture);
+                    }
+                    ItemLocation::Storage => {
+                        self.client_state
+                            .follow_mut(client_state().storage().inventory())
+                            .update_item_sprite(item_id, texture);
+                    }
+                    ItemLocation::Cart => {
+                        self.client_state
+                            .follow_mut(client_state().cart().inventory())
+                            .update_item_sprite(item_id, texture);
+                    }
+                    ItemLocation::Vending => {
+                        self.client_state
+                            .follow_mut(client_state().vending())
+                            .update_item_sprite(item_id, texture.clone());
+                        self.client_state
+                            .follow_mut(client_state().vending_shop())
+                            .update_item_sprite(item_id, texture);
+                    }
+                    ItemLocation::Mail => {
+                        self.client_state
+                            .follow_mut(client_state().mailbox())
+                            .update_item_sprite(item_id, texture.clone());
+                        self.client_state
+                            .follow_mut(client_state().mail_composer())
+                            .update_item_sprite(item_id, texture);
+                    }
                 },
                 (LoaderId::Map(..), LoadableResource::Map { map, position }) => {
                     match self.client_state.try_follow(this_player()).is_none() {
@@ -2596,6 +3994,8 @@ impl Client {
                 false => &self.start_camera,
             };
 
+            map.advance_clouds(current_camera.focus_point(), delta_time);
+
             let (view_matrix, projection_matrix) = current_camera.view_projection_matrices();
             let camera_position = current_camera.camera_position().to_homogeneous();
 
@@ -2701,6 +4101,8 @@ impl Client {
             };
             let mut indicator_instruction = None;
             let mut water_instruction = None;
+            let mut sky_instruction = None;
+            let mut fog_instruction = None;
 
             // Marker
             {
@@ -2877,6 +4279,8 @@ impl Client {
                     client_tick,
                 );
 
+                self.effect_holder.render_sprites(&mut self.entity_instructions, entity_camera);
+
                 #[cfg(feature = "debug")]
                 if render_options.show_entities_debug {
                     map.render_entities_debug(
@@ -2895,6 +4299,9 @@ impl Client {
                 #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_options.show_water))]
                 map.render_water(&mut water_instruction, animation_timer_ms);
 
+                map.render_sky(&mut sky_instruction, &mut fog_instruction);
+                map.render_clouds(&mut self.cloud_instructions, current_camera.focus_point());
+
                 #[cfg(feature = "debug")]
                 if render_options.show_bounding_boxes {
                     let culling_camera: &dyn Camera = match currently_playing {
@@ -2937,6 +4344,25 @@ impl Client {
 
                 self.effect_holder.render(&mut self.effect_renderer, current_camera);
 
+                for entity in self.client_state.follow(client_state().entities()) {
+                    entity.render_shop_sign(
+                        &self.middle_interface_renderer,
+                        current_camera,
+                        self.client_state.follow(client_state().world_theme()),
+                        screen_size,
+                    );
+
+                    // Cast bars are shown for every entity so that players can react to
+                    // dangerous skills.
+                    entity.render_cast_bar(
+                        &self.middle_interface_renderer,
+                        current_camera,
+                        self.client_state.follow(client_state().world_theme()),
+                        screen_size,
+                        client_tick,
+                    );
+                }
+
                 if let Some(player) = self.client_state.try_follow(this_entity()) {
                     #[cfg(feature = "debug")]
                     profile_block!("render player status");
@@ -2947,6 +4373,13 @@ impl Client {
                         self.client_state.follow(client_state().world_theme()),
                         screen_size,
                     );
+
+                    player.render_status_effects(
+                        &self.middle_interface_renderer,
+                        self.client_state.follow(client_state().world_theme()),
+                        screen_size,
+                        client_tick,
+                    );
                 }
 
                 let mouse_mode = self.interface.get_mouse_mode();
@@ -2977,6 +4410,7 @@ impl Client {
                             .iter()
                             .find(|entity| entity.get_entity_id() == entity_id)
                             .map(|entity| match entity.get_entity_type() {
+                                _ if entity.get_companion_type().is_some() => MouseCursorState::Default,
                                 EntityType::Npc => MouseCursorState::Dialog,
                                 EntityType::Warp => MouseCursorState::Warp,
                                 EntityType::Monster => MouseCursorState::Attack,
@@ -3113,13 +4547,17 @@ impl Client {
                                         top: 15.0 * scaling.get_factor(),
                                     };
 
+                                    let name_position = input_report.mouse_position + offset;
+
                                     self.middle_interface_renderer.render_text(
                                         name,
-                                        input_report.mouse_position + offset,
+                                        name_position,
                                         Color::WHITE,
                                         FontSize(16.0),
                                         AlignHorizontal::Mid,
                                     );
+
+                                    entity.render_guild_emblem(&self.middle_interface_renderer, &self.guild_emblems, name_position);
                                 }
                             }
                         }
@@ -3161,6 +4599,29 @@ impl Client {
                     );
                 }
 
+                if *self.client_state.follow(client_state().game_settings().show_latency())
+                    && let Some(latency) = self.networking_system.map_server_latency()
+                {
+                    let world_theme = self.client_state.follow(client_state().world_theme());
+
+                    #[allow(unused_mut)]
+                    let mut text_offset = world_theme.overlay.text_offset;
+
+                    // Don't overlap with the frames per second.
+                    #[cfg(feature = "debug")]
+                    if render_options.show_frames_per_second {
+                        text_offset.top += world_theme.overlay.font_size.0 * scaling.get_factor();
+                    }
+
+                    self.top_interface_renderer.render_text(
+                        &format!("{} ms", latency.average.as_millis()),
+                        text_offset,
+                        world_theme.overlay.foreground_color,
+                        world_theme.overlay.font_size,
+                        AlignHorizontal::Left,
+                    );
+                }
+
                 if self.show_interface {
                     self.mouse_cursor.render(
                         &self.top_interface_renderer,
@@ -3221,6 +4682,9 @@ impl Client {
                 point_shadow_entities: &self.point_shadow_entity_instructions,
                 effects: self.effect_renderer.get_instructions(),
                 water: water_instruction,
+                sky: sky_instruction,
+                fog: fog_instruction,
+                clouds: &self.cloud_instructions,
                 map_picker_tile_vertex_buffer: Some(map.get_tile_picker_vertex_buffer()),
                 map_picker_tile_index_buffer: Some(map.get_tile_picker_index_buffer()),
                 font_map_texture: Some(self.font_loader.get_font_map()),
@@ -229,6 +229,44 @@ impl GameInterfaceRenderer {
         );
     }
 
+    /// Renders the text on a rectangle, centered above the given position.
+    pub fn render_sign(
+        &self,
+        text: &str,
+        position: ScreenPosition,
+        background_color: Color,
+        text_color: Color,
+        font_size: FontSize,
+        padding: ScreenSize,
+    ) {
+        let scaled_font_size = FontSize(font_size.0 * self.scaling.get_factor());
+        let text_size = self
+            .font_loader
+            .layout_text(text, text_color, self.highlight_color, scaled_font_size, 1.0, None, None);
+
+        let background_size = ScreenSize {
+            width: text_size.x,
+            height: text_size.y,
+        } + padding * 2.0;
+        let background_position = position
+            - ScreenSize {
+                width: background_size.width / 2.0,
+                height: background_size.height,
+            };
+
+        self.render_rectangle(background_position, background_size, background_color);
+        self.render_text(
+            text,
+            ScreenPosition {
+                left: position.left,
+                top: background_position.top + padding.height,
+            },
+            text_color,
+            font_size,
+            AlignHorizontal::Mid,
+        );
+    }
+
     pub fn render_damage_text(&self, text: &str, position: ScreenPosition, color: Color, font_size: FontSize) {
         self.render_text(text, position, color, font_size, AlignHorizontal::Mid);
     }
@@ -0,0 +1,182 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
+
+const KEY_SIZE: usize = 32;
+const NONCE_SIZE: usize = 16;
+const TAG_SIZE: usize = blake3::OUT_LEN;
+const ENCRYPTION_CONTEXT: &str = "korangar credential store v1 encryption";
+const AUTHENTICATION_CONTEXT: &str = "korangar credential store v1 authentication";
+
+/// Encrypts remembered passwords before they are written to disk.
+///
+/// The key is a random secret stored in a separate file. On Unix, the file is
+/// created so that only the current user can read it. On other platforms it
+/// gets the default permissions of the client directory. Passwords are
+/// encrypted with a BLAKE3 key stream and authenticated with a keyed BLAKE3
+/// hash over the nonce and the ciphertext, using independent keys derived
+/// from the secret.
+pub struct CredentialStore {
+    encryption_key: [u8; KEY_SIZE],
+    authentication_key: [u8; KEY_SIZE],
+}
+
+impl CredentialStore {
+    const KEY_FILE_NAME: &'static str = "client/credentials.key";
+
+    fn from_secret(secret: &[u8; KEY_SIZE]) -> Self {
+        Self {
+            encryption_key: blake3::derive_key(ENCRYPTION_CONTEXT, secret),
+            authentication_key: blake3::derive_key(AUTHENTICATION_CONTEXT, secret),
+        }
+    }
+
+    /// Load the secret from the key file or create a new one if there is none
+    /// yet.
+    pub fn load_or_create() -> Option<Self> {
+        match std::fs::read(Self::KEY_FILE_NAME) {
+            Ok(data) => {
+                let Ok(secret) = <[u8; KEY_SIZE]>::try_from(data.as_slice()) else {
+                    #[cfg(feature = "debug")]
+                    print_debug!("key file {} has an invalid size", Self::KEY_FILE_NAME.magenta());
+
+                    return None;
+                };
+
+                Some(Self::from_secret(&secret))
+            }
+            Err(_) => {
+                #[cfg(feature = "debug")]
+                print_debug!("creating new key file {}", Self::KEY_FILE_NAME.magenta());
+
+                let mut secret = [0; KEY_SIZE];
+                getrandom::fill(&mut secret).ok()?;
+
+                let mut options = OpenOptions::new();
+                options.write(true).create_new(true);
+
+                #[cfg(unix)]
+                options.mode(0o600);
+
+                if let Err(_error) = options.open(Self::KEY_FILE_NAME).and_then(|mut file| file.write_all(&secret)) {
+                    #[cfg(feature = "debug")]
+                    print_debug!(
+                        "failed to create key file {}: {:?}",
+                        Self::KEY_FILE_NAME.magenta(),
+                        _error.red()
+                    );
+
+                    return None;
+                }
+
+                Some(Self::from_secret(&secret))
+            }
+        }
+    }
+
+    fn apply_key_stream(&self, nonce: &[u8], data: &mut [u8]) {
+        let mut key_stream = vec![0; data.len()];
+
+        blake3::Hasher::new_keyed(&self.encryption_key)
+            .update(nonce)
+            .finalize_xof()
+            .fill(&mut key_stream);
+
+        data.iter_mut().zip(key_stream).for_each(|(byte, key)| *byte ^= key);
+    }
+
+    fn authentication_tag(&self, nonce: &[u8], ciphertext: &[u8]) -> blake3::Hash {
+        blake3::Hasher::new_keyed(&self.authentication_key)
+            .update(nonce)
+            .update(ciphertext)
+            .finalize()
+    }
+
+    /// Encrypt a password. The result is hex encoded so it can be stored in
+    /// the settings file.
+    pub fn encrypt(&self, password: &str) -> Option<String> {
+        let mut nonce = [0; NONCE_SIZE];
+        getrandom::fill(&mut nonce).ok()?;
+
+        let mut ciphertext = password.as_bytes().to_vec();
+        self.apply_key_stream(&nonce, &mut ciphertext);
+        let tag = self.authentication_tag(&nonce, &ciphertext);
+
+        let encoded = nonce
+            .iter()
+            .chain(ciphertext.iter())
+            .chain(tag.as_bytes().iter())
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        Some(encoded)
+    }
+
+    /// Decrypt a password that was encrypted with [`Self::encrypt`]. Returns
+    /// `None` if the data was encrypted with a different key or has been
+    /// tampered with.
+    pub fn decrypt(&self, encrypted: &str) -> Option<String> {
+        if !encrypted.is_ascii() || !encrypted.len().is_multiple_of(2) {
+            return None;
+        }
+
+        let data = (0..encrypted.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&encrypted[index..index + 2], 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+
+        if data.len() < NONCE_SIZE + TAG_SIZE {
+            return None;
+        }
+
+        let (nonce, rest) = data.split_at(NONCE_SIZE);
+        let (ciphertext, tag) = rest.split_at(rest.len() - TAG_SIZE);
+
+        // The comparison of `blake3::Hash` is done in constant time.
+        if self.authentication_tag(nonce, ciphertext) != blake3::Hash::from_slice(tag).ok()? {
+            return None;
+        }
+
+        let mut plaintext = ciphertext.to_vec();
+        self.apply_key_stream(nonce, &mut plaintext);
+
+        String::from_utf8(plaintext).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CredentialStore;
+
+    #[test]
+    fn encrypt_decrypt_round_trip() {
+        let store = CredentialStore::from_secret(&[7; 32]);
+        let encrypted = store.encrypt("hunter2").unwrap();
+
+        assert!(!encrypted.contains("hunter2"));
+        assert_eq!(store.decrypt(&encrypted).as_deref(), Some("hunter2"));
+    }
+
+    #[test]
+    fn decrypt_rejects_tampered_data() {
+        let store = CredentialStore::from_secret(&[7; 32]);
+        let mut encrypted = store.encrypt("hunter2").unwrap();
+
+        // Flip a bit in the first byte of the ciphertext.
+        let byte = u8::from_str_radix(&encrypted[32..34], 16).unwrap() ^ 1;
+        encrypted.replace_range(32..34, &format!("{byte:02x}"));
+
+        assert_eq!(store.decrypt(&encrypted), None);
+    }
+
+    #[test]
+    fn decrypt_rejects_other_key() {
+        let encrypted = CredentialStore::from_secret(&[7; 32]).encrypt("hunter2").unwrap();
+
+        assert_eq!(CredentialStore::from_secret(&[8; 32]).decrypt(&encrypted), None);
+    }
+}
@@ -8,11 +8,20 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Serialize, Deserialize, RustState, StateElement)]
 pub struct GameSettings {
     pub auto_attack: bool,
+    // Settings saved before these options existed don't have these fields.
+    #[serde(default)]
+    pub reconnect_to_map_server: bool,
+    #[serde(default)]
+    pub show_latency: bool,
 }
 
 impl Default for GameSettings {
     fn default() -> Self {
-        Self { auto_attack: true }
+        Self {
+            auto_attack: true,
+            reconnect_to_map_server: false,
+            show_latency: false,
+        }
     }
 }
 
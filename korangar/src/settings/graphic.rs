@@ -7,7 +7,8 @@ use rust_state::RustState;
 use serde::{Deserialize, Serialize};
 
 use crate::graphics::{
-    LimitFramerate, Msaa, PresentModeInfo, ScreenSpaceAntiAliasing, ShadowDetail, ShadowMethod, ShadowResolution, Ssaa, TextureSamplerType,
+    LimitFramerate, Msaa, PresentModeInfo, ScreenSpaceAntiAliasing, ScreenshotScale, ShadowDetail, ShadowMethod, ShadowResolution, Ssaa,
+    TextureSamplerType,
 };
 
 #[derive(Clone, Serialize, Deserialize, RustState, StateElement)]
@@ -25,6 +26,19 @@ pub struct GraphicsSettings {
     pub shadow_detail: ShadowDetail,
     pub sdsm: bool,
     pub high_quality_interface: bool,
+    // Settings saved before screenshots existed don't have these fields.
+    #[serde(default = "default_screenshot_scale")]
+    pub screenshot_scale: ScreenshotScale,
+    #[serde(default = "default_screenshot_interface")]
+    pub screenshot_interface: bool,
+}
+
+fn default_screenshot_scale() -> ScreenshotScale {
+    ScreenshotScale::X1
+}
+
+fn default_screenshot_interface() -> bool {
+    true
 }
 
 impl Default for GraphicsSettings {
@@ -43,6 +57,8 @@ impl Default for GraphicsSettings {
             shadow_detail: ShadowDetail::Medium,
             sdsm: true,
             high_quality_interface: true,
+            screenshot_scale: default_screenshot_scale(),
+            screenshot_interface: default_screenshot_interface(),
         }
     }
 }
@@ -124,6 +140,7 @@ pub struct GraphicsSettingsCapabilities {
     shadow_method_options: Vec<ShadowMethod>,
     shadow_resolution_options: Vec<ShadowResolution>,
     shadow_detail_options: Vec<ShadowDetail>,
+    screenshot_scale_options: Vec<ScreenshotScale>,
     vsync_setting_disabled: bool,
 }
 
@@ -152,6 +169,7 @@ impl Default for GraphicsSettingsCapabilities {
             shadow_method_options: vec![ShadowMethod::Hard, ShadowMethod::SoftPCF, ShadowMethod::SoftPCSS],
             shadow_resolution_options: vec![ShadowResolution::Normal, ShadowResolution::Ultra, ShadowResolution::Insane],
             shadow_detail_options: vec![ShadowDetail::Low, ShadowDetail::Medium, ShadowDetail::High, ShadowDetail::Ultra],
+            screenshot_scale_options: vec![ScreenshotScale::X1, ScreenshotScale::X2, ScreenshotScale::X3, ScreenshotScale::X4],
             vsync_setting_disabled: true,
         }
     }
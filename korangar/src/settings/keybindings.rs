@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
+use korangar_interface::element::StateElement;
+use ragnarok_packets::HotbarSlot;
+use ron::ser::PrettyConfig;
+use rust_state::RustState;
+use serde::{Deserialize, Serialize};
+use winit::keyboard::KeyCode;
+
+use crate::input::InputEvent;
+use crate::inventory::HOTBAR_SLOT_COUNT;
+
+/// Actions that can be bound to a key combination.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyAction {
+    ToggleMenuWindow,
+    ToggleInventoryWindow,
+    ToggleEquipmentWindow,
+    ToggleSkillTreeWindow,
+    ToggleStatsWindow,
+    ToggleFriendListWindow,
+    TogglePartyWindow,
+    ToggleGuildWindow,
+    TogglePetWindow,
+    ToggleHomunculusWindow,
+    ToggleMercenaryWindow,
+    ToggleCartWindow,
+    ToggleMailboxWindow,
+    ToggleGameSettingsWindow,
+    ToggleInterfaceSettingsWindow,
+    ToggleGraphicsSettingsWindow,
+    ToggleAudioSettingsWindow,
+    ToggleKeybindingsWindow,
+    ToggleShowInterface,
+    TakeScreenshot,
+    CloseTopWindow,
+    /// Cast the skill in the given slot of the hotbar.
+    UseHotbarSlot(u16),
+}
+
+impl KeyAction {
+    /// All actions except for the hotbar slots.
+    pub const SHORTCUTS: [KeyAction; 21] = [
+        KeyAction::ToggleMenuWindow,
+        KeyAction::ToggleInventoryWindow,
+        KeyAction::ToggleEquipmentWindow,
+        KeyAction::ToggleSkillTreeWindow,
+        KeyAction::ToggleStatsWindow,
+        KeyAction::ToggleFriendListWindow,
+        KeyAction::TogglePartyWindow,
+        KeyAction::ToggleGuildWindow,
+        KeyAction::TogglePetWindow,
+        KeyAction::ToggleHomunculusWindow,
+        KeyAction::ToggleMercenaryWindow,
+        KeyAction::ToggleCartWindow,
+        KeyAction::ToggleMailboxWindow,
+        KeyAction::ToggleGameSettingsWindow,
+        KeyAction::ToggleInterfaceSettingsWindow,
+        KeyAction::ToggleGraphicsSettingsWindow,
+        KeyAction::ToggleAudioSettingsWindow,
+        KeyAction::ToggleKeybindingsWindow,
+        KeyAction::ToggleShowInterface,
+        KeyAction::TakeScreenshot,
+        KeyAction::CloseTopWindow,
+    ];
+
+    fn all() -> impl Iterator<Item = KeyAction> {
+        Self::SHORTCUTS
+            .into_iter()
+            .chain((0..HOTBAR_SLOT_COUNT as u16).map(KeyAction::UseHotbarSlot))
+    }
+
+    /// Event that is emitted when the key combination is pressed.
+    pub fn pressed_event(self) -> InputEvent {
+        match self {
+            KeyAction::ToggleMenuWindow => InputEvent::ToggleMenuWindow,
+            KeyAction::ToggleInventoryWindow => InputEvent::ToggleInventoryWindow,
+            KeyAction::ToggleEquipmentWindow => InputEvent::ToggleEquipmentWindow,
+            KeyAction::ToggleSkillTreeWindow => InputEvent::ToggleSkillTreeWindow,
+            KeyAction::ToggleStatsWindow => InputEvent::ToggleStatsWindow,
+            KeyAction::ToggleFriendListWindow => InputEvent::ToggleFriendListWindow,
+            KeyAction::TogglePartyWindow => InputEvent::TogglePartyWindow,
+            KeyAction::ToggleGuildWindow => InputEvent::ToggleGuildWindow,
+            KeyAction::TogglePetWindow => InputEvent::TogglePetWindow,
+            KeyAction::ToggleHomunculusWindow => InputEvent::ToggleHomunculusWindow,
+            KeyAction::ToggleMercenaryWindow => InputEvent::ToggleMercenaryWindow,
+            KeyAction::ToggleCartWindow => InputEvent::ToggleCartWindow,
+            KeyAction::ToggleMailboxWindow => InputEvent::ToggleMailboxWindow,
+            KeyAction::ToggleGameSettingsWindow => InputEvent::ToggleGameSettingsWindow,
+            KeyAction::ToggleInterfaceSettingsWindow => InputEvent::ToggleInterfaceSettingsWindow,
+            KeyAction::ToggleGraphicsSettingsWindow => InputEvent::ToggleGraphicsSettingsWindow,
+            KeyAction::ToggleAudioSettingsWindow => InputEvent::ToggleAudioSettingsWindow,
+            KeyAction::ToggleKeybindingsWindow => InputEvent::ToggleKeybindingsWindow,
+            KeyAction::ToggleShowInterface => InputEvent::ToggleShowInterface,
+            KeyAction::TakeScreenshot => InputEvent::TakeScreenshot,
+            KeyAction::CloseTopWindow => InputEvent::CloseTopWindow,
+            KeyAction::UseHotbarSlot(slot) => InputEvent::CastSkill { slot: HotbarSlot(slot) },
+        }
+    }
+
+    /// Event that is emitted when the key is released.
+    pub fn released_event(self) -> Option<InputEvent> {
+        match self {
+            KeyAction::UseHotbarSlot(slot) => Some(InputEvent::StopSkill { slot: HotbarSlot(slot) }),
+            _ => None,
+        }
+    }
+}
+
+/// Modifier keys that have to be held down for a [`KeyCombination`].
+/// Left and right modifier keys are treated the same.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyModifiers {
+    pub control: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+/// A key together with the modifier keys that have to be held down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyCombination {
+    pub key: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyCombination {
+    pub const fn new(key: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { key, modifiers }
+    }
+
+    const fn plain(key: KeyCode) -> Self {
+        Self::new(key, KeyModifiers {
+            control: false,
+            alt: false,
+            shift: false,
+        })
+    }
+
+    const fn control(key: KeyCode) -> Self {
+        Self::new(key, KeyModifiers {
+            control: true,
+            alt: false,
+            shift: false,
+        })
+    }
+
+    const fn alt(key: KeyCode) -> Self {
+        Self::new(key, KeyModifiers {
+            control: false,
+            alt: true,
+            shift: false,
+        })
+    }
+}
+
+impl Display for KeyCombination {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.modifiers.control {
+            formatter.write_str("Ctrl+")?;
+        }
+
+        if self.modifiers.alt {
+            formatter.write_str("Alt+")?;
+        }
+
+        if self.modifiers.shift {
+            formatter.write_str("Shift+")?;
+        }
+
+        // Key codes are named after their position on a QWERTY keyboard, e.g.
+        // `KeyE` or `Digit1`. We strip the prefix to keep the names short.
+        let name = format!("{:?}", self.key);
+        let short_name = name
+            .strip_prefix("Key")
+            .or_else(|| name.strip_prefix("Digit"))
+            .filter(|short_name| short_name.len() == 1)
+            .unwrap_or(&name);
+
+        formatter.write_str(short_name)
+    }
+}
+
+/// Mapping from [`KeyAction`]s to the key combinations that trigger them.
+#[derive(Clone, Serialize, Deserialize, RustState, StateElement)]
+pub struct Keybindings {
+    #[hidden_element]
+    bindings: HashMap<KeyAction, Option<KeyCombination>>,
+}
+
+impl Default for Keybindings {
+    fn default() -> Self {
+        let bindings = KeyAction::all().map(|action| (action, Self::default_combination(action))).collect();
+
+        Self { bindings }
+    }
+}
+
+impl Keybindings {
+    const FILE_NAME: &'static str = "client/keybindings.ron";
+
+    pub fn new() -> Self {
+        Self::load().unwrap_or_else(|| {
+            #[cfg(feature = "debug")]
+            print_debug!("failed to load keybindings from {}", Self::FILE_NAME.magenta());
+            Default::default()
+        })
+    }
+
+    pub fn load() -> Option<Self> {
+        #[cfg(feature = "debug")]
+        print_debug!("loading keybindings from {}", Self::FILE_NAME.magenta());
+
+        let mut keybindings: Self = std::fs::read_to_string(Self::FILE_NAME)
+            .ok()
+            .and_then(|data| ron::from_str(&data).ok())?;
+
+        // Actions that were added after the file was saved get their default
+        // binding, unless the user already uses the key combination for something
+        // else.
+        for action in KeyAction::all() {
+            if !keybindings.bindings.contains_key(&action) {
+                let combination = Self::default_combination(action).filter(|&combination| keybindings.bound_action(combination).is_none());
+                keybindings.bindings.insert(action, combination);
+            }
+        }
+
+        Some(keybindings)
+    }
+
+    pub fn save(&self) {
+        #[cfg(feature = "debug")]
+        print_debug!("saving keybindings to {}", Self::FILE_NAME.magenta());
+
+        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+
+        if let Err(_error) = std::fs::write(Self::FILE_NAME, data) {
+            #[cfg(feature = "debug")]
+            print_debug!(
+                "failed to save keybindings to {}: {:?}",
+                Self::FILE_NAME.magenta(),
+                _error.red()
+            );
+        }
+    }
+
+    /// The default bindings match the shortcuts of the official client where
+    /// possible.
+    fn default_combination(action: KeyAction) -> Option<KeyCombination> {
+        match action {
+            KeyAction::ToggleMenuWindow => Some(KeyCombination::plain(KeyCode::Escape)),
+            KeyAction::ToggleInventoryWindow => Some(KeyCombination::alt(KeyCode::KeyE)),
+            KeyAction::ToggleEquipmentWindow => Some(KeyCombination::alt(KeyCode::KeyQ)),
+            KeyAction::ToggleSkillTreeWindow => Some(KeyCombination::alt(KeyCode::KeyS)),
+            KeyAction::ToggleStatsWindow => Some(KeyCombination::alt(KeyCode::KeyA)),
+            KeyAction::ToggleFriendListWindow => Some(KeyCombination::alt(KeyCode::KeyZ)),
+            KeyAction::TogglePartyWindow => None,
+            KeyAction::ToggleGuildWindow => None,
+            KeyAction::TogglePetWindow => Some(KeyCombination::alt(KeyCode::KeyJ)),
+            KeyAction::ToggleHomunculusWindow => Some(KeyCombination::alt(KeyCode::KeyR)),
+            KeyAction::ToggleMercenaryWindow => None,
+            KeyAction::ToggleCartWindow => Some(KeyCombination::alt(KeyCode::KeyW)),
+            KeyAction::ToggleMailboxWindow => None,
+            KeyAction::ToggleGameSettingsWindow => Some(KeyCombination::control(KeyCode::KeyS)),
+            KeyAction::ToggleInterfaceSettingsWindow => Some(KeyCombination::control(KeyCode::KeyI)),
+            KeyAction::ToggleGraphicsSettingsWindow => Some(KeyCombination::control(KeyCode::KeyG)),
+            KeyAction::ToggleAudioSettingsWindow => Some(KeyCombination::control(KeyCode::KeyA)),
+            KeyAction::ToggleKeybindingsWindow => Some(KeyCombination::control(KeyCode::KeyK)),
+            KeyAction::ToggleShowInterface => Some(KeyCombination::control(KeyCode::KeyH)),
+            KeyAction::TakeScreenshot => Some(KeyCombination::plain(KeyCode::PrintScreen)),
+            KeyAction::CloseTopWindow => Some(KeyCombination::control(KeyCode::KeyQ)),
+            KeyAction::UseHotbarSlot(0) => Some(KeyCombination::plain(KeyCode::KeyJ)),
+            KeyAction::UseHotbarSlot(1) => Some(KeyCombination::plain(KeyCode::KeyL)),
+            KeyAction::UseHotbarSlot(2) => Some(KeyCombination::plain(KeyCode::KeyU)),
+            KeyAction::UseHotbarSlot(_) => None,
+        }
+    }
+
+    pub fn get(&self, action: KeyAction) -> Option<KeyCombination> {
+        self.bindings.get(&action).copied().flatten()
+    }
+
+    /// Iterate over all actions that are bound to a key combination.
+    pub fn bound_actions(&self) -> impl Iterator<Item = (KeyAction, KeyCombination)> + '_ {
+        self.bindings
+            .iter()
+            .filter_map(|(action, combination)| combination.map(|combination| (*action, combination)))
+    }
+
+    fn bound_action(&self, combination: KeyCombination) -> Option<KeyAction> {
+        self.bound_actions()
+            .find(|(_, bound_combination)| *bound_combination == combination)
+            .map(|(action, _)| action)
+    }
+
+    /// Bind the key combination to the action. If the key combination is
+    /// already bound to a different action, nothing is changed and that action
+    /// is returned instead.
+    pub fn rebind(&mut self, action: KeyAction, combination: KeyCombination) -> Result<(), KeyAction> {
+        if let Some(bound_action) = self.bound_action(combination)
+            && bound_action != action
+        {
+            return Err(bound_action);
+        }
+
+        self.bindings.insert(action, Some(combination));
+        Ok(())
+    }
+
+    pub fn unbind(&mut self, action: KeyAction) {
+        self.bindings.insert(action, None);
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl Drop for Keybindings {
+    fn drop(&mut self) {
+        self.save();
+    }
+}
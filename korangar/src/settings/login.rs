@@ -5,24 +5,28 @@ use korangar_debug::logging::{Colorize, print_debug};
 use korangar_interface::element::StateElement;
 use ron::ser::PrettyConfig;
 use rust_state::{MapItem, RustState};
-use serde::ser::SerializeStruct;
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 
+use super::credentials::CredentialStore;
 use crate::loaders::ServiceId;
 
-#[derive(Clone, Default, RustState, Serialize, Deserialize, StateElement)]
+#[derive(RustState, StateElement)]
 pub struct LoginSettings {
     // TODO: Unhide this element.
     #[hidden_element]
     pub service_settings: HashMap<ServiceId, ServiceSettings>,
     pub recent_service_id: Option<ServiceId>,
+    /// Used to encrypt remembered passwords. If there is no credential store,
+    /// passwords are not saved at all.
+    #[hidden_element]
+    credential_store: Option<CredentialStore>,
 }
 
 impl MapItem for ServiceSettings {
     type Id = ServiceId;
 }
 
-#[derive(Clone, Default, RustState, Deserialize)]
+#[derive(Clone, Default, RustState)]
 pub struct ServiceSettings {
     pub username: String,
     pub password: String,
@@ -30,26 +34,25 @@ pub struct ServiceSettings {
     pub remember_password: bool,
 }
 
-impl Serialize for ServiceSettings {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let mut serde_state = Serializer::serialize_struct(serializer, "ServiceSettings", 4)?;
-        SerializeStruct::serialize_field(
-            &mut serde_state,
-            "username",
-            if self.remember_username { self.username.as_str() } else { "" },
-        )?;
-        SerializeStruct::serialize_field(
-            &mut serde_state,
-            "password",
-            if self.remember_password { self.password.as_str() } else { "" },
-        )?;
-        SerializeStruct::serialize_field(&mut serde_state, "remember_username", &self.remember_username)?;
-        SerializeStruct::serialize_field(&mut serde_state, "remember_password", &self.remember_password)?;
-        SerializeStruct::end(serde_state)
-    }
+/// The representation of [`ServiceSettings`] in the settings file.
+#[derive(Serialize, Deserialize)]
+struct StoredServiceSettings {
+    username: String,
+    /// Plain text password, written by versions of the client that did not
+    /// encrypt passwords yet. It is only read to migrate old files.
+    #[serde(default, skip_serializing)]
+    password: String,
+    #[serde(default)]
+    encrypted_password: String,
+    remember_username: bool,
+    remember_password: bool,
+}
+
+/// The representation of [`LoginSettings`] in the settings file.
+#[derive(Serialize, Deserialize)]
+struct StoredLoginSettings {
+    service_settings: HashMap<ServiceId, StoredServiceSettings>,
+    recent_service_id: Option<ServiceId>,
 }
 
 impl LoginSettings {
@@ -60,7 +63,11 @@ impl LoginSettings {
             #[cfg(feature = "debug")]
             print_debug!("failed to load login settings from {}", Self::FILE_NAME.magenta());
 
-            Default::default()
+            Self {
+                service_settings: HashMap::new(),
+                recent_service_id: None,
+                credential_store: CredentialStore::load_or_create(),
+            }
         })
     }
 
@@ -68,16 +75,104 @@ impl LoginSettings {
         #[cfg(feature = "debug")]
         print_debug!("loading login settings from {}", Self::FILE_NAME.magenta());
 
-        std::fs::read_to_string(Self::FILE_NAME)
+        let stored: StoredLoginSettings = std::fs::read_to_string(Self::FILE_NAME)
             .ok()
-            .and_then(|data| ron::from_str(&data).ok())
+            .and_then(|data| ron::from_str(&data).ok())?;
+
+        let credential_store = CredentialStore::load_or_create();
+        let mut contains_plain_text_password = false;
+
+        let service_settings = stored
+            .service_settings
+            .into_iter()
+            .map(|(service_id, stored)| {
+                // Files written by older versions of the client contain the password in
+                // plain text.
+                let password = match stored.password.is_empty() {
+                    true if stored.encrypted_password.is_empty() => String::new(),
+                    true => credential_store
+                        .as_ref()
+                        .and_then(|credential_store| credential_store.decrypt(&stored.encrypted_password))
+                        .unwrap_or_else(|| {
+                            #[cfg(feature = "debug")]
+                            print_debug!(
+                                "failed to decrypt the remembered password of service {:?}, the key file is missing or invalid",
+                                service_id
+                            );
+
+                            String::new()
+                        }),
+                    false => {
+                        contains_plain_text_password = true;
+                        stored.password
+                    }
+                };
+
+                let settings = ServiceSettings {
+                    username: stored.username,
+                    password,
+                    remember_username: stored.remember_username,
+                    remember_password: stored.remember_password,
+                };
+
+                (service_id, settings)
+            })
+            .collect();
+
+        let login_settings = Self {
+            service_settings,
+            recent_service_id: stored.recent_service_id,
+            credential_store,
+        };
+
+        // Save right away so plain text passwords don't stay on disk any longer
+        // than necessary.
+        if contains_plain_text_password {
+            #[cfg(feature = "debug")]
+            print_debug!("encrypting plain text passwords in {}", Self::FILE_NAME.magenta());
+
+            login_settings.save();
+        }
+
+        Some(login_settings)
     }
 
     pub fn save(&self) {
         #[cfg(feature = "debug")]
         print_debug!("saving login settings to {}", Self::FILE_NAME.magenta());
 
-        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+        let service_settings = self
+            .service_settings
+            .iter()
+            .map(|(service_id, settings)| {
+                let username = match settings.remember_username {
+                    true => settings.username.clone(),
+                    false => String::new(),
+                };
+
+                let encrypted_password = match (&self.credential_store, settings.remember_password) {
+                    (Some(credential_store), true) => credential_store.encrypt(&settings.password).unwrap_or_default(),
+                    _ => String::new(),
+                };
+
+                let stored = StoredServiceSettings {
+                    username,
+                    password: String::new(),
+                    encrypted_password,
+                    remember_username: settings.remember_username,
+                    remember_password: settings.remember_password,
+                };
+
+                (*service_id, stored)
+            })
+            .collect();
+
+        let stored = StoredLoginSettings {
+            service_settings,
+            recent_service_id: self.recent_service_id,
+        };
+
+        let data = ron::ser::to_string_pretty(&stored, PrettyConfig::new()).unwrap();
 
         if let Err(_error) = std::fs::write(Self::FILE_NAME, data) {
             #[cfg(feature = "debug")]
@@ -1,13 +1,16 @@
 //! Module that implements all persistent setting files.
 
 mod audio;
+mod credentials;
 mod game;
 mod graphic;
 mod interface;
+mod keybindings;
 mod login;
 
 pub use audio::*;
 pub use game::*;
 pub use graphic::*;
 pub use interface::*;
+pub use keybindings::*;
 pub use login::*;
@@ -0,0 +1,245 @@
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+use korangar_interface::element::StateElement;
+use ragnarok_packets::{
+    AccountId, CharacterId, GuildId, GuildInformation, GuildMemberInformation, GuildPositionInformation, GuildPositionName, GuildRelation,
+    GuildRelationType,
+};
+use rust_state::RustState;
+
+use crate::graphics::Texture;
+use crate::world::ResourceState;
+
+#[derive(RustState, StateElement)]
+pub struct GuildMember {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    /// The member list does not contain any names, so this stays empty until
+    /// the map server answers our name request.
+    pub name: String,
+    pub job: usize,
+    pub base_level: usize,
+    pub position_id: u32,
+    pub position_name: String,
+    pub online: bool,
+}
+
+#[derive(RustState, StateElement)]
+pub struct GuildPosition {
+    pub position_id: u32,
+    pub name: String,
+    pub tax_rate: u32,
+}
+
+/// The guild of the player. A guild without an id means that the player is
+/// currently not in a guild.
+#[derive(Default, RustState, StateElement)]
+pub struct Guild {
+    guild_id: Option<GuildId>,
+    name: String,
+    emblem_id: u32,
+    is_master: bool,
+    pub level: u32,
+    pub member_count: u32,
+    pub maximum_member_count: u32,
+    pub average_level: u32,
+    pub experience: u32,
+    pub next_level_experience: u32,
+    pub managed_territory: String,
+    notice_subject: String,
+    notice: String,
+    members: Vec<GuildMember>,
+    pub positions: Vec<GuildPosition>,
+    pub allies: Vec<String>,
+    pub antagonists: Vec<String>,
+}
+
+impl Guild {
+    pub fn is_in_guild(&self) -> bool {
+        self.guild_id.is_some()
+    }
+
+    pub fn set_player_guild(&mut self, guild_id: GuildId, name: String, emblem_id: u32, is_master: bool) {
+        // Entering a different guild invalidates everything we know so far.
+        if self.guild_id != Some(guild_id) {
+            *self = Self::default();
+        }
+
+        self.guild_id = Some(guild_id);
+        self.name = name;
+        self.emblem_id = emblem_id;
+        self.is_master = is_master;
+    }
+
+    pub fn set_information(&mut self, information: GuildInformation) {
+        self.guild_id = Some(information.guild_id);
+        self.name = information.name;
+        self.emblem_id = information.emblem_id;
+        self.level = information.level;
+        self.member_count = information.member_count;
+        self.maximum_member_count = information.maximum_member_count;
+        self.average_level = information.average_level;
+        self.experience = information.experience;
+        self.next_level_experience = information.next_level_experience;
+        self.managed_territory = information.managed_territory;
+    }
+
+    pub fn set_relations(&mut self, relations: Vec<GuildRelation>) {
+        self.allies.clear();
+        self.antagonists.clear();
+
+        for relation in relations {
+            match relation.relation_type {
+                GuildRelationType::Alliance => self.allies.push(relation.guild_name),
+                GuildRelationType::Antagonist => self.antagonists.push(relation.guild_name),
+            }
+        }
+    }
+
+    /// Replaces the member list and returns the characters whose names are
+    /// still unknown.
+    pub fn set_members(&mut self, members: Vec<GuildMemberInformation>) -> Vec<CharacterId> {
+        let members: Vec<GuildMember> = members
+            .into_iter()
+            .map(|member| {
+                // Keep names we already resolved, so we don't have to request them again.
+                let name = self
+                    .members
+                    .iter()
+                    .find(|existing| existing.character_id == member.character_id)
+                    .map(|existing| existing.name.clone())
+                    .unwrap_or_default();
+
+                GuildMember {
+                    account_id: member.account_id,
+                    character_id: member.character_id,
+                    name,
+                    job: member.job as usize,
+                    base_level: member.base_level as usize,
+                    position_id: member.position_id,
+                    position_name: self.position_name(member.position_id),
+                    online: member.online != 0,
+                }
+            })
+            .collect();
+
+        self.members = members;
+        self.members
+            .iter()
+            .filter(|member| member.name.is_empty())
+            .map(|member| member.character_id)
+            .collect()
+    }
+
+    pub fn set_positions(&mut self, positions: Vec<GuildPositionInformation>) {
+        self.positions = positions
+            .into_iter()
+            .map(|position| GuildPosition {
+                position_id: position.position_id,
+                name: self.position_name(position.position_id),
+                tax_rate: position.tax_rate,
+            })
+            .collect();
+    }
+
+    pub fn set_position_names(&mut self, names: Vec<GuildPositionName>) {
+        for GuildPositionName { position_id, name } in names {
+            match self.positions.iter_mut().find(|position| position.position_id == position_id) {
+                Some(position) => position.name = name.clone(),
+                None => self.positions.push(GuildPosition {
+                    position_id,
+                    name: name.clone(),
+                    tax_rate: 0,
+                }),
+            }
+
+            self.members
+                .iter_mut()
+                .filter(|member| member.position_id == position_id)
+                .for_each(|member| member.position_name = name.clone());
+        }
+    }
+
+    pub fn set_notice(&mut self, subject: String, notice: String) {
+        self.notice_subject = subject;
+        self.notice = notice;
+    }
+
+    pub fn set_member_name(&mut self, character_id: CharacterId, name: String) {
+        if let Some(member) = self.members.iter_mut().find(|member| member.character_id == character_id) {
+            member.name = name;
+        }
+    }
+
+    pub fn set_member_online(&mut self, account_id: AccountId, character_id: CharacterId, online: bool) {
+        if let Some(member) = self
+            .members
+            .iter_mut()
+            .find(|member| member.account_id == account_id && member.character_id == character_id)
+        {
+            member.online = online;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn position_name(&self, position_id: u32) -> String {
+        self.positions
+            .iter()
+            .find(|position| position.position_id == position_id)
+            .map(|position| position.name.clone())
+            .unwrap_or_default()
+    }
+}
+
+struct GuildEmblem {
+    emblem_id: u32,
+    texture: ResourceState<Arc<Texture>>,
+}
+
+/// Emblems of all guilds that entities around the player belong to.
+#[derive(Default)]
+pub struct GuildEmblems {
+    emblems: HashMap<GuildId, GuildEmblem>,
+}
+
+impl GuildEmblems {
+    /// Returns `true` if the emblem of the guild is unknown or older than
+    /// `emblem_id`. The emblem is marked as requested, so it is only
+    /// requested once.
+    pub fn should_request(&mut self, guild_id: GuildId, emblem_id: u32) -> bool {
+        match self.emblems.get_mut(&guild_id) {
+            Some(emblem) if emblem.emblem_id >= emblem_id => false,
+            Some(emblem) => {
+                emblem.emblem_id = emblem_id;
+                emblem.texture = ResourceState::Requested;
+                true
+            }
+            None => {
+                self.emblems.insert(guild_id, GuildEmblem {
+                    emblem_id,
+                    texture: ResourceState::Requested,
+                });
+                true
+            }
+        }
+    }
+
+    pub fn set_emblem(&mut self, guild_id: GuildId, emblem_id: u32, texture: Arc<Texture>) {
+        self.emblems.insert(guild_id, GuildEmblem {
+            emblem_id,
+            texture: ResourceState::Available(texture),
+        });
+    }
+
+    pub fn get_emblem(&self, guild_id: GuildId) -> Option<&Arc<Texture>> {
+        self.emblems.get(&guild_id).and_then(|emblem| emblem.texture.as_option())
+    }
+
+    pub fn clear(&mut self) {
+        self.emblems.clear();
+    }
+}
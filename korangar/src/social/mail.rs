@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use korangar_interface::element::StateElement;
+use korangar_networking::{InventoryItem, InventoryItemDetails, MailSummary, NoMetadata};
+use ragnarok_packets::{InventoryIndex, ItemId, MailId, MailOpenType};
+use rust_state::RustState;
+
+use crate::graphics::Texture;
+use crate::loaders::{AsyncLoader, ItemLocation};
+use crate::world::ResourceMetadata;
+
+/// The mail that is currently being read.
+#[derive(Default, RustState, StateElement)]
+pub struct OpenedMail {
+    #[hidden_element]
+    mail: Option<(MailOpenType, MailId)>,
+    pub sender_name: String,
+    pub title: String,
+    pub text: String,
+    pub zeny: u64,
+    // TODO: Unhide this.
+    #[hidden_element]
+    items: Vec<InventoryItem<ResourceMetadata>>,
+}
+
+/// The mailbox of the player. The mails are only sent while the mailbox is
+/// open.
+#[derive(Default, RustState, StateElement)]
+pub struct Mailbox {
+    /// The player has mail that wasn't read yet.
+    pub has_new_mail: bool,
+    mails: Vec<MailSummary>,
+    pub opened_mail: OpenedMail,
+}
+
+impl Mailbox {
+    pub fn set_has_new_mail(&mut self, has_new_mail: bool) {
+        self.has_new_mail = has_new_mail;
+    }
+
+    pub fn set_mails(&mut self, mails: Vec<MailSummary>) {
+        self.has_new_mail = mails.iter().any(|mail| !mail.is_read);
+        self.mails = mails;
+    }
+
+    /// The open type and id of the mail that is currently being read.
+    pub fn opened_mail_id(&self) -> Option<(MailOpenType, MailId)> {
+        self.opened_mail.mail
+    }
+
+    pub fn has_opened_items(&self) -> bool {
+        !self.opened_mail.items.is_empty()
+    }
+
+    pub fn open_mail(
+        &mut self,
+        async_loader: &AsyncLoader,
+        open_type: MailOpenType,
+        mail_id: MailId,
+        text: String,
+        zeny: u64,
+        items: Vec<InventoryItem<NoMetadata>>,
+    ) {
+        let (sender_name, title) = match self.mails.iter_mut().find(|mail| mail.mail_id == mail_id) {
+            Some(mail) => {
+                mail.is_read = true;
+                (mail.sender_name.clone(), mail.title.clone())
+            }
+            None => Default::default(),
+        };
+
+        self.has_new_mail = self.mails.iter().any(|mail| !mail.is_read);
+        self.opened_mail = OpenedMail {
+            mail: Some((open_type, mail_id)),
+            sender_name,
+            title,
+            text,
+            zeny,
+            items: items
+                .into_iter()
+                .map(|item| async_loader.request_item_metadata_load(ItemLocation::Mail, item))
+                .collect(),
+        };
+    }
+
+    pub fn zeny_retrieved(&mut self, mail_id: MailId) {
+        if let Some(mail) = self.mails.iter_mut().find(|mail| mail.mail_id == mail_id) {
+            mail.has_zeny = false;
+        }
+
+        if self.opened_mail_id().is_some_and(|(_, opened_id)| opened_id == mail_id) {
+            self.opened_mail.zeny = 0;
+        }
+    }
+
+    pub fn items_retrieved(&mut self, mail_id: MailId) {
+        if let Some(mail) = self.mails.iter_mut().find(|mail| mail.mail_id == mail_id) {
+            mail.has_items = false;
+        }
+
+        if self.opened_mail_id().is_some_and(|(_, opened_id)| opened_id == mail_id) {
+            self.opened_mail.items.clear();
+        }
+    }
+
+    pub fn remove_mail(&mut self, mail_id: MailId) {
+        self.mails.retain(|mail| mail.mail_id != mail_id);
+
+        if self.opened_mail_id().is_some_and(|(_, opened_id)| opened_id == mail_id) {
+            self.opened_mail = OpenedMail::default();
+        }
+    }
+
+    /// Forget the mails once the mailbox is closed, but keep the notification.
+    pub fn close(&mut self) {
+        self.mails.clear();
+        self.opened_mail = OpenedMail::default();
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
+        self.opened_mail
+            .items
+            .iter_mut()
+            .filter(|item| item.item_id == item_id)
+            .for_each(|item| item.metadata.texture = Some(texture.clone()));
+    }
+}
+
+/// The mail that the player is writing.
+#[derive(Default, RustState, StateElement)]
+pub struct MailComposer {
+    // TODO: Unhide this.
+    #[hidden_element]
+    attachments: Vec<InventoryItem<ResourceMetadata>>,
+    /// Weight of all attached items.
+    pub weight: u16,
+    /// The mail is sent once the server found the receiver.
+    #[hidden_element]
+    awaiting_receiver: bool,
+}
+
+impl MailComposer {
+    /// Attach an item. The amount of the item is the total amount that is
+    /// attached, so an existing attachment of the same item is replaced.
+    pub fn attach_item(&mut self, async_loader: &AsyncLoader, item: InventoryItem<NoMetadata>, weight: u16) {
+        let item = async_loader.request_item_metadata_load(ItemLocation::Mail, item);
+
+        match self.attachments.iter_mut().find(|attachment| attachment.index == item.index) {
+            Some(attachment) => *attachment = item,
+            None => self.attachments.push(item),
+        }
+
+        self.weight = weight;
+    }
+
+    /// Detach an item. The amount is the amount of the item that is still
+    /// attached.
+    pub fn detach_item(&mut self, index: InventoryIndex, amount: u16, weight: u16) {
+        match amount {
+            0 => self.attachments.retain(|attachment| attachment.index != index),
+            _ => {
+                if let Some(attachment) = self.attachments.iter_mut().find(|attachment| attachment.index == index)
+                    && let InventoryItemDetails::Regular {
+                        amount: attached_amount, ..
+                    } = &mut attachment.details
+                {
+                    *attached_amount = amount;
+                }
+            }
+        }
+
+        self.weight = weight;
+    }
+
+    pub fn await_receiver(&mut self) {
+        self.awaiting_receiver = true;
+    }
+
+    /// Returns `true` if the mail should be sent once the receiver is known.
+    pub fn take_awaiting_receiver(&mut self) -> bool {
+        std::mem::take(&mut self.awaiting_receiver)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn update_item_sprite(&mut self, item_id: ItemId, texture: Arc<Texture>) {
+        self.attachments
+            .iter_mut()
+            .filter(|item| item.item_id == item_id)
+            .for_each(|item| item.metadata.texture = Some(texture.clone()));
+    }
+}
@@ -0,0 +1,7 @@
+mod guild;
+mod mail;
+mod party;
+
+pub use self::guild::{Guild, GuildEmblems, GuildMember, GuildPathExt};
+pub use self::mail::{MailComposer, MailComposerPathExt, Mailbox, MailboxPathExt, OpenedMail, OpenedMailPathExt};
+pub use self::party::{Party, PartyPathExt};
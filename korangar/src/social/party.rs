@@ -0,0 +1,115 @@
+use korangar_interface::element::StateElement;
+use korangar_networking::PartyMember;
+use ragnarok_packets::{AccountId, PartyExperienceShare, PartyItemShare, PartyMemberRole, TilePosition};
+use rust_state::RustState;
+
+/// The party of the player. A party without a name means that the player is
+/// currently not in a party.
+#[derive(Default, RustState, StateElement)]
+pub struct Party {
+    name: String,
+    members: Vec<PartyMember>,
+    /// Experience is shared evenly between all members.
+    even_experience_share: bool,
+    /// Picked up items are shared evenly between all members.
+    even_item_pickup: bool,
+    /// Dropped items are distributed evenly between all members.
+    even_item_distribution: bool,
+}
+
+impl Party {
+    pub fn is_in_party(&self) -> bool {
+        !self.name.is_empty()
+    }
+
+    pub fn experience_share(&self) -> PartyExperienceShare {
+        match self.even_experience_share {
+            true => PartyExperienceShare::EvenShare,
+            false => PartyExperienceShare::EachTake,
+        }
+    }
+
+    pub fn item_pickup_rule(&self) -> PartyItemShare {
+        match self.even_item_pickup {
+            true => PartyItemShare::EvenShare,
+            false => PartyItemShare::EachTake,
+        }
+    }
+
+    pub fn item_distribution_rule(&self) -> PartyItemShare {
+        match self.even_item_distribution {
+            true => PartyItemShare::EvenShare,
+            false => PartyItemShare::EachTake,
+        }
+    }
+
+    pub fn set(&mut self, name: String, members: Vec<PartyMember>) {
+        self.name = name;
+        self.members = members;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn update_member(&mut self, name: String, member: PartyMember) {
+        self.name = name;
+
+        match self.members.iter_mut().find(|existing| existing.account_id == member.account_id) {
+            Some(existing) => {
+                // The server does not send the health again, so we keep the last known
+                // values.
+                let health_points = existing.health_points;
+                let maximum_health_points = existing.maximum_health_points;
+
+                *existing = member;
+                existing.health_points = health_points;
+                existing.maximum_health_points = maximum_health_points;
+            }
+            None => self.members.push(member),
+        }
+    }
+
+    pub fn remove_member(&mut self, account_id: AccountId) {
+        self.members.retain(|member| member.account_id != account_id);
+    }
+
+    pub fn update_member_health(&mut self, account_id: AccountId, health_points: u32, maximum_health_points: u32) {
+        if let Some(member) = self.members.iter_mut().find(|member| member.account_id == account_id) {
+            member.health_points = Some(health_points);
+            member.maximum_health_points = Some(maximum_health_points);
+        }
+    }
+
+    pub fn update_member_position(&mut self, account_id: AccountId, position: TilePosition) {
+        if let Some(member) = self.members.iter_mut().find(|member| member.account_id == account_id) {
+            member.position = Some(position);
+        }
+    }
+
+    pub fn set_options(
+        &mut self,
+        experience_share: PartyExperienceShare,
+        item_pickup_rule: PartyItemShare,
+        item_distribution_rule: PartyItemShare,
+    ) {
+        self.even_experience_share = experience_share == PartyExperienceShare::EvenShare;
+        self.even_item_pickup = item_pickup_rule == PartyItemShare::EvenShare;
+        self.even_item_distribution = item_distribution_rule == PartyItemShare::EvenShare;
+    }
+
+    pub fn set_item_rules(&mut self, item_pickup_rule: PartyItemShare, item_distribution_rule: PartyItemShare) {
+        self.even_item_pickup = item_pickup_rule == PartyItemShare::EvenShare;
+        self.even_item_distribution = item_distribution_rule == PartyItemShare::EvenShare;
+    }
+
+    pub fn change_leader(&mut self, old_leader_account_id: AccountId, new_leader_account_id: AccountId) {
+        for member in &mut self.members {
+            if member.account_id == old_leader_account_id {
+                member.role = PartyMemberRole::Member;
+            } else if member.account_id == new_leader_account_id {
+                member.role = PartyMemberRole::Leader;
+            }
+        }
+    }
+}
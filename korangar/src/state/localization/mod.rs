@@ -147,6 +147,7 @@ pub struct Localization {
     game_settings_button_text: String,
     interface_settings_button_text: String,
     audio_settings_button_text: String,
+    keybindings_button_text: String,
     log_out_button_text: String,
     exit_button_text: String,
     character_overview_window_title: String,
@@ -158,6 +159,13 @@ pub struct Localization {
     stats_button_text: String,
     skill_tree_button_text: String,
     friend_list_button_text: String,
+    party_button_text: String,
+    guild_button_text: String,
+    pet_button_text: String,
+    homunculus_button_text: String,
+    mercenary_button_text: String,
+    cart_button_text: String,
+    mail_button_text: String,
     menu_button_text: String,
     chat_window_title: String,
     chat_text_box_message: String,
@@ -174,6 +182,71 @@ pub struct Localization {
     friend_list_window_title: String,
     friend_list_text_box_message: String,
     remove_button_text: String,
+    party_window_title: String,
+    create_party_text_box_message: String,
+    invite_to_party_text_box_message: String,
+    party_even_experience_share_text: String,
+    party_even_item_pickup_text: String,
+    party_even_item_distribution_text: String,
+    party_leader_button_text: String,
+    expel_button_text: String,
+    leave_party_button_text: String,
+    guild_window_title: String,
+    guild_notice_text: String,
+    guild_members_text: String,
+    guild_positions_text: String,
+    guild_alliances_text: String,
+    guild_antagonists_text: String,
+    trade_window_title: String,
+    trade_own_offer_text: String,
+    trade_zeny_text_box_message: String,
+    lock_trade_button_text: String,
+    commit_trade_button_text: String,
+    cancel_trade_button_text: String,
+    storage_window_title: String,
+    close_storage_button_text: String,
+    cart_window_title: String,
+    vending_setup_window_title: String,
+    shop_title_text_box_message: String,
+    item_price_text_box_message: String,
+    open_vending_button_text: String,
+    cancel_vending_button_text: String,
+    vending_window_title: String,
+    close_vending_button_text: String,
+    vending_shop_window_title: String,
+    buy_vending_items_button_text: String,
+    mailbox_window_title: String,
+    refresh_mailbox_button_text: String,
+    write_mail_button_text: String,
+    take_mail_zeny_button_text: String,
+    take_mail_items_button_text: String,
+    delete_mail_button_text: String,
+    mail_compose_window_title: String,
+    mail_receiver_text_box_message: String,
+    mail_title_text_box_message: String,
+    mail_text_text_box_message: String,
+    mail_zeny_text_box_message: String,
+    send_mail_button_text: String,
+    cancel_mail_button_text: String,
+    pet_window_title: String,
+    pet_egg_window_title: String,
+    homunculus_window_title: String,
+    mercenary_window_title: String,
+    companion_level_text: String,
+    hunger_text: String,
+    intimacy_text: String,
+    health_points_text: String,
+    spell_points_text: String,
+    experience_text: String,
+    faith_text: String,
+    kill_count_text: String,
+    feed_button_text: String,
+    performance_button_text: String,
+    return_to_egg_button_text: String,
+    unequip_accessory_button_text: String,
+    call_back_button_text: String,
+    vaporize_button_text: String,
+    dismiss_button_text: String,
     hotbar_window_title: String,
     inventory_window_title: String,
     respawn_window_title: String,
@@ -197,6 +270,20 @@ pub struct Localization {
     dexterity_text: String,
     luck_text: String,
     auto_attack_button_text: String,
+    reconnect_button_text: String,
+    show_latency_button_text: String,
+    keybindings_window_title: String,
+    keybinding_shortcuts_text: String,
+    show_interface_text: String,
+    take_screenshot_text: String,
+    close_window_text: String,
+    hotbar_slot_text: String,
+    unbound_key_text: String,
+    press_key_text: String,
+    keybinding_conflict_text: String,
+    unbind_key_button_text: String,
+    cancel_rebind_button_text: String,
+    reset_keybindings_button_text: String,
 }
 
 impl Localization {
@@ -33,17 +33,24 @@ use self::cache_statistics::CacheStatistics;
 #[cfg(feature = "debug")]
 use crate::PacketHistory;
 use crate::character_slots::CharacterSlots;
+use crate::companion::{Companions, PetEgg};
 #[cfg(feature = "debug")]
 use crate::graphics::RenderOptions;
 use crate::graphics::{Color, CornerDiameter, ScreenClip, ScreenPosition, ScreenSize, ShadowPadding};
 use crate::input::{InputEvent, MouseInputMode};
-use crate::interface::windows::{ChatWindowState, DialogWindowState, FriendListWindowState, LoginWindowState, WindowCache, WindowClass};
+use crate::interface::windows::{
+    ChatWindowState, DialogWindowState, FriendListWindowState, KeybindingsWindowState, LoginWindowState, MailComposeWindowState,
+    PartyWindowState, TradeWindowState, VendingSetupWindowState, WindowCache, WindowClass,
+};
 #[cfg(feature = "debug")]
 use crate::interface::windows::{ProfilerWindowState, ThemeInspectorWindowState};
-use crate::inventory::{Hotbar, Inventory, SkillTree};
-use crate::loaders::{ClientInfo, FontLoader, FontSize, GameFileLoader, OverflowBehavior, load_client_info};
+use crate::inventory::{Cart, Hotbar, Inventory, SkillTree, Storage, Trade, Vending, VendingSetup, VendingShop};
+use crate::loaders::{ClientInfo, FontLoader, FontSize, GameFileLoader, ItemLocation, OverflowBehavior, load_client_info};
 use crate::renderer::InterfaceRenderer;
-use crate::settings::{GameSettings, GraphicsSettingsCapabilities, InterfaceSettings, InterfaceSettingsCapabilities, LoginSettings};
+use crate::settings::{
+    GameSettings, GraphicsSettingsCapabilities, InterfaceSettings, InterfaceSettingsCapabilities, Keybindings, LoginSettings,
+};
+use crate::social::{Guild, MailComposer, Mailbox, Party};
 use crate::state::theme::WorldTheme;
 #[cfg(feature = "debug")]
 use crate::world::Object;
@@ -85,6 +92,8 @@ pub struct ClientState {
     audio_settings: AudioSettings,
     /// Saved game settings.
     game_settings: GameSettings,
+    /// Saved keybindings.
+    keybindings: Keybindings,
     /// Saved interface settings.
     interface_settings: InterfaceSettings,
     /// Interface capabilities used in the interface settings window.
@@ -110,6 +119,16 @@ pub struct ClientState {
     chat_window: ChatWindowState,
     /// Internal state of the friend list window.
     friend_list_window: FriendListWindowState,
+    /// Internal state of the party window.
+    party_window: PartyWindowState,
+    /// Internal state of the trade window.
+    trade_window: TradeWindowState,
+    /// Internal state of the vending setup window.
+    vending_setup_window: VendingSetupWindowState,
+    /// Internal state of the mail compose window.
+    mail_compose_window: MailComposeWindowState,
+    /// Internal state of the keybindings window.
+    keybindings_window: KeybindingsWindowState,
     /// Internal state of the dialog window.
     dialog_window: DialogWindowState,
 
@@ -122,6 +141,14 @@ pub struct ClientState {
     chat_messages: Vec<ChatMessage>,
     /// List of all friends.
     friend_list: Vec<Friend>,
+    /// The party of the player.
+    party: Party,
+    /// The guild of the player.
+    guild: Guild,
+    /// The pet, homunculus and mercenary of the player.
+    companions: Companions,
+    /// Pet eggs that can be hatched with the pet incubator the player used.
+    pet_eggs: Vec<PetEgg>,
     /// List of items offered in the shop.
     // TODO: Unhide this
     #[hidden_element]
@@ -147,6 +174,22 @@ pub struct ClientState {
     inventory: Inventory,
     /// Player skill tree.
     skill_tree: SkillTree,
+    /// The current trade with another player.
+    trade: Trade,
+    /// Account storage of the player.
+    storage: Storage,
+    /// Pushcart of the player.
+    cart: Cart,
+    /// Items picked for the vending shop the player is setting up.
+    vending_setup: VendingSetup,
+    /// The open vending shop of the player.
+    vending: Vending,
+    /// The vending shop of another player that is being browsed.
+    vending_shop: VendingShop,
+    /// The mailbox of the player.
+    mailbox: Mailbox,
+    /// The mail that the player is writing.
+    mail_composer: MailComposer,
 
     /// List of all available character servers.
     character_servers: Vec<CharacterServerInformation>,
@@ -221,6 +264,7 @@ impl ClientState {
             let mut login_settings = LoginSettings::new();
             let audio_settings = AudioSettings::new();
             let game_settings = GameSettings::new();
+            let keybindings = Keybindings::new();
             let interface_settings = InterfaceSettings::new();
             let interface_settings_capabilities = InterfaceSettingsCapabilities::default();
         });
@@ -289,6 +333,26 @@ impl ClientState {
             let friend_list_window = FriendListWindowState::default();
         });
 
+        time_phase!("create party state", {
+            let party = Party::default();
+            let party_window = PartyWindowState::default();
+        });
+
+        time_phase!("create guild state", {
+            let guild = Guild::default();
+        });
+
+        time_phase!("create mail state", {
+            let mailbox = Mailbox::default();
+            let mail_composer = MailComposer::default();
+            let mail_compose_window = MailComposeWindowState::default();
+        });
+
+        time_phase!("create companion state", {
+            let companions = Companions::default();
+            let pet_eggs = Vec::new();
+        });
+
         time_phase!("create player resources", {
             let dialog_window = DialogWindowState::default();
 
@@ -298,8 +362,17 @@ impl ClientState {
             let sell_cart = Vec::default();
             let player_name = String::new();
             let hotbar = Hotbar::default();
-            let inventory = Inventory::default();
+            let inventory = Inventory::new(ItemLocation::Inventory);
             let skill_tree = SkillTree::default();
+            let trade = Trade::default();
+            let trade_window = TradeWindowState::default();
+            let keybindings_window = KeybindingsWindowState::default();
+            let storage = Storage::default();
+            let cart = Cart::default();
+            let vending_setup = VendingSetup::default();
+            let vending_setup_window = VendingSetupWindowState::default();
+            let vending = Vending::default();
+            let vending_shop = VendingShop::default();
         });
 
         time_phase!("create window resources", {
@@ -342,6 +415,7 @@ impl ClientState {
             login_settings,
             audio_settings,
             game_settings,
+            keybindings,
             interface_settings,
             interface_settings_capabilities,
             graphics_settings,
@@ -353,11 +427,20 @@ impl ClientState {
             login_window,
             chat_window,
             friend_list_window,
+            party_window,
+            trade_window,
+            vending_setup_window,
+            mail_compose_window,
+            keybindings_window,
             dialog_window,
             entities: Vec::new(),
             dead_entities: Vec::new(),
             chat_messages,
             friend_list,
+            party,
+            guild,
+            companions,
+            pet_eggs,
             shop_items,
             buy_cart,
             sell_items,
@@ -365,6 +448,14 @@ impl ClientState {
             player_name,
             hotbar,
             inventory,
+            trade,
+            storage,
+            cart,
+            vending_setup,
+            vending,
+            vending_shop,
+            mailbox,
+            mail_composer,
             skill_tree,
             character_servers,
             character_slots,
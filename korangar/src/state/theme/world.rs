@@ -25,18 +25,22 @@ impl Default for OverlayTheme {
 }
 
 #[derive(Serialize, Deserialize, RustState, StateElement)]
+// Themes saved before cast bars existed don't have all fields.
+#[serde(default)]
 pub struct StatusBarTheme {
     pub background_color: Color,
     pub player_health_color: Color,
     pub enemy_health_color: Color,
     pub spell_point_color: Color,
     pub activity_point_color: Color,
+    pub cast_color: Color,
     pub player_bar_width: f32,
     pub enemy_bar_width: f32,
     pub health_height: f32,
     pub enemy_health_height: f32,
     pub spell_point_height: f32,
     pub activity_point_height: f32,
+    pub cast_height: f32,
     pub border_size: ScreenSize,
     pub gap: f32,
 }
@@ -49,12 +53,14 @@ impl Default for StatusBarTheme {
             enemy_health_color: Color::rgb_u8(206, 49, 116),
             spell_point_color: Color::rgb_u8(0, 129, 163),
             activity_point_color: Color::rgb_u8(218, 145, 81),
+            cast_color: Color::rgb_u8(132, 114, 219),
             player_bar_width: 85.0,
             enemy_bar_width: 60.0,
             health_height: 8.0,
             enemy_health_height: 6.0,
             spell_point_height: 4.0,
             activity_point_height: 4.0,
+            cast_height: 4.0,
             border_size: ScreenSize { width: 2.0, height: 1.0 },
             gap: 1.0,
         }
@@ -87,12 +93,61 @@ impl Default for CursorTheme {
     }
 }
 
+#[derive(Serialize, Deserialize, RustState, StateElement)]
+pub struct ShopSignTheme {
+    pub background_color: Color,
+    pub foreground_color: Color,
+    pub font_size: FontSize,
+    pub padding: ScreenSize,
+    /// Height above the feet of the vendor in world units.
+    pub height: f32,
+}
+
+impl Default for ShopSignTheme {
+    fn default() -> Self {
+        Self {
+            background_color: Color::rgba_u8(40, 40, 40, 200),
+            foreground_color: Color::rgb_u8(250, 230, 130),
+            font_size: FontSize(14.0),
+            padding: ScreenSize { width: 6.0, height: 3.0 },
+            height: 25.0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, RustState, StateElement)]
+pub struct StatusEffectTheme {
+    pub foreground_color: Color,
+    pub font_size: FontSize,
+    pub icon_size: f32,
+    pub gap: f32,
+    /// Distance of the icon bar from the top right corner of the window.
+    pub offset: ScreenPosition,
+}
+
+impl Default for StatusEffectTheme {
+    fn default() -> Self {
+        Self {
+            foreground_color: Color::monochrome_u8(255),
+            font_size: FontSize(12.0),
+            icon_size: 24.0,
+            gap: 4.0,
+            offset: ScreenPosition { left: 10.0, top: 10.0 },
+        }
+    }
+}
+
 #[derive(Default, Serialize, Deserialize, RustState, StateElement)]
 pub struct WorldTheme {
     pub overlay: OverlayTheme,
     pub status_bar: StatusBarTheme,
     pub indicator: IndicatorTheme,
     pub cursor: CursorTheme,
+    // Themes saved before shop signs existed don't have this field.
+    #[serde(default)]
+    pub shop_sign: ShopSignTheme,
+    #[serde(default)]
+    pub status_effect: StatusEffectTheme,
 }
 
 impl WorldTheme {
@@ -92,6 +92,14 @@ impl AnimationState {
         }
     }
 
+    /// Animation state that plays the idle action once instead of looping it.
+    pub fn once(entity_type: EntityType, start_time: ClientTick) -> Self {
+        Self {
+            looping: false,
+            ..Self::new(entity_type, start_time)
+        }
+    }
+
     pub fn idle(&mut self, entity_type: EntityType, client_tick: ClientTick) {
         self.action_type = AnimationActionType::Idle;
         self.action_base_offset = self.action_type.action_base_offset(entity_type);
@@ -122,6 +130,15 @@ impl AnimationState {
         self.looping = true;
     }
 
+    pub fn cast(&mut self, entity_type: EntityType, client_tick: ClientTick) {
+        self.action_type = AnimationActionType::Skill;
+        self.action_base_offset = self.action_type.action_base_offset(entity_type);
+        self.start_time = client_tick;
+        self.duration = None;
+        self.factor = None;
+        self.looping = false;
+    }
+
     pub fn dead(&mut self, entity_type: EntityType, client_tick: ClientTick) {
         self.action_type = AnimationActionType::Die;
         self.action_base_offset = self.action_type.action_base_offset(entity_type);
@@ -138,6 +155,10 @@ impl AnimationState {
         )
     }
 
+    pub fn is_casting(&self) -> bool {
+        self.action_type == AnimationActionType::Skill
+    }
+
     pub fn is_walking(&self) -> bool {
         self.action_type == AnimationActionType::Walk
     }
@@ -295,7 +316,8 @@ impl AnimationData {
         entity_position: Point3<f32>,
         animation_state: &AnimationState,
         direction: Direction,
-        fade_alpha: f32,
+        tint: Color,
+        greyscale: f32,
     ) {
         let frame = self.get_frame(animation_state, camera, direction);
         let world_matrix = self.calculate_world_matrix(camera, frame, entity_position);
@@ -312,7 +334,7 @@ impl AnimationData {
 
             let position = world_matrix.transform_point(Point3::from_value(0.0));
             let distance = camera.distance_to(position);
-            let color = frame_part.color * fade_alpha;
+            let color = frame_part.color * tint;
 
             instructions.push(EntityInstruction {
                 world: world_matrix,
@@ -324,6 +346,7 @@ impl AnimationData {
                 extra_depth_offset: 0.005 * index as f32,
                 curvature,
                 color,
+                greyscale,
                 mirror: frame_part.mirror,
                 entity_id,
                 add_to_picker,
@@ -1,19 +1,26 @@
 use std::sync::Arc;
 
-use cgmath::{Point3, Rad, Vector2, Vector3};
+use cgmath::{Point3, Rad, Vector2, Vector3, Zero};
+use korangar_audio::AudioEngine;
 use korangar_collision::{Frustum, Sphere};
 use korangar_container::Cacheable;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
 use ragnarok_formats::map::EffectSource;
-use ragnarok_packets::EntityId;
+use ragnarok_packets::{ClientTick, Direction, EntityId};
 use wgpu::BlendFactor;
 
-use crate::graphics::{Color, Texture};
+use crate::graphics::{Color, EntityInstruction, Texture};
+use crate::loaders::{ActionLoader, AnimationLoader, EffectLoader, GameFileLoader, SpriteLoader, TextureLoader};
 use crate::renderer::EffectRenderer;
 #[cfg(feature = "debug")]
 use crate::renderer::MarkerRenderer;
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
-use crate::world::{Camera, PointLightId, PointLightManager};
+use crate::world::{AnimationData, AnimationState, Camera, EffectInfo, EntityPartFile, EntityType, PointLightId, PointLightManager};
+
+/// Distance at which the sounds of effects can still be heard.
+const EFFECT_SOUND_RANGE: f32 = 250.0;
 
 pub trait EffectBase {
     fn update(&mut self, entities: &[crate::world::Entity], delta_time: f32) -> bool;
@@ -23,6 +30,8 @@ pub trait EffectBase {
     fn register_point_lights(&self, point_light_manager: &mut PointLightManager, camera: &dyn Camera);
 
     fn render(&self, renderer: &mut EffectRenderer, camera: &dyn Camera);
+
+    fn render_sprites(&self, instructions: &mut Vec<EntityInstruction>, camera: &dyn Camera);
 }
 
 pub trait EffectSourceExt {
@@ -288,6 +297,7 @@ impl FrameTimer {
     }
 }
 
+#[derive(Clone, Copy)]
 pub enum EffectCenter {
     Entity(EntityId, Point3<f32>),
     Position(Point3<f32>),
@@ -299,6 +309,15 @@ impl EffectCenter {
             EffectCenter::Entity(_, position) | EffectCenter::Position(position) => *position,
         }
     }
+
+    /// Move the center along with the entity it is attached to.
+    fn follow_entity(&mut self, entities: &[crate::world::Entity]) {
+        if let EffectCenter::Entity(entity_id, position) = self
+            && let Some(entity) = entities.iter().find(|entity| entity.get_entity_id() == *entity_id)
+        {
+            *position = entity.get_position();
+        }
+    }
 }
 
 pub struct EffectWithLight {
@@ -347,12 +366,7 @@ impl EffectBase for EffectWithLight {
     fn update(&mut self, entities: &[crate::world::Entity], delta_time: f32) -> bool {
         const FADE_SPEED: f32 = 5.0;
 
-        if let EffectCenter::Entity(entity_id, position) = &mut self.center
-            && let Some(entity) = entities.iter().find(|entity| entity.get_entity_id() == *entity_id)
-        {
-            let new_position = entity.get_position();
-            *position = new_position;
-        }
+        self.center.follow_entity(entities);
 
         if !self.gets_deleted && !self.frame_timer.update(delta_time) && !self.repeating {
             self.gets_deleted = true;
@@ -374,6 +388,10 @@ impl EffectBase for EffectWithLight {
     }
 
     fn register_point_lights(&self, point_light_manager: &mut PointLightManager, camera: &dyn Camera) {
+        if self.light_intensity <= 0.0 {
+            return;
+        }
+
         let frustum = Frustum::new(camera.view_projection_matrix(), true);
 
         let light_position = self.center.to_position() + self.light_offset;
@@ -399,6 +417,85 @@ impl EffectBase for EffectWithLight {
             );
         }
     }
+
+    fn render_sprites(&self, _instructions: &mut Vec<EntityInstruction>, _camera: &dyn Camera) {}
+}
+
+/// Effect that plays the animation of a sprite, e.g. the stars of a stunned
+/// entity.
+pub struct SpriteEffect {
+    animation_data: Arc<AnimationData>,
+    animation_state: AnimationState,
+    elapsed_time: f32,
+    center: EffectCenter,
+    effect_offset: Vector3<f32>,
+    repeating: bool,
+    gets_deleted: bool,
+}
+
+impl SpriteEffect {
+    pub fn new(animation_data: Arc<AnimationData>, center: EffectCenter, effect_offset: Vector3<f32>, repeating: bool) -> Self {
+        let animation_state = match repeating {
+            true => AnimationState::new(EntityType::Warp, ClientTick(0)),
+            false => AnimationState::once(EntityType::Warp, ClientTick(0)),
+        };
+
+        Self {
+            animation_data,
+            animation_state,
+            elapsed_time: 0.0,
+            center,
+            effect_offset,
+            repeating,
+            gets_deleted: false,
+        }
+    }
+}
+
+impl EffectBase for SpriteEffect {
+    fn update(&mut self, entities: &[crate::world::Entity], delta_time: f32) -> bool {
+        self.center.follow_entity(entities);
+
+        self.elapsed_time += delta_time;
+        self.animation_state.update(ClientTick((self.elapsed_time * 1000.0) as u32));
+
+        if !self.repeating && self.animation_data.is_animation_over(&self.animation_state) {
+            self.gets_deleted = true;
+        }
+
+        !self.gets_deleted
+    }
+
+    fn mark_for_deletion(&mut self) {
+        self.gets_deleted = true;
+    }
+
+    fn register_point_lights(&self, _point_light_manager: &mut PointLightManager, _camera: &dyn Camera) {}
+
+    fn render(&self, _renderer: &mut EffectRenderer, _camera: &dyn Camera) {}
+
+    fn render_sprites(&self, instructions: &mut Vec<EntityInstruction>, camera: &dyn Camera) {
+        if self.gets_deleted {
+            return;
+        }
+
+        let entity_id = match self.center {
+            EffectCenter::Entity(entity_id, _) => entity_id,
+            EffectCenter::Position(_) => EntityId(0),
+        };
+
+        self.animation_data.render(
+            instructions,
+            camera,
+            false,
+            entity_id,
+            self.center.to_position() + self.effect_offset,
+            &self.animation_state,
+            Direction::North,
+            Color::WHITE,
+            0.0,
+        );
+    }
 }
 
 #[derive(Default)]
@@ -415,6 +512,105 @@ impl EffectHolder {
         self.effects.push((effect, Some(entity_id)));
     }
 
+    /// Add an effect described by an entry of the effect table. If a unit is
+    /// given, the effect is removed together with the unit.
+    pub fn add_from_info(
+        &mut self,
+        effect_info: &EffectInfo,
+        effect_loader: &EffectLoader,
+        texture_loader: &TextureLoader,
+        animation_loader: &AnimationLoader,
+        sprite_loader: &SpriteLoader,
+        action_loader: &ActionLoader,
+        audio_engine: &AudioEngine<GameFileLoader>,
+        center: EffectCenter,
+        point_light_id: PointLightId,
+        unit: Option<EntityId>,
+    ) {
+        if let Some(sound) = &effect_info.sound {
+            let sound_effect_key = audio_engine.load(sound);
+            audio_engine.play_spatial_sound_effect(sound_effect_key, center.to_position(), EFFECT_SOUND_RANGE);
+        }
+
+        if let Some(sprite) = &effect_info.sprite {
+            self.add_sprite(
+                sprite,
+                animation_loader,
+                sprite_loader,
+                action_loader,
+                center,
+                effect_info.offset,
+                effect_info.repeating,
+                unit,
+            );
+        }
+
+        let Some(file) = &effect_info.file else {
+            return;
+        };
+
+        let Ok(effect) = effect_loader.get_or_load(file, texture_loader) else {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] failed to load effect {}", "error".red(), file.magenta());
+            return;
+        };
+
+        let frame_timer = effect.new_frame_timer();
+        let (light_offset, light_color, light_intensity) = match &effect_info.light {
+            Some(light) => (light.offset, light.color, light.intensity),
+            None => (Vector3::zero(), Color::BLACK, 0.0),
+        };
+
+        let effect = Box::new(EffectWithLight::new(
+            effect,
+            frame_timer,
+            center,
+            effect_info.offset,
+            point_light_id,
+            light_offset,
+            light_color,
+            light_intensity,
+            effect_info.repeating,
+        ));
+
+        self.effects.push((effect, unit));
+    }
+
+    fn add_sprite(
+        &mut self,
+        sprite: &str,
+        animation_loader: &AnimationLoader,
+        sprite_loader: &SpriteLoader,
+        action_loader: &ActionLoader,
+        center: EffectCenter,
+        effect_offset: Vector3<f32>,
+        repeating: bool,
+        unit: Option<EntityId>,
+    ) {
+        // The animation loader expects the sprite of the first part to exist.
+        if !sprite_loader.sprite_exists(&format!("{sprite}.spr")) {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] failed to load effect sprite {}", "error".red(), sprite.magenta());
+            return;
+        }
+
+        let part_files = [EntityPartFile {
+            file_path: sprite.to_owned(),
+            palette_path: None,
+            behind_body: false,
+        }];
+
+        let Ok(animation_data) = animation_loader.load(sprite_loader, action_loader, EntityType::Warp, &part_files) else {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] failed to load effect sprite {}", "error".red(), sprite.magenta());
+            return;
+        };
+
+        let effect = Box::new(SpriteEffect::new(animation_data, center, effect_offset, repeating));
+
+        self.effects.push((effect, unit));
+    }
+
     pub fn remove_unit(&mut self, removed_entity_id: EntityId) {
         self.effects
             .iter_mut()
@@ -439,4 +635,10 @@ impl EffectHolder {
     pub fn render(&self, renderer: &mut EffectRenderer, camera: &dyn Camera) {
         self.effects.iter().for_each(|(effect, _)| effect.render(renderer, camera));
     }
+
+    pub fn render_sprites(&self, instructions: &mut Vec<EntityInstruction>, camera: &dyn Camera) {
+        self.effects
+            .iter()
+            .for_each(|(effect, _)| effect.render_sprites(instructions, camera));
+    }
 }
@@ -1,15 +1,19 @@
+use std::ops::RangeInclusive;
 use std::string::String;
 use std::sync::Arc;
 
 use arrayvec::ArrayVec;
-use cgmath::{EuclideanSpace, Point3, Vector2, VectorSpace};
+use cgmath::{EuclideanSpace, Point3, Vector2, Vector3, VectorSpace};
 use korangar_audio::{AudioEngine, SoundEffectKey};
 #[cfg(feature = "debug")]
 use korangar_debug::logging::Colorize;
+use korangar_interface::application::Clip;
 use korangar_interface::element::StateElement;
 use korangar_interface::window::{StateWindow, Window};
-use korangar_networking::EntityData;
-use ragnarok_packets::{AccountId, CharacterInformation, ClientTick, Direction, EntityId, Sex, StatType, TilePosition, WorldPosition};
+use korangar_networking::{BodyState, CompanionType, EntityData, EntityState, EquipmentLook, EquipmentLookChange, StatusEffect};
+use ragnarok_packets::{
+    AccountId, CharacterInformation, ClientTick, Direction, EntityId, GuildId, Sex, StatType, StatusEffectId, TilePosition, WorldPosition,
+};
 use rust_state::{Path, RustState, VecItem};
 #[cfg(feature = "debug")]
 use smallvec::smallvec_inline;
@@ -20,25 +24,32 @@ use wgpu::{BufferUsages, Device, Queue};
 use crate::graphics::reduce_vertices;
 #[cfg(feature = "debug")]
 use crate::graphics::{BindlessSupport, DebugRectangleInstruction};
-use crate::graphics::{EntityInstruction, ScreenPosition, ScreenSize};
+use crate::graphics::{Color, EntityInstruction, ScreenClip, ScreenPosition, ScreenSize, Texture};
 use crate::loaders::GameFileLoader;
 #[cfg(feature = "debug")]
 use crate::loaders::{GAT_TILE_SIZE, split_mesh_by_texture};
-use crate::renderer::GameInterfaceRenderer;
 #[cfg(feature = "debug")]
 use crate::renderer::MarkerRenderer;
+use crate::renderer::{AlignHorizontal, GameInterfaceRenderer, SpriteRenderer};
+use crate::social::GuildEmblems;
 use crate::state::ClientState;
 use crate::state::theme::{InterfaceThemeType, WorldTheme};
-use crate::world::{ActionEvent, AnimationData, AnimationState, Camera, JobIdentity, Library, MAX_WALK_PATH_SIZE, Map, PathFinder};
+use crate::world::{
+    AccessoryName, ActionEvent, AnimationData, AnimationState, Camera, JobIdentity, Library, MAX_WALK_PATH_SIZE, Map, PathFinder, RobeName,
+    WeaponName,
+};
 #[cfg(feature = "debug")]
 use crate::world::{MarkerIdentifier, SubMesh};
 #[cfg(feature = "debug")]
-use crate::{Buffer, Color, ModelVertex};
+use crate::{Buffer, ModelVertex};
 
 const MALE_HAIR_LOOKUP: &[usize] = &[2, 2, 1, 7, 5, 4, 3, 6, 8, 9, 10, 12, 11];
 const FEMALE_HAIR_LOOKUP: &[usize] = &[2, 2, 4, 7, 1, 5, 3, 6, 12, 10, 9, 11, 8];
+const SHIELD_NAME_LOOKUP: &[&str] = &["", "_가드", "_버클러", "_방패", "_미러실드"];
 const SOUND_COOLDOWN_DURATION: u32 = 200;
 const SPATIAL_SOUND_RANGE: f32 = 250.0;
+const GUILD_EMBLEM_SIZE: f32 = 24.0;
+const GUILD_EMBLEM_GAP: f32 = 2.0;
 
 #[derive(Clone)]
 pub enum ResourceState<T> {
@@ -100,6 +111,17 @@ pub enum EntityType {
     Warp,
 }
 
+/// Job ids of homunculi and the evolved homunculi S. Their sprites are in a
+/// separate folder.
+const HOMUNCULUS_JOB_IDS: [RangeInclusive<usize>; 2] = [6001..=6016, 6048..=6052];
+
+/// Job ids of mercenaries. Their sprites are in a separate folder.
+const MERCENARY_JOB_IDS: RangeInclusive<usize> = 6017..=6046;
+
+fn is_homunculus(job_id: usize) -> bool {
+    HOMUNCULUS_JOB_IDS.iter().any(|job_ids| job_ids.contains(&job_id))
+}
+
 impl From<usize> for EntityType {
     fn from(value: usize) -> Self {
         match value {
@@ -108,6 +130,8 @@ impl From<usize> for EntityType {
             0..=44 | 4000..=5999 => EntityType::Player,
             46..=999 | 10000..=19999 => EntityType::Npc,
             1000..=3999 | 20000..=29999 => EntityType::Monster,
+            // Homunculi and mercenaries move and fight like monsters.
+            value if is_homunculus(value) || MERCENARY_JOB_IDS.contains(&value) => EntityType::Monster,
             _ => EntityType::Npc,
         }
     }
@@ -198,6 +222,52 @@ impl FadeState {
     }
 }
 
+/// A skill that an entity is currently casting.
+#[derive(Copy, Clone, Debug)]
+pub struct SkillCast {
+    start_time: ClientTick,
+    cast_time: u32,
+}
+
+impl SkillCast {
+    pub fn progress(&self, client_tick: ClientTick) -> f32 {
+        let elapsed = client_tick.0.wrapping_sub(self.start_time.0);
+        (elapsed as f32 / self.cast_time as f32).min(1.0)
+    }
+
+    pub fn is_complete(&self, client_tick: ClientTick) -> bool {
+        client_tick.0.wrapping_sub(self.start_time.0) >= self.cast_time
+    }
+}
+
+/// Tint of entities that are frozen.
+const FROZEN_TINT: Color = Color::rgb(0.55, 0.75, 1.0);
+/// Tint of entities that are stunned.
+const STUNNED_TINT: Color = Color::rgb(1.0, 0.9, 0.45);
+/// Entities that are hiding are only drawn partially transparent.
+const HIDDEN_ALPHA: f32 = 0.5;
+
+/// A status effect that is active on an entity.
+#[derive(Clone, Debug)]
+pub struct ActiveStatusEffect {
+    pub status_effect: StatusEffect,
+    /// Icon of the status effect. Only loaded for the player.
+    pub icon: Option<Arc<Texture>>,
+    received_at: ClientTick,
+}
+
+impl ActiveStatusEffect {
+    /// Time in milliseconds until the status effect expires. `None` if the
+    /// status effect doesn't expire.
+    pub fn remaining_time(&self, client_tick: ClientTick) -> Option<u32> {
+        let elapsed = client_tick.0.wrapping_sub(self.received_at.0);
+
+        self.status_effect
+            .remaining_time
+            .map(|remaining_time| remaining_time.saturating_sub(elapsed))
+    }
+}
+
 #[derive(Clone, RustState, StateElement)]
 pub struct Common {
     pub entity_id: EntityId,
@@ -224,6 +294,30 @@ pub struct Common {
     sound_state: SoundState,
     #[hidden_element]
     fade_state: FadeState,
+    #[hidden_element]
+    equipment_look: EquipmentLook,
+    #[hidden_element]
+    hair_color: u16,
+    #[hidden_element]
+    clothes_color: u16,
+    #[hidden_element]
+    companion_type: Option<CompanionType>,
+    /// Type of the pushcart. `0` means that the entity has no cart.
+    #[hidden_element]
+    cart_type: u32,
+    /// Title of the vending shop if the entity is vending.
+    #[hidden_element]
+    shop_title: Option<String>,
+    #[hidden_element]
+    guild_id: Option<GuildId>,
+    #[hidden_element]
+    emblem_id: u32,
+    #[hidden_element]
+    skill_cast: Option<SkillCast>,
+    #[hidden_element]
+    state: EntityState,
+    #[hidden_element]
+    status_effects: Vec<ActiveStatusEffect>,
 }
 
 #[cfg_attr(feature = "debug", korangar_debug::profile)]
@@ -377,7 +471,57 @@ fn get_sprite_path_for_player_job(job_id: usize) -> &'static str {
     }
 }
 
-fn get_entity_part_files(library: &Library, entity_type: EntityType, job_id: usize, sex: Sex, head: Option<usize>) -> Vec<String> {
+/// A sprite and action file that make up one part of an entity, e.g. the
+/// body or a headgear.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EntityPartFile {
+    /// Path of the sprite and action file without the extension.
+    pub file_path: String,
+    /// Path of the palette file without the extension. If this is `None`, the
+    /// palette embedded in the sprite is used.
+    pub palette_path: Option<String>,
+    /// If the part is drawn behind the body instead of being attached to it,
+    /// e.g. the cart of a merchant.
+    pub behind_body: bool,
+}
+
+impl EntityPartFile {
+    fn new(file_path: String) -> Self {
+        Self {
+            file_path,
+            palette_path: None,
+            behind_body: false,
+        }
+    }
+
+    fn with_palette(file_path: String, palette_path: Option<String>) -> Self {
+        Self {
+            file_path,
+            palette_path,
+            behind_body: false,
+        }
+    }
+
+    fn behind_body(file_path: String) -> Self {
+        Self {
+            file_path,
+            palette_path: None,
+            behind_body: true,
+        }
+    }
+}
+
+fn get_entity_part_files(library: &Library, common: &Common, head: Option<usize>) -> Vec<EntityPartFile> {
+    let Common {
+        entity_type,
+        job_id,
+        sex,
+        hair_color,
+        clothes_color,
+        cart_type,
+        ..
+    } = *common;
+
     let sex_sprite_path = match sex == Sex::Female {
         true => "여",
         false => "남",
@@ -396,6 +540,66 @@ fn get_entity_part_files(library: &Library, entity_type: EntityType, job_id: usi
         format!("인간족\\머리통\\{}\\{}_{}", sex_sprite_path, head_id, sex_sprite_path)
     }
 
+    // A palette index of `0` means that the palette embedded in the sprite is used.
+    fn player_body_palette_path(sex_sprite_path: &str, job_id: usize, clothes_color: u16) -> Option<String> {
+        (clothes_color != 0).then(|| {
+            format!(
+                "몸\\{}_{}_{}",
+                get_sprite_path_for_player_job(job_id),
+                sex_sprite_path,
+                clothes_color
+            )
+        })
+    }
+
+    fn player_head_palette_path(sex_sprite_path: &str, head_id: usize, hair_color: u16) -> Option<String> {
+        (hair_color != 0).then(|| format!("머리\\머리{}_{}_{}", head_id, sex_sprite_path, hair_color))
+    }
+
+    fn player_headgear_path(library: &Library, sex_sprite_path: &str, view_id: u16) -> Option<String> {
+        let accessory_name = library.try_get::<AccessoryName>(view_id as usize)?;
+        Some(format!("악세사리\\{}\\{}{}", sex_sprite_path, sex_sprite_path, accessory_name))
+    }
+
+    fn player_robe_path(library: &Library, sex_sprite_path: &str, job_id: usize, view_id: u16) -> Option<String> {
+        let robe_name = library.try_get::<RobeName>(view_id as usize)?;
+        Some(format!(
+            "로브\\{}\\{}\\{}_{}",
+            robe_name,
+            sex_sprite_path,
+            get_sprite_path_for_player_job(job_id),
+            sex_sprite_path
+        ))
+    }
+
+    fn player_weapon_path(library: &Library, sex_sprite_path: &str, job_id: usize, view_id: u32) -> Option<String> {
+        let weapon_name = library.try_get::<WeaponName>(view_id as usize)?;
+        let job_sprite_path = get_sprite_path_for_player_job(job_id);
+        Some(format!(
+            "인간족\\{}\\{}_{}{}",
+            job_sprite_path, job_sprite_path, sex_sprite_path, weapon_name
+        ))
+    }
+
+    fn player_shield_path(sex_sprite_path: &str, job_id: usize, view_id: u32) -> Option<String> {
+        // TODO: Newer shields use their item id as the view id, which needs to be
+        // resolved through the item info first.
+        let shield_name = SHIELD_NAME_LOOKUP.get(view_id as usize).filter(|name| !name.is_empty())?;
+        let job_sprite_path = get_sprite_path_for_player_job(job_id);
+        Some(format!(
+            "방패\\{}\\{}_{}{}",
+            job_sprite_path, job_sprite_path, sex_sprite_path, shield_name
+        ))
+    }
+
+    // The first cart has no number in its file name.
+    fn player_cart_path(cart_type: u32) -> String {
+        match cart_type {
+            1 => "이팩트\\손수레".to_owned(),
+            cart_type => format!("이팩트\\손수레{cart_type}"),
+        }
+    }
+
     let head_id = match (sex, head) {
         (Sex::Male, Some(head)) if (0..MALE_HAIR_LOOKUP.len()).contains(&head) => MALE_HAIR_LOOKUP[head],
         (Sex::Male, Some(head)) => head,
@@ -405,13 +609,87 @@ fn get_entity_part_files(library: &Library, entity_type: EntityType, job_id: usi
     };
 
     match entity_type {
-        EntityType::Player => vec![
-            player_body_path(sex_sprite_path, job_id),
-            player_head_path(sex_sprite_path, head_id),
-        ],
-        EntityType::Npc => vec![format!("npc\\{}", library.get::<JobIdentity>(job_id).to_string())],
-        EntityType::Monster => vec![format!("몬스터\\{}", library.get::<JobIdentity>(job_id).to_string())],
-        EntityType::Warp | EntityType::Hidden => vec![format!("npc\\{}", library.get::<JobIdentity>(job_id).to_string())], // TODO: change
+        EntityType::Player => {
+            let EquipmentLook {
+                weapon,
+                shield,
+                head_top,
+                head_middle,
+                head_bottom,
+                robe,
+            } = common.equipment_look;
+
+            // Headgears that occupy multiple slots are sent once per slot, but should only
+            // be rendered once.
+            let head_middle = (head_middle != head_top).then_some(head_middle);
+            let head_bottom = (head_bottom != head_top && Some(head_bottom) != head_middle).then_some(head_bottom);
+
+            // The body needs to come first, since all other parts are attached to it.
+            // TODO: The draw order of the garment, weapon and shield depends on the
+            // direction the player is facing.
+            let mut part_files = vec![EntityPartFile::with_palette(
+                player_body_path(sex_sprite_path, job_id),
+                player_body_palette_path(sex_sprite_path, job_id, clothes_color),
+            )];
+
+            if robe != 0
+                && let Some(robe_path) = player_robe_path(library, sex_sprite_path, job_id, robe)
+            {
+                part_files.push(EntityPartFile::new(robe_path));
+            }
+
+            part_files.push(EntityPartFile::with_palette(
+                player_head_path(sex_sprite_path, head_id),
+                player_head_palette_path(sex_sprite_path, head_id, hair_color),
+            ));
+
+            for view_id in [head_bottom, head_middle, Some(head_top)].into_iter().flatten() {
+                if view_id != 0
+                    && let Some(headgear_path) = player_headgear_path(library, sex_sprite_path, view_id)
+                {
+                    part_files.push(EntityPartFile::new(headgear_path));
+                }
+            }
+
+            if weapon != 0
+                && let Some(weapon_path) = player_weapon_path(library, sex_sprite_path, job_id, weapon)
+            {
+                part_files.push(EntityPartFile::new(weapon_path));
+            }
+
+            if shield != 0
+                && let Some(shield_path) = player_shield_path(sex_sprite_path, job_id, shield)
+            {
+                part_files.push(EntityPartFile::new(shield_path));
+            }
+
+            if cart_type != 0 {
+                part_files.push(EntityPartFile::behind_body(player_cart_path(cart_type)));
+            }
+
+            part_files
+        }
+        EntityType::Npc => vec![EntityPartFile::new(format!(
+            "npc\\{}",
+            library.get::<JobIdentity>(job_id).to_string()
+        ))],
+        EntityType::Monster if MERCENARY_JOB_IDS.contains(&job_id) => vec![EntityPartFile::new(format!(
+            "인간족\\용병\\{}",
+            library.get::<JobIdentity>(job_id).to_string()
+        ))],
+        EntityType::Monster if is_homunculus(job_id) => vec![EntityPartFile::new(format!(
+            "homun\\{}",
+            library.get::<JobIdentity>(job_id).to_string()
+        ))],
+        EntityType::Monster => vec![EntityPartFile::new(format!(
+            "몬스터\\{}",
+            library.get::<JobIdentity>(job_id).to_string()
+        ))],
+        // TODO: change
+        EntityType::Warp | EntityType::Hidden => vec![EntityPartFile::new(format!(
+            "npc\\{}",
+            library.get::<JobIdentity>(job_id).to_string()
+        ))],
     }
 }
 
@@ -426,6 +704,10 @@ impl Common {
         let health_points = entity_data.health_points as usize;
         let maximum_health_points = entity_data.maximum_health_points as usize;
         let sex = entity_data.sex;
+        let equipment_look = entity_data.equipment_look;
+        let hair_color = entity_data.hair_color;
+        let clothes_color = entity_data.clothes_color;
+        let companion_type = entity_data.companion_type;
 
         let active_movement = None;
         let entity_type = job_id.into();
@@ -455,16 +737,31 @@ impl Common {
                 direction: FadeDirection::In,
                 start_time: client_tick,
             },
+            equipment_look,
+            hair_color,
+            clothes_color,
+            companion_type,
+            cart_type: 0,
+            shop_title: None,
+            guild_id: entity_data.guild_id,
+            emblem_id: entity_data.emblem_id,
+            skill_cast: None,
+            state: entity_data.state,
+            status_effects: Vec::new(),
         }
     }
 
-    pub fn get_entity_part_files(&self, library: &Library) -> Vec<String> {
-        get_entity_part_files(library, self.entity_type, self.job_id, self.sex, None)
+    pub fn get_entity_part_files(&self, library: &Library) -> Vec<EntityPartFile> {
+        get_entity_part_files(library, self, None)
     }
 
     pub fn update(&mut self, audio_engine: &AudioEngine<GameFileLoader>, map: &Map, camera: &dyn Camera, client_tick: ClientTick) {
         self.update_movement(map, client_tick);
-        self.animation_state.update(client_tick);
+
+        // Entities that are turned to stone or frozen don't move at all.
+        if !matches!(self.state.body_state, BodyState::Stone | BodyState::Frozen) {
+            self.animation_state.update(client_tick);
+        }
 
         if let FadeState::Fading { direction, .. } = self.fade_state
             && direction == FadeDirection::In
@@ -473,6 +770,10 @@ impl Common {
             self.fade_state = FadeState::Opaque;
         }
 
+        if self.skill_cast.is_some_and(|skill_cast| skill_cast.is_complete(client_tick)) {
+            self.stop_casting(client_tick);
+        }
+
         if let Some(animation_data) = self.animation_data.as_ref() {
             if animation_data.is_animation_over(&self.animation_state) && self.animation_state.is_attack() {
                 self.animation_state.idle(self.entity_type, client_tick);
@@ -556,6 +857,14 @@ impl Common {
         self.animation_state.idle(self.entity_type, client_tick);
     }
 
+    fn stop_casting(&mut self, client_tick: ClientTick) {
+        self.skill_cast = None;
+
+        if self.animation_state.is_casting() {
+            self.animation_state.idle(self.entity_type, client_tick);
+        }
+    }
+
     pub fn move_from_to(
         &mut self,
         map: &Map,
@@ -877,7 +1186,20 @@ impl Common {
 
     pub fn render(&self, instructions: &mut Vec<EntityInstruction>, camera: &dyn Camera, add_to_picker: bool, client_tick: ClientTick) {
         if let Some(animation_data) = self.animation_data.as_ref() {
-            let fade_alpha = self.fade_state.calculate_alpha(client_tick);
+            let mut alpha = self.fade_state.calculate_alpha(client_tick);
+
+            if self.state.hidden {
+                alpha *= HIDDEN_ALPHA;
+            }
+
+            let (tint, greyscale) = match self.state.body_state {
+                BodyState::Stone => (Color::WHITE, 1.0),
+                BodyState::Petrifying => (Color::WHITE, 0.5),
+                BodyState::Frozen => (FROZEN_TINT, 0.0),
+                BodyState::Stunned => (STUNNED_TINT, 0.0),
+                _ => (Color::WHITE, 0.0),
+            };
+
             animation_data.render(
                 instructions,
                 camera,
@@ -886,7 +1208,8 @@ impl Common {
                 self.world_position,
                 &self.animation_state,
                 self.direction,
-                fade_alpha,
+                tint * alpha,
+                greyscale,
             );
         }
     }
@@ -1121,9 +1444,8 @@ impl Player {
         );
     }
 
-    pub fn get_entity_part_files(&self, library: &Library) -> Vec<String> {
-        let common = self.get_common();
-        get_entity_part_files(library, common.entity_type, common.job_id, common.sex, Some(self.hair_id))
+    pub fn get_entity_part_files(&self, library: &Library) -> Vec<EntityPartFile> {
+        get_entity_part_files(library, self.get_common(), Some(self.hair_id))
     }
 }
 
@@ -1232,6 +1554,11 @@ impl Entity {
         self.get_common().entity_type
     }
 
+    /// Set if the entity is a pet, homunculus or mercenary.
+    pub fn get_companion_type(&self) -> Option<CompanionType> {
+        self.get_common().companion_type
+    }
+
     pub fn get_fade_state(&self) -> FadeState {
         self.get_common().fade_state
     }
@@ -1266,11 +1593,47 @@ impl Entity {
         }
     }
 
+    pub fn set_hair_color(&mut self, hair_color: u16) {
+        self.get_common_mut().hair_color = hair_color;
+    }
+
+    pub fn set_clothes_color(&mut self, clothes_color: u16) {
+        self.get_common_mut().clothes_color = clothes_color;
+    }
+
+    pub fn set_cart_type(&mut self, cart_type: u32) {
+        self.get_common_mut().cart_type = cart_type;
+    }
+
+    pub fn set_shop_title(&mut self, shop_title: Option<String>) {
+        self.get_common_mut().shop_title = shop_title;
+    }
+
+    pub fn get_shop_title(&self) -> Option<&str> {
+        self.get_common().shop_title.as_deref()
+    }
+
+    /// Returns the guild of the entity together with the id of its emblem.
+    pub fn get_guild(&self) -> Option<(GuildId, u32)> {
+        let common = self.get_common();
+        common.guild_id.map(|guild_id| (guild_id, common.emblem_id))
+    }
+
+    pub fn set_guild(&mut self, guild_id: Option<GuildId>, emblem_id: u32) {
+        let common = self.get_common_mut();
+        common.guild_id = guild_id;
+        common.emblem_id = emblem_id;
+    }
+
+    pub fn update_equipment_look(&mut self, change: EquipmentLookChange) {
+        self.get_common_mut().equipment_look.apply(change);
+    }
+
     pub fn set_animation_data(&mut self, animation_data: Arc<AnimationData>) {
         self.get_common_mut().animation_data = Some(animation_data)
     }
 
-    pub fn get_entity_part_files(&self, library: &Library) -> Vec<String> {
+    pub fn get_entity_part_files(&self, library: &Library) -> Vec<EntityPartFile> {
         match self {
             Self::Player(player) => player.get_entity_part_files(library),
             Self::Npc(npc) => npc.get_common().get_entity_part_files(library),
@@ -1331,6 +1694,43 @@ impl Entity {
             .attack(entity_type, attack_duration, critical, client_tick);
     }
 
+    pub fn set_casting(&mut self, cast_time: u32, client_tick: ClientTick) {
+        let entity_type = self.get_entity_type();
+        let common = self.get_common_mut();
+
+        common.skill_cast = Some(SkillCast {
+            start_time: client_tick,
+            cast_time,
+        });
+        common.animation_state.cast(entity_type, client_tick);
+    }
+
+    pub fn stop_casting(&mut self, client_tick: ClientTick) {
+        self.get_common_mut().stop_casting(client_tick);
+    }
+
+    /// Adds a status effect to the entity, replacing an existing status effect
+    /// with the same id.
+    pub fn add_status_effect(&mut self, status_effect: StatusEffect, icon: Option<Arc<Texture>>, client_tick: ClientTick) {
+        let status_effects = &mut self.get_common_mut().status_effects;
+        status_effects.retain(|active| active.status_effect.status_effect_id != status_effect.status_effect_id);
+        status_effects.push(ActiveStatusEffect {
+            status_effect,
+            icon,
+            received_at: client_tick,
+        });
+    }
+
+    pub fn remove_status_effect(&mut self, status_effect_id: StatusEffectId) {
+        self.get_common_mut()
+            .status_effects
+            .retain(|active| active.status_effect.status_effect_id != status_effect_id);
+    }
+
+    pub fn set_state(&mut self, state: EntityState) {
+        self.get_common_mut().state = state;
+    }
+
     pub fn stopped_moving(&self) -> bool {
         self.get_common().stopped_moving
     }
@@ -1399,6 +1799,163 @@ impl Entity {
             Self::Npc(npc) => npc.render_status(renderer, camera, theme, window_size),
         }
     }
+
+    /// Renders the progress of the current skill cast below the status bars.
+    pub fn render_cast_bar(
+        &self,
+        renderer: &GameInterfaceRenderer,
+        camera: &dyn Camera,
+        theme: &WorldTheme,
+        window_size: ScreenSize,
+        client_tick: ClientTick,
+    ) {
+        let common = self.get_common();
+
+        let Some(skill_cast) = common.skill_cast else {
+            return;
+        };
+
+        let clip_space_position = camera.view_projection_matrix() * common.world_position.to_homogeneous();
+        let screen_position = camera.clip_to_screen_space(clip_space_position);
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height + 5.0,
+        };
+
+        let gap = theme.status_bar.gap;
+        let (bar_width, status_height) = match self {
+            Self::Player(_) => (
+                theme.status_bar.player_bar_width,
+                theme.status_bar.health_height + theme.status_bar.spell_point_height + theme.status_bar.activity_point_height + gap * 2.0,
+            ),
+            Self::Npc(_) => (theme.status_bar.enemy_bar_width, theme.status_bar.enemy_health_height),
+        };
+
+        let cast_position = final_position + ScreenPosition::only_top(status_height + theme.status_bar.border_size.height * 2.0 + gap);
+        let cast_size = ScreenSize {
+            width: bar_width,
+            height: theme.status_bar.cast_height,
+        };
+
+        renderer.render_rectangle(
+            cast_position - theme.status_bar.border_size - ScreenSize::only_width(bar_width / 2.0),
+            cast_size + theme.status_bar.border_size * 2.0,
+            theme.status_bar.background_color,
+        );
+
+        renderer.render_bar(
+            cast_position,
+            cast_size,
+            theme.status_bar.cast_color,
+            1.0,
+            skill_cast.progress(client_tick),
+        );
+    }
+
+    /// Renders the icons of the status effects in a column at the top right of
+    /// the window, with the remaining time below each icon.
+    pub fn render_status_effects(
+        &self,
+        renderer: &GameInterfaceRenderer,
+        theme: &WorldTheme,
+        window_size: ScreenSize,
+        client_tick: ClientTick,
+    ) {
+        let theme = &theme.status_effect;
+        let mut position = ScreenPosition {
+            left: window_size.width - theme.offset.left - theme.icon_size,
+            top: theme.offset.top,
+        };
+
+        for status_effect in self.get_common().status_effects.iter() {
+            // Status effects without an icon are not shown.
+            let Some(icon) = &status_effect.icon else {
+                continue;
+            };
+
+            renderer.render_sprite(
+                icon.clone(),
+                position,
+                ScreenSize::uniform(theme.icon_size),
+                ScreenClip::unbound(),
+                Color::WHITE,
+                false,
+            );
+
+            position.top += theme.icon_size;
+
+            if let Some(remaining_time) = status_effect.remaining_time(client_tick) {
+                renderer.render_text(
+                    &format_remaining_time(remaining_time),
+                    position + ScreenPosition::only_left(theme.icon_size / 2.0),
+                    theme.foreground_color,
+                    theme.font_size,
+                    AlignHorizontal::Mid,
+                );
+
+                position.top += theme.font_size.0;
+            }
+
+            position.top += theme.gap;
+        }
+    }
+
+    /// Renders the emblem of the entity's guild centered above the name at
+    /// `name_position`.
+    pub fn render_guild_emblem(&self, renderer: &GameInterfaceRenderer, guild_emblems: &GuildEmblems, name_position: ScreenPosition) {
+        let Some(guild_emblem) = self.get_guild().and_then(|(guild_id, _)| guild_emblems.get_emblem(guild_id)) else {
+            return;
+        };
+
+        let emblem_position = ScreenPosition {
+            left: name_position.left - GUILD_EMBLEM_SIZE / 2.0,
+            top: name_position.top - GUILD_EMBLEM_SIZE - GUILD_EMBLEM_GAP,
+        };
+
+        renderer.render_sprite(
+            guild_emblem.clone(),
+            emblem_position,
+            ScreenSize::uniform(GUILD_EMBLEM_SIZE),
+            ScreenClip::unbound(),
+            Color::WHITE,
+            false,
+        );
+    }
+
+    /// Renders the title of the vending shop above the entity, if it has one.
+    pub fn render_shop_sign(&self, renderer: &GameInterfaceRenderer, camera: &dyn Camera, theme: &WorldTheme, window_size: ScreenSize) {
+        let Some(shop_title) = self.get_shop_title() else {
+            return;
+        };
+
+        let sign_position = self.get_common().world_position + Vector3::new(0.0, theme.shop_sign.height, 0.0);
+        let clip_space_position = camera.view_projection_matrix() * sign_position.to_homogeneous();
+        let screen_position = camera.clip_to_screen_space(clip_space_position);
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height,
+        };
+
+        renderer.render_sign(
+            shop_title,
+            final_position,
+            theme.shop_sign.background_color,
+            theme.shop_sign.foreground_color,
+            theme.shop_sign.font_size,
+            theme.shop_sign.padding,
+        );
+    }
+}
+
+/// Formats the remaining time in the largest unit that fits.
+fn format_remaining_time(milliseconds: u32) -> String {
+    let seconds = milliseconds.div_ceil(1000);
+
+    match seconds {
+        3600.. => format!("{}h", seconds / 3600),
+        60.. => format!("{}m", seconds / 60),
+        _ => format!("{seconds}s"),
+    }
 }
 
 impl VecItem for Entity {
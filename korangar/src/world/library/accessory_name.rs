@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+use hashbrown::HashMap;
+use korangar_loaders::FileLoader;
+use mlua::Lua;
+
+use super::{Library, Table, fix_encoding};
+use crate::loaders::GameFileLoader;
+
+/// Sprite name of a headgear, indexed by its view id.
+pub struct AccessoryName(Cow<'static, str>);
+
+impl Display for AccessoryName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Table for AccessoryName {
+    type Key<'a> = usize;
+    type Storage = HashMap<usize, AccessoryName>;
+
+    fn load(game_file_loader: &GameFileLoader) -> mlua::Result<Self::Storage> {
+        let state = Lua::new();
+
+        // The name table uses the constants defined in the id table, so both need to
+        // be loaded into the same state.
+        for path in [
+            "data\\luafiles514\\lua files\\datainfo\\accessoryid.lub",
+            "data\\luafiles514\\lua files\\datainfo\\accname.lub",
+        ] {
+            match game_file_loader.get(path) {
+                Ok(data) => state.load(&data).exec()?,
+                Err(_) => return Ok(HashMap::new()),
+            }
+        }
+
+        let globals = state.globals();
+        let mut result = HashMap::new();
+
+        if let Ok(table) = globals.get::<mlua::Table>("AccNameTable") {
+            for (view_id, name) in table.pairs::<usize, String>().flatten() {
+                result.insert(view_id, AccessoryName(fix_encoding(name).into()));
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn try_get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> Option<&'a Self> {
+        library.accessory_name_table.get(&key)
+    }
+
+    fn get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> &'a Self {
+        static DEFAULT: AccessoryName = AccessoryName(Cow::Borrowed(""));
+        Self::try_get(library, key).unwrap_or(&DEFAULT)
+    }
+}
@@ -0,0 +1,165 @@
+use cgmath::Vector3;
+use hashbrown::HashMap;
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
+use korangar_loaders::FileLoader;
+use ragnarok_packets::{EffectId, SkillId, UnitId, VisualEffect};
+use serde::Deserialize;
+
+use super::{Library, Table};
+use crate::graphics::Color;
+use crate::loaders::GameFileLoader;
+
+const EFFECT_TABLE_FILE: &str = "data\\effect_table.ron";
+
+/// Key into the effect table. Visual effects, special effects and units are
+/// identified by their name, skills by their id and ground casts by the id of
+/// their element.
+#[derive(Debug, Clone, Copy)]
+pub enum EffectKey<'a> {
+    VisualEffect(&'a VisualEffect),
+    SpecialEffect(EffectId),
+    Skill(SkillId),
+    Unit(&'a UnitId),
+    /// Magic circle that is shown on the ground while a skill is being cast.
+    GroundCast(u32),
+}
+
+/// Point light that is shown while an effect is playing.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EffectLight {
+    pub color: Color,
+    pub intensity: f32,
+    /// Offset of the light from the center of the effect.
+    #[serde(default = "zero_offset")]
+    pub offset: Vector3<f32>,
+}
+
+/// Everything that is needed to display an effect.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EffectInfo {
+    /// Path of the STR file, relative to the effect directory.
+    #[serde(default)]
+    pub file: Option<String>,
+    /// Path of the sprite and action file without the extension, relative to
+    /// the sprite directory. Used for effects that are sprite animations
+    /// instead of STR files.
+    #[serde(default)]
+    pub sprite: Option<String>,
+    /// Sound effect that is played when the effect starts.
+    #[serde(default)]
+    pub sound: Option<String>,
+    #[serde(default)]
+    pub light: Option<EffectLight>,
+    /// Offset of the effect from its center.
+    #[serde(default = "zero_offset")]
+    pub offset: Vector3<f32>,
+    /// The effect is played until it is removed, for example when the skill
+    /// unit disappears.
+    #[serde(default)]
+    pub repeating: bool,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct EffectTable {
+    visual_effects: HashMap<String, EffectInfo>,
+    special_effects: HashMap<String, EffectInfo>,
+    skills: HashMap<u16, EffectInfo>,
+    units: HashMap<String, EffectInfo>,
+    ground_casts: HashMap<u32, EffectInfo>,
+}
+
+fn zero_offset() -> Vector3<f32> {
+    Vector3::new(0.0, 0.0, 0.0)
+}
+
+impl Table for EffectInfo {
+    type Key<'a> = EffectKey<'a>;
+    type Storage = EffectTable;
+
+    fn load(game_file_loader: &GameFileLoader) -> mlua::Result<Self::Storage> {
+        let Ok(data) = game_file_loader.get(EFFECT_TABLE_FILE) else {
+            return Ok(EffectTable::default());
+        };
+
+        match ron::de::from_bytes(&data) {
+            Ok(effect_table) => Ok(effect_table),
+            Err(_error) => {
+                #[cfg(feature = "debug")]
+                print_debug!(
+                    "[{}] failed to parse {}: {:?}",
+                    "error".red(),
+                    EFFECT_TABLE_FILE.magenta(),
+                    _error
+                );
+
+                Ok(EffectTable::default())
+            }
+        }
+    }
+
+    fn try_get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> Option<&'a Self> {
+        let effect_table = &library.effect_info_table;
+
+        // The names of the effects and units are the names of the enum variants.
+        match key {
+            EffectKey::VisualEffect(effect) => effect_table.visual_effects.get(&format!("{effect:?}")),
+            EffectKey::SpecialEffect(effect_id) => effect_table.special_effects.get(&format!("{effect_id:?}")),
+            EffectKey::Skill(skill_id) => effect_table.skills.get(&skill_id.0),
+            EffectKey::Unit(unit_id) => effect_table.units.get(&format!("{unit_id:?}")),
+            EffectKey::GroundCast(element) => effect_table.ground_casts.get(&element),
+        }
+    }
+
+    fn get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> &'a Self {
+        static DEFAULT: EffectInfo = EffectInfo {
+            file: None,
+            sprite: None,
+            sound: None,
+            light: None,
+            offset: Vector3::new(0.0, 0.0, 0.0),
+            repeating: false,
+        };
+        Self::try_get(library, key).unwrap_or(&DEFAULT)
+    }
+}
+
+#[cfg(test)]
+mod parse {
+    use super::EffectTable;
+
+    #[test]
+    fn effect_table() {
+        let table = r#"(
+            visual_effects: {
+                "JobLevelUp": (file: Some("joblvup.str")),
+            },
+            skills: {
+                28: (file: Some("heal.str"), sound: Some("_heal_effect.wav")),
+                5: (sprite: Some("이팩트\\비정상상태\\스턴")),
+            },
+            units: {
+                "Firewall": (
+                    file: Some("firewall.str"),
+                    light: Some((color: (red: 1.0, green: 0.12, blue: 0.0, alpha: 1.0), intensity: 60.0)),
+                    repeating: true,
+                ),
+            },
+            ground_casts: {
+                1: (file: Some("magic_target.str"), repeating: true),
+            },
+        )"#;
+
+        let effect_table: EffectTable = ron::de::from_str(table).unwrap();
+
+        assert_eq!(effect_table.visual_effects["JobLevelUp"].file.as_deref(), Some("joblvup.str"));
+        assert!(effect_table.special_effects.is_empty());
+        assert_eq!(effect_table.skills[&28].sound.as_deref(), Some("_heal_effect.wav"));
+        assert_eq!(effect_table.skills[&5].sprite.as_deref(), Some("이팩트\\비정상상태\\스턴"));
+        assert!(effect_table.skills[&5].file.is_none());
+        assert!(effect_table.units["Firewall"].repeating);
+        assert_eq!(effect_table.units["Firewall"].light.as_ref().unwrap().intensity, 60.0);
+        assert!(effect_table.ground_casts[&1].repeating);
+    }
+}
@@ -0,0 +1,110 @@
+use hashbrown::HashMap;
+use korangar_loaders::FileLoader;
+
+use super::{Library, Table};
+use crate::graphics::Color;
+use crate::loaders::GameFileLoader;
+
+/// Distance fog of a map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FogParameter {
+    /// Distance at which the fog starts, as a fraction of the fog distance.
+    pub near: f32,
+    /// Distance at which the fog reaches its full density, as a fraction of
+    /// the fog distance.
+    pub far: f32,
+    pub color: Color,
+    /// How much of the fog color is mixed in at full density.
+    pub density: f32,
+}
+
+impl Table for FogParameter {
+    type Key<'a> = &'a str;
+    type Storage = HashMap<String, FogParameter>;
+
+    fn load(game_file_loader: &GameFileLoader) -> mlua::Result<Self::Storage> {
+        match game_file_loader.get("data\\fogparametertable.txt") {
+            Ok(data) => Ok(Self::parse_fog_parameter_table(&String::from_utf8_lossy(&data))),
+            Err(_) => Ok(HashMap::new()),
+        }
+    }
+
+    fn try_get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> Option<&'a Self> {
+        library.fog_parameter_table.get(key)
+    }
+
+    fn get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> &'a Self {
+        static DEFAULT: FogParameter = FogParameter {
+            near: 0.0,
+            far: 1.0,
+            color: Color::BLACK,
+            density: 0.0,
+        };
+        Self::try_get(library, key).unwrap_or(&DEFAULT)
+    }
+}
+
+impl FogParameter {
+    /// The table consists of entries that are terminated by a `#`. Every map
+    /// has five entries: the map file, the near and far distance, the color as
+    /// `0xAARRGGBB` and the density.
+    fn parse_fog_parameter_table(table: &str) -> HashMap<String, FogParameter> {
+        let mut result = HashMap::new();
+
+        let entries: Vec<&str> = table
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .flat_map(|line| line.split('#'))
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .collect();
+
+        for fields in entries.chunks_exact(5) {
+            let [map_rsw, near, far, color, density] = fields else {
+                continue;
+            };
+
+            let Some(fog_parameter) = Self::parse_fog_parameter(near, far, color, density) else {
+                continue;
+            };
+
+            let resource_name = map_rsw.strip_suffix(".rsw").unwrap_or(map_rsw).to_string();
+            result.insert(resource_name, fog_parameter);
+        }
+
+        result
+    }
+
+    fn parse_fog_parameter(near: &str, far: &str, color: &str, density: &str) -> Option<FogParameter> {
+        let color = u32::from_str_radix(color.trim_start_matches("0x").trim_start_matches("0X"), 16).ok()?;
+        let [_, red, green, blue] = color.to_be_bytes();
+
+        Some(FogParameter {
+            near: near.parse().ok()?,
+            far: far.parse().ok()?,
+            color: Color::rgb_u8(red, green, blue),
+            density: density.parse().ok()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod parse {
+    use super::FogParameter;
+    use crate::graphics::Color;
+
+    #[test]
+    fn fog_parameter_table() {
+        let table = "// comment#\nairplane.rsw#\n0.1#\n0.9#\n0xFF8C9EB4#\n0.3#\n\nprontera.rsw#0.3#1.0#0x00FFFFFF#0.2#\n";
+        let fog_parameters = FogParameter::parse_fog_parameter_table(table);
+
+        assert_eq!(fog_parameters.len(), 2);
+        assert_eq!(fog_parameters["airplane"], FogParameter {
+            near: 0.1,
+            far: 0.9,
+            color: Color::rgb_u8(0x8C, 0x9E, 0xB4),
+            density: 0.3,
+        });
+        assert_eq!(fog_parameters["prontera"].color, Color::rgb_u8(0xFF, 0xFF, 0xFF));
+    }
+}
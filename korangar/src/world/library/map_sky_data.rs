@@ -10,29 +10,37 @@ use crate::loaders::GameFileLoader;
 #[derive(Debug, Clone)]
 pub struct MapSkyData {
     old_cloud_effect: Option<usize>,
-    bg_color: Option<Color>,
-    bg_fog: bool,
+    /// Color of the background.
+    pub bg_color: Option<Color>,
+    /// The background is covered by the fog of the map.
+    pub bg_fog: bool,
     star_effect: bool,
-    cloud_effect: Vec<CloudEffect>,
+    pub cloud_effect: Vec<CloudEffect>,
 }
 
-#[allow(unused)]
+/// A layer of clouds. Times and speeds are given in frames.
 #[derive(Debug, Clone)]
 pub struct CloudEffect {
-    num: usize,
-    cull_dist: usize,
-    color: Color,
-    size: usize,
-    size_extra: usize,
-    expand_rate: f32,
-    alpha_inc_time: usize,
-    alpha_inc_time_extra: usize,
-    alpha_inc_speed: usize,
-    alpha_dec_time: usize,
-    alpha_dec_time_extra: usize,
-    alpha_dec_speed: f32,
-    height: usize,
-    height_extra: usize,
+    /// Number of clouds.
+    pub num: usize,
+    /// Maximum horizontal distance of a cloud from the point the camera is
+    /// looking at.
+    pub cull_dist: usize,
+    pub color: Color,
+    pub size: usize,
+    pub size_extra: usize,
+    /// Growth of the size per frame.
+    pub expand_rate: f32,
+    pub alpha_inc_time: usize,
+    pub alpha_inc_time_extra: usize,
+    /// Increase of the alpha per frame, on a scale of 0 to 255.
+    pub alpha_inc_speed: usize,
+    pub alpha_dec_time: usize,
+    pub alpha_dec_time_extra: usize,
+    /// Decrease of the alpha per frame, on a scale of 0 to 1.
+    pub alpha_dec_speed: f32,
+    pub height: usize,
+    pub height_extra: usize,
 }
 
 impl Table for MapSkyData {
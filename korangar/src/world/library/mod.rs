@@ -1,22 +1,40 @@
+mod accessory_name;
+mod effect_info;
+mod fog_parameter;
 mod item_info;
 mod item_name;
 mod item_resource;
 mod job_identity;
 mod map_sky_data;
+mod robe_name;
+mod status_icon;
+mod weapon_name;
 
 use encoding_rs::EUC_KR;
 
+pub use self::accessory_name::AccessoryName;
+pub use self::effect_info::{EffectInfo, EffectKey, EffectLight};
+pub use self::fog_parameter::FogParameter;
 pub use self::item_info::ItemInfo;
 pub use self::item_name::{ItemName, ItemNameKey};
 pub use self::item_resource::{ItemResource, ItemResourceKey};
 pub use self::job_identity::JobIdentity;
-pub use self::map_sky_data::MapSkyData;
+pub use self::map_sky_data::{CloudEffect, MapSkyData};
+pub use self::robe_name::RobeName;
+pub use self::status_icon::StatusIcon;
+pub use self::weapon_name::WeaponName;
 use crate::loaders::GameFileLoader;
 
 pub struct Library {
     job_identity_table: <JobIdentity as Table>::Storage,
     item_info_table: <ItemInfo as Table>::Storage,
     map_sky_data_table: <MapSkyData as Table>::Storage,
+    fog_parameter_table: <FogParameter as Table>::Storage,
+    accessory_name_table: <AccessoryName as Table>::Storage,
+    weapon_name_table: <WeaponName as Table>::Storage,
+    robe_name_table: <RobeName as Table>::Storage,
+    effect_info_table: <EffectInfo as Table>::Storage,
+    status_icon_table: <StatusIcon as Table>::Storage,
 }
 
 impl Library {
@@ -24,14 +42,31 @@ impl Library {
         let job_identity_table = JobIdentity::load(game_file_loader)?;
         let item_info_table = ItemInfo::load(game_file_loader)?;
         let map_sky_data_table = MapSkyData::load(game_file_loader)?;
+        let fog_parameter_table = FogParameter::load(game_file_loader)?;
+        let accessory_name_table = AccessoryName::load(game_file_loader)?;
+        let weapon_name_table = WeaponName::load(game_file_loader)?;
+        let robe_name_table = RobeName::load(game_file_loader)?;
+        let effect_info_table = EffectInfo::load(game_file_loader)?;
+        let status_icon_table = StatusIcon::load(game_file_loader)?;
 
         Ok(Self {
             job_identity_table,
             item_info_table,
             map_sky_data_table,
+            fog_parameter_table,
+            accessory_name_table,
+            weapon_name_table,
+            robe_name_table,
+            effect_info_table,
+            status_icon_table,
         })
     }
 
+    #[inline(always)]
+    pub fn try_get<T: Table>(&self, key: T::Key<'_>) -> Option<&T> {
+        T::try_get(self, key)
+    }
+
     #[inline(always)]
     pub fn get<T: Table>(&self, key: T::Key<'_>) -> &T {
         T::get(self, key)
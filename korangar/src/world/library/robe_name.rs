@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+use hashbrown::HashMap;
+use korangar_loaders::FileLoader;
+use mlua::Lua;
+
+use super::{Library, Table, fix_encoding};
+use crate::loaders::GameFileLoader;
+
+/// Sprite folder of a garment, indexed by its view id.
+pub struct RobeName(Cow<'static, str>);
+
+impl Display for RobeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Table for RobeName {
+    type Key<'a> = usize;
+    type Storage = HashMap<usize, RobeName>;
+
+    fn load(game_file_loader: &GameFileLoader) -> mlua::Result<Self::Storage> {
+        let state = Lua::new();
+
+        // The name table uses the constants defined in the id table, so both need to
+        // be loaded into the same state.
+        for path in [
+            "data\\luafiles514\\lua files\\datainfo\\spriterobeid.lub",
+            "data\\luafiles514\\lua files\\datainfo\\spriterobename.lub",
+        ] {
+            match game_file_loader.get(path) {
+                Ok(data) => state.load(&data).exec()?,
+                Err(_) => return Ok(HashMap::new()),
+            }
+        }
+
+        let globals = state.globals();
+        let mut result = HashMap::new();
+
+        if let Ok(table) = globals.get::<mlua::Table>("RobeNameTable") {
+            for (view_id, name) in table.pairs::<usize, String>().flatten() {
+                result.insert(view_id, RobeName(fix_encoding(name).into()));
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn try_get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> Option<&'a Self> {
+        library.robe_name_table.get(&key)
+    }
+
+    fn get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> &'a Self {
+        static DEFAULT: RobeName = RobeName(Cow::Borrowed(""));
+        Self::try_get(library, key).unwrap_or(&DEFAULT)
+    }
+}
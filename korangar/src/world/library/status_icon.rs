@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+use hashbrown::HashMap;
+use korangar_loaders::FileLoader;
+use mlua::Lua;
+use ragnarok_packets::StatusEffectId;
+
+use super::{Library, Table};
+use crate::loaders::GameFileLoader;
+
+/// Priorities that the icon table is grouped by. Not every version of the
+/// table defines them itself.
+const ICON_PRIORITIES: [&str; 4] = ["PRIORITY_RED", "PRIORITY_BLUE", "PRIORITY_GREEN", "PRIORITY_WHITE"];
+
+/// Icon of a status effect, relative to the effect texture folder.
+pub struct StatusIcon(Cow<'static, str>);
+
+impl Display for StatusIcon {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Table for StatusIcon {
+    type Key<'a> = StatusEffectId;
+    type Storage = HashMap<u16, StatusIcon>;
+
+    fn load(game_file_loader: &GameFileLoader) -> mlua::Result<Self::Storage> {
+        let state = Lua::new();
+        let globals = state.globals();
+
+        for (priority, name) in ICON_PRIORITIES.into_iter().enumerate() {
+            globals.set(name, priority)?;
+        }
+
+        // The icon table uses the constants defined in the id table, so both need to
+        // be loaded into the same state.
+        for path in [
+            "data\\luafiles514\\lua files\\stateicon\\efstids.lub",
+            "data\\luafiles514\\lua files\\stateicon\\stateiconimginfo.lub",
+        ] {
+            match game_file_loader.get(path) {
+                Ok(data) => state.load(&data).exec()?,
+                Err(_) => return Ok(HashMap::new()),
+            }
+        }
+
+        let mut result = HashMap::new();
+
+        if let Ok(table) = globals.get::<mlua::Table>("StateIconImgList") {
+            for (_priority, icons) in table.pairs::<usize, mlua::Table>().flatten() {
+                for (status_effect_id, icon) in icons.pairs::<u16, String>().flatten() {
+                    result.insert(status_effect_id, StatusIcon(icon.into()));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn try_get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> Option<&'a Self> {
+        library.status_icon_table.get(&key.0)
+    }
+
+    fn get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> &'a Self {
+        static DEFAULT: StatusIcon = StatusIcon(Cow::Borrowed(""));
+        Self::try_get(library, key).unwrap_or(&DEFAULT)
+    }
+}
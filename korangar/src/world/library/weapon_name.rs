@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+use hashbrown::HashMap;
+use korangar_loaders::FileLoader;
+use mlua::Lua;
+
+use super::{Library, Table, fix_encoding};
+use crate::loaders::GameFileLoader;
+
+/// Sprite suffix of a weapon, indexed by its view id.
+pub struct WeaponName(Cow<'static, str>);
+
+impl Display for WeaponName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Table for WeaponName {
+    type Key<'a> = usize;
+    type Storage = HashMap<usize, WeaponName>;
+
+    fn load(game_file_loader: &GameFileLoader) -> mlua::Result<Self::Storage> {
+        let Ok(data) = game_file_loader.get("data\\luafiles514\\lua files\\datainfo\\weapontable.lub") else {
+            return Ok(HashMap::new());
+        };
+
+        let state = Lua::new();
+        state.load(&data).exec()?;
+
+        let globals = state.globals();
+        let mut names = HashMap::new();
+
+        if let Ok(table) = globals.get::<mlua::Table>("WeaponNameTable") {
+            for (view_id, name) in table.pairs::<usize, String>().flatten() {
+                names.insert(view_id, fix_encoding(name));
+            }
+        }
+
+        // Most weapons don't have their own sprite and instead use the sprite of
+        // their weapon type.
+        if let Ok(table) = globals.get::<mlua::Table>("Expansion_Weapon_IDs") {
+            for (view_id, weapon_type) in table.pairs::<usize, usize>().flatten() {
+                if !names.contains_key(&view_id)
+                    && let Some(name) = names.get(&weapon_type).cloned()
+                {
+                    names.insert(view_id, name);
+                }
+            }
+        }
+
+        let result = names
+            .into_iter()
+            .map(|(view_id, name)| (view_id, WeaponName(name.into())))
+            .collect();
+
+        Ok(result)
+    }
+
+    fn try_get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> Option<&'a Self> {
+        library.weapon_name_table.get(&key)
+    }
+
+    fn get<'a, 'b>(library: &'a Library, key: Self::Key<'b>) -> &'a Self {
+        static DEFAULT: WeaponName = WeaponName(Cow::Borrowed(""));
+        Self::try_get(library, key).unwrap_or(&DEFAULT)
+    }
+}
@@ -1,4 +1,5 @@
 mod lighting;
+mod sky;
 
 #[cfg(feature = "debug")]
 use std::collections::HashSet;
@@ -24,14 +25,18 @@ use rust_state::RustState;
 use wgpu::Queue;
 
 pub use self::lighting::Lighting;
+pub use self::sky::Sky;
 use super::{Camera, Entity, Object, PointLightId, PointLightManager, ResourceSet, ResourceSetBuffer, SubMesh, Video};
 #[cfg(feature = "debug")]
 use super::{LightSourceExt, Model, PointLightSet};
+use crate::graphics::{
+    CloudInstruction, EntityInstruction, FogInstruction, IndicatorInstruction, ModelInstruction, SkyInstruction, Texture, TextureSet,
+    WaterInstruction, WaterVertex,
+};
 #[cfg(feature = "debug")]
 use crate::graphics::{
     DebugAabbInstruction, DebugCircleInstruction, DebugRectangleInstruction, ModelBatch, RenderOptions, ScreenPosition, ScreenSize,
 };
-use crate::graphics::{EntityInstruction, IndicatorInstruction, ModelInstruction, Texture, TextureSet, WaterInstruction, WaterVertex};
 use crate::loaders::GAT_TILE_SIZE;
 #[cfg(feature = "debug")]
 use crate::renderer::MarkerRenderer;
@@ -102,6 +107,7 @@ pub struct Map {
     height: u16,
     level_bound: AABB,
     lighting: Lighting,
+    sky: Sky,
     water_plane: Option<WaterPlane>,
     tiles: Vec<Tile>,
     sub_meshes: Vec<SubMesh>,
@@ -136,6 +142,7 @@ impl Map {
         height: u16,
         level_bound: AABB,
         lighting: Lighting,
+        sky: Sky,
         water_plane: Option<WaterPlane>,
         tiles: Vec<Tile>,
         sub_meshes: Vec<SubMesh>,
@@ -157,6 +164,7 @@ impl Map {
             height,
             level_bound,
             lighting,
+            sky,
             water_plane,
             tiles,
             sub_meshes,
@@ -181,6 +189,7 @@ impl Map {
         height: u16,
         level_bound: AABB,
         lighting: Lighting,
+        sky: Sky,
         water_plane: Option<WaterPlane>,
         tiles: Vec<Tile>,
         sub_meshes: Vec<SubMesh>,
@@ -207,6 +216,7 @@ impl Map {
             height,
             level_bound,
             lighting,
+            sky,
             water_plane,
             tiles,
             sub_meshes,
@@ -375,6 +385,16 @@ impl Map {
         });
     }
 
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn render_sky(&self, sky_instruction: &mut Option<SkyInstruction>, fog_instruction: &mut Option<FogInstruction>) {
+        self.sky.render_sky(sky_instruction, fog_instruction);
+    }
+
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn render_clouds(&self, cloud_instructions: &mut Vec<CloudInstruction>, focus_point: Point3<f32>) {
+        self.sky.render_clouds(cloud_instructions, focus_point);
+    }
+
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
     pub fn render_water<'a>(&'a self, water_instruction: &mut Option<WaterInstruction<'a>>, animation_timer_ms: f32) {
         if let Some(water_plane) = self.water_plane.as_ref() {
@@ -830,6 +850,10 @@ impl Map {
             video.check_for_next_frame();
         }
     }
+
+    pub fn advance_clouds(&self, focus_point: Point3<f32>, delta_time: f64) {
+        self.sky.advance_clouds(focus_point, delta_time);
+    }
 }
 
 impl Traversable for Map {
@@ -0,0 +1,160 @@
+use std::sync::Mutex;
+
+use cgmath::{EuclideanSpace, InnerSpace, Point3, Vector2};
+use rand_aes::tls::rand_f32;
+
+use crate::graphics::{CloudInstruction, Color, FogInstruction, SkyInstruction};
+use crate::world::{CloudEffect, FogParameter, MapSkyData};
+
+/// Distance from the camera that the fog parameters are relative to.
+const FOG_DISTANCE: f32 = 1000.0;
+/// Duration of a frame that the timings and speeds of the cloud effects refer
+/// to.
+const CLOUD_FRAME_TIME: f32 = 1.0 / 60.0;
+/// Clouds start fading out at this fraction of their cull distance, so they
+/// don't suddenly disappear when the camera moves away from them.
+const CLOUD_FADE_DISTANCE: f32 = 0.8;
+
+struct Cloud {
+    position: Point3<f32>,
+    size: f32,
+    alpha: f32,
+    fade_in_time: f32,
+    fade_out_time: f32,
+}
+
+impl Cloud {
+    fn spawn(effect: &CloudEffect, focus_point: Point3<f32>) -> Self {
+        let angle = rand_f32() * std::f32::consts::TAU;
+        let distance = rand_f32().sqrt() * effect.cull_dist as f32;
+        // The height is given in the coordinate system of the map files, where the y
+        // axis points down, so the clouds float below the focus point.
+        let height = effect.height as f32 + rand_f32() * effect.height_extra as f32;
+
+        Self {
+            position: Point3::new(
+                focus_point.x + angle.cos() * distance,
+                focus_point.y - height,
+                focus_point.z + angle.sin() * distance,
+            ),
+            size: effect.size as f32 + rand_f32() * effect.size_extra as f32,
+            alpha: 0.0,
+            fade_in_time: (effect.alpha_inc_time as f32 + rand_f32() * effect.alpha_inc_time_extra as f32) * CLOUD_FRAME_TIME,
+            fade_out_time: (effect.alpha_dec_time as f32 + rand_f32() * effect.alpha_dec_time_extra as f32) * CLOUD_FRAME_TIME,
+        }
+    }
+
+    /// Returns `false` once the cloud has faded out.
+    fn update(&mut self, effect: &CloudEffect, delta_time: f32) -> bool {
+        let frames = delta_time / CLOUD_FRAME_TIME;
+
+        self.size += effect.expand_rate * frames;
+
+        if self.fade_in_time > 0.0 {
+            self.alpha = f32::min(self.alpha + effect.alpha_inc_speed as f32 / 255.0 * frames, 1.0);
+            self.fade_in_time -= delta_time;
+            return true;
+        }
+
+        self.alpha = f32::max(self.alpha - effect.alpha_dec_speed * frames, 0.0);
+        self.fade_out_time -= delta_time;
+
+        self.fade_out_time > 0.0 && self.alpha > 0.0
+    }
+}
+
+struct CloudLayer {
+    effect: CloudEffect,
+    clouds: Vec<Cloud>,
+}
+
+/// Background color, distance fog and clouds of a map.
+pub struct Sky {
+    background_color: Option<Color>,
+    fog: Option<FogParameter>,
+    cloud_layers: Mutex<Vec<CloudLayer>>,
+}
+
+impl Sky {
+    pub fn new(map_sky_data: &MapSkyData, fog_parameter: Option<&FogParameter>) -> Self {
+        let background_color = match (map_sky_data.bg_fog, fog_parameter) {
+            (true, Some(fog_parameter)) => Some(fog_parameter.color),
+            _ => map_sky_data.bg_color,
+        };
+
+        let cloud_layers = map_sky_data
+            .cloud_effect
+            .iter()
+            .map(|effect| CloudLayer {
+                effect: effect.clone(),
+                clouds: Vec::with_capacity(effect.num),
+            })
+            .collect();
+
+        Self {
+            background_color,
+            fog: fog_parameter.cloned(),
+            cloud_layers: Mutex::new(cloud_layers),
+        }
+    }
+
+    /// Fade the clouds in and out. Clouds that faded out are spawned again
+    /// around the focus point.
+    pub fn advance_clouds(&self, focus_point: Point3<f32>, delta_time: f64) {
+        let mut cloud_layers = self.cloud_layers.lock().unwrap();
+
+        for layer in cloud_layers.iter_mut() {
+            let effect = &layer.effect;
+
+            for cloud in layer.clouds.iter_mut() {
+                if !cloud.update(effect, delta_time as f32) {
+                    *cloud = Cloud::spawn(effect, focus_point);
+                }
+            }
+
+            while layer.clouds.len() < effect.num {
+                layer.clouds.push(Cloud::spawn(effect, focus_point));
+            }
+        }
+    }
+
+    pub fn render_sky(&self, sky_instruction: &mut Option<SkyInstruction>, fog_instruction: &mut Option<FogInstruction>) {
+        *sky_instruction = self.background_color.map(|color| SkyInstruction { color });
+
+        *fog_instruction = self.fog.as_ref().map(|fog| FogInstruction {
+            color: fog.color,
+            start: fog.near * FOG_DISTANCE,
+            end: fog.far * FOG_DISTANCE,
+            density: fog.density,
+        });
+    }
+
+    pub fn render_clouds(&self, instructions: &mut Vec<CloudInstruction>, focus_point: Point3<f32>) {
+        let cloud_layers = self.cloud_layers.lock().unwrap();
+
+        for layer in cloud_layers.iter() {
+            let cull_distance = layer.effect.cull_dist as f32;
+            let fade_distance = cull_distance * CLOUD_FADE_DISTANCE;
+
+            for cloud in layer.clouds.iter() {
+                let offset = cloud.position.to_vec() - focus_point.to_vec();
+                let distance = Vector2::new(offset.x, offset.z).magnitude();
+
+                if distance >= cull_distance || cloud.alpha <= 0.0 {
+                    continue;
+                }
+
+                let distance_fade = 1.0 - f32::max(distance - fade_distance, 0.0) / (cull_distance - fade_distance);
+
+                instructions.push(CloudInstruction {
+                    position: cloud.position,
+                    size: cloud.size,
+                    color: Color {
+                        alpha: cloud.alpha * distance_fade,
+                        ..layer.effect.color
+                    },
+                });
+            }
+        }
+    }
+}
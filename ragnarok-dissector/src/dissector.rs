@@ -0,0 +1,852 @@
+//! Decoding of reassembled TCP streams into Ragnarok Online packets.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::Duration;
+
+use ragnarok_bytes::ByteReader;
+use ragnarok_packets::handler::{HandlerResult, NoPacketCallback, PacketDirection, PacketHandler, ServerKind};
+use ragnarok_packets::packet_versions::version_20200401;
+use ragnarok_packets::*;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::reassembly::{Reassembly, StreamReassembler, TcpSegment};
+
+/// Packet versions with a different layout for some packets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PacketVersion {
+    _20200401,
+    #[default]
+    _20220406,
+}
+
+/// Ports the servers are listening on. Traffic on other ports is ignored.
+#[derive(Debug, Clone, Copy)]
+pub struct ServerPorts {
+    pub login: u16,
+    pub character: u16,
+    pub map: u16,
+}
+
+impl Default for ServerPorts {
+    fn default() -> Self {
+        Self {
+            login: 6900,
+            character: 6121,
+            map: 5121,
+        }
+    }
+}
+
+impl ServerPorts {
+    fn server(&self, port: u16) -> Option<ServerKind> {
+        match port {
+            port if port == self.login => Some(ServerKind::Login),
+            port if port == self.character => Some(ServerKind::Character),
+            port if port == self.map => Some(ServerKind::Map),
+            _ => None,
+        }
+    }
+
+    /// Find out which server a connection belongs to and in which direction
+    /// the data is flowing.
+    pub fn classify(&self, source: SocketAddr, destination: SocketAddr) -> Option<(ServerKind, PacketDirection)> {
+        // The destination port is checked first, so connections are classified
+        // correctly even if the client happens to use one of the server ports.
+        if let Some(server) = self.server(destination.port()) {
+            return Some((server, PacketDirection::Outgoing));
+        }
+
+        self.server(source.port()).map(|server| (server, PacketDirection::Incoming))
+    }
+}
+
+/// A packet that was successfully decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedPacket {
+    pub header: PacketHeader,
+    /// Name of the packet type without its module path.
+    pub name: &'static str,
+    pub is_ping: bool,
+    /// [`Debug`] output of the packet.
+    pub fields: String,
+    /// The packet serialized to JSON.
+    pub json: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketContent {
+    Decoded(DecodedPacket),
+    /// The account id that the character server sends before any packet.
+    AccountId(AccountId),
+    /// No packet is known for the header. Since the length of the packet is
+    /// unknown as well, `bytes` contains all data up to the end of the
+    /// segment, including the header.
+    Unknown {
+        bytes: Vec<u8>,
+    },
+    /// The packet could not be decoded. `bytes` contains all data up to the
+    /// end of the segment, including the header.
+    Failed {
+        error: String,
+        bytes: Vec<u8>,
+    },
+}
+
+/// A single packet of a capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DissectedPacket {
+    pub timestamp: Duration,
+    pub server: ServerKind,
+    pub direction: PacketDirection,
+    pub content: PacketContent,
+}
+
+impl DissectedPacket {
+    pub fn header(&self) -> Option<PacketHeader> {
+        match &self.content {
+            PacketContent::Decoded(packet) => Some(packet.header),
+            PacketContent::AccountId(_) => None,
+            PacketContent::Unknown { bytes } | PacketContent::Failed { bytes, .. } => {
+                (bytes.len() >= 2).then(|| PacketHeader(u16::from_le_bytes([bytes[0], bytes[1]])))
+            }
+        }
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        match &self.content {
+            PacketContent::Decoded(packet) => Some(packet.name),
+            PacketContent::AccountId(_) => Some("AccountId"),
+            PacketContent::Unknown { .. } | PacketContent::Failed { .. } => None,
+        }
+    }
+
+    pub fn is_ping(&self) -> bool {
+        matches!(&self.content, PacketContent::Decoded(packet) if packet.is_ping)
+    }
+}
+
+/// Selects packets either by the name of their type or by their header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketSelector {
+    Name(String),
+    Header(PacketHeader),
+}
+
+impl FromStr for PacketSelector {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+            Some(header) => u16::from_str_radix(header, 16)
+                .map(|header| Self::Header(PacketHeader(header)))
+                .map_err(|_| format!("invalid packet header {value}")),
+            None => Ok(Self::Name(value.to_owned())),
+        }
+    }
+}
+
+/// Decides which packets are part of the output. Empty lists don't filter
+/// anything.
+#[derive(Debug, Clone, Default)]
+pub struct PacketFilter {
+    pub servers: Vec<ServerKind>,
+    pub packets: Vec<PacketSelector>,
+    pub skip_pings: bool,
+}
+
+impl PacketFilter {
+    pub fn matches(&self, packet: &DissectedPacket) -> bool {
+        if self.skip_pings && packet.is_ping() {
+            return false;
+        }
+
+        if !self.servers.is_empty() && !self.servers.contains(&packet.server) {
+            return false;
+        }
+
+        self.packets.is_empty()
+            || self.packets.iter().any(|selector| match selector {
+                PacketSelector::Name(name) => packet.name() == Some(name.as_str()),
+                PacketSelector::Header(header) => packet.header() == Some(*header),
+            })
+    }
+}
+
+type DecodingHandler = PacketHandler<Option<DecodedPacket>, (), NoPacketCallback>;
+
+fn decode<P: Packet + Serialize>(packet: P) -> Option<DecodedPacket> {
+    let type_name = std::any::type_name::<P>();
+    let fields = format!("{packet:?}");
+    let json = serde_json::to_value(&packet).unwrap_or_else(|_| Value::String(fields.clone()));
+
+    Some(DecodedPacket {
+        header: P::HEADER,
+        name: type_name.rsplit("::").next().unwrap_or(type_name),
+        is_ping: P::IS_PING,
+        fields,
+        json,
+    })
+}
+
+macro_rules! create_handler {
+    ([$($packet:ty),* $(,)?]) => {{
+        let mut packet_handler = DecodingHandler::default();
+        $(
+            packet_handler.register(decode::<$packet>).unwrap();
+        )*
+        packet_handler
+    }};
+}
+
+/// Register the packets of an older packet version over the current ones.
+macro_rules! replace_packets {
+    ($packet_handler:expr, [$($packet:ty),* $(,)?]) => {{
+        $(
+            $packet_handler.replace(decode::<$packet>);
+        )*
+    }};
+}
+
+/// One direction of a TCP connection.
+struct Flow {
+    server: ServerKind,
+    direction: PacketDirection,
+    reassembler: StreamReassembler,
+    buffer: Vec<u8>,
+    last_timestamp: Duration,
+    /// The character server sends the account id as raw bytes after the
+    /// client logged in.
+    expects_account_id: bool,
+}
+
+impl Flow {
+    fn new(server: ServerKind, direction: PacketDirection) -> Self {
+        Self {
+            server,
+            direction,
+            reassembler: StreamReassembler::default(),
+            buffer: Vec::new(),
+            last_timestamp: Duration::ZERO,
+            expects_account_id: false,
+        }
+    }
+}
+
+/// A TCP packet taken from the capture.
+#[derive(Debug, Clone, Copy)]
+pub struct TcpPacket<'a> {
+    pub timestamp: Duration,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub segment: TcpSegment<'a>,
+}
+
+/// Decodes the Ragnarok Online traffic of a capture.
+pub struct Dissector {
+    ports: ServerPorts,
+    login_incoming_handler: DecodingHandler,
+    login_outgoing_handler: DecodingHandler,
+    character_incoming_handler: DecodingHandler,
+    character_outgoing_handler: DecodingHandler,
+    map_incoming_handler: DecodingHandler,
+    map_outgoing_handler: DecodingHandler,
+    flows: HashMap<(SocketAddr, SocketAddr), Flow>,
+}
+
+impl Dissector {
+    pub fn new(ports: ServerPorts, packet_version: PacketVersion) -> Self {
+        let login_incoming_handler = create_handler!([
+            LoginServerLoginSuccessPacket,
+            LoginFailedPacket,
+            LoginFailedPacket2,
+            PasswordSaltPacket,
+        ]);
+
+        let login_outgoing_handler = create_handler!([
+            LoginServerLoginPacket,
+            LoginServerKeepalivePacket,
+            RequestPasswordSaltPacket,
+            LoginServerHashedLoginPacket,
+        ]);
+
+        let mut character_incoming_handler = create_handler!([
+            LoginFailedPacket,
+            CharacterServerLoginSuccessPacket,
+            CharacterListPacket,
+            CharacterSlotPagePacket,
+            CharacterBanListPacket,
+            LoginPincodePacket,
+            RequestCharacterListSuccessPacket,
+            Packet0b18,
+            CharacterSelectionSuccessPacket,
+            CharacterSelectionFailedPacket,
+            MapServerUnavailablePacket,
+            CreateCharacterSuccessPacket,
+            CharacterCreationFailedPacket,
+            CharacterDeletionSuccessPacket,
+            CharacterDeletionFailedPacket,
+            SwitchCharacterSlotResponsePacket,
+        ]);
+
+        let character_outgoing_handler = create_handler!([
+            CharacterServerLoginPacket,
+            CharacterServerKeepalivePacket,
+            RequestCharacterListPacket,
+            SelectCharacterPacket,
+            CreateCharacterPacket,
+            DeleteCharacterPacket,
+            SwitchCharacterSlotPacket,
+        ]);
+
+        let mut map_incoming_handler = create_handler!([
+            MapServerLoginSuccessPacket,
+            MapServerPingPacket,
+            BroadcastMessagePacket,
+            Broadcast2MessagePacket,
+            OverheadMessagePacket,
+            ServerMessagePacket,
+            MessageTablePacket,
+            EntityMessagePacket,
+            DisplayEmotionPacket,
+            EntityMovePacket,
+            EntityStopMovePacket,
+            PlayerMovePacket,
+            ChangeMapPacket,
+            ResurrectionPacket,
+            EntityAppearedPacket,
+            EntityAppeared2Packet,
+            MovingEntityAppearedPacket,
+            EntityDisappearedPacket,
+            UpdateStatPacket,
+            UpdateStatPacket1,
+            UpdateStatPacket2,
+            UpdateStatPacket3,
+            UpdateAttackRangePacket,
+            RequestStatUpResponsePacket,
+            NewMailStatusPacket,
+            AchievementUpdatePacket,
+            AchievementListPacket,
+            CriticalWeightUpdatePacket,
+            SpriteChangePacket,
+            InventoyStartPacket,
+            RegularItemListPacket,
+            EquippableItemListPacket,
+            InventoyEndPacket,
+            EquippableSwitchItemListPacket,
+            MapTypePacket,
+            UpdateSkillTreePacket,
+            UpdateHotkeysPacket,
+            InitialStatsPacket,
+            UpdatePartyInvitationStatePacket,
+            UpdateShowEquipPacket,
+            UpdateConfigurationPacket,
+            NavigateToMonsterPacket,
+            MarkMinimapPositionPacket,
+            NextButtonPacket,
+            CloseButtonPacket,
+            DialogMenuPacket,
+            DisplaySpecialEffectPacket,
+            DisplaySkillCooldownPacket,
+            DisplaySkillEffectAndDamagePacket,
+            DisplaySkillEffectNoDamagePacket,
+            DisplayPlayerHealEffect,
+            StatusChangePacket,
+            EntityStatusChangePacket,
+            StatusChangeSequencePacket,
+            DamagePacket1,
+            DamagePacket3,
+            QuestNotificationPacket1,
+            HuntingQuestNotificationPacket,
+            HuntingQuestUpdateObjectivePacket,
+            QuestRemovedPacket,
+            QuestListPacket,
+            VisualEffectPacket,
+            DisplayGainedExperiencePacket,
+            DisplayImagePacket,
+            StateChangePacket,
+            QuestEffectPacket,
+            ItemPickupPacket,
+            RemoveItemFromInventoryPacket,
+            ServerTickPacket,
+            RequestPlayerDetailsSuccessPacket,
+            RequestEntityDetailsSuccessPacket,
+            UpdateEntityHealthPointsPacket,
+            RequestPlayerAttackFailedPacket,
+            NpcDialogPacket,
+            RequestEquipItemStatusPacket,
+            RequestUnequipItemStatusPacket,
+            EquipAmmunitionPacket,
+            AmmunitionActionPacket,
+            Packet8302,
+            Packet0b18,
+            RestartResponsePacket,
+            DisconnectResponsePacket,
+            UseSkillSuccessPacket,
+            UseSkillSuccessPacket2,
+            CastCancelPacket,
+            ToUseSkillSuccessPacket,
+            NotifySkillUnitPacket,
+            SkillUnitDisappearPacket,
+            NotifyGroundSkillPacket,
+            FriendListPacket,
+            FriendOnlineStatusPacket,
+            FriendRequestPacket,
+            FriendRequestResultPacket,
+            NotifyFriendRemovedPacket,
+            PartyInvitePacket,
+            CreatePartyResultPacket,
+            PartyInviteResultPacket,
+            PartyMemberLeftPacket,
+            PartyInformationPacket,
+            PartyMemberJoinedPacket,
+            UpdatePartyMemberHealthPacket,
+            UpdatePartyMemberPositionPacket,
+            PartyMessagePacket,
+            PartyOptionsPacket,
+            PartyLeaderChangedPacket,
+            UpdateGuildIdPacket,
+            GuildInformationPacket,
+            GuildRelationsPacket,
+            GuildMemberListPacket,
+            GuildPositionListPacket,
+            GuildPositionNamesPacket,
+            GuildNoticePacket,
+            GuildEmblemPacket,
+            ChangeGuildPacket,
+            GuildMemberStatusPacket,
+            GuildMessagePacket,
+            CharacterNamePacket,
+            TradeRequestPacket,
+            TradeRequestResultPacket,
+            AddTradeItemResultPacket,
+            TradeItemAddedPacket,
+            TradeLockedPacket,
+            TradeCancelledPacket,
+            TradeCompletedPacket,
+            StorageItemCountPacket,
+            StorageItemAddedPacket,
+            StorageItemRemovedPacket,
+            StorageClosedPacket,
+            ReputationPacket,
+            ClanInfoPacket,
+            ClanOnlineCountPacket,
+            ChangeMapCellPacket,
+            OpenMarketPacket,
+            BuyOrSellPacket,
+            ShopItemListPacket,
+            BuyShopItemsResultPacket,
+            ParameterChangePacket,
+            SellListPacket,
+            SellItemsResultPacket,
+            PetEggListPacket,
+            PetInformationPacket,
+            PetFeedResultPacket,
+            PetStatePacket,
+            HomunculusInformationPacket,
+            HomunculusSkillListPacket,
+            HomunculusStatePacket,
+            HomunculusFeedResultPacket,
+            MercenaryInformationPacket,
+            MercenarySkillListPacket,
+            MercenaryParameterPacket,
+            CartItemCountPacket,
+            CartItemAddedPacket,
+            CartItemRemovedPacket,
+            CartRemovedPacket,
+            AddCartItemFailedPacket,
+            OpenVendingPacket,
+            VendingOpenResultPacket,
+            OwnVendingItemListPacket,
+            VendingItemSoldPacket,
+            VendingShopOpenedPacket,
+            VendingShopClosedPacket,
+            VendingItemListPacket,
+            BuyVendingItemFailedPacket,
+            MailListPacket,
+            ReadMailPacket,
+            MailZenyResultPacket,
+            MailItemsResultPacket,
+            MailDeletedPacket,
+            WriteMailOpenedPacket,
+            MailItemAddedPacket,
+            MailItemRemovedPacket,
+            MailReceiverPacket,
+            SendMailResultPacket,
+        ]);
+
+        let map_outgoing_handler = create_handler!([
+            MapServerLoginPacket,
+            MapLoadedPacket,
+            RestartPacket,
+            RequestPlayerMovePacket,
+            RequestWarpToMapPacket,
+            RequestDetailsPacket,
+            RequestActionPacket,
+            RequestStatUpPacket,
+            GlobalMessagePacket,
+            StartDialogPacket,
+            NextDialogPacket,
+            CloseDialogPacket,
+            ChooseDialogOptionPacket,
+            RequestEquipItemPacket,
+            RequestUnequipItemPacket,
+            UseSkillAtIdPacket,
+            UseSkillOnGroundPacket,
+            StartUseSkillPacket,
+            EndUseSkillPacket,
+            AddFriendPacket,
+            RemoveFriendPacket,
+            FriendRequestResponsePacket,
+            CreatePartyPacket,
+            InvitePartyMemberPacket,
+            PartyInviteResponsePacket,
+            LeavePartyPacket,
+            ExpelPartyMemberPacket,
+            SendPartyMessagePacket,
+            ChangePartyOptionsPacket,
+            ChangePartyLeaderPacket,
+            RequestGuildInformationPacket,
+            RequestGuildEmblemPacket,
+            SendGuildMessagePacket,
+            RequestCharacterNamePacket,
+            RequestTradePacket,
+            TradeRequestResponsePacket,
+            AddTradeItemPacket,
+            LockTradePacket,
+            CancelTradePacket,
+            CommitTradePacket,
+            AddStorageItemPacket,
+            AddStorageItemFromCartPacket,
+            RemoveStorageItemPacket,
+            CloseStoragePacket,
+            SetHotkeyData1Packet,
+            SetHotkeyData2Packet,
+            SelectBuyOrSellPacket,
+            BuyItemsPacket,
+            BuyShopItemsPacket,
+            CloseShopPacket,
+            SellItemsPacket,
+            RequestServerTickPacket,
+            SelectPetEggPacket,
+            PetCommandPacket,
+            RenamePetPacket,
+            HomunculusCommandPacket,
+            RenameHomunculusPacket,
+            MoveCompanionPacket,
+            CompanionAttackPacket,
+            MoveCompanionToOwnerPacket,
+            MercenaryCommandPacket,
+            AddCartItemPacket,
+            RemoveCartItemPacket,
+            SetupVendingPacket,
+            CloseVendingPacket,
+            RequestVendingItemsPacket,
+            BuyVendingItemsPacket,
+            OpenMailboxPacket,
+            RefreshMailboxPacket,
+            CloseMailboxPacket,
+            RequestReadMailPacket,
+            RequestMailZenyPacket,
+            RequestMailItemsPacket,
+            DeleteMailPacket,
+            OpenWriteMailPacket,
+            CancelWriteMailPacket,
+            AddMailItemPacket,
+            RemoveMailItemPacket,
+            CheckMailReceiverPacket,
+            SendMailPacket,
+        ]);
+
+        if packet_version == PacketVersion::_20200401 {
+            replace_packets!(character_incoming_handler, [
+                version_20200401::CharacterListPacket,
+                version_20200401::CreateCharacterSuccessPacket,
+                version_20200401::RequestCharacterListSuccessPacket,
+            ]);
+
+            replace_packets!(map_incoming_handler, [
+                version_20200401::EquippableItemListPacket,
+                version_20200401::ItemPickupPacket,
+                version_20200401::TradeItemAddedPacket,
+                version_20200401::StorageItemAddedPacket,
+                version_20200401::HomunculusInformationPacket,
+                version_20200401::CartItemAddedPacket,
+                version_20200401::OwnVendingItemListPacket,
+                version_20200401::VendingItemListPacket,
+                version_20200401::ReadMailPacket,
+                version_20200401::MailItemAddedPacket,
+            ]);
+        }
+
+        Self {
+            ports,
+            login_incoming_handler,
+            login_outgoing_handler,
+            character_incoming_handler,
+            character_outgoing_handler,
+            map_incoming_handler,
+            map_outgoing_handler,
+            flows: HashMap::new(),
+        }
+    }
+
+    /// Process a single TCP packet. Returns all Ragnarok Online packets that
+    /// are complete after adding the data to its connection.
+    pub fn process(&mut self, packet: TcpPacket) -> Vec<DissectedPacket> {
+        let Some((server, direction)) = self.ports.classify(packet.source, packet.destination) else {
+            return Vec::new();
+        };
+
+        let key = (packet.source, packet.destination);
+        let flow = self.flows.entry(key).or_insert_with(|| Flow::new(server, direction));
+
+        let buffered = flow.buffer.len();
+
+        match flow.reassembler.push(packet.segment, &mut flow.buffer) {
+            Reassembly::Continuous => {}
+            // Anything that was left in the buffer can't be completed anymore.
+            Reassembly::NewConnection | Reassembly::Gap => {
+                flow.buffer.drain(..buffered);
+            }
+        }
+
+        flow.last_timestamp = packet.timestamp;
+
+        let mut packets = Vec::new();
+        self.decode_flow(key, &mut packets);
+
+        let logged_in = packets.iter().any(|packet| {
+            matches!(&packet.content, PacketContent::Decoded(decoded) if decoded.header == CharacterServerLoginPacket::HEADER)
+                && packet.server == ServerKind::Character
+                && packet.direction == PacketDirection::Outgoing
+        });
+
+        if logged_in {
+            let reverse_key = (packet.destination, packet.source);
+            let reverse_flow = self
+                .flows
+                .entry(reverse_key)
+                .or_insert_with(|| Flow::new(ServerKind::Character, PacketDirection::Incoming));
+
+            reverse_flow.expects_account_id = true;
+
+            // The server might have been faster than the capture.
+            self.decode_flow(reverse_key, &mut packets);
+        }
+
+        packets
+    }
+
+    fn decode_flow(&mut self, key: (SocketAddr, SocketAddr), packets: &mut Vec<DissectedPacket>) {
+        let flow = self.flows.get_mut(&key).unwrap();
+
+        let packet_handler = match (flow.server, flow.direction) {
+            (ServerKind::Login, PacketDirection::Incoming) => &mut self.login_incoming_handler,
+            (ServerKind::Login, PacketDirection::Outgoing) => &mut self.login_outgoing_handler,
+            (ServerKind::Character, PacketDirection::Incoming) => &mut self.character_incoming_handler,
+            (ServerKind::Character, PacketDirection::Outgoing) => &mut self.character_outgoing_handler,
+            (ServerKind::Map, PacketDirection::Incoming) => &mut self.map_incoming_handler,
+            (ServerKind::Map, PacketDirection::Outgoing) => &mut self.map_outgoing_handler,
+        };
+
+        let (timestamp, server, direction) = (flow.last_timestamp, flow.server, flow.direction);
+        let packet = |content| DissectedPacket {
+            timestamp,
+            server,
+            direction,
+            content,
+        };
+
+        let mut offset = 0;
+
+        loop {
+            let remaining = &flow.buffer[offset..];
+
+            if flow.expects_account_id {
+                let Some(account_id) = remaining.first_chunk::<4>() else {
+                    break;
+                };
+
+                packets.push(packet(PacketContent::AccountId(AccountId(u32::from_le_bytes(*account_id)))));
+                flow.expects_account_id = false;
+                offset += 4;
+                continue;
+            }
+
+            let mut byte_reader = ByteReader::without_metadata(remaining);
+
+            match packet_handler.process_one(&mut byte_reader) {
+                HandlerResult::Ok(decoded) => {
+                    offset += byte_reader.get_offset();
+                    packets.extend(decoded.map(PacketContent::Decoded).map(packet));
+                }
+                HandlerResult::PacketCutOff => break,
+                HandlerResult::UnhandledPacket => {
+                    packets.push(packet(PacketContent::Unknown { bytes: remaining.to_vec() }));
+                    offset = flow.buffer.len();
+                }
+                HandlerResult::InternalError(error) => {
+                    packets.push(packet(PacketContent::Failed {
+                        error: format!("{error:?}"),
+                        bytes: remaining.to_vec(),
+                    }));
+                    offset = flow.buffer.len();
+                }
+            }
+        }
+
+        flow.buffer.drain(..offset);
+    }
+
+    /// Report the data that is left over at the end of the capture.
+    pub fn finish(&mut self) -> Vec<DissectedPacket> {
+        let mut packets: Vec<_> = self
+            .flows
+            .values_mut()
+            .filter(|flow| !flow.buffer.is_empty())
+            .map(|flow| DissectedPacket {
+                timestamp: flow.last_timestamp,
+                server: flow.server,
+                direction: flow.direction,
+                content: PacketContent::Failed {
+                    error: "packet is cut off at the end of the capture".to_owned(),
+                    bytes: std::mem::take(&mut flow.buffer),
+                },
+            })
+            .collect();
+
+        packets.sort_by_key(|packet| packet.timestamp);
+        packets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::time::Duration;
+
+    use ragnarok_bytes::ByteWriter;
+    use ragnarok_packets::handler::{PacketDirection, ServerKind};
+    use ragnarok_packets::{AccountId, CharacterServerLoginPacket, ClientTick, PacketExt, PacketHeader, RequestServerTickPacket, Sex};
+    use serde_json::json;
+
+    use super::{DissectedPacket, Dissector, PacketContent, PacketFilter, PacketSelector, PacketVersion, ServerPorts, TcpPacket};
+    use crate::reassembly::TcpSegment;
+
+    const CLIENT: &str = "127.0.0.1:50000";
+
+    fn tcp_packet<'a>(source: &str, destination: &str, sequence_number: u32, payload: &'a [u8]) -> TcpPacket<'a> {
+        TcpPacket {
+            timestamp: Duration::from_millis(sequence_number.into()),
+            source: source.parse::<SocketAddr>().unwrap(),
+            destination: destination.parse::<SocketAddr>().unwrap(),
+            segment: TcpSegment {
+                sequence_number,
+                syn: false,
+                payload,
+            },
+        }
+    }
+
+    fn packet_bytes(packet: impl PacketExt) -> Vec<u8> {
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        byte_writer.into_inner()
+    }
+
+    fn decoded_name(packet: &DissectedPacket) -> Option<&'static str> {
+        match &packet.content {
+            PacketContent::Decoded(decoded) => Some(decoded.name),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn packet_split_across_segments() {
+        let mut dissector = Dissector::new(ServerPorts::default(), PacketVersion::default());
+        let bytes = packet_bytes(RequestServerTickPacket {
+            client_tick: ClientTick(100),
+        });
+
+        assert!(dissector.process(tcp_packet(CLIENT, "127.0.0.1:5121", 0, &bytes[..3])).is_empty());
+
+        let packets = dissector.process(tcp_packet(CLIENT, "127.0.0.1:5121", 3, &bytes[3..]));
+
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].server, ServerKind::Map);
+        assert_eq!(packets[0].direction, PacketDirection::Outgoing);
+        assert_eq!(decoded_name(&packets[0]), Some("RequestServerTickPacket"));
+        assert!(packets[0].is_ping());
+    }
+
+    #[test]
+    fn account_id_after_character_server_login() {
+        let mut dissector = Dissector::new(ServerPorts::default(), PacketVersion::default());
+        let bytes = packet_bytes(CharacterServerLoginPacket {
+            account_id: AccountId(2000001),
+            login_id1: 1,
+            login_id2: 2,
+            unknown: 0,
+            sex: Sex::Female,
+        });
+
+        let packets = dissector.process(tcp_packet(CLIENT, "127.0.0.1:6121", 0, &bytes));
+        assert_eq!(decoded_name(&packets[0]), Some("CharacterServerLoginPacket"));
+
+        let PacketContent::Decoded(decoded) = &packets[0].content else {
+            unreachable!()
+        };
+        assert_eq!(
+            decoded.json,
+            json!({ "account_id": 2000001, "login_id1": 1, "login_id2": 2, "unknown": 0, "sex": "Female" })
+        );
+
+        let packets = dissector.process(tcp_packet("127.0.0.1:6121", CLIENT, 0, &2000001u32.to_le_bytes()));
+        assert_eq!(packets[0].content, PacketContent::AccountId(AccountId(2000001)));
+    }
+
+    #[test]
+    fn unknown_packet() {
+        let mut dissector = Dissector::new(ServerPorts::default(), PacketVersion::default());
+
+        let packets = dissector.process(tcp_packet("127.0.0.1:6900", CLIENT, 0, &[0xFF, 0xFF, 1, 2]));
+
+        assert_eq!(packets[0].content, PacketContent::Unknown {
+            bytes: vec![0xFF, 0xFF, 1, 2]
+        });
+        assert_eq!(packets[0].header(), Some(PacketHeader(0xFFFF)));
+        assert!(dissector.finish().is_empty());
+    }
+
+    #[test]
+    fn other_ports_are_ignored() {
+        let mut dissector = Dissector::new(ServerPorts::default(), PacketVersion::default());
+
+        assert!(dissector.process(tcp_packet(CLIENT, "127.0.0.1:80", 0, &[0xFF, 0xFF])).is_empty());
+    }
+
+    #[test]
+    fn filter() {
+        let mut dissector = Dissector::new(ServerPorts::default(), PacketVersion::default());
+        let bytes = packet_bytes(RequestServerTickPacket {
+            client_tick: ClientTick(100),
+        });
+        let packet = dissector.process(tcp_packet(CLIENT, "127.0.0.1:5121", 0, &bytes)).remove(0);
+
+        let filter = |servers, packets, skip_pings| PacketFilter {
+            servers,
+            packets,
+            skip_pings,
+        };
+
+        assert!(filter(vec![], vec![], false).matches(&packet));
+        assert!(!filter(vec![], vec![], true).matches(&packet));
+        assert!(!filter(vec![ServerKind::Login], vec![], false).matches(&packet));
+        assert!(filter(vec![ServerKind::Map], vec!["0x0360".parse().unwrap()], false).matches(&packet));
+        assert!(filter(vec![], vec![PacketSelector::Name("RequestServerTickPacket".to_owned())], false).matches(&packet));
+        assert!(!filter(vec![], vec!["ServerTickPacket".parse().unwrap()], false).matches(&packet));
+    }
+}
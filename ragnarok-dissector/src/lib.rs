@@ -0,0 +1,14 @@
+//! Dissector for captured Ragnarok Online traffic.
+//!
+//! TCP segments are reassembled per connection, split into packets and
+//! decoded with the packet definitions of [`ragnarok_packets`].
+
+mod dissector;
+mod output;
+mod reassembly;
+
+pub use self::dissector::{
+    DecodedPacket, DissectedPacket, Dissector, PacketContent, PacketFilter, PacketSelector, PacketVersion, ServerPorts, TcpPacket,
+};
+pub use self::output::{OutputFormat, PacketWriter};
+pub use self::reassembly::{Reassembly, StreamReassembler, TcpSegment};
@@ -0,0 +1,267 @@
+use std::io::{BufWriter, Write};
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Parser, ValueEnum};
+use etherparse::{NetSlice, SlicedPacket, TransportSlice};
+use pcap::{Activated, Capture, Linktype};
+use ragnarok_dissector::{
+    Dissector, OutputFormat, PacketFilter, PacketSelector, PacketVersion, PacketWriter, ServerPorts, TcpPacket, TcpSegment,
+};
+use ragnarok_packets::handler::ServerKind;
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Server {
+    Login,
+    Character,
+    Map,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Version {
+    #[value(name = "20200401")]
+    _20200401,
+    #[value(name = "20220406")]
+    _20220406,
+}
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Arguments {
+    /// Capture file to read (.pcap or .pcapng).
+    #[arg(short, long, conflicts_with = "interface", required_unless_present = "interface")]
+    file: Option<PathBuf>,
+
+    /// Network interface to capture live traffic from.
+    #[arg(short, long)]
+    interface: Option<String>,
+
+    /// BPF filter applied to the capture, e.g. "host 127.0.0.1".
+    #[arg(long)]
+    capture_filter: Option<String>,
+
+    /// Port of the login server.
+    #[arg(long, default_value_t = ServerPorts::default().login)]
+    login_port: u16,
+
+    /// Port of the character server.
+    #[arg(long, default_value_t = ServerPorts::default().character)]
+    character_port: u16,
+
+    /// Port of the map server.
+    #[arg(long, default_value_t = ServerPorts::default().map)]
+    map_port: u16,
+
+    /// Only show packets of this server. Can be used multiple times.
+    #[arg(short, long)]
+    server: Vec<Server>,
+
+    /// Only show packets with this name (e.g. "PlayerMovePacket") or header
+    /// (e.g. "0x0087"). Can be used multiple times.
+    #[arg(short, long)]
+    packet: Vec<PacketSelector>,
+
+    /// Hide keep-alive and other ping packets.
+    #[arg(long)]
+    skip_pings: bool,
+
+    /// Output format. Timestamps are in seconds since the first packet.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Packet version of the client that was captured.
+    #[arg(long, value_enum, default_value_t = Version::_20220406)]
+    packet_version: Version,
+}
+
+/// Extract the addresses and the TCP segment of a captured packet.
+fn parse_tcp(linktype: Linktype, data: &[u8]) -> Option<(SocketAddr, SocketAddr, TcpSegment<'_>)> {
+    let sliced_packet = match linktype {
+        Linktype::ETHERNET => SlicedPacket::from_ethernet(data),
+        Linktype::LINUX_SLL => SlicedPacket::from_linux_sll(data),
+        // Loopback captures start with the four byte address family.
+        Linktype::NULL | Linktype::LOOP => SlicedPacket::from_ip(data.get(4..)?),
+        Linktype::RAW | Linktype::IPV4 | Linktype::IPV6 => SlicedPacket::from_ip(data),
+        _ => return None,
+    }
+    .ok()?;
+
+    let (source_address, destination_address): (IpAddr, IpAddr) = match sliced_packet.net? {
+        NetSlice::Ipv4(slice) => (slice.header().source_addr().into(), slice.header().destination_addr().into()),
+        NetSlice::Ipv6(slice) => (slice.header().source_addr().into(), slice.header().destination_addr().into()),
+        NetSlice::Arp(_) => return None,
+    };
+
+    let Some(TransportSlice::Tcp(tcp_slice)) = sliced_packet.transport else {
+        return None;
+    };
+
+    let segment = TcpSegment {
+        sequence_number: tcp_slice.sequence_number(),
+        syn: tcp_slice.syn(),
+        payload: tcp_slice.payload(),
+    };
+
+    Some((
+        SocketAddr::new(source_address, tcp_slice.source_port()),
+        SocketAddr::new(destination_address, tcp_slice.destination_port()),
+        segment,
+    ))
+}
+
+enum DissectError {
+    Capture(pcap::Error),
+    Output(std::io::Error),
+}
+
+impl From<std::io::Error> for DissectError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Output(error)
+    }
+}
+
+fn dissect(
+    mut capture: Capture<dyn Activated>,
+    mut dissector: Dissector,
+    filter: &PacketFilter,
+    packet_writer: &mut PacketWriter<impl Write>,
+) -> Result<(), DissectError> {
+    let linktype = capture.get_datalink();
+    let mut first_timestamp = None;
+
+    let mut capture_error = None;
+
+    loop {
+        let captured = match capture.next_packet() {
+            Ok(captured) => captured,
+            // Live captures time out when no traffic arrives for a while.
+            Err(pcap::Error::TimeoutExpired) => continue,
+            Err(pcap::Error::NoMorePackets) => break,
+            Err(error) => {
+                // Still write the packets that were decoded so far, since
+                // truncated capture files are common.
+                capture_error = Some(error);
+                break;
+            }
+        };
+
+        let timestamp = Duration::from_secs(captured.header.ts.tv_sec as u64) + Duration::from_micros(captured.header.ts.tv_usec as u64);
+        let first_timestamp = *first_timestamp.get_or_insert(timestamp);
+
+        let Some((source, destination, segment)) = parse_tcp(linktype, captured.data) else {
+            continue;
+        };
+
+        let tcp_packet = TcpPacket {
+            timestamp: timestamp.saturating_sub(first_timestamp),
+            source,
+            destination,
+            segment,
+        };
+
+        for packet in dissector.process(tcp_packet).iter().filter(|packet| filter.matches(packet)) {
+            packet_writer.write(packet)?;
+        }
+    }
+
+    for packet in dissector.finish().iter().filter(|packet| filter.matches(packet)) {
+        packet_writer.write(packet)?;
+    }
+
+    packet_writer.flush()?;
+
+    match capture_error {
+        Some(error) => Err(DissectError::Capture(error)),
+        None => Ok(()),
+    }
+}
+
+fn main() -> ExitCode {
+    let arguments = Arguments::parse();
+
+    let ports = ServerPorts {
+        login: arguments.login_port,
+        character: arguments.character_port,
+        map: arguments.map_port,
+    };
+
+    let packet_version = match arguments.packet_version {
+        Version::_20200401 => PacketVersion::_20200401,
+        Version::_20220406 => PacketVersion::_20220406,
+    };
+
+    let format = match arguments.format {
+        Format::Text => OutputFormat::Text,
+        Format::Json => OutputFormat::Json,
+        Format::Csv => OutputFormat::Csv,
+    };
+
+    let filter = PacketFilter {
+        servers: arguments
+            .server
+            .iter()
+            .map(|server| match server {
+                Server::Login => ServerKind::Login,
+                Server::Character => ServerKind::Character,
+                Server::Map => ServerKind::Map,
+            })
+            .collect(),
+        packets: arguments.packet,
+        skip_pings: arguments.skip_pings,
+    };
+
+    let dissector = Dissector::new(ports, packet_version);
+
+    let stdout = std::io::stdout().lock();
+    let mut packet_writer = match PacketWriter::new(BufWriter::new(stdout), format) {
+        Ok(packet_writer) => packet_writer,
+        Err(error) => {
+            eprintln!("Failed to write output: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match (&arguments.file, &arguments.interface) {
+        (Some(path), _) => Capture::from_file(path).map(Capture::<dyn Activated>::from),
+        (None, Some(interface)) => Capture::from_device(interface.as_str())
+            .and_then(|capture| capture.immediate_mode(true).open())
+            .map(Capture::<dyn Activated>::from),
+        (None, None) => unreachable!("either a file or an interface is required"),
+    };
+
+    let mut capture = match result {
+        Ok(capture) => capture,
+        Err(error) => {
+            eprintln!("Failed to open capture: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(capture_filter) = &arguments.capture_filter
+        && let Err(error) = capture.filter(capture_filter, true)
+    {
+        eprintln!("Invalid capture filter: {error}");
+        return ExitCode::FAILURE;
+    }
+
+    match dissect(capture, dissector, &filter, &mut packet_writer) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(DissectError::Capture(error)) => {
+            eprintln!("Failed to read capture: {error}");
+            ExitCode::FAILURE
+        }
+        Err(DissectError::Output(error)) => {
+            eprintln!("Failed to write output: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Writing dissected packets in different formats.
+
+use std::io::Write;
+
+use ragnarok_packets::handler::{PacketDirection, ServerKind};
+use serde_json::{Map, Value, json};
+
+use crate::dissector::{DissectedPacket, PacketContent};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// One line per packet, meant to be read by humans.
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+    /// Comma separated values with a header row.
+    Csv,
+}
+
+const CSV_COLUMNS: &[&str] = &[
+    "timestamp",
+    "server",
+    "direction",
+    "header",
+    "status",
+    "packet",
+    "fields",
+    "error",
+    "bytes",
+];
+
+fn server_name(server: ServerKind) -> &'static str {
+    match server {
+        ServerKind::Login => "login",
+        ServerKind::Character => "character",
+        ServerKind::Map => "map",
+    }
+}
+
+fn direction_name(direction: PacketDirection) -> &'static str {
+    match direction {
+        PacketDirection::Incoming => "incoming",
+        PacketDirection::Outgoing => "outgoing",
+    }
+}
+
+fn status_name(content: &PacketContent) -> &'static str {
+    match content {
+        PacketContent::Decoded(_) | PacketContent::AccountId(_) => "decoded",
+        PacketContent::Unknown { .. } => "unknown",
+        PacketContent::Failed { .. } => "failed",
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// The fields of the packet, the error and the raw bytes. Bytes are only
+/// included for packets that couldn't be decoded.
+fn details(content: &PacketContent) -> (Option<String>, Option<&str>, Option<&[u8]>) {
+    match content {
+        PacketContent::Decoded(packet) => (Some(packet.fields.clone()), None, None),
+        PacketContent::AccountId(account_id) => (Some(format!("{account_id:?}")), None, None),
+        PacketContent::Unknown { bytes } => (None, None, Some(bytes)),
+        PacketContent::Failed { error, bytes } => (None, Some(error), Some(bytes)),
+    }
+}
+
+/// Quote a value if it contains characters that have a special meaning in
+/// CSV.
+fn csv_escape(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_owned(),
+    }
+}
+
+pub struct PacketWriter<W: Write> {
+    writer: W,
+    format: OutputFormat,
+}
+
+impl<W: Write> PacketWriter<W> {
+    /// Create a new writer. For CSV output this already writes the header
+    /// row.
+    pub fn new(mut writer: W, format: OutputFormat) -> std::io::Result<Self> {
+        if format == OutputFormat::Csv {
+            writeln!(writer, "{}", CSV_COLUMNS.join(","))?;
+        }
+
+        Ok(Self { writer, format })
+    }
+
+    pub fn write(&mut self, packet: &DissectedPacket) -> std::io::Result<()> {
+        match self.format {
+            OutputFormat::Text => self.write_text(packet),
+            OutputFormat::Json => self.write_json(packet),
+            OutputFormat::Csv => self.write_csv(packet),
+        }
+    }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+
+    fn header(packet: &DissectedPacket) -> String {
+        packet.header().map(|header| format!("0x{:04X}", header.0)).unwrap_or_default()
+    }
+
+    fn write_text(&mut self, packet: &DissectedPacket) -> std::io::Result<()> {
+        write!(
+            self.writer,
+            "[{:>12.6}] {:<9} {:<8} {:<6} ",
+            packet.timestamp.as_secs_f64(),
+            server_name(packet.server),
+            direction_name(packet.direction),
+            Self::header(packet)
+        )?;
+
+        match &packet.content {
+            PacketContent::Decoded(decoded) => writeln!(self.writer, "{}", decoded.fields),
+            PacketContent::AccountId(account_id) => writeln!(self.writer, "{account_id:?}"),
+            PacketContent::Unknown { bytes } => writeln!(self.writer, "unknown packet: {}", hex(bytes)),
+            PacketContent::Failed { error, bytes } => writeln!(self.writer, "failed packet: {} ({error})", hex(bytes)),
+        }
+    }
+
+    fn write_json(&mut self, packet: &DissectedPacket) -> std::io::Result<()> {
+        let (_, error, bytes) = details(&packet.content);
+
+        let mut object = Map::new();
+        object.insert("timestamp".to_owned(), json!(packet.timestamp.as_secs_f64()));
+        object.insert("server".to_owned(), json!(server_name(packet.server)));
+        object.insert("direction".to_owned(), json!(direction_name(packet.direction)));
+        object.insert("header".to_owned(), json!(packet.header().map(|_| Self::header(packet))));
+        object.insert("status".to_owned(), json!(status_name(&packet.content)));
+        object.insert("packet".to_owned(), json!(packet.name()));
+
+        let fields = match &packet.content {
+            PacketContent::Decoded(decoded) => Some(decoded.json.clone()),
+            PacketContent::AccountId(account_id) => Some(json!(account_id)),
+            PacketContent::Unknown { .. } | PacketContent::Failed { .. } => None,
+        };
+
+        if let Some(fields) = fields {
+            object.insert("fields".to_owned(), fields);
+        }
+
+        if let Some(error) = error {
+            object.insert("error".to_owned(), json!(error));
+        }
+
+        if let Some(bytes) = bytes {
+            object.insert("bytes".to_owned(), json!(hex(bytes)));
+        }
+
+        serde_json::to_writer(&mut self.writer, &Value::Object(object))?;
+        writeln!(self.writer)
+    }
+
+    fn write_csv(&mut self, packet: &DissectedPacket) -> std::io::Result<()> {
+        let (fields, error, bytes) = details(&packet.content);
+
+        let values = [
+            format!("{:.6}", packet.timestamp.as_secs_f64()),
+            server_name(packet.server).to_owned(),
+            direction_name(packet.direction).to_owned(),
+            Self::header(packet),
+            status_name(&packet.content).to_owned(),
+            packet.name().unwrap_or_default().to_owned(),
+            fields.unwrap_or_default(),
+            error.unwrap_or_default().to_owned(),
+            bytes.map(hex).unwrap_or_default(),
+        ];
+
+        let line = values.iter().map(|value| csv_escape(value)).collect::<Vec<_>>().join(",");
+        writeln!(self.writer, "{line}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ragnarok_packets::handler::{PacketDirection, ServerKind};
+    use ragnarok_packets::{AccountId, PacketHeader};
+    use serde_json::json;
+
+    use super::{OutputFormat, PacketWriter};
+    use crate::dissector::{DecodedPacket, DissectedPacket, PacketContent};
+
+    fn write(format: OutputFormat, packets: &[DissectedPacket]) -> String {
+        let mut output = Vec::new();
+        let mut packet_writer = PacketWriter::new(&mut output, format).unwrap();
+
+        packets.iter().for_each(|packet| packet_writer.write(packet).unwrap());
+
+        String::from_utf8(output).unwrap()
+    }
+
+    fn packets() -> [DissectedPacket; 2] {
+        [
+            DissectedPacket {
+                timestamp: Duration::from_millis(1500),
+                server: ServerKind::Map,
+                direction: PacketDirection::Outgoing,
+                content: PacketContent::Decoded(DecodedPacket {
+                    header: PacketHeader(0x0F3),
+                    name: "GlobalMessagePacket",
+                    is_ping: false,
+                    fields: "GlobalMessagePacket { message: \"hi, there\" }".to_owned(),
+                    json: json!({ "message": "hi, there" }),
+                }),
+            },
+            DissectedPacket {
+                timestamp: Duration::from_secs(2),
+                server: ServerKind::Character,
+                direction: PacketDirection::Incoming,
+                content: PacketContent::Unknown {
+                    bytes: vec![0x34, 0x12, 0xFF],
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn json() {
+        let output = write(OutputFormat::Json, &packets());
+
+        assert_eq!(
+            output,
+            concat!(
+                r#"{"timestamp":1.5,"server":"map","direction":"outgoing","header":"0x00F3","status":"decoded","#,
+                r#""packet":"GlobalMessagePacket","fields":{"message":"hi, there"}}"#,
+                "\n",
+                r#"{"timestamp":2.0,"server":"character","direction":"incoming","header":"0x1234","status":"unknown","#,
+                r#""packet":null,"bytes":"3412ff"}"#,
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let output = write(OutputFormat::Csv, &packets());
+
+        assert_eq!(
+            output,
+            concat!(
+                "timestamp,server,direction,header,status,packet,fields,error,bytes\n",
+                r#"1.500000,map,outgoing,0x00F3,decoded,GlobalMessagePacket,"GlobalMessagePacket { message: ""hi, there"" }",,"#,
+                "\n",
+                "2.000000,character,incoming,0x1234,unknown,,,,3412ff\n",
+            )
+        );
+    }
+
+    #[test]
+    fn account_id() {
+        let packet = DissectedPacket {
+            timestamp: Duration::ZERO,
+            server: ServerKind::Character,
+            direction: PacketDirection::Incoming,
+            content: PacketContent::AccountId(AccountId(2000001)),
+        };
+
+        assert!(write(OutputFormat::Json, &[packet]).contains(r#""packet":"AccountId","fields":2000001"#));
+    }
+}
@@ -0,0 +1,220 @@
+//! Reassembly of TCP segments into a continuous byte stream.
+
+use std::collections::HashMap;
+
+/// Maximum number of bytes that are kept while waiting for a missing segment.
+/// Once exceeded, the missing segment is considered lost.
+const MAXIMUM_PENDING_BYTES: usize = 1024 * 1024;
+
+/// A single TCP segment of a connection.
+#[derive(Debug, Clone, Copy)]
+pub struct TcpSegment<'a> {
+    pub sequence_number: u32,
+    pub syn: bool,
+    pub payload: &'a [u8],
+}
+
+/// Result of pushing a segment into the [`StreamReassembler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reassembly {
+    /// The stream continues where it left off.
+    Continuous,
+    /// A new connection was started with a SYN.
+    NewConnection,
+    /// One or more segments were lost, so the new data doesn't directly
+    /// follow the previous data.
+    Gap,
+}
+
+/// Reassembles one direction of a TCP connection. Segments may arrive out of
+/// order or more than once, but the data is always appended in order.
+#[derive(Debug, Default)]
+pub struct StreamReassembler {
+    next_sequence_number: Option<u32>,
+    pending_segments: HashMap<u32, Vec<u8>>,
+    pending_bytes: usize,
+}
+
+/// Distance from `from` to `to`, taking wrapping sequence numbers into
+/// account.
+fn sequence_distance(from: u32, to: u32) -> i32 {
+    to.wrapping_sub(from) as i32
+}
+
+impl StreamReassembler {
+    /// Push a segment and append all data that is now in order to `stream`.
+    pub fn push(&mut self, segment: TcpSegment, stream: &mut Vec<u8>) -> Reassembly {
+        let mut reassembly = Reassembly::Continuous;
+
+        if segment.syn {
+            *self = Self::default();
+            // The SYN flag counts as one byte of the sequence.
+            self.next_sequence_number = Some(segment.sequence_number.wrapping_add(1));
+            reassembly = Reassembly::NewConnection;
+        }
+
+        // The capture might start in the middle of a connection.
+        let next_sequence_number = *self.next_sequence_number.get_or_insert(segment.sequence_number);
+        let sequence_number = match segment.syn {
+            true => segment.sequence_number.wrapping_add(1),
+            false => segment.sequence_number,
+        };
+
+        if segment.payload.is_empty() {
+            return reassembly;
+        }
+
+        if sequence_distance(next_sequence_number, sequence_number) > 0 {
+            // Segment arrived early, so we wait for the ones in between.
+            // Retransmissions replace the pending payload only if they carry more data.
+            let payload = self.pending_segments.entry(sequence_number).or_default();
+
+            if payload.len() < segment.payload.len() {
+                self.pending_bytes = self.pending_bytes - payload.len() + segment.payload.len();
+                *payload = segment.payload.to_vec();
+            }
+
+            if self.pending_bytes > MAXIMUM_PENDING_BYTES {
+                self.skip_missing_segment();
+                self.append_pending(stream);
+                return Reassembly::Gap;
+            }
+
+            return reassembly;
+        }
+
+        self.append(sequence_number, segment.payload, stream);
+        self.append_pending(stream);
+
+        reassembly
+    }
+
+    /// Append data that starts at or before the next expected sequence number.
+    /// Bytes that were already appended (retransmissions) are skipped.
+    fn append(&mut self, sequence_number: u32, payload: &[u8], stream: &mut Vec<u8>) {
+        let next_sequence_number = self.next_sequence_number.unwrap();
+        let already_appended = sequence_distance(sequence_number, next_sequence_number) as usize;
+
+        if let Some(new_data) = payload.get(already_appended..)
+            && !new_data.is_empty()
+        {
+            stream.extend_from_slice(new_data);
+            self.next_sequence_number = Some(next_sequence_number.wrapping_add(new_data.len() as u32));
+        }
+    }
+
+    fn append_pending(&mut self, stream: &mut Vec<u8>) {
+        loop {
+            let next_sequence_number = self.next_sequence_number.unwrap();
+            let Some(sequence_number) = self
+                .pending_segments
+                .keys()
+                .copied()
+                .find(|&sequence_number| sequence_distance(next_sequence_number, sequence_number) <= 0)
+            else {
+                break;
+            };
+
+            let payload = self.pending_segments.remove(&sequence_number).unwrap();
+            self.pending_bytes -= payload.len();
+            self.append(sequence_number, &payload, stream);
+        }
+    }
+
+    fn skip_missing_segment(&mut self) {
+        let next_sequence_number = self.next_sequence_number.unwrap();
+
+        self.next_sequence_number = self
+            .pending_segments
+            .keys()
+            .copied()
+            .min_by_key(|&sequence_number| sequence_distance(next_sequence_number, sequence_number));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Reassembly, StreamReassembler, TcpSegment};
+
+    fn segment(sequence_number: u32, payload: &[u8]) -> TcpSegment<'_> {
+        TcpSegment {
+            sequence_number,
+            syn: false,
+            payload,
+        }
+    }
+
+    #[test]
+    fn in_order() {
+        let mut reassembler = StreamReassembler::default();
+        let mut stream = Vec::new();
+
+        reassembler.push(segment(100, b"abc"), &mut stream);
+        reassembler.push(segment(103, b"def"), &mut stream);
+
+        assert_eq!(stream, b"abcdef");
+    }
+
+    #[test]
+    fn out_of_order_and_retransmitted() {
+        let mut reassembler = StreamReassembler::default();
+        let mut stream = Vec::new();
+
+        let syn = TcpSegment {
+            sequence_number: 99,
+            syn: true,
+            payload: &[],
+        };
+
+        assert_eq!(reassembler.push(syn, &mut stream), Reassembly::NewConnection);
+        reassembler.push(segment(103, b"def"), &mut stream);
+        assert!(stream.is_empty());
+
+        reassembler.push(segment(100, b"abc"), &mut stream);
+        reassembler.push(segment(101, b"bcd"), &mut stream);
+        reassembler.push(segment(106, b"g"), &mut stream);
+
+        assert_eq!(stream, b"abcdefg");
+    }
+
+    #[test]
+    fn retransmitted_early_segment() {
+        let mut reassembler = StreamReassembler::default();
+        let mut stream = Vec::new();
+
+        reassembler.push(segment(100, b"abc"), &mut stream);
+        reassembler.push(segment(106, b"gh"), &mut stream);
+        reassembler.push(segment(106, b"gh"), &mut stream);
+        reassembler.push(segment(106, b"g"), &mut stream);
+        assert_eq!(reassembler.pending_bytes, 2);
+
+        reassembler.push(segment(103, b"def"), &mut stream);
+
+        assert_eq!(stream, b"abcdefgh");
+        assert_eq!(reassembler.pending_bytes, 0);
+    }
+
+    #[test]
+    fn wrapping_sequence_numbers() {
+        let mut reassembler = StreamReassembler::default();
+        let mut stream = Vec::new();
+
+        reassembler.push(segment(u32::MAX - 1, b"ab"), &mut stream);
+        reassembler.push(segment(1, b"d"), &mut stream);
+        reassembler.push(segment(0, b"c"), &mut stream);
+
+        assert_eq!(stream, b"abcd");
+    }
+
+    #[test]
+    fn lost_segment() {
+        let mut reassembler = StreamReassembler::default();
+        let mut stream = Vec::new();
+        let large_payload = vec![0; super::MAXIMUM_PENDING_BYTES + 1];
+
+        reassembler.push(segment(0, b"a"), &mut stream);
+
+        assert_eq!(reassembler.push(segment(10, &large_payload), &mut stream), Reassembly::Gap);
+        assert_eq!(stream.len(), 1 + large_payload.len());
+    }
+}
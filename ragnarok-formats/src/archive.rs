@@ -3,6 +3,12 @@ use ragnarok_bytes::{ByteConvertable, FixedByteSize};
 use crate::signature::Signature;
 
 /// Represents the Header of the GRF file.
+///
+/// Version 0x300 archives use a 64-bit file table offset, which spans
+/// `file_table_offset` and `reserved_files`. Use
+/// [`get_file_table_offset`](Self::get_file_table_offset) and
+/// [`get_file_count`](Self::get_file_count) to read the values independent of
+/// the version.
 #[derive(Clone, ByteConvertable, FixedByteSize)]
 pub struct Header {
     #[new_default]
@@ -18,8 +24,18 @@ pub struct Header {
 impl Header {
     pub const FILE_OFFSET: usize = 7;
 
+    pub fn get_file_table_offset(&self) -> u64 {
+        match self.version {
+            0x300 => ((self.reserved_files as u64) << 32) | self.file_table_offset as u64,
+            _ => self.file_table_offset as u64,
+        }
+    }
+
     pub fn get_file_count(&self) -> usize {
-        (self.file_count - self.reserved_files) as usize - Self::FILE_OFFSET
+        match self.version {
+            0x300 => (self.file_count as usize).saturating_sub(Self::FILE_OFFSET),
+            _ => (self.file_count.saturating_sub(self.reserved_files) as usize).saturating_sub(Self::FILE_OFFSET),
+        }
     }
 }
 
@@ -34,6 +50,18 @@ pub struct FileTableRow {
     pub offset: u32,
 }
 
+/// Represents file information about each of the files stored in a version
+/// 0x300 GRF. The only difference to [`FileTableRow`] is the 64-bit offset.
+#[derive(Clone, Debug, ByteConvertable)]
+pub struct LargeFileTableRow {
+    pub file_name: String,
+    pub compressed_size: u32,
+    pub compressed_size_aligned: u32,
+    pub uncompressed_size: u32,
+    pub flags: u8,
+    pub offset: u64,
+}
+
 /// Stores the table of files the parent GRF is holding.
 #[derive(Clone, ByteConvertable, FixedByteSize)]
 pub struct AssetTable {
@@ -10,6 +10,7 @@ pub mod color;
 pub mod effect;
 pub mod map;
 pub mod model;
+pub mod palette;
 pub mod signature;
 pub mod sprite;
 pub mod transform;
@@ -0,0 +1,12 @@
+use ragnarok_bytes::ByteConvertable;
+
+use crate::sprite::Palette;
+
+/// A standalone palette file (`.pal`). These are used to swap the palette
+/// embedded in a sprite, e.g. to change the hair or clothes color of a
+/// player. The file has no header and contains just the 256 colors.
+#[derive(Clone, Debug, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct PaletteData {
+    pub palette: Palette,
+}
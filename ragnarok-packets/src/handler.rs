@@ -1,4 +1,6 @@
+use std::any::Any;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 use ragnarok_bytes::{ByteReader, ConversionError, ConversionResult, FromBytes};
 
@@ -23,6 +25,21 @@ pub struct DuplicateHandlerError {
     pub packet_header: PacketHeader,
 }
 
+/// The server on the other side of a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerKind {
+    Login,
+    Character,
+    Map,
+}
+
+/// Whether a packet was received from or sent to a server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketDirection {
+    Incoming,
+    Outgoing,
+}
+
 /// Trait for monitoring the incoming and outgoing packets.
 pub trait PacketCallback: Clone + 'static {
     /// Called by the [`PacketHandler`] when a packet is received.
@@ -51,6 +68,108 @@ pub trait PacketCallback: Clone + 'static {
     fn failed_packet(&self, bytes: Vec<u8>, error: Box<ConversionError>) {
         let _ = (bytes, error);
     }
+
+    /// Called when a connection to a server was established.
+    fn server_connected(&self, server: ServerKind) {
+        let _ = server;
+    }
+
+    /// Called with the raw bytes of every packet that is received from or
+    /// sent to a server, except for keep-alive packets. Outgoing packets are
+    /// passed before their header is obfuscated.
+    fn raw_packet(&self, server: ServerKind, direction: PacketDirection, bytes: &[u8]) {
+        let _ = (server, direction, bytes);
+    }
+}
+
+impl<Callback> PacketCallback for Option<Callback>
+where
+    Callback: PacketCallback,
+{
+    fn incoming_packet<Packet>(&self, packet: &Packet)
+    where
+        Packet: ragnarok_packets::Packet,
+    {
+        if let Some(callback) = self {
+            callback.incoming_packet(packet);
+        }
+    }
+
+    fn outgoing_packet<Packet>(&self, packet: &Packet)
+    where
+        Packet: ragnarok_packets::Packet,
+    {
+        if let Some(callback) = self {
+            callback.outgoing_packet(packet);
+        }
+    }
+
+    fn unknown_packet(&self, bytes: Vec<u8>) {
+        if let Some(callback) = self {
+            callback.unknown_packet(bytes);
+        }
+    }
+
+    fn failed_packet(&self, bytes: Vec<u8>, error: Box<ConversionError>) {
+        if let Some(callback) = self {
+            callback.failed_packet(bytes, error);
+        }
+    }
+
+    fn server_connected(&self, server: ServerKind) {
+        if let Some(callback) = self {
+            callback.server_connected(server);
+        }
+    }
+
+    fn raw_packet(&self, server: ServerKind, direction: PacketDirection, bytes: &[u8]) {
+        if let Some(callback) = self {
+            callback.raw_packet(server, direction, bytes);
+        }
+    }
+}
+
+/// Combine two callbacks so that both of them are called for every packet.
+impl<First, Second> PacketCallback for (First, Second)
+where
+    First: PacketCallback,
+    Second: PacketCallback,
+{
+    fn incoming_packet<Packet>(&self, packet: &Packet)
+    where
+        Packet: ragnarok_packets::Packet,
+    {
+        self.0.incoming_packet(packet);
+        self.1.incoming_packet(packet);
+    }
+
+    fn outgoing_packet<Packet>(&self, packet: &Packet)
+    where
+        Packet: ragnarok_packets::Packet,
+    {
+        self.0.outgoing_packet(packet);
+        self.1.outgoing_packet(packet);
+    }
+
+    fn unknown_packet(&self, bytes: Vec<u8>) {
+        self.0.unknown_packet(bytes.clone());
+        self.1.unknown_packet(bytes);
+    }
+
+    fn failed_packet(&self, bytes: Vec<u8>, error: Box<ConversionError>) {
+        self.0.failed_packet(bytes.clone(), error.clone());
+        self.1.failed_packet(bytes, error);
+    }
+
+    fn server_connected(&self, server: ServerKind) {
+        self.0.server_connected(server);
+        self.1.server_connected(server);
+    }
+
+    fn raw_packet(&self, server: ServerKind, direction: PacketDirection, bytes: &[u8]) {
+        self.0.raw_packet(server, direction, bytes);
+        self.1.raw_packet(server, direction, bytes);
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -60,6 +179,10 @@ impl PacketCallback for NoPacketCallback {}
 
 pub type HandlerFunction<Output, Meta> = Box<dyn Fn(&mut ByteReader<Meta>) -> ConversionResult<Output>>;
 
+/// Handler that takes an already parsed packet. These are kept around so
+/// packets can be converted and passed to the handler of a different packet.
+type PacketFunction<Packet, Output> = Rc<dyn Fn(Packet) -> Output>;
+
 /// A struct to help with reading packets from a [`ByteReader`] and
 /// converting them to some common event type.
 ///
@@ -69,6 +192,8 @@ where
     Meta: 'static,
 {
     handlers: HashMap<PacketHeader, HandlerFunction<Output, Meta>>,
+    /// Type erased [`PacketFunction`]s of all registered packets.
+    packet_functions: HashMap<PacketHeader, Box<dyn Any>>,
     packet_callback: Callback,
 }
 
@@ -80,6 +205,7 @@ where
     fn default() -> Self {
         Self {
             handlers: Default::default(),
+            packet_functions: Default::default(),
             packet_callback: Default::default(),
         }
     }
@@ -88,49 +214,88 @@ where
 impl<Output, Meta, Callback> PacketHandler<Output, Meta, Callback>
 where
     Meta: Default + 'static,
-    Output: Default,
+    Output: Default + 'static,
     Callback: PacketCallback,
 {
     /// Create a new packet handler with a callback.
     pub fn with_callback(packet_callback: Callback) -> Self {
         Self {
             handlers: Default::default(),
+            packet_functions: Default::default(),
             packet_callback,
         }
     }
 
+    /// Get the callback that is monitoring the packets.
+    pub fn packet_callback(&self) -> &Callback {
+        &self.packet_callback
+    }
+
     /// Register a new packet handler.
     pub fn register<Packet, Return>(&mut self, handler: impl Fn(Packet) -> Return + 'static) -> Result<(), DuplicateHandlerError>
     where
         Packet: ragnarok_packets::Packet,
         Return: Into<Output>,
     {
-        let packet_callback = self.packet_callback.clone();
-        let old_handler = self.handlers.insert(
-            Packet::HEADER,
-            Box::new(move |byte_reader| {
-                let packet = Packet::payload_from_bytes(byte_reader)?;
+        self.insert_packet_function::<Packet>(Rc::new(move |packet| handler(packet).into()))
+    }
 
-                packet_callback.incoming_packet(&packet);
+    /// Register a packet handler, replacing any handler that was registered
+    /// for the same header before.
+    pub fn replace<Packet, Return>(&mut self, handler: impl Fn(Packet) -> Return + 'static)
+    where
+        Packet: ragnarok_packets::Packet,
+        Return: Into<Output>,
+    {
+        self.handlers.remove(&Packet::HEADER);
+        self.register(handler).expect("old handler was removed");
+    }
 
-                Ok(handler(packet).into())
-            }),
-        );
+    /// Register a noop packet handler.
+    pub fn register_noop<Packet>(&mut self) -> Result<(), DuplicateHandlerError>
+    where
+        Packet: ragnarok_packets::Packet,
+    {
+        self.insert_packet_function::<Packet>(Rc::new(|_| Output::default()))
+    }
 
-        match old_handler.is_some() {
-            true => Err(DuplicateHandlerError {
-                packet_header: Packet::HEADER,
-            }),
-            false => Ok(()),
+    /// Register a packet that is converted to `Target` and handled by the
+    /// handler of `Target`. This is used for packets that have a different
+    /// header or layout in some packet versions.
+    ///
+    /// If both packets have the same header, the handler of `Target` is
+    /// replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no handler is registered for `Target`.
+    pub fn register_converted<Packet, Target>(&mut self) -> Result<(), DuplicateHandlerError>
+    where
+        Packet: ragnarok_packets::Packet + Into<Target>,
+        Target: ragnarok_packets::Packet,
+    {
+        let target_function = self
+            .packet_functions
+            .get(&Target::HEADER)
+            .and_then(|packet_function| packet_function.downcast_ref::<PacketFunction<Target, Output>>())
+            .cloned()
+            .expect("target packet needs to be registered first");
+
+        if Packet::HEADER == Target::HEADER {
+            self.handlers.remove(&Target::HEADER);
         }
+
+        self.insert_packet_function::<Packet>(Rc::new(move |packet: Packet| target_function(packet.into())))
     }
 
-    /// Register a noop packet handler.
-    pub fn register_noop<Packet>(&mut self) -> Result<(), DuplicateHandlerError>
+    fn insert_packet_function<Packet>(&mut self, packet_function: PacketFunction<Packet, Output>) -> Result<(), DuplicateHandlerError>
     where
         Packet: ragnarok_packets::Packet,
     {
         let packet_callback = self.packet_callback.clone();
+
+        self.packet_functions.insert(Packet::HEADER, Box::new(packet_function.clone()));
+
         let old_handler = self.handlers.insert(
             Packet::HEADER,
             Box::new(move |byte_reader| {
@@ -138,7 +303,7 @@ where
 
                 packet_callback.incoming_packet(&packet);
 
-                Ok(Output::default())
+                Ok(packet_function(packet))
             }),
         );
 
@@ -185,3 +350,83 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ragnarok_bytes::ByteReader;
+    use ragnarok_macros::{Packet, ServerPacket};
+
+    use super::{HandlerResult, NoPacketCallback, PacketHandler};
+
+    #[derive(Debug, Clone, Packet, ServerPacket)]
+    #[header(0x0001)]
+    struct NewPacket {
+        value: u32,
+    }
+
+    #[derive(Debug, Clone, Packet, ServerPacket)]
+    #[header(0x0002)]
+    struct OldPacket {
+        value: u16,
+    }
+
+    impl From<OldPacket> for NewPacket {
+        fn from(packet: OldPacket) -> Self {
+            Self {
+                value: packet.value.into(),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Packet, ServerPacket)]
+    #[header(0x0001)]
+    struct OldLayoutPacket {
+        value: u8,
+    }
+
+    impl From<OldLayoutPacket> for NewPacket {
+        fn from(packet: OldLayoutPacket) -> Self {
+            Self {
+                value: packet.value.into(),
+            }
+        }
+    }
+
+    fn packet_handler() -> PacketHandler<u32, (), NoPacketCallback> {
+        let mut packet_handler = PacketHandler::default();
+        packet_handler.register(|packet: NewPacket| packet.value).unwrap();
+        packet_handler
+    }
+
+    fn process(packet_handler: &mut PacketHandler<u32, (), NoPacketCallback>, bytes: &[u8]) -> Option<u32> {
+        match packet_handler.process_one(&mut ByteReader::without_metadata(bytes)) {
+            HandlerResult::Ok(output) => Some(output),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn converted_header() {
+        let mut packet_handler = packet_handler();
+        packet_handler.register_converted::<OldPacket, NewPacket>().unwrap();
+
+        assert_eq!(process(&mut packet_handler, &[0x01, 0x00, 0x05, 0x00, 0x00, 0x00]), Some(5));
+        assert_eq!(process(&mut packet_handler, &[0x02, 0x00, 0x07, 0x00]), Some(7));
+    }
+
+    #[test]
+    fn replaced_handler() {
+        let mut packet_handler = packet_handler();
+        packet_handler.replace(|packet: OldLayoutPacket| u32::from(packet.value) * 2);
+
+        assert_eq!(process(&mut packet_handler, &[0x01, 0x00, 0x09]), Some(18));
+    }
+
+    #[test]
+    fn converted_layout() {
+        let mut packet_handler = packet_handler();
+        packet_handler.register_converted::<OldLayoutPacket, NewPacket>().unwrap();
+
+        assert_eq!(process(&mut packet_handler, &[0x01, 0x00, 0x09]), Some(9));
+    }
+}
@@ -2,6 +2,7 @@
 #![cfg_attr(feature = "interface", feature(impl_trait_in_assoc_type))]
 
 pub mod handler;
+pub mod packet_versions;
 mod position;
 
 use std::net::Ipv4Addr;
@@ -21,6 +22,7 @@ extern crate self as ragnarok_packets;
 
 /// The header of a Ragnarok Online packet. It is always two bytes long.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PacketHeader(pub u16);
 
 /// Base trait that all packets implement.
@@ -100,64 +102,106 @@ pub trait CharacterServerPacket: Packet {}
 /// Marker trait for map server packets.
 pub trait MapServerPacket: Packet {}
 
+/// Serialize arrays that are longer than the ones supported by [`serde`].
+#[cfg(feature = "serde")]
+fn serialize_array<T, S, const SIZE: usize>(array: &[T; SIZE], serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: serde::Serialize,
+    S: serde::Serializer,
+{
+    serializer.collect_seq(array)
+}
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ClientTick(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AccountId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CharacterId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PartyId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildId(pub u32);
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EntityId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SkillId(pub u16);
 
+/// Id of a status effect, such as Blessing or Stun. Also used to look up the
+/// icon of the status effect.
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StatusEffectId(pub u16);
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SkillLevel(pub u16);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HotbarTab(pub u16);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HotbarSlot(pub u16);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ShopId(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Price(pub u32);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MailId(pub u64);
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AttackRange(pub u16);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ServerAddress(pub [u8; 4]);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserId(pub [u8; 24]);
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AuthToken(pub [u8; 17]);
 
 impl From<ServerAddress> for Ipv4Addr {
@@ -168,6 +212,7 @@ impl From<ServerAddress> for Ipv4Addr {
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TilePosition {
     pub x: u16,
     pub y: u16,
@@ -175,6 +220,7 @@ pub struct TilePosition {
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LargeTilePosition {
     pub x: u32,
     pub y: u32,
@@ -182,6 +228,7 @@ pub struct LargeTilePosition {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ColorBGRA {
     pub blue: u8,
     pub green: u8,
@@ -191,6 +238,7 @@ pub struct ColorBGRA {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ColorRGBA {
     pub red: u8,
     pub green: u8,
@@ -199,28 +247,52 @@ pub struct ColorRGBA {
 }
 
 /// Item index is always actual index + 2.
+///
+/// The item lists are shared with the storage, which uses an offset of 1
+/// instead, so the conversion has to wrap.
 #[derive(Clone, Copy, Debug, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct InventoryIndex(pub u16);
 
 impl FromBytes for InventoryIndex {
     fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        u16::from_bytes(byte_reader).map(|raw| Self(raw - 2))
+        u16::from_bytes(byte_reader).map(|raw| Self(raw.wrapping_sub(2)))
     }
 }
 
 impl ToBytes for InventoryIndex {
     fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
-        u16::to_bytes(&(self.0 + 2), byte_writer)
+        u16::to_bytes(&self.0.wrapping_add(2), byte_writer)
+    }
+}
+
+/// Storage index is always actual index + 1.
+#[derive(Clone, Copy, Debug, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StorageIndex(pub u16);
+
+impl FromBytes for StorageIndex {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        u16::from_bytes(byte_reader).map(|raw| Self(raw.wrapping_sub(1)))
+    }
+}
+
+impl ToBytes for StorageIndex {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        u16::to_bytes(&self.0.wrapping_add(1), byte_writer)
     }
 }
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ItemId(pub u32);
 
 #[derive(Copy, Debug, Clone, ByteConvertable, FixedByteSize, PartialEq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Sex {
     Female,
     Male,
@@ -233,6 +305,7 @@ pub enum Sex {
 /// entered email and password.
 #[derive(Debug, Clone, Packet, ClientPacket, LoginServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0064)]
 pub struct LoginServerLoginPacket {
     /// Unused
@@ -247,11 +320,50 @@ pub struct LoginServerLoginPacket {
     pub client_type: u8,
 }
 
+/// Sent by the client to request the salt that is used to hash the password
+/// for [LoginServerHashedLoginPacket].
+#[derive(Debug, Clone, Default, Packet, ClientPacket, LoginServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01DB)]
+pub struct RequestPasswordSaltPacket {}
+
+/// Sent by the login server as a response to [RequestPasswordSaltPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, LoginServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01DC)]
+#[variable_length]
+pub struct PasswordSaltPacket {
+    #[repeating_remaining]
+    pub salt: Vec<u8>,
+}
+
+/// Alternative to [LoginServerLoginPacket] that sends the MD5 hash of the
+/// password combined with the salt from [PasswordSaltPacket] instead of the
+/// password itself.
+#[derive(Debug, Clone, Packet, ClientPacket, LoginServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01DD)]
+pub struct LoginServerHashedLoginPacket {
+    /// Unused
+    #[new_default]
+    pub version: [u8; 4],
+    #[length(24)]
+    pub name: String,
+    pub password_hash: [u8; 16],
+    /// Unused
+    #[new_default]
+    pub client_type: u8,
+}
+
 /// Sent by the login server as a response to [LoginServerLoginPacket]
 /// succeeding. After receiving this packet, the client will connect to one of
 /// the character servers provided by this packet.
 #[derive(Debug, Clone, Packet, ServerPacket, LoginServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0AC4)]
 #[variable_length]
 pub struct LoginServerLoginSuccessPacket {
@@ -278,6 +390,7 @@ pub struct LoginServerLoginSuccessPacket {
 /// character slots.
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x082D)]
 pub struct CharacterServerLoginSuccessPacket {
     /// Always 29 on rAthena
@@ -293,6 +406,7 @@ pub struct CharacterServerLoginSuccessPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x006B)]
 #[variable_length]
 pub struct CharacterListPacket {
@@ -307,6 +421,7 @@ pub struct CharacterListPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09A0)]
 pub struct CharacterSlotPagePacket {
     pub page_quantity: u32,
@@ -314,6 +429,7 @@ pub struct CharacterSlotPagePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x020D)]
 #[variable_length]
 pub struct CharacterBanListPacket {
@@ -323,6 +439,7 @@ pub struct CharacterBanListPacket {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CharacterBanInformation {
     pub character_id: CharacterId,
     #[length(20)]
@@ -331,6 +448,7 @@ pub struct CharacterBanInformation {
 
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x08B9)]
 pub struct LoginPincodePacket {
     pub pincode_seed: u32,
@@ -340,6 +458,7 @@ pub struct LoginPincodePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B18)]
 pub struct Packet0b18 {
     /// Possibly inventory related
@@ -350,6 +469,7 @@ pub struct Packet0b18 {
 /// Sent by the map server as a response to [MapServerLoginPacket] succeeding.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x02EB)]
 pub struct MapServerLoginSuccessPacket {
     pub client_tick: ClientTick,
@@ -362,6 +482,7 @@ pub struct MapServerLoginSuccessPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum LoginFailedReason {
     #[numeric_value(1)]
     ServerClosed,
@@ -373,6 +494,7 @@ pub enum LoginFailedReason {
 
 #[derive(Debug, Clone, Packet, ServerPacket, LoginServer, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0081)]
 pub struct LoginFailedPacket {
     pub reason: LoginFailedReason,
@@ -380,6 +502,7 @@ pub struct LoginFailedPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0840)]
 #[variable_length]
 pub struct MapServerUnavailablePacket {
@@ -389,6 +512,7 @@ pub struct MapServerUnavailablePacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum LoginFailedReason2 {
     UnregisteredId,
     IncorrectPassword,
@@ -403,6 +527,7 @@ pub enum LoginFailedReason2 {
 
 #[derive(Debug, Clone, Packet, ServerPacket, LoginServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x083E)]
 pub struct LoginFailedPacket2 {
     pub reason: LoginFailedReason2,
@@ -410,6 +535,7 @@ pub struct LoginFailedPacket2 {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CharacterSelectionFailedReason {
     RejectedFromServer,
 }
@@ -418,6 +544,7 @@ pub enum CharacterSelectionFailedReason {
 /// failing. Provides a reason for the character selection failing.
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x006C)]
 pub struct CharacterSelectionFailedPacket {
     pub reason: CharacterSelectionFailedReason,
@@ -428,6 +555,7 @@ pub struct CharacterSelectionFailedPacket {
 /// selected character.
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0AC5)]
 pub struct CharacterSelectionSuccessPacket {
     pub character_id: CharacterId,
@@ -437,11 +565,13 @@ pub struct CharacterSelectionSuccessPacket {
     pub map_server_port: u16,
     // NOTE: Could be `new_default` but Rust doesn't implement `[u8; 128]: Default`.
     #[new_value([0; 128])]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_array"))]
     pub unknown: [u8; 128],
 }
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CharacterCreationFailedReason {
     CharacterNameAlreadyUsed,
     NotOldEnough,
@@ -455,6 +585,7 @@ pub enum CharacterCreationFailedReason {
 /// failing. Provides a reason for the character creation failing.
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x006E)]
 pub struct CharacterCreationFailedPacket {
     pub reason: CharacterCreationFailedReason,
@@ -464,6 +595,7 @@ pub struct CharacterCreationFailedPacket {
 /// connection alive.
 #[derive(Debug, Clone, Packet, ClientPacket, LoginServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0200)]
 #[ping]
 pub struct LoginServerKeepalivePacket {
@@ -473,6 +605,7 @@ pub struct LoginServerKeepalivePacket {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CharacterServerInformation {
     pub server_ip: ServerAddress,
     pub server_port: u16,
@@ -482,6 +615,7 @@ pub struct CharacterServerInformation {
     pub server_type: u16, // ServerType
     pub display_new: u16, // bool16 ?
     #[new_value([0; 128])]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_array"))]
     pub unknown: [u8; 128],
 }
 
@@ -490,6 +624,7 @@ pub struct CharacterServerInformation {
 /// Attempts to log into the character server using the provided information.
 #[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0065)]
 pub struct CharacterServerLoginPacket {
     pub account_id: AccountId,
@@ -505,6 +640,7 @@ pub struct CharacterServerLoginPacket {
 /// information.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0436)]
 pub struct MapServerLoginPacket {
     pub account_id: AccountId,
@@ -518,6 +654,7 @@ pub struct MapServerLoginPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0283)]
 pub struct Packet8302 {
     pub entity_id: EntityId,
@@ -529,6 +666,7 @@ pub struct Packet8302 {
 /// information.
 #[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0A39)]
 pub struct CreateCharacterPacket {
     #[length(24)]
@@ -544,6 +682,7 @@ pub struct CreateCharacterPacket {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CharacterInformation {
     pub character_id: CharacterId,
     pub experience: i64,
@@ -567,10 +706,10 @@ pub struct CharacterInformation {
     pub weapon: i16,
     pub base_level: i16,
     pub sp_point: i16,
-    pub accessory: i16,
+    pub head_bottom: i16,
     pub shield: i16,
-    pub accessory2: i16,
-    pub accessory3: i16,
+    pub head_top: i16,
+    pub head_middle: i16,
     pub head_palette: i16,
     pub body_palette: i16,
     #[length(24)]
@@ -587,7 +726,8 @@ pub struct CharacterInformation {
     #[length(16)]
     pub map_name: String,
     pub deletion_reverse_date: i32,
-    pub robe_palette: i32,
+    /// View id of the garment.
+    pub robe: i32,
     pub character_slot_change_count: i32,
     pub character_name_change_count: i32,
     pub sex: Sex,
@@ -608,6 +748,7 @@ impl rust_state::VecItem for CharacterInformation {
 /// character.
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B6F)]
 pub struct CreateCharacterSuccessPacket {
     pub character_information: CharacterInformation,
@@ -617,6 +758,7 @@ pub struct CreateCharacterSuccessPacket {
 /// Requests a list of every character associated with the account.
 #[derive(Debug, Clone, Default, Packet, ClientPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09A1)]
 pub struct RequestCharacterListPacket {}
 
@@ -624,6 +766,7 @@ pub struct RequestCharacterListPacket {}
 /// succeeding. Provides the requested list of character information.
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B72)]
 #[variable_length]
 pub struct RequestCharacterListSuccessPacket {
@@ -634,6 +777,7 @@ pub struct RequestCharacterListSuccessPacket {
 /// Sent by the map server to the client.
 #[derive(Debug, Clone, Default, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B1D)]
 #[ping]
 pub struct MapServerPingPacket {}
@@ -642,6 +786,7 @@ pub struct MapServerPingPacket {}
 /// Attempts to path the player towards the provided position.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0881)]
 pub struct RequestPlayerMovePacket {
     pub position: WorldPosition,
@@ -652,6 +797,7 @@ pub struct RequestPlayerMovePacket {
 /// the provided information.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0140)]
 pub struct RequestWarpToMapPacket {
     #[length(16)]
@@ -665,6 +811,7 @@ pub struct RequestWarpToMapPacket {
 /// timestamp of when it started (for synchronization).
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0086)]
 pub struct EntityMovePacket {
     pub entity_id: EntityId,
@@ -675,6 +822,7 @@ pub struct EntityMovePacket {
 // TODO: Handle this to improve the combat system.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0088)]
 pub struct EntityStopMovePacket {
     pub entity_id: EntityId,
@@ -687,6 +835,7 @@ pub struct EntityStopMovePacket {
 /// timestamp of when it started (for synchronization).
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0087)]
 pub struct PlayerMovePacket {
     pub starting_timestamp: ClientTick,
@@ -699,6 +848,7 @@ pub struct PlayerMovePacket {
 /// information.
 #[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x01FB)]
 pub struct DeleteCharacterPacket {
     pub character_id: CharacterId,
@@ -713,6 +863,7 @@ pub struct DeleteCharacterPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CharacterDeletionFailedReason {
     NotAllowed,
     CharacterNotFound,
@@ -723,6 +874,7 @@ pub enum CharacterDeletionFailedReason {
 /// failing. Provides a reason for the character deletion failing.
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0070)]
 pub struct CharacterDeletionFailedPacket {
     pub reason: CharacterDeletionFailedReason,
@@ -732,6 +884,7 @@ pub struct CharacterDeletionFailedPacket {
 /// succeeding.
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x006F)]
 pub struct CharacterDeletionSuccessPacket {}
 
@@ -739,6 +892,7 @@ pub struct CharacterDeletionSuccessPacket {}
 /// character. Attempts to select the character in the specified slot.
 #[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0066)]
 pub struct SelectCharacterPacket {
     pub selected_slot: u8,
@@ -748,6 +902,7 @@ pub struct SelectCharacterPacket {
 /// the server. Provides the message to be displayed in the chat window.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x008E)]
 #[variable_length]
 pub struct ServerMessagePacket {
@@ -757,6 +912,7 @@ pub struct ServerMessagePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0291)]
 pub struct MessageTablePacket {
     pub message_id: u16,
@@ -767,6 +923,7 @@ pub struct MessageTablePacket {
 /// display name.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0368)]
 pub struct RequestDetailsPacket {
     pub entity_id: EntityId,
@@ -776,6 +933,7 @@ pub struct RequestDetailsPacket {
 /// [RequestDetailsPacket]. Provides additional information about the player.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0A30)]
 pub struct RequestPlayerDetailsSuccessPacket {
     pub character_id: CharacterId,
@@ -794,6 +952,7 @@ pub struct RequestPlayerDetailsSuccessPacket {
 /// [RequestDetailsPacket]. Provides additional information about the entity.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0ADF)]
 pub struct RequestEntityDetailsSuccessPacket {
     pub entity_id: EntityId,
@@ -804,8 +963,11 @@ pub struct RequestEntityDetailsSuccessPacket {
     pub title: String,
 }
 
+/// Sent by the map server to the client when the player logs in or receives a
+/// new mail.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09E7)]
 pub struct NewMailStatusPacket {
     pub new_available: u8,
@@ -813,6 +975,7 @@ pub struct NewMailStatusPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AchievementData {
     pub acheivement_id: u32,
     pub is_completed: u8,
@@ -823,6 +986,7 @@ pub struct AchievementData {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0A24)]
 pub struct AchievementUpdatePacket {
     pub total_score: u32,
@@ -834,6 +998,7 @@ pub struct AchievementUpdatePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0A23)]
 #[variable_length]
 pub struct AchievementListPacket {
@@ -849,6 +1014,7 @@ pub struct AchievementListPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0ADE)]
 pub struct CriticalWeightUpdatePacket {
     pub weight: u32,
@@ -856,6 +1022,7 @@ pub struct CriticalWeightUpdatePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x01D7)]
 pub struct SpriteChangePacket {
     pub account_id: AccountId,
@@ -866,6 +1033,7 @@ pub struct SpriteChangePacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SpriteChangeType {
     Base,
     Hair,
@@ -883,26 +1051,40 @@ pub enum SpriteChangeType {
     Body2,
 }
 
+/// The item container that an item list belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum InventoryType {
+    Inventory,
+    Cart,
+    Storage,
+    GuildStorage,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B08)]
 #[variable_length]
 pub struct InventoyStartPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[length_remaining]
     pub inventory_name: String,
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B0B)]
 pub struct InventoyEndPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     pub flag: u8, // maybe char ?
 }
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ItemOptions {
     pub index: u16,
     pub value: u16,
@@ -912,6 +1094,7 @@ pub struct ItemOptions {
 bitflags::bitflags! {
     #[derive(Debug, Clone)]
     #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
     pub struct RegularItemFlags: u8 {
         const IDENTIFIED = 0b01;
         const IN_ETC_TAB = 0b10;
@@ -938,6 +1121,7 @@ impl ToBytes for RegularItemFlags {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct RegularItemInformation {
     pub index: InventoryIndex,
     pub item_id: ItemId,
@@ -951,10 +1135,11 @@ pub struct RegularItemInformation {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B09)]
 #[variable_length]
 pub struct RegularItemListPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[repeating_remaining]
     pub item_information: Vec<RegularItemInformation>,
 }
@@ -962,6 +1147,7 @@ pub struct RegularItemListPacket {
 bitflags::bitflags! {
     #[derive(Debug, Clone)]
     #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
     pub struct EquippableItemFlags: u8 {
         const IDENTIFIED = 0b001;
         const IS_BROKEN = 0b010;
@@ -989,6 +1175,7 @@ impl ToBytes for EquippableItemFlags {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EquippableItemInformation {
     pub index: InventoryIndex,
     pub item_id: ItemId,
@@ -1008,16 +1195,18 @@ pub struct EquippableItemInformation {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B39)]
 #[variable_length]
 pub struct EquippableItemListPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[repeating_remaining]
     pub item_information: Vec<EquippableItemInformation>,
 }
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EquippableSwitchItemInformation {
     pub index: InventoryIndex,
     pub position: u32,
@@ -1025,6 +1214,7 @@ pub struct EquippableSwitchItemInformation {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0A9B)]
 #[variable_length]
 pub struct EquippableSwitchItemListPacket {
@@ -1034,6 +1224,7 @@ pub struct EquippableSwitchItemListPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x099B)]
 pub struct MapTypePacket {
     pub map_type: u16,
@@ -1045,6 +1236,7 @@ pub struct MapTypePacket {
 /// information on how the message should be displayed.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x01C3)]
 #[variable_length]
 pub struct Broadcast2MessagePacket {
@@ -1061,6 +1253,7 @@ pub struct Broadcast2MessagePacket {
 /// command. Provides the message to be displayed in the chat window.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x009A)]
 #[variable_length]
 pub struct BroadcastMessagePacket {
@@ -1073,6 +1266,7 @@ pub struct BroadcastMessagePacket {
 /// window and the speach bubble.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x008D)]
 #[variable_length]
 pub struct OverheadMessagePacket {
@@ -1086,6 +1280,7 @@ pub struct OverheadMessagePacket {
 /// color of the message, and the ID of the entity it originated from.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x02C1)]
 #[variable_length]
 pub struct EntityMessagePacket {
@@ -1097,6 +1292,7 @@ pub struct EntityMessagePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00C0)]
 pub struct DisplayEmotionPacket {
     pub entity_id: EntityId,
@@ -1108,6 +1304,7 @@ pub struct DisplayEmotionPacket {
 /// All UpdateStatPackets do the same, they just have different sizes
 /// correlating to the space the updated value requires.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StatType {
     Weight(u32),
     MaximumWeight(u32),
@@ -1344,6 +1541,7 @@ impl std::fmt::Display for StatType {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B0)]
 pub struct UpdateStatPacket {
     #[length(6)]
@@ -1352,16 +1550,18 @@ pub struct UpdateStatPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0196)]
 pub struct StatusChangeSequencePacket {
-    pub index: u16,
-    pub id: u32,
+    pub status_effect_id: StatusEffectId,
+    pub entity_id: EntityId,
     pub state: u8,
 }
 
 /// Sent by the character server to the client when loading onto a new map.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00BD)]
 pub struct InitialStatsPacket {
     pub stat_points: u16,
@@ -1397,6 +1597,7 @@ pub struct InitialStatsPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0141)]
 pub struct UpdateStatPacket1 {
     #[length(12)]
@@ -1405,6 +1606,7 @@ pub struct UpdateStatPacket1 {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0ACB)]
 pub struct UpdateStatPacket2 {
     #[length(10)]
@@ -1413,6 +1615,7 @@ pub struct UpdateStatPacket2 {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00BE)]
 pub struct UpdateStatPacket3 {
     #[length(3)]
@@ -1421,6 +1624,7 @@ pub struct UpdateStatPacket3 {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x013A)]
 pub struct UpdateAttackRangePacket {
     pub attack_range: AttackRange,
@@ -1428,6 +1632,7 @@ pub struct UpdateAttackRangePacket {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StatUpType {
     Strength { amount: u8 },
     Agility { amount: u8 },
@@ -1476,6 +1681,7 @@ impl ToBytes for StatUpType {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00BB)]
 pub struct RequestStatUpPacket {
     pub stat_type: StatUpType,
@@ -1485,6 +1691,7 @@ pub struct RequestStatUpPacket {
 /// even if the request fails.
 #[derive(Debug, Clone, ByteConvertable, PartialEq, Eq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RequestStatUpResult {
     Failure,
     Success,
@@ -1492,6 +1699,7 @@ pub enum RequestStatUpResult {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00BC)]
 pub struct RequestStatUpResponsePacket {
     pub staus_type: u16,
@@ -1501,6 +1709,7 @@ pub struct RequestStatUpResponsePacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x08D4)]
 pub struct SwitchCharacterSlotPacket {
     // TODO: Type this more strongly.
@@ -1515,6 +1724,7 @@ pub struct SwitchCharacterSlotPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Action {
     Attack,
     PickUpItem,
@@ -1529,6 +1739,7 @@ pub enum Action {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0437)]
 pub struct RequestActionPacket {
     pub npc_id: EntityId,
@@ -1537,6 +1748,7 @@ pub struct RequestActionPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00F3)]
 #[variable_length]
 pub struct GlobalMessagePacket {
@@ -1546,6 +1758,7 @@ pub struct GlobalMessagePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0139)]
 pub struct RequestPlayerAttackFailedPacket {
     pub target_entity_id: EntityId,
@@ -1556,6 +1769,7 @@ pub struct RequestPlayerAttackFailedPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0977)]
 pub struct UpdateEntityHealthPointsPacket {
     pub entity_id: EntityId,
@@ -1565,6 +1779,7 @@ pub struct UpdateEntityHealthPointsPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum DamageType {
     Damage,
     PickUpItem,
@@ -1584,6 +1799,7 @@ pub enum DamageType {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x008A)]
 pub struct DamagePacket1 {
     pub source_entity_id: EntityId,
@@ -1601,6 +1817,7 @@ pub struct DamagePacket1 {
 // FIX: This one is the attack animation one
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x08C8)]
 pub struct DamagePacket3 {
     pub source_entity_id: EntityId,
@@ -1618,6 +1835,7 @@ pub struct DamagePacket3 {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x007F)]
 #[ping]
 pub struct ServerTickPacket {
@@ -1626,6 +1844,7 @@ pub struct ServerTickPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0360)]
 #[ping]
 pub struct RequestServerTickPacket {
@@ -1634,6 +1853,7 @@ pub struct RequestServerTickPacket {
 
 #[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum SwitchCharacterSlotResponseStatus {
     Success,
@@ -1642,6 +1862,7 @@ pub enum SwitchCharacterSlotResponseStatus {
 
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B70)]
 pub struct SwitchCharacterSlotResponsePacket {
     #[new_default]
@@ -1652,6 +1873,7 @@ pub struct SwitchCharacterSlotResponsePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0091)]
 pub struct ChangeMapPacket {
     #[length(16)]
@@ -1661,6 +1883,7 @@ pub struct ChangeMapPacket {
 
 #[derive(Debug, Clone, ByteConvertable, PartialEq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum DisappearanceReason {
     OutOfSight,
     Died,
@@ -1671,6 +1894,7 @@ pub enum DisappearanceReason {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0080)]
 pub struct EntityDisappearedPacket {
     pub entity_id: EntityId,
@@ -1679,6 +1903,7 @@ pub struct EntityDisappearedPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09FD)]
 #[variable_length]
 pub struct MovingEntityAppearedPacket {
@@ -1693,10 +1918,10 @@ pub struct MovingEntityAppearedPacket {
     pub head: u16,
     pub weapon: u32,
     pub shield: u32,
-    pub accessory: u16,
+    pub head_bottom: u16,
     pub move_start_time: u32,
-    pub accessory2: u16,
-    pub accessory3: u16,
+    pub head_top: u16,
+    pub head_middle: u16,
     pub head_palette: u16,
     pub body_palette: u16,
     pub head_direction: u16,
@@ -1722,6 +1947,7 @@ pub struct MovingEntityAppearedPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0148)]
 pub struct ResurrectionPacket {
     pub entity_id: EntityId,
@@ -1732,6 +1958,7 @@ pub struct ResurrectionPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09FE)]
 #[variable_length]
 pub struct EntityAppearedPacket {
@@ -1746,9 +1973,9 @@ pub struct EntityAppearedPacket {
     pub head: u16,
     pub weapon: u32,
     pub shield: u32,
-    pub accessory: u16,
-    pub accessory2: u16,
-    pub accessory3: u16,
+    pub head_bottom: u16,
+    pub head_top: u16,
+    pub head_middle: u16,
     pub head_palette: u16,
     pub body_palette: u16,
     pub head_direction: u16,
@@ -1774,6 +2001,7 @@ pub struct EntityAppearedPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09FF)]
 #[variable_length]
 pub struct EntityAppeared2Packet {
@@ -1788,9 +2016,9 @@ pub struct EntityAppeared2Packet {
     pub head: u16,
     pub weapon: u32,
     pub shield: u32,
-    pub accessory: u16,
-    pub accessory2: u16,
-    pub accessory3: u16,
+    pub head_bottom: u16,
+    pub head_top: u16,
+    pub head_middle: u16,
     pub head_palette: u16,
     pub body_palette: u16,
     pub head_direction: u16,
@@ -1817,6 +2045,7 @@ pub struct EntityAppeared2Packet {
 
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u32)]
 pub enum SkillType {
     #[numeric_value(0)]
@@ -1835,6 +2064,7 @@ pub enum SkillType {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SkillInformation {
     pub skill_id: SkillId,
     pub skill_type: SkillType,
@@ -1848,6 +2078,7 @@ pub struct SkillInformation {
 
 #[derive(Debug, Clone, Packet, ServerPacket)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x010F)]
 #[variable_length]
 pub struct UpdateSkillTreePacket {
@@ -1857,6 +2088,7 @@ pub struct UpdateSkillTreePacket {
 
 #[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HotkeyData {
     pub is_skill: u8,
     pub skill_id: u32,
@@ -1873,15 +2105,18 @@ impl HotkeyData {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B20)]
 pub struct UpdateHotkeysPacket {
     pub rotate: u8,
     pub tab: HotbarTab,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_array"))]
     pub hotkeys: [HotkeyData; 38],
 }
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x02BA)]
 pub struct SetHotkeyData1Packet {
     pub slot: HotbarSlot,
@@ -1890,6 +2125,7 @@ pub struct SetHotkeyData1Packet {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B21)]
 pub struct SetHotkeyData2Packet {
     pub tab: HotbarTab,
@@ -1899,6 +2135,7 @@ pub struct SetHotkeyData2Packet {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x02C9)]
 pub struct UpdatePartyInvitationStatePacket {
     pub allowed: u8, // always 0 on rAthena
@@ -1906,6 +2143,7 @@ pub struct UpdatePartyInvitationStatePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x02DA)]
 pub struct UpdateShowEquipPacket {
     pub open_equip_window: u8,
@@ -1913,6 +2151,7 @@ pub struct UpdateShowEquipPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x02D9)]
 pub struct UpdateConfigurationPacket {
     pub config_type: u32,
@@ -1921,6 +2160,7 @@ pub struct UpdateConfigurationPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x08E2)]
 pub struct NavigateToMonsterPacket {
     pub target_type: u8, // 3 - entity; 0 - coordinates; 1 - coordinates but fails if you're alweady on the map
@@ -1934,6 +2174,7 @@ pub struct NavigateToMonsterPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u32)]
 pub enum MarkerType {
     DisplayFor15Seconds,
@@ -1943,6 +2184,7 @@ pub enum MarkerType {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0144)]
 pub struct MarkMinimapPositionPacket {
     pub npc_id: EntityId,
@@ -1954,6 +2196,7 @@ pub struct MarkMinimapPositionPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B5)]
 pub struct NextButtonPacket {
     pub npc_id: EntityId,
@@ -1961,6 +2204,7 @@ pub struct NextButtonPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B6)]
 pub struct CloseButtonPacket {
     pub npc_id: EntityId,
@@ -1968,6 +2212,7 @@ pub struct CloseButtonPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B7)]
 #[variable_length]
 pub struct DialogMenuPacket {
@@ -1978,6 +2223,7 @@ pub struct DialogMenuPacket {
 
 #[derive(Debug, Clone, Copy, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u32)]
 pub enum EffectId {
     Hit1,
@@ -3124,6 +3370,7 @@ pub enum EffectId {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x01F3)]
 pub struct DisplaySpecialEffectPacket {
     pub entity_id: EntityId,
@@ -3132,6 +3379,7 @@ pub struct DisplaySpecialEffectPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x043D)]
 pub struct DisplaySkillCooldownPacket {
     pub skill_id: SkillId,
@@ -3140,6 +3388,7 @@ pub struct DisplaySkillCooldownPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x01DE)]
 pub struct DisplaySkillEffectAndDamagePacket {
     pub skill_id: SkillId,
@@ -3156,6 +3405,7 @@ pub struct DisplaySkillEffectAndDamagePacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum HealType {
     #[numeric_value(5)]
@@ -3166,6 +3416,7 @@ pub enum HealType {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0A27)]
 pub struct DisplayPlayerHealEffect {
     pub heal_type: HealType,
@@ -3174,6 +3425,7 @@ pub struct DisplayPlayerHealEffect {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09CB)]
 pub struct DisplaySkillEffectNoDamagePacket {
     pub skill_id: SkillId,
@@ -3185,9 +3437,10 @@ pub struct DisplaySkillEffectNoDamagePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0983)]
 pub struct StatusChangePacket {
-    pub index: u16,
+    pub status_effect_id: StatusEffectId,
     pub entity_id: EntityId,
     pub state: u8,
     pub duration_in_milliseconds: u32,
@@ -3195,8 +3448,23 @@ pub struct StatusChangePacket {
     pub value: [u32; 3],
 }
 
+/// Sent by the map server for every status effect that an entity already has
+/// when it comes into view.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0984)]
+pub struct EntityStatusChangePacket {
+    pub entity_id: EntityId,
+    pub status_effect_id: StatusEffectId,
+    pub duration_in_milliseconds: u32,
+    pub remaining_in_milliseconds: u32,
+    pub value: [u32; 3],
+}
+
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ObjectiveDetails1 {
     pub hunt_identification: u32,
     pub objective_type: u32,
@@ -3210,6 +3478,7 @@ pub struct ObjectiveDetails1 {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09F9)]
 pub struct QuestNotificationPacket1 {
     pub quest_id: u32,
@@ -3224,6 +3493,7 @@ pub struct QuestNotificationPacket1 {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HuntingObjective {
     pub quest_id: u32,
     pub mob_id: u32,
@@ -3233,6 +3503,7 @@ pub struct HuntingObjective {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x08FE)]
 #[variable_length]
 pub struct HuntingQuestNotificationPacket {
@@ -3242,6 +3513,7 @@ pub struct HuntingQuestNotificationPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09FA)]
 #[variable_length]
 pub struct HuntingQuestUpdateObjectivePacket {
@@ -3252,6 +3524,7 @@ pub struct HuntingQuestUpdateObjectivePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x02B4)]
 pub struct QuestRemovedPacket {
     pub quest_id: u32,
@@ -3259,6 +3532,7 @@ pub struct QuestRemovedPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct QuestDetails {
     pub hunt_identification: u32,
     pub objective_type: u32,
@@ -3273,6 +3547,7 @@ pub struct QuestDetails {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Quest {
     pub quest_id: u32,
     pub active: u8,
@@ -3286,6 +3561,7 @@ pub struct Quest {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09F8)]
 #[variable_length]
 pub struct QuestListPacket {
@@ -3297,6 +3573,7 @@ pub struct QuestListPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u32)]
 pub enum VisualEffect {
     BaseLevelUp,
@@ -3313,6 +3590,7 @@ pub enum VisualEffect {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x019B)]
 pub struct VisualEffectPacket {
     pub entity_id: EntityId,
@@ -3321,6 +3599,7 @@ pub struct VisualEffectPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum ExperienceType {
     #[numeric_value(1)]
@@ -3330,6 +3609,7 @@ pub enum ExperienceType {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum ExperienceSource {
     Regular,
@@ -3338,6 +3618,7 @@ pub enum ExperienceSource {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0ACC)]
 pub struct DisplayGainedExperiencePacket {
     pub account_id: AccountId,
@@ -3348,6 +3629,7 @@ pub struct DisplayGainedExperiencePacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ImageLocation {
     BottomLeft,
     BottomMiddle,
@@ -3360,6 +3642,7 @@ pub enum ImageLocation {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x01B3)]
 pub struct DisplayImagePacket {
     #[length(64)]
@@ -3369,6 +3652,7 @@ pub struct DisplayImagePacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0229)]
 pub struct StateChangePacket {
     pub entity_id: EntityId,
@@ -3380,6 +3664,7 @@ pub struct StateChangePacket {
 
 #[derive(Debug, Clone, ByteConvertable, PartialEq, Eq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ItemPickupResult {
     Success,
     Invalid,
@@ -3393,6 +3678,7 @@ pub enum ItemPickupResult {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B41)]
 pub struct ItemPickupPacket {
     pub index: InventoryIndex,
@@ -3415,6 +3701,7 @@ pub struct ItemPickupPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum RemoveItemReason {
     Normal,
@@ -3429,6 +3716,7 @@ pub enum RemoveItemReason {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x07FA)]
 pub struct RemoveItemFromInventoryPacket {
     pub remove_reason: RemoveItemReason,
@@ -3439,6 +3727,7 @@ pub struct RemoveItemFromInventoryPacket {
 // TODO: improve names
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum QuestEffect {
     Quest,
@@ -3458,6 +3747,7 @@ pub enum QuestEffect {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum QuestColor {
     Yellow,
@@ -3468,6 +3758,7 @@ pub enum QuestColor {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0446)]
 pub struct QuestEffectPacket {
     pub entity_id: EntityId,
@@ -3478,6 +3769,7 @@ pub struct QuestEffectPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B4)]
 #[variable_length]
 pub struct NpcDialogPacket {
@@ -3488,11 +3780,13 @@ pub struct NpcDialogPacket {
 
 #[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x007D)]
 pub struct MapLoadedPacket {}
 
 #[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0187)]
 #[ping]
 pub struct CharacterServerKeepalivePacket {
@@ -3503,6 +3797,7 @@ pub struct CharacterServerKeepalivePacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0090)]
 pub struct StartDialogPacket {
     pub npc_id: EntityId,
@@ -3512,6 +3807,7 @@ pub struct StartDialogPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B9)]
 pub struct NextDialogPacket {
     pub npc_id: EntityId,
@@ -3519,6 +3815,7 @@ pub struct NextDialogPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0146)]
 pub struct CloseDialogPacket {
     pub npc_id: EntityId,
@@ -3526,6 +3823,7 @@ pub struct CloseDialogPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B8)]
 pub struct ChooseDialogOptionPacket {
     pub npc_id: EntityId,
@@ -3535,6 +3833,7 @@ pub struct ChooseDialogOptionPacket {
 bitflags::bitflags! {
     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
     pub struct EquipPosition: u32 {
         const NONE = 0;
         const HEAD_LOWER = 1;
@@ -3584,6 +3883,7 @@ impl ToBytes for EquipPosition {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0998)]
 pub struct RequestEquipItemPacket {
     pub inventory_index: InventoryIndex,
@@ -3592,6 +3892,7 @@ pub struct RequestEquipItemPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RequestEquipItemStatus {
     Success,
     Failed,
@@ -3600,6 +3901,7 @@ pub enum RequestEquipItemStatus {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0999)]
 pub struct RequestEquipItemStatusPacket {
     pub inventory_index: InventoryIndex,
@@ -3610,6 +3912,7 @@ pub struct RequestEquipItemStatusPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x013C)]
 pub struct EquipAmmunitionPacket {
     pub inventory_index: InventoryIndex,
@@ -3617,6 +3920,7 @@ pub struct EquipAmmunitionPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AmmunitionActionType {
     EquipProperAmmunitionFirst,
     WeightLimitExceeded1,
@@ -3626,6 +3930,7 @@ pub enum AmmunitionActionType {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x013B)]
 pub struct AmmunitionActionPacket {
     pub action_type: AmmunitionActionType,
@@ -3633,6 +3938,7 @@ pub struct AmmunitionActionPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00AB)]
 pub struct RequestUnequipItemPacket {
     pub inventory_index: InventoryIndex,
@@ -3640,6 +3946,7 @@ pub struct RequestUnequipItemPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RequestUnequipItemStatus {
     Success,
     Failed,
@@ -3647,6 +3954,7 @@ pub enum RequestUnequipItemStatus {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x099A)]
 pub struct RequestUnequipItemStatusPacket {
     pub inventory_index: InventoryIndex,
@@ -3656,6 +3964,7 @@ pub struct RequestUnequipItemStatusPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RestartType {
     Respawn,
     Disconnect,
@@ -3663,6 +3972,7 @@ pub enum RestartType {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B1)]
 pub struct ParameterChangePacket {
     pub variable_id: u16,
@@ -3671,6 +3981,7 @@ pub struct ParameterChangePacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B2)]
 pub struct RestartPacket {
     pub restart_type: RestartType,
@@ -3680,6 +3991,7 @@ pub struct RestartPacket {
 // should be implemented manually
 #[derive(Debug, Clone, ByteConvertable, PartialEq, Eq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RestartResponseStatus {
     Nothing,
     Ok,
@@ -3687,6 +3999,7 @@ pub enum RestartResponseStatus {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00B3)]
 pub struct RestartResponsePacket {
     pub result: RestartResponseStatus,
@@ -3696,6 +4009,7 @@ pub struct RestartResponsePacket {
 // should be implemented manually
 #[derive(Debug, Clone, ByteConvertable, PartialEq, Eq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum DisconnectResponseStatus {
     Ok,
@@ -3704,6 +4018,7 @@ pub enum DisconnectResponseStatus {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x018B)]
 pub struct DisconnectResponsePacket {
     pub result: DisconnectResponseStatus,
@@ -3711,6 +4026,7 @@ pub struct DisconnectResponsePacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0438)]
 pub struct UseSkillAtIdPacket {
     pub skill_level: SkillLevel,
@@ -3720,6 +4036,7 @@ pub struct UseSkillAtIdPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0AF4)]
 pub struct UseSkillOnGroundPacket {
     pub skill_level: SkillLevel,
@@ -3731,6 +4048,7 @@ pub struct UseSkillOnGroundPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B10)]
 pub struct StartUseSkillPacket {
     pub skill_id: SkillId,
@@ -3740,6 +4058,7 @@ pub struct StartUseSkillPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B11)]
 pub struct EndUseSkillPacket {
     pub skill_id: SkillId,
@@ -3747,6 +4066,7 @@ pub struct EndUseSkillPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x07FB)]
 pub struct UseSkillSuccessPacket {
     pub source_entity: EntityId,
@@ -3760,6 +4080,32 @@ pub struct UseSkillSuccessPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0B1A)]
+pub struct UseSkillSuccessPacket2 {
+    pub source_entity: EntityId,
+    pub destination_entity: EntityId,
+    pub position: TilePosition,
+    pub skill_id: SkillId,
+    pub element: u32,
+    pub delay_time: u32,
+    pub disposable: u8,
+    pub attack_motion: u32,
+}
+
+/// Sent by the map server when an entity stops casting a skill before the cast
+/// time is over.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01B9)]
+pub struct CastCancelPacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0110)]
 pub struct ToUseSkillSuccessPacket {
     pub skill_id: SkillId,
@@ -3771,6 +4117,7 @@ pub struct ToUseSkillSuccessPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u32)]
 pub enum UnitId {
     #[numeric_value(0x7E)]
@@ -3966,6 +4313,7 @@ pub enum UnitId {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09CA)]
 pub struct NotifySkillUnitPacket {
     pub lenght: u16,
@@ -3980,6 +4328,7 @@ pub struct NotifySkillUnitPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0117)]
 pub struct NotifyGroundSkillPacket {
     pub skill_id: SkillId,
@@ -3991,6 +4340,7 @@ pub struct NotifyGroundSkillPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0120)]
 pub struct SkillUnitDisappearPacket {
     pub entity_id: EntityId,
@@ -3998,6 +4348,7 @@ pub struct SkillUnitDisappearPacket {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Friend {
     pub account_id: AccountId,
     pub character_id: CharacterId,
@@ -4007,6 +4358,7 @@ pub struct Friend {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0202)]
 pub struct AddFriendPacket {
     #[length(24)]
@@ -4015,6 +4367,7 @@ pub struct AddFriendPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0203)]
 pub struct RemoveFriendPacket {
     pub account_id: AccountId,
@@ -4023,6 +4376,7 @@ pub struct RemoveFriendPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x020A)]
 pub struct NotifyFriendRemovedPacket {
     pub account_id: AccountId,
@@ -4031,6 +4385,7 @@ pub struct NotifyFriendRemovedPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0201)]
 #[variable_length]
 pub struct FriendListPacket {
@@ -4040,6 +4395,7 @@ pub struct FriendListPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum OnlineState {
     Online,
     Offline,
@@ -4047,6 +4403,7 @@ pub enum OnlineState {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0206)]
 pub struct FriendOnlineStatusPacket {
     pub account_id: AccountId,
@@ -4058,6 +4415,7 @@ pub struct FriendOnlineStatusPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0207)]
 pub struct FriendRequestPacket {
     pub requestee: Friend,
@@ -4065,6 +4423,7 @@ pub struct FriendRequestPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u32)]
 pub enum FriendRequestResponse {
     Reject,
@@ -4073,6 +4432,7 @@ pub enum FriendRequestResponse {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0208)]
 pub struct FriendRequestResponsePacket {
     pub account_id: AccountId,
@@ -4082,6 +4442,7 @@ pub struct FriendRequestResponsePacket {
 
 #[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum FriendRequestResult {
     Accepted,
@@ -4092,6 +4453,7 @@ pub enum FriendRequestResult {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0209)]
 pub struct FriendRequestResultPacket {
     pub result: FriendRequestResult,
@@ -4100,6 +4462,7 @@ pub struct FriendRequestResultPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x02C6)]
 pub struct PartyInvitePacket {
     pub party_id: PartyId,
@@ -4107,8 +4470,1881 @@ pub struct PartyInvitePacket {
     pub party_name: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PartyItemShare {
+    EachTake,
+    EvenShare,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[numeric_type(u32)]
+pub enum PartyExperienceShare {
+    EachTake,
+    EvenShare,
+}
+
+/// Sent by the client to the map server when the player wants to create a new
+/// party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01E8)]
+pub struct CreatePartyPacket {
+    #[length(24)]
+    pub party_name: String,
+    pub item_pickup_rule: PartyItemShare,
+    pub item_distribution_rule: PartyItemShare,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CreatePartyResult {
+    Success,
+    NameAlreadyExists,
+    AlreadyInParty,
+    NotAllowedOnMap,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`CreatePartyPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00FA)]
+pub struct CreatePartyResultPacket {
+    pub result: CreatePartyResult,
+}
+
+/// Sent by the client to the map server when the player wants to invite
+/// another character into their party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x02C4)]
+pub struct InvitePartyMemberPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[numeric_type(u32)]
+pub enum PartyInviteResult {
+    AlreadyInParty,
+    Rejected,
+    Accepted,
+    PartyFull,
+    SameAccount,
+    InvitationsBlocked,
+    UnknownError,
+    CharacterNotOnline,
+    NotAllowedOnMap,
+    NotAllowedOnTargetMap,
+}
+
+/// Sent by the map server to the client in response to an
+/// [`InvitePartyMemberPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x02C5)]
+pub struct PartyInviteResultPacket {
+    #[length(24)]
+    pub name: String,
+    pub result: PartyInviteResult,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PartyInviteResponse {
+    Reject,
+    Accept,
+}
+
+/// Sent by the client to the map server when the player accepts or rejects a
+/// party invitation.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x02C7)]
+pub struct PartyInviteResponsePacket {
+    pub party_id: PartyId,
+    pub response: PartyInviteResponse,
+}
+
+/// Sent by the client to the map server when the player wants to leave their
+/// party.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0100)]
+pub struct LeavePartyPacket {}
+
+/// Sent by the client to the map server when the party leader wants to remove
+/// a member from the party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0103)]
+pub struct ExpelPartyMemberPacket {
+    pub account_id: AccountId,
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PartyLeaveReason {
+    Left,
+    Expelled,
+    CannotLeave,
+    CannotExpel,
+}
+
+/// Sent by the map server to the client when a character left or was expelled
+/// from the party.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0105)]
+pub struct PartyMemberLeftPacket {
+    pub account_id: AccountId,
+    #[length(24)]
+    pub name: String,
+    pub reason: PartyLeaveReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PartyMemberRole {
+    Leader,
+    Member,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PartyMemberState {
+    Online,
+    Offline,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PartyMemberInformation {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    #[length(24)]
+    pub name: String,
+    #[length(16)]
+    pub map_name: String,
+    pub role: PartyMemberRole,
+    pub state: PartyMemberState,
+    pub job: u16,
+    pub base_level: u16,
+}
+
+/// Sent by the map server to the client when joining a party or logging in
+/// while being in a party. Provides the name of the party and all of its
+/// members.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A44)]
+#[variable_length]
+pub struct PartyInformationPacket {
+    #[length(24)]
+    pub party_name: String,
+    #[repeating_remaining]
+    pub members: Vec<PartyMemberInformation>,
+}
+
+/// Sent by the map server to the client when a character joins the party or
+/// the information about a party member changes.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0AE4)]
+pub struct PartyMemberJoinedPacket {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub role: u32, // 0 for the party leader
+    pub job: u16,
+    pub base_level: u16,
+    pub position: TilePosition,
+    pub state: PartyMemberState,
+    #[length(24)]
+    pub party_name: String,
+    #[length(24)]
+    pub name: String,
+    #[length(16)]
+    pub map_name: String,
+    pub item_pickup_rule: PartyItemShare,
+    pub item_distribution_rule: PartyItemShare,
+}
+
+/// Sent by the map server to the client to update the health of a party
+/// member.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x080E)]
+pub struct UpdatePartyMemberHealthPacket {
+    pub account_id: AccountId,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+}
+
+/// Sent by the map server to the client to update the position of a party
+/// member on the current map.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0107)]
+pub struct UpdatePartyMemberPositionPacket {
+    pub account_id: AccountId,
+    pub position: TilePosition,
+}
+
+/// Sent by the client to the map server when the player sends a message in
+/// the party chat.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0108)]
+#[variable_length]
+pub struct SendPartyMessagePacket {
+    #[length_remaining_off_by_one]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when a party member sent a message in
+/// the party chat.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0109)]
+#[variable_length]
+pub struct PartyMessagePacket {
+    pub account_id: AccountId,
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Sent by the client to the map server when the party leader wants to change
+/// how experience and items are shared.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x07D7)]
+pub struct ChangePartyOptionsPacket {
+    pub experience_share: PartyExperienceShare,
+    pub item_pickup_rule: PartyItemShare,
+    pub item_distribution_rule: PartyItemShare,
+}
+
+/// Sent by the map server to the client when joining a party or after the
+/// share settings of the party changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x07D8)]
+pub struct PartyOptionsPacket {
+    pub experience_share: PartyExperienceShare,
+    pub item_pickup_rule: PartyItemShare,
+    pub item_distribution_rule: PartyItemShare,
+}
+
+/// Sent by the client to the map server when the party leader wants to pass
+/// leadership on to another party member.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x07DA)]
+pub struct ChangePartyLeaderPacket {
+    pub account_id: AccountId,
+}
+
+/// Sent by the map server to the client when the leader of the party changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x07FC)]
+pub struct PartyLeaderChangedPacket {
+    pub old_leader_account_id: AccountId,
+    pub new_leader_account_id: AccountId,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[numeric_type(u32)]
+pub enum GuildInformationType {
+    Basic,
+    Members,
+    Positions,
+}
+
+/// Sent by the client to the map server to request information about the
+/// guild of the player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x014F)]
+pub struct RequestGuildInformationPacket {
+    pub information_type: GuildInformationType,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildInformation {
+    pub guild_id: GuildId,
+    pub level: u32,
+    pub member_count: u32,
+    pub maximum_member_count: u32,
+    pub average_level: u32,
+    pub experience: u32,
+    pub next_level_experience: u32,
+    pub tax_points: u32,
+    pub honor: u32,
+    pub virtue: u32,
+    pub emblem_id: u32,
+    #[length(24)]
+    pub name: String,
+    #[length(16)]
+    pub managed_territory: String,
+    pub zeny: u32,
+    pub master_character_id: CharacterId,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildInformationPacket`]. Provides the basic information about the
+/// guild.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A84)]
+pub struct GuildInformationPacket {
+    pub information: GuildInformation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[numeric_type(u32)]
+pub enum GuildRelationType {
+    Alliance,
+    Antagonist,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildRelation {
+    pub relation_type: GuildRelationType,
+    pub guild_id: GuildId,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+/// Sent by the map server to the client together with the
+/// [`GuildInformationPacket`]. Provides the alliances and antagonists of the
+/// guild.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x014C)]
+#[variable_length]
+pub struct GuildRelationsPacket {
+    #[repeating_remaining]
+    pub relations: Vec<GuildRelation>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildMemberInformation {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub head: u16,
+    pub head_palette: u16,
+    pub sex: u16,
+    pub job: u16,
+    pub base_level: u16,
+    pub contributed_experience: u32,
+    pub online: u32,
+    pub position_id: u32,
+    pub last_login: u32,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildInformationPacket`]. Provides the members of the guild. The
+/// names of the members are not included and need to be requested with
+/// [`RequestCharacterNamePacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0AA5)]
+#[variable_length]
+pub struct GuildMemberListPacket {
+    #[repeating_remaining]
+    pub members: Vec<GuildMemberInformation>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildPositionInformation {
+    pub position_id: u32,
+    pub permissions: u32,
+    pub ranking: u32,
+    pub tax_rate: u32,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildInformationPacket`]. Provides the permissions and tax rates
+/// of all guild positions.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0160)]
+#[variable_length]
+pub struct GuildPositionListPacket {
+    #[repeating_remaining]
+    pub positions: Vec<GuildPositionInformation>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GuildPositionName {
+    pub position_id: u32,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildInformationPacket`]. Provides the titles of all guild
+/// positions.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0166)]
+#[variable_length]
+pub struct GuildPositionNamesPacket {
+    #[repeating_remaining]
+    pub names: Vec<GuildPositionName>,
+}
+
+/// Sent by the map server to the client when entering the map server or
+/// after the guild notice changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x016F)]
+pub struct GuildNoticePacket {
+    #[length(60)]
+    pub subject: String,
+    #[length(120)]
+    pub notice: String,
+}
+
+/// Sent by the map server to the client when entering the map server.
+/// Provides the guild of the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x016C)]
+pub struct UpdateGuildIdPacket {
+    pub guild_id: GuildId,
+    pub emblem_id: u32,
+    pub mode: u32,
+    pub is_master: u8,
+    pub inter_server_id: u32,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+/// Sent by the map server to the client when the guild or the guild emblem of
+/// an entity changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01B4)]
+pub struct ChangeGuildPacket {
+    pub entity_id: EntityId,
+    pub guild_id: GuildId,
+    pub emblem_id: u16,
+}
+
+/// Sent by the client to the map server to request the emblem of a guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0151)]
+pub struct RequestGuildEmblemPacket {
+    pub guild_id: GuildId,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildEmblemPacket`]. The emblem is a zlib compressed bitmap.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0152)]
+#[variable_length]
+pub struct GuildEmblemPacket {
+    pub guild_id: GuildId,
+    pub emblem_id: u32,
+    #[repeating_remaining]
+    pub emblem_data: Vec<u8>,
+}
+
+/// Sent by the map server to the client when a guild member logs in or out.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01F2)]
+pub struct GuildMemberStatusPacket {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub online: u32,
+    pub sex: u16,
+    pub hair_style: u16,
+    pub hair_color: u16,
+}
+
+/// Sent by the client to the map server when the player sends a message in
+/// the guild chat.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x017E)]
+#[variable_length]
+pub struct SendGuildMessagePacket {
+    #[length_remaining_off_by_one]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when a guild member sent a message in
+/// the guild chat.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x017F)]
+#[variable_length]
+pub struct GuildMessagePacket {
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Sent by the client to the map server to request the name of a character.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0369)]
+pub struct RequestCharacterNamePacket {
+    pub character_id: CharacterId,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestCharacterNamePacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0AF7)]
+pub struct CharacterNamePacket {
+    pub flag: u16,
+    pub character_id: CharacterId,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the client to the map server when the player wants to trade with
+/// another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00E4)]
+pub struct RequestTradePacket {
+    pub entity_id: EntityId,
+}
+
+/// Sent by the map server to the client when another player wants to trade
+/// with the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01F4)]
+pub struct TradeRequestPacket {
+    #[length(24)]
+    pub character_name: String,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TradeRequestResponse {
+    #[numeric_value(3)]
+    Accept,
+    #[numeric_value(4)]
+    Reject,
+}
+
+/// Sent by the client to the map server when the player accepts or rejects a
+/// trade request.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00E6)]
+pub struct TradeRequestResponsePacket {
+    pub response: TradeRequestResponse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TradeRequestResult {
+    TooFarAway,
+    CharacterDoesNotExist,
+    Failed,
+    Accepted,
+    Cancelled,
+    Busy,
+}
+
+/// Sent by the map server to both players once a trade request was answered.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01F5)]
+pub struct TradeRequestResultPacket {
+    pub result: TradeRequestResult,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+/// Sent by the client to the map server to add an item or zeny to the trade.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00E8)]
+pub struct AddTradeItemPacket {
+    /// Inventory index + 2 for items, 0 for zeny.
+    pub index: u16,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AddTradeItemResult {
+    Success,
+    Overweight,
+    TradeClosed,
+    TooManyItems,
+    TooManyOfThisItem,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`AddTradeItemPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00EA)]
+pub struct AddTradeItemResultPacket {
+    /// Inventory index + 2 for items, 0 for zeny.
+    pub index: u16,
+    pub result: AddTradeItemResult,
+}
+
+/// Sent by the map server to the client when the trade partner added an item
+/// or zeny to the trade. Zeny are sent with an item id of 0.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0B42)]
+pub struct TradeItemAddedPacket {
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub amount: u32,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub equip_position: EquipPosition,
+    pub look: u16,
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Sent by the client to the map server when the player locks their side of
+/// the trade.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00EB)]
+pub struct LockTradePacket {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TradeParticipant {
+    Player,
+    Partner,
+}
+
+/// Sent by the map server to the client when one side of the trade was
+/// locked.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00EC)]
+pub struct TradeLockedPacket {
+    pub participant: TradeParticipant,
+}
+
+/// Sent by the client to the map server to cancel the trade.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00ED)]
+pub struct CancelTradePacket {}
+
+/// Sent by the map server to the client when the trade was cancelled by
+/// either side.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00EE)]
+pub struct TradeCancelledPacket {}
+
+/// Sent by the client to the map server to complete the trade once both
+/// sides are locked.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00EF)]
+pub struct CommitTradePacket {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TradeResult {
+    Success,
+    Failed,
+}
+
+/// Sent by the map server to the client once the trade is completed or
+/// failed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00F0)]
+pub struct TradeCompletedPacket {
+    pub result: TradeResult,
+}
+
+/// Sent by the map server to the client after the storage item list to
+/// inform the client about the capacity of the storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00F2)]
+pub struct StorageItemCountPacket {
+    pub item_count: u16,
+    pub maximum_item_count: u16,
+}
+
+/// Sent by the client to the map server when the player moves an item from
+/// the inventory into the storage.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0364)]
+pub struct AddStorageItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server when the player moves an item from
+/// the cart into the storage.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0129)]
+pub struct AddStorageItemFromCartPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server when the player moves an item from
+/// the storage into the inventory.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0365)]
+pub struct RemoveStorageItemPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+}
+
+/// Sent by the map server to the client when an item was added to the
+/// storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0B44)]
+pub struct StorageItemAddedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server to the client when an item was removed from the
+/// storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00F6)]
+pub struct StorageItemRemovedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server when the player closes the storage.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00F7)]
+pub struct CloseStoragePacket {}
+
+/// Sent by the map server to the client when the storage was closed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x00F8)]
+pub struct StorageClosedPacket {}
+
+/// Sent by the map server to the client when the player uses a pet
+/// incubator. Contains the inventory indices of all pet eggs that can be
+/// hatched.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01A6)]
+#[variable_length]
+pub struct PetEggListPacket {
+    #[repeating_remaining]
+    pub eggs: Vec<InventoryIndex>,
+}
+
+/// Sent by the client to the map server when the player selected a pet egg
+/// to hatch.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01A7)]
+pub struct SelectPetEggPacket {
+    pub index: InventoryIndex,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PetCommand {
+    /// Request the [`PetInformationPacket`].
+    Information,
+    Feed,
+    Performance,
+    ReturnToEgg,
+    UnequipAccessory,
+}
+
+/// Sent by the client to the map server when the player uses the pet menu.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01A1)]
+pub struct PetCommandPacket {
+    pub command: PetCommand,
+}
+
+/// Sent by the map server to the client when the pet of the player was
+/// hatched or when the client requested it with [`PetCommand::Information`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01A2)]
+pub struct PetInformationPacket {
+    #[length(24)]
+    pub name: String,
+    pub renamed: u8,
+    pub level: u16,
+    pub hunger: u16,
+    pub intimacy: u16,
+    /// Item id of the equipped accessory, `0` if there is none.
+    pub accessory: u16,
+    pub job: u16,
+}
+
+/// Sent by the map server to the client after the player fed the pet.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01A3)]
+pub struct PetFeedResultPacket {
+    pub success: u8,
+    pub item_id: ItemId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PetStateType {
+    /// Sent when the pet is spawned to tell the client its entity id.
+    Initialize,
+    Intimacy,
+    Hunger,
+    Accessory,
+    Performance,
+    HairStyle,
+}
+
+/// Sent by the map server to the client when a property of the pet of the
+/// player changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01A4)]
+pub struct PetStatePacket {
+    pub state_type: PetStateType,
+    pub entity_id: EntityId,
+    pub value: u32,
+}
+
+/// Sent by the client to the map server when the player renames the pet.
+/// A pet can only be renamed once.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01A5)]
+pub struct RenamePetPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server to the client when the homunculus of the player
+/// was called or when one of its properties changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0B76)]
+pub struct HomunculusInformationPacket {
+    #[length(24)]
+    pub name: String,
+    /// Bit 0 is set if the homunculus was renamed, bit 1 if it is vaporized
+    /// and bit 2 if it is dead.
+    pub flags: u8,
+    pub level: u16,
+    pub hunger: u16,
+    /// Intimacy divided by 100.
+    pub intimacy: u16,
+    pub equipped_item: u16,
+    pub attack: u16,
+    pub magic_attack: u16,
+    pub hit: u16,
+    pub critical: u16,
+    pub defense: u16,
+    pub magic_defense: u16,
+    pub flee: u16,
+    pub attack_speed: u16,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+    pub spell_points: u16,
+    pub maximum_spell_points: u16,
+    pub experience: u64,
+    pub next_level_experience: u64,
+    pub skill_points: u16,
+    pub attack_range: u16,
+}
+
+/// Sent by the map server to the client to inform about the skills of the
+/// homunculus.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0235)]
+#[variable_length]
+pub struct HomunculusSkillListPacket {
+    #[repeating_remaining]
+    pub skill_information: Vec<SkillInformation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum HomunculusStateType {
+    /// Sent when the homunculus is spawned to tell the client its entity id.
+    Initialize,
+    Intimacy,
+    Hunger,
+}
+
+/// Sent by the map server to the client when a property of the homunculus
+/// of the player changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0230)]
+pub struct HomunculusStatePacket {
+    pub unused: u8,
+    pub state_type: HomunculusStateType,
+    pub entity_id: EntityId,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum HomunculusCommand {
+    /// Request the [`HomunculusInformationPacket`].
+    Information,
+    Feed,
+    /// Vaporize the homunculus.
+    Delete,
+}
+
+/// Sent by the client to the map server when the player uses the homunculus
+/// menu.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x022D)]
+pub struct HomunculusCommandPacket {
+    /// Always `0` for homunculi.
+    pub companion_type: u16,
+    pub command: HomunculusCommand,
+}
+
+/// Sent by the map server to the client after the player fed the
+/// homunculus.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x022F)]
+pub struct HomunculusFeedResultPacket {
+    pub success: u8,
+    pub item_id: ItemId,
+}
+
+/// Sent by the client to the map server when the player renames the
+/// homunculus. A homunculus can only be renamed once.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0231)]
+pub struct RenameHomunculusPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the client to the map server to move the homunculus or the
+/// mercenary of the player to a position.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0232)]
+pub struct MoveCompanionPacket {
+    pub entity_id: EntityId,
+    pub position: WorldPosition,
+}
+
+/// Sent by the client to the map server to make the homunculus or the
+/// mercenary of the player attack an entity.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0233)]
+pub struct CompanionAttackPacket {
+    pub entity_id: EntityId,
+    pub target_entity_id: EntityId,
+    /// `0` for a single attack, `1` to keep attacking.
+    pub action: u8,
+}
+
+/// Sent by the client to the map server to make the homunculus or the
+/// mercenary of the player return to the player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0234)]
+pub struct MoveCompanionToOwnerPacket {
+    pub entity_id: EntityId,
+}
+
+/// Sent by the map server to the client when the mercenary of the player was
+/// summoned or when the client requested it with
+/// [`MercenaryCommand::Information`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x029B)]
+pub struct MercenaryInformationPacket {
+    pub entity_id: EntityId,
+    pub attack: u16,
+    pub magic_attack: u16,
+    pub hit: u16,
+    pub critical: u16,
+    pub defense: u16,
+    pub magic_defense: u16,
+    pub flee: u16,
+    pub attack_speed: u16,
+    #[length(24)]
+    pub name: String,
+    pub level: u16,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+    pub spell_points: u32,
+    pub maximum_spell_points: u32,
+    /// Unix timestamp of when the contract of the mercenary ends.
+    pub expiration_time: u32,
+    pub faith: u16,
+    pub call_count: u32,
+    pub kill_count: u32,
+    pub attack_range: u16,
+}
+
+/// Sent by the map server to the client to inform about the skills of the
+/// mercenary.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x029D)]
+#[variable_length]
+pub struct MercenarySkillListPacket {
+    #[repeating_remaining]
+    pub skill_information: Vec<SkillInformation>,
+}
+
+/// Sent by the map server to the client when a single property of the
+/// mercenary changed. The parameter uses the same values as [`StatType`],
+/// e.g. `5` for the health points.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x02A2)]
+pub struct MercenaryParameterPacket {
+    pub parameter: u16,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MercenaryCommand {
+    /// Request the [`MercenaryInformationPacket`].
+    #[numeric_value(0)]
+    Information,
+    /// End the contract with the mercenary.
+    #[numeric_value(2)]
+    Dismiss,
+}
+
+/// Sent by the client to the map server when the player uses the mercenary
+/// menu.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x029F)]
+pub struct MercenaryCommandPacket {
+    pub command: MercenaryCommand,
+}
+
+/// Sent by the map server to the client to inform the client about the
+/// capacity and weight of the cart.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0121)]
+pub struct CartItemCountPacket {
+    pub item_count: u16,
+    pub maximum_item_count: u16,
+    pub weight: u32,
+    pub maximum_weight: u32,
+}
+
+/// Sent by the map server to the client when an item was added to the cart.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0B45)]
+pub struct CartItemAddedPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server to the client when an item was removed from the
+/// cart.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0125)]
+pub struct CartItemRemovedPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server when the player moves an item from
+/// the inventory into the cart.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0126)]
+pub struct AddCartItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server when the player moves an item from
+/// the cart into the inventory.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0127)]
+pub struct RemoveCartItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the map server to the client when the cart was removed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x012B)]
+pub struct CartRemovedPacket {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AddCartItemFailedReason {
+    Overweight,
+    TooManyItems,
+}
+
+/// Sent by the map server to the client when an item could not be added to
+/// the cart.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x012C)]
+pub struct AddCartItemFailedPacket {
+    pub reason: AddCartItemFailedReason,
+}
+
+/// Sent by the map server to the client when the player uses the vending
+/// skill. The client should then let the player pick the items to sell.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x012D)]
+pub struct OpenVendingPacket {
+    pub maximum_item_count: u16,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VendingItemSetup {
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub price: Price,
+}
+
+/// Sent by the client to the map server to open a vending shop with items
+/// from the cart. Sending `0` for `open` cancels the setup.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x01B2)]
+#[variable_length]
+pub struct SetupVendingPacket {
+    #[length(80)]
+    pub shop_title: String,
+    pub open: u8,
+    #[repeating_remaining]
+    pub items: Vec<VendingItemSetup>,
+}
+
+/// Sent by the map server to the client after trying to open a vending shop.
+/// A result of `0` means that the shop was opened.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A28)]
+pub struct VendingOpenResultPacket {
+    pub result: u8,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OwnVendingItemInformation {
+    pub price: Price,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server to the client after the vending shop of the player
+/// was opened. The indices are cart indices.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0136)]
+#[variable_length]
+pub struct OwnVendingItemListPacket {
+    pub account_id: AccountId,
+    #[repeating_remaining]
+    pub items: Vec<OwnVendingItemInformation>,
+}
+
+/// Sent by the map server to the client when another player bought an item
+/// from the vending shop of the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09E5)]
+pub struct VendingItemSoldPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub buyer_character_id: CharacterId,
+    pub date: u32,
+    pub zeny: u32,
+}
+
+/// Sent by the client to the map server when the player closes their
+/// vending shop.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x012E)]
+pub struct CloseVendingPacket {}
+
+/// Sent by the map server to the client when a player nearby opened a
+/// vending shop. Also sent for shops that come into view.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0131)]
+pub struct VendingShopOpenedPacket {
+    pub entity_id: EntityId,
+    #[length(80)]
+    pub shop_title: String,
+}
+
+/// Sent by the map server to the client when a player nearby closed their
+/// vending shop.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0132)]
+pub struct VendingShopClosedPacket {
+    pub entity_id: EntityId,
+}
+
+/// Sent by the client to the map server when the player clicks on a vending
+/// shop of another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0130)]
+pub struct RequestVendingItemsPacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VendingItemInformation {
+    pub price: Price,
+    pub amount: u16,
+    pub index: InventoryIndex,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub location: u32,
+    pub sprite_number: u16,
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server to the client with the items of the vending shop
+/// of another player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0800)]
+#[variable_length]
+pub struct VendingItemListPacket {
+    pub entity_id: EntityId,
+    pub vending_id: u32,
+    #[repeating_remaining]
+    pub items: Vec<VendingItemInformation>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VendingPurchase {
+    pub amount: u16,
+    pub index: InventoryIndex,
+}
+
+/// Sent by the client to the map server to buy items from the vending shop
+/// of another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0801)]
+#[variable_length]
+pub struct BuyVendingItemsPacket {
+    pub entity_id: EntityId,
+    pub vending_id: u32,
+    #[repeating_remaining]
+    pub items: Vec<VendingPurchase>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum BuyVendingItemFailedReason {
+    #[numeric_value(1)]
+    NotEnoughZeny,
+    #[numeric_value(2)]
+    Overweight,
+    #[numeric_value(4)]
+    OutOfStock,
+    #[numeric_value(5)]
+    VendorIsTrading,
+    #[numeric_value(6)]
+    InvalidShop,
+    #[numeric_value(7)]
+    NoSales,
+}
+
+/// Sent by the map server to the client when an item could not be bought
+/// from a vending shop.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0135)]
+pub struct BuyVendingItemFailedPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub reason: BuyVendingItemFailedReason,
+}
+
+/// The tab of the mailbox a mail is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MailOpenType {
+    Character,
+    Account,
+    Returned,
+    Unset,
+}
+
+/// Sent by the client to the map server when the player opens the mailbox.
+/// The server answers with the newest mails.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0AC0)]
+pub struct OpenMailboxPacket {
+    pub mail_id: MailId,
+    pub unknown: [u8; 16],
+}
+
+/// Sent by the client to the map server to request the mails that are newer
+/// than the given mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0AC1)]
+pub struct RefreshMailboxPacket {
+    pub mail_id: MailId,
+    pub unknown: [u8; 16],
+}
+
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09E9)]
+pub struct CloseMailboxPacket {}
+
+/// Entry of the mail list. The mail type is a set of flags, where `0x2` means
+/// that the mail has zeny attached, `0x4` that it has items attached and `0x8`
+/// that it was sent by an NPC.
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MailListEntry {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+    pub is_read: u8,
+    pub mail_type: u8,
+    #[length(24)]
+    pub sender_name: String,
+    /// Seconds until the mail expires.
+    pub expiration_time: u32,
+    pub title_length: u16,
+    #[length(title_length)]
+    pub title: String,
+}
+
+/// Sent by the map server to the client with a page of the mailbox.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0AC2)]
+#[variable_length]
+pub struct MailListPacket {
+    pub is_end: u8,
+    #[length_remaining]
+    pub mails: Vec<MailListEntry>,
+}
+
+/// Sent by the client to the map server to read the contents of a mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09EA)]
+pub struct RequestReadMailPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MailItemInformation {
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub location: u32,
+    pub item_type: u8,
+    pub sprite_number: u16,
+    pub bind_on_equip_type: u16,
+    pub option_data: [ItemOptions; 5], // fix count
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server to the client with the contents of a mail.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09EB)]
+#[variable_length]
+pub struct ReadMailPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+    pub text_length: u16,
+    pub zeny: u64,
+    pub item_count: u8,
+    #[length(text_length)]
+    pub text: String,
+    #[repeating(item_count)]
+    pub items: Vec<MailItemInformation>,
+}
+
+/// Sent by the client to the map server to take the zeny attached to a mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09F1)]
+pub struct RequestMailZenyPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+}
+
+/// Sent by the map server to the client after trying to take the zeny
+/// attached to a mail. A result of `0` means that the zeny were taken.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09F2)]
+pub struct MailZenyResultPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub result: u8,
+}
+
+/// Sent by the client to the map server to take the items attached to a
+/// mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09F3)]
+pub struct RequestMailItemsPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MailItemsResult {
+    Success,
+    Failure,
+    Overweight,
+}
+
+/// Sent by the map server to the client after trying to take the items
+/// attached to a mail.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09F4)]
+pub struct MailItemsResultPacket {
+    pub mail_id: MailId,
+    pub open_type: MailOpenType,
+    pub result: MailItemsResult,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09F5)]
+pub struct DeleteMailPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+}
+
+/// Sent by the map server to the client when a mail was deleted.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09F6)]
+pub struct MailDeletedPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+}
+
+/// Sent by the client to the map server when the player starts writing a
+/// mail. Items can only be attached after this.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A08)]
+pub struct OpenWriteMailPacket {
+    #[length(24)]
+    pub receiver_name: String,
+}
+
+/// Sent by the map server to the client after the player started writing a
+/// mail. A result of `1` means that the player can write the mail.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A12)]
+pub struct WriteMailOpenedPacket {
+    #[length(24)]
+    pub receiver_name: String,
+    pub result: u8,
+}
+
+/// Sent by the client to the map server when the player stops writing a mail.
+/// The attached items are given back to the player.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A03)]
+pub struct CancelWriteMailPacket {}
+
+/// Sent by the client to the map server to attach an item from the inventory
+/// to the mail that is being written.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A04)]
+pub struct AddMailItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AddMailItemResult {
+    Success,
+    Overweight,
+    Failure,
+    TooManyItems,
+    NotTradeable,
+}
+
+/// Sent by the map server to the client after trying to attach an item to a
+/// mail. The amount is the total amount of the item that is attached and the
+/// weight is the weight of all attached items.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A05)]
+pub struct MailItemAddedPacket {
+    pub result: AddMailItemResult,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub weight: u16,
+    pub favorite: u8,
+    pub location: u32,
+    pub enchantment_level: u8,
+}
+
+/// Sent by the client to the map server to detach an item from the mail that
+/// is being written.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A06)]
+pub struct RemoveMailItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+/// Sent by the map server to the client after trying to detach an item from a
+/// mail. A result of `1` means that the item was detached. The amount is the
+/// amount that is still attached.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A07)]
+pub struct MailItemRemovedPacket {
+    pub result: u8,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub weight: u16,
+}
+
+/// Sent by the client to the map server to look up the character a mail
+/// should be sent to.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A13)]
+pub struct CheckMailReceiverPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server to the client with the character a mail should be
+/// sent to. The character id is `0` if there is no character with that name.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A51)]
+pub struct MailReceiverPacket {
+    pub character_id: CharacterId,
+    pub job: u16,
+    pub base_level: u16,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the client to the map server to send the mail that is being
+/// written. The lengths of the title and text include the terminator.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09EC)]
+#[variable_length]
+pub struct SendMailPacket {
+    #[length(24)]
+    pub receiver_name: String,
+    #[length(24)]
+    pub sender_name: String,
+    pub zeny: u64,
+    pub title_length: u16,
+    pub text_length: u16,
+    pub receiver_character_id: CharacterId,
+    #[length(title_length)]
+    pub title: String,
+    #[length(text_length)]
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SendMailResult {
+    Success,
+    Failure,
+    TooManyMails,
+    InvalidAttachment,
+    InvalidReceiver,
+}
+
+/// Sent by the map server to the client after trying to send a mail.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09ED)]
+pub struct SendMailResultPacket {
+    pub result: SendMailResult,
+}
+
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ReputationEntry {
     pub reputation_type: u64,
     pub points: i64,
@@ -4116,6 +6352,7 @@ pub struct ReputationEntry {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B8D)]
 #[variable_length]
 pub struct ReputationPacket {
@@ -4126,6 +6363,7 @@ pub struct ReputationPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Aliance {
     #[length(24)]
     pub name: String,
@@ -4133,6 +6371,7 @@ pub struct Aliance {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Antagonist {
     #[length(24)]
     pub name: String,
@@ -4140,6 +6379,7 @@ pub struct Antagonist {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x098A)]
 #[variable_length]
 pub struct ClanInfoPacket {
@@ -4162,6 +6402,7 @@ pub struct ClanInfoPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0988)]
 pub struct ClanOnlineCountPacket {
     pub online_members: u16,
@@ -4170,6 +6411,7 @@ pub struct ClanOnlineCountPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0192)]
 pub struct ChangeMapCellPacket {
     pub position: TilePosition,
@@ -4180,6 +6422,7 @@ pub struct ChangeMapCellPacket {
 
 #[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MarketItemInformation {
     pub name_id: u32,
     pub item_type: u8,
@@ -4191,6 +6434,7 @@ pub struct MarketItemInformation {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B7A)]
 #[variable_length]
 pub struct OpenMarketPacket {
@@ -4200,6 +6444,7 @@ pub struct OpenMarketPacket {
 
 #[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ShopItemInformation {
     pub item_id: ItemId,
     pub price: Price,
@@ -4211,6 +6456,7 @@ pub struct ShopItemInformation {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B77)]
 #[variable_length]
 pub struct ShopItemListPacket {
@@ -4220,6 +6466,7 @@ pub struct ShopItemListPacket {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00C4)]
 pub struct BuyOrSellPacket {
     pub shop_id: ShopId,
@@ -4227,6 +6474,7 @@ pub struct BuyOrSellPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BuyOrSellOption {
     Buy,
     Sell,
@@ -4234,6 +6482,7 @@ pub enum BuyOrSellOption {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00C5)]
 pub struct SelectBuyOrSellPacket {
     pub shop_id: ShopId,
@@ -4242,6 +6491,7 @@ pub struct SelectBuyOrSellPacket {
 
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BuyItemResult {
     #[numeric_value(0)]
     Successful,
@@ -4267,6 +6517,7 @@ pub enum BuyItemResult {
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BuyItemInformation {
     pub amount: u16,
     pub item_id: u16,
@@ -4274,6 +6525,7 @@ pub struct BuyItemInformation {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00C8)]
 #[variable_length]
 pub struct BuyItemsPacket {
@@ -4283,6 +6535,7 @@ pub struct BuyItemsPacket {
 
 #[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BuyShopItemInformation {
     pub item_id: ItemId,
     pub amount: u32,
@@ -4290,6 +6543,7 @@ pub struct BuyShopItemInformation {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09D6)]
 #[variable_length]
 pub struct BuyShopItemsPacket {
@@ -4298,6 +6552,7 @@ pub struct BuyShopItemsPacket {
 
 #[derive(Debug, Clone, Copy, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[numeric_type(u16)]
 pub enum BuyShopItemsResult {
     #[numeric_value(0)]
@@ -4308,6 +6563,7 @@ pub enum BuyShopItemsResult {
 
 #[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BoughtShopItemInformation {
     pub item_id: ItemId,
     pub amount: u16,
@@ -4316,6 +6572,7 @@ pub struct BoughtShopItemInformation {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x0B4E)]
 #[variable_length]
 pub struct BuyShopItemsResultPacket {
@@ -4326,11 +6583,13 @@ pub struct BuyShopItemsResultPacket {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x09D4)]
 pub struct CloseShopPacket {}
 
 #[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SellItemInformation {
     pub inventory_index: InventoryIndex,
     pub price: Price,
@@ -4339,6 +6598,7 @@ pub struct SellItemInformation {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00C7)]
 #[variable_length]
 pub struct SellListPacket {
@@ -4348,6 +6608,7 @@ pub struct SellListPacket {
 
 #[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SoldItemInformation {
     pub inventory_index: InventoryIndex,
     pub amount: u16,
@@ -4355,6 +6616,7 @@ pub struct SoldItemInformation {
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00C9)]
 #[variable_length]
 pub struct SellItemsPacket {
@@ -4364,6 +6626,7 @@ pub struct SellItemsPacket {
 
 #[derive(Debug, Clone, Copy, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SellItemsResult {
     Success,
     Error,
@@ -4371,6 +6634,7 @@ pub enum SellItemsResult {
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[header(0x00CB)]
 pub struct SellItemsResultPacket {
     pub result: SellItemsResult,
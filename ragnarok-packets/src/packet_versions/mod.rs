@@ -0,0 +1,5 @@
+//! Packets that have a different header or layout in older packet versions.
+//! Every packet in here can be converted to its counterpart in the crate root,
+//! so the rest of the client only has to deal with a single layout.
+
+pub mod version_20200401;
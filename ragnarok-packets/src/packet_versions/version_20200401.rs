@@ -0,0 +1,725 @@
+//! Packets of the 2020-04-01 client. Health and spell points of characters
+//! were still 32 and 16 bits wide, items did not have an enchantment grade
+//! yet and the experience of homunculi was still 32 bits wide.
+
+use ragnarok_bytes::{ByteConvertable, FixedByteSize, FromBytes, ToBytes};
+use ragnarok_macros::{CharacterServer, MapServer, Packet, ServerPacket};
+
+use crate::{
+    AccountId, AddMailItemResult, CharacterId, EntityId, EquipPosition, EquippableItemFlags, InventoryIndex, InventoryType, ItemId,
+    ItemOptions, ItemPickupResult, MailId, MailOpenType, Price, Sex, StorageIndex,
+};
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CharacterInformation {
+    pub character_id: CharacterId,
+    pub experience: i64,
+    pub money: i32,
+    pub job_experience: i64,
+    pub job_level: i32,
+    pub body_state: i32,
+    pub health_state: i32,
+    pub effect_state: i32,
+    pub virtue: i32,
+    pub honor: i32,
+    pub stat_points: i16,
+    pub health_points: i32,
+    pub maximum_health_points: i32,
+    pub spell_points: i16,
+    pub maximum_spell_points: i16,
+    pub movement_speed: i16,
+    pub job: i16,
+    pub head: i16,
+    pub body: i16,
+    pub weapon: i16,
+    pub base_level: i16,
+    pub sp_point: i16,
+    pub head_bottom: i16,
+    pub shield: i16,
+    pub head_top: i16,
+    pub head_middle: i16,
+    pub head_palette: i16,
+    pub body_palette: i16,
+    #[length(24)]
+    pub name: String,
+    pub strength: u8,
+    pub agility: u8,
+    pub vitality: u8,
+    pub intelligence: u8,
+    pub dexterity: u8,
+    pub luck: u8,
+    pub character_number: u8,
+    pub hair_color: u8,
+    pub b_is_changed_char: i16,
+    #[length(16)]
+    pub map_name: String,
+    pub deletion_reverse_date: i32,
+    /// View id of the garment.
+    pub robe: i32,
+    pub character_slot_change_count: i32,
+    pub character_name_change_count: i32,
+    pub sex: Sex,
+}
+
+impl From<CharacterInformation> for crate::CharacterInformation {
+    fn from(character_information: CharacterInformation) -> Self {
+        let CharacterInformation {
+            character_id,
+            experience,
+            money,
+            job_experience,
+            job_level,
+            body_state,
+            health_state,
+            effect_state,
+            virtue,
+            honor,
+            stat_points,
+            health_points,
+            maximum_health_points,
+            spell_points,
+            maximum_spell_points,
+            movement_speed,
+            job,
+            head,
+            body,
+            weapon,
+            base_level,
+            sp_point,
+            head_bottom,
+            shield,
+            head_top,
+            head_middle,
+            head_palette,
+            body_palette,
+            name,
+            strength,
+            agility,
+            vitality,
+            intelligence,
+            dexterity,
+            luck,
+            character_number,
+            hair_color,
+            b_is_changed_char,
+            map_name,
+            deletion_reverse_date,
+            robe,
+            character_slot_change_count,
+            character_name_change_count,
+            sex,
+        } = character_information;
+
+        Self {
+            character_id,
+            experience,
+            money,
+            job_experience,
+            job_level,
+            body_state,
+            health_state,
+            effect_state,
+            virtue,
+            honor,
+            stat_points,
+            health_points: health_points.into(),
+            maximum_health_points: maximum_health_points.into(),
+            spell_points: spell_points.into(),
+            maximum_spell_points: maximum_spell_points.into(),
+            movement_speed,
+            job,
+            head,
+            body,
+            weapon,
+            base_level,
+            sp_point,
+            head_bottom,
+            shield,
+            head_top,
+            head_middle,
+            head_palette,
+            body_palette,
+            name,
+            strength,
+            agility,
+            vitality,
+            intelligence,
+            dexterity,
+            luck,
+            character_number,
+            hair_color,
+            b_is_changed_char,
+            map_name,
+            deletion_reverse_date,
+            robe,
+            character_slot_change_count,
+            character_name_change_count,
+            sex,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x006B)]
+#[variable_length]
+pub struct CharacterListPacket {
+    pub maximum_slot_count: u8,
+    pub available_slot_count: u8,
+    pub vip_slot_count: u8,
+    #[new_default]
+    pub unknown: [u8; 20],
+    #[repeating_remaining]
+    pub character_information: Vec<CharacterInformation>,
+}
+
+impl From<CharacterListPacket> for crate::CharacterListPacket {
+    fn from(packet: CharacterListPacket) -> Self {
+        Self {
+            maximum_slot_count: packet.maximum_slot_count,
+            available_slot_count: packet.available_slot_count,
+            vip_slot_count: packet.vip_slot_count,
+            unknown: packet.unknown,
+            character_information: packet.character_information.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x006D)]
+pub struct CreateCharacterSuccessPacket {
+    pub character_information: CharacterInformation,
+}
+
+impl From<CreateCharacterSuccessPacket> for crate::CreateCharacterSuccessPacket {
+    fn from(packet: CreateCharacterSuccessPacket) -> Self {
+        Self {
+            character_information: packet.character_information.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x099D)]
+#[variable_length]
+pub struct RequestCharacterListSuccessPacket {
+    #[repeating_remaining]
+    pub character_information: Vec<CharacterInformation>,
+}
+
+impl From<RequestCharacterListSuccessPacket> for crate::RequestCharacterListSuccessPacket {
+    fn from(packet: RequestCharacterListSuccessPacket) -> Self {
+        Self {
+            character_information: packet.character_information.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EquippableItemInformation {
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub equip_position: EquipPosition,
+    pub equipped_position: EquipPosition,
+    pub refinement_level: u8,
+    pub slot: [u32; 4], // card ?
+    pub hire_expiration_date: u32,
+    pub bind_on_equip_type: u16,
+    pub w_item_sprite_number: u16,
+    pub option_count: u8,
+    pub option_data: [ItemOptions; 5], // fix count
+    pub flags: EquippableItemFlags,
+}
+
+impl From<EquippableItemInformation> for crate::EquippableItemInformation {
+    fn from(item: EquippableItemInformation) -> Self {
+        Self {
+            index: item.index,
+            item_id: item.item_id,
+            item_type: item.item_type,
+            equip_position: item.equip_position,
+            equipped_position: item.equipped_position,
+            slot: item.slot,
+            hire_expiration_date: item.hire_expiration_date,
+            bind_on_equip_type: item.bind_on_equip_type,
+            w_item_sprite_number: item.w_item_sprite_number,
+            option_count: item.option_count,
+            option_data: item.option_data,
+            refinement_level: item.refinement_level,
+            enchantment_level: 0,
+            flags: item.flags,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0B0A)]
+#[variable_length]
+pub struct EquippableItemListPacket {
+    pub inventory_type: InventoryType,
+    #[repeating_remaining]
+    pub item_information: Vec<EquippableItemInformation>,
+}
+
+impl From<EquippableItemListPacket> for crate::EquippableItemListPacket {
+    fn from(packet: EquippableItemListPacket) -> Self {
+        Self {
+            inventory_type: packet.inventory_type,
+            item_information: packet.item_information.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A37)]
+pub struct ItemPickupPacket {
+    pub index: InventoryIndex,
+    pub count: u16,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub equip_position: EquipPosition,
+    pub item_type: u8,
+    pub result: ItemPickupResult,
+    pub hire_expiration_date: u32,
+    pub bind_on_equip_type: u16,
+    pub option_data: [ItemOptions; 5], // fix count
+    pub favorite: u8,
+    pub look: u16,
+}
+
+impl From<ItemPickupPacket> for crate::ItemPickupPacket {
+    fn from(packet: ItemPickupPacket) -> Self {
+        Self {
+            index: packet.index,
+            count: packet.count,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified,
+            is_broken: packet.is_broken,
+            cards: packet.cards,
+            equip_position: packet.equip_position,
+            item_type: packet.item_type,
+            result: packet.result,
+            hire_expiration_date: packet.hire_expiration_date,
+            bind_on_equip_type: packet.bind_on_equip_type,
+            option_data: packet.option_data,
+            favorite: packet.favorite,
+            look: packet.look,
+            refinement_level: packet.refinement_level,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the map server to the client when the trade partner added an item
+/// or zeny to the trade. Zeny are sent with an item id of 0.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A09)]
+pub struct TradeItemAddedPacket {
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub amount: u32,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub equip_position: EquipPosition,
+    pub look: u16,
+}
+
+impl From<TradeItemAddedPacket> for crate::TradeItemAddedPacket {
+    fn from(packet: TradeItemAddedPacket) -> Self {
+        Self {
+            item_id: packet.item_id,
+            item_type: packet.item_type,
+            amount: packet.amount,
+            is_identified: packet.is_identified,
+            is_broken: packet.is_broken,
+            cards: packet.cards,
+            option_data: packet.option_data,
+            equip_position: packet.equip_position,
+            look: packet.look,
+            refinement_level: packet.refinement_level,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the map server to the client when an item was added to the
+/// storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A0A)]
+pub struct StorageItemAddedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+}
+
+impl From<StorageItemAddedPacket> for crate::StorageItemAddedPacket {
+    fn from(packet: StorageItemAddedPacket) -> Self {
+        Self {
+            index: packet.index,
+            amount: packet.amount,
+            item_id: packet.item_id,
+            item_type: packet.item_type,
+            is_identified: packet.is_identified,
+            is_broken: packet.is_broken,
+            cards: packet.cards,
+            option_data: packet.option_data,
+            refinement_level: packet.refinement_level,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the map server to the client when the homunculus of the player
+/// was called or when one of its properties changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09F7)]
+pub struct HomunculusInformationPacket {
+    #[length(24)]
+    pub name: String,
+    pub flags: u8,
+    pub level: u16,
+    pub hunger: u16,
+    pub intimacy: u16,
+    pub equipped_item: u16,
+    pub attack: u16,
+    pub magic_attack: u16,
+    pub hit: u16,
+    pub critical: u16,
+    pub defense: u16,
+    pub magic_defense: u16,
+    pub flee: u16,
+    pub attack_speed: u16,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+    pub spell_points: u16,
+    pub maximum_spell_points: u16,
+    pub experience: u32,
+    pub next_level_experience: u32,
+    pub skill_points: u16,
+    pub attack_range: u16,
+}
+
+impl From<HomunculusInformationPacket> for crate::HomunculusInformationPacket {
+    fn from(packet: HomunculusInformationPacket) -> Self {
+        Self {
+            name: packet.name,
+            flags: packet.flags,
+            level: packet.level,
+            hunger: packet.hunger,
+            intimacy: packet.intimacy,
+            equipped_item: packet.equipped_item,
+            attack: packet.attack,
+            magic_attack: packet.magic_attack,
+            hit: packet.hit,
+            critical: packet.critical,
+            defense: packet.defense,
+            magic_defense: packet.magic_defense,
+            flee: packet.flee,
+            attack_speed: packet.attack_speed,
+            health_points: packet.health_points,
+            maximum_health_points: packet.maximum_health_points,
+            spell_points: packet.spell_points,
+            maximum_spell_points: packet.maximum_spell_points,
+            experience: packet.experience.into(),
+            next_level_experience: packet.next_level_experience.into(),
+            skill_points: packet.skill_points,
+            attack_range: packet.attack_range,
+        }
+    }
+}
+
+/// Sent by the map server to the client when an item was added to the cart.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A0B)]
+pub struct CartItemAddedPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+}
+
+impl From<CartItemAddedPacket> for crate::CartItemAddedPacket {
+    fn from(packet: CartItemAddedPacket) -> Self {
+        Self {
+            index: packet.index,
+            amount: packet.amount,
+            item_id: packet.item_id,
+            item_type: packet.item_type,
+            is_identified: packet.is_identified,
+            is_broken: packet.is_broken,
+            cards: packet.cards,
+            option_data: packet.option_data,
+            refinement_level: packet.refinement_level,
+            enchantment_level: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OwnVendingItemInformation {
+    pub price: Price,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+}
+
+impl From<OwnVendingItemInformation> for crate::OwnVendingItemInformation {
+    fn from(item_information: OwnVendingItemInformation) -> Self {
+        Self {
+            price: item_information.price,
+            index: item_information.index,
+            amount: item_information.amount,
+            item_type: item_information.item_type,
+            item_id: item_information.item_id,
+            is_identified: item_information.is_identified,
+            is_broken: item_information.is_broken,
+            refinement_level: item_information.refinement_level,
+            cards: item_information.cards,
+            option_data: item_information.option_data,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the map server to the client after the vending shop of the player
+/// was opened. The indices are cart indices.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0136)]
+#[variable_length]
+pub struct OwnVendingItemListPacket {
+    pub account_id: AccountId,
+    #[repeating_remaining]
+    pub items: Vec<OwnVendingItemInformation>,
+}
+
+impl From<OwnVendingItemListPacket> for crate::OwnVendingItemListPacket {
+    fn from(packet: OwnVendingItemListPacket) -> Self {
+        Self {
+            account_id: packet.account_id,
+            items: packet.items.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VendingItemInformation {
+    pub price: Price,
+    pub amount: u16,
+    pub index: InventoryIndex,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub location: u32,
+    pub sprite_number: u16,
+}
+
+impl From<VendingItemInformation> for crate::VendingItemInformation {
+    fn from(item_information: VendingItemInformation) -> Self {
+        Self {
+            price: item_information.price,
+            amount: item_information.amount,
+            index: item_information.index,
+            item_type: item_information.item_type,
+            item_id: item_information.item_id,
+            is_identified: item_information.is_identified,
+            is_broken: item_information.is_broken,
+            refinement_level: item_information.refinement_level,
+            cards: item_information.cards,
+            option_data: item_information.option_data,
+            location: item_information.location,
+            sprite_number: item_information.sprite_number,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the map server to the client with the items of the vending shop
+/// of another player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0800)]
+#[variable_length]
+pub struct VendingItemListPacket {
+    pub entity_id: EntityId,
+    pub vending_id: u32,
+    #[repeating_remaining]
+    pub items: Vec<VendingItemInformation>,
+}
+
+impl From<VendingItemListPacket> for crate::VendingItemListPacket {
+    fn from(packet: VendingItemListPacket) -> Self {
+        Self {
+            entity_id: packet.entity_id,
+            vending_id: packet.vending_id,
+            items: packet.items.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MailItemInformation {
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub location: u32,
+    pub item_type: u8,
+    pub sprite_number: u16,
+    pub bind_on_equip_type: u16,
+    pub option_data: [ItemOptions; 5], // fix count
+}
+
+impl From<MailItemInformation> for crate::MailItemInformation {
+    fn from(item_information: MailItemInformation) -> Self {
+        Self {
+            amount: item_information.amount,
+            item_id: item_information.item_id,
+            is_identified: item_information.is_identified,
+            is_broken: item_information.is_broken,
+            refinement_level: item_information.refinement_level,
+            cards: item_information.cards,
+            location: item_information.location,
+            item_type: item_information.item_type,
+            sprite_number: item_information.sprite_number,
+            bind_on_equip_type: item_information.bind_on_equip_type,
+            option_data: item_information.option_data,
+            enchantment_level: 0,
+        }
+    }
+}
+
+/// Sent by the map server to the client with the contents of a mail.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x09EB)]
+#[variable_length]
+pub struct ReadMailPacket {
+    pub open_type: MailOpenType,
+    pub mail_id: MailId,
+    pub text_length: u16,
+    pub zeny: u64,
+    pub item_count: u8,
+    #[length(text_length)]
+    pub text: String,
+    #[repeating(item_count)]
+    pub items: Vec<MailItemInformation>,
+}
+
+impl From<ReadMailPacket> for crate::ReadMailPacket {
+    fn from(packet: ReadMailPacket) -> Self {
+        Self {
+            open_type: packet.open_type,
+            mail_id: packet.mail_id,
+            text_length: packet.text_length,
+            zeny: packet.zeny,
+            item_count: packet.item_count,
+            text: packet.text,
+            items: packet.items.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Sent by the map server to the client after trying to attach an item to a
+/// mail. The amount is the total amount of the item that is attached and the
+/// weight is the weight of all attached items.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[header(0x0A05)]
+pub struct MailItemAddedPacket {
+    pub result: AddMailItemResult,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub weight: u16,
+    pub favorite: u8,
+    pub location: u32,
+}
+
+impl From<MailItemAddedPacket> for crate::MailItemAddedPacket {
+    fn from(packet: MailItemAddedPacket) -> Self {
+        Self {
+            result: packet.result,
+            index: packet.index,
+            amount: packet.amount,
+            item_id: packet.item_id,
+            item_type: packet.item_type,
+            is_identified: packet.is_identified,
+            is_broken: packet.is_broken,
+            refinement_level: packet.refinement_level,
+            cards: packet.cards,
+            option_data: packet.option_data,
+            weight: packet.weight,
+            favorite: packet.favorite,
+            location: packet.location,
+            enchantment_level: 0,
+        }
+    }
+}
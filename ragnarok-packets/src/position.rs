@@ -4,6 +4,7 @@ use crate::TilePosition;
 
 #[derive(Debug, Copy, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Direction {
     North = 0,
     NorthEast = 1,
@@ -40,6 +41,7 @@ impl From<u16> for Direction {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct InvalidDirectionError;
 
 impl TryFrom<[isize; 2]> for Direction {
@@ -62,6 +64,7 @@ impl TryFrom<[isize; 2]> for Direction {
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct WorldPosition {
     pub x: u16,
     pub y: u16,
@@ -122,6 +125,7 @@ impl ToBytes for WorldPosition {
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct WorldPosition2 {
     pub x1: u16,
     pub y1: u16,